@@ -0,0 +1,529 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Emits piecewise Chebyshev tables as dependency-free Rust or C source.
+//!
+//! The generated evaluator locates the piece by one multiplication and evaluates the
+//! Clenshaw recurrence, matching `ellip::chebyshev`. It uses no math library, so it can
+//! be compiled for targets without an FPU or `libm`.
+
+use std::fmt::Write;
+
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    C,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    F32,
+    F64,
+}
+
+impl Precision {
+    fn rust_type(self) -> &'static str {
+        match self {
+            Precision::F32 => "f32",
+            Precision::F64 => "f64",
+        }
+    }
+
+    fn c_type(self) -> &'static str {
+        match self {
+            Precision::F32 => "float",
+            Precision::F64 => "double",
+        }
+    }
+
+    /// Machine epsilon of the output type.
+    pub fn epsilon(self) -> f64 {
+        match self {
+            Precision::F32 => f32::EPSILON as f64,
+            Precision::F64 => f64::EPSILON,
+        }
+    }
+
+    /// Formats the literal with the shortest representation that round-trips.
+    fn literal(self, value: f64, language: Language) -> String {
+        match (self, language) {
+            (Precision::F64, _) => format!("{:?}", value),
+            (Precision::F32, Language::Rust) => format!("{:?}", value as f32),
+            (Precision::F32, Language::C) => format!("{:?}f", value as f32),
+        }
+    }
+}
+
+/// Piecewise Chebyshev table in one or two dimensions.
+///
+/// The domain is split into `pieces` uniform pieces per dimension. The coefficients of
+/// each piece are flattened in row-major order, with the pieces ordered as in
+/// `ellip::chebyshev`, and the first coefficient along each dimension halved.
+#[derive(Debug, Clone)]
+pub struct Approximation {
+    /// Identifier of the generated module or function.
+    pub name: String,
+    /// One-line description of the approximated function.
+    pub description: String,
+    /// Interval (lo, hi) of each dimension.
+    pub domain: Vec<(f64, f64)>,
+    /// Number of pieces per dimension.
+    pub pieces: usize,
+    /// Number of coefficients per piece and dimension, i.e., the degree plus one.
+    pub n: usize,
+    /// Coefficients of each piece, nᵈ per piece in d dimensions, concatenated.
+    pub coeffs: Vec<f64>,
+    /// Maximum absolute error on the verification grid.
+    pub max_error: f64,
+}
+
+impl Approximation {
+    fn dims(&self) -> usize {
+        self.domain.len()
+    }
+
+    fn scale(&self, dim: usize) -> f64 {
+        let (lo, hi) = self.domain[dim];
+        self.pieces as f64 / (hi - lo)
+    }
+
+    fn piece_len(&self) -> usize {
+        self.n.pow(self.dims() as u32)
+    }
+
+    /// Evaluates the table in the precision `T`, as the generated code does.
+    /// Returns NAN outside the domain.
+    pub fn eval<T: Float>(&self, x: &[T]) -> T {
+        assert_eq!(x.len(), self.dims(), "Wrong number of arguments");
+        let mut index = 0;
+        let mut t = [T::zero(); 2];
+        for (dim, &xi) in x.iter().enumerate().rev() {
+            let (lo, hi) = self.domain[dim];
+            let (lo, hi) = (T::from(lo).unwrap(), T::from(hi).unwrap());
+            if !(xi >= lo && xi <= hi) {
+                return T::nan();
+            }
+            let u = (xi - lo) * T::from(self.scale(dim)).unwrap();
+            let i = u.to_usize().unwrap_or(0).min(self.pieces - 1);
+            t[dim] = T::from(2.0).unwrap() * (u - T::from(i).unwrap()) - T::one();
+            index = index * self.pieces + i;
+        }
+
+        let len = self.piece_len();
+        let c: Vec<T> = self.coeffs[index * len..(index + 1) * len]
+            .iter()
+            .map(|&c| T::from(c).unwrap())
+            .collect();
+        match self.dims() {
+            1 => clenshaw(&c, t[0]),
+            _ => {
+                let rows: Vec<T> = c.chunks(self.n).map(|ci| clenshaw(ci, t[1])).collect();
+                clenshaw(&rows, t[0])
+            }
+        }
+    }
+
+    /// Returns the maximum absolute difference between the table evaluated in the
+    /// precision `T` and `f` on a grid of `m + 1` points per piece and dimension.
+    /// Returns the first error of `f`.
+    pub fn verify<T: Float, E>(
+        &self,
+        m: usize,
+        f: impl Fn(&[f64]) -> Result<f64, E>,
+    ) -> Result<f64, E> {
+        let grid = |dim: usize, k: usize| {
+            let (lo, hi) = self.domain[dim];
+            lo + (hi - lo) * k as f64 / (m * self.pieces) as f64
+        };
+        let total = m * self.pieces + 1;
+        let mut max_error = 0.0f64;
+        for k in 0..total.pow(self.dims() as u32) {
+            let x: Vec<f64> = (0..self.dims())
+                .map(|dim| grid(dim, k / total.pow(dim as u32) % total))
+                .collect();
+            let xt: Vec<T> = x.iter().map(|&xi| T::from(xi).unwrap()).collect();
+            let approx = self.eval(&xt).to_f64().unwrap();
+            max_error = max_error.max((approx - f(&x)?).abs());
+        }
+        Ok(max_error)
+    }
+
+    /// Lowers the degree while the dropped trailing coefficients of every piece sum to at most
+    /// `epsilon` times its largest coefficient, so they do not change the result in a type
+    /// with that epsilon. The largest sum is added to `max_error`.
+    pub fn truncate(&mut self, epsilon: f64) {
+        let dims = self.dims();
+        let largest: Vec<f64> = self
+            .coeffs
+            .chunks(self.piece_len())
+            .map(|piece| piece.iter().fold(0.0f64, |acc, c| acc.max(c.abs())))
+            .collect();
+        let mut dropped = vec![0.0f64; largest.len()];
+        while self.n > 1 {
+            let (n, len) = (self.n, self.piece_len());
+            // Coefficients of degree n - 1 along any dimension
+            let is_last = |k: usize| (0..dims).any(|dim| k / n.pow(dim as u32) % n == n - 1);
+            let next: Vec<f64> = self
+                .coeffs
+                .chunks(len)
+                .zip(&dropped)
+                .map(|(piece, sum)| {
+                    sum + (0..len)
+                        .filter(|&k| is_last(k))
+                        .map(|k| piece[k].abs())
+                        .sum::<f64>()
+                })
+                .collect();
+            if next.iter().zip(&largest).any(|(sum, l)| *sum > epsilon * l) {
+                break;
+            }
+            self.coeffs = self
+                .coeffs
+                .chunks(len)
+                .flat_map(|piece| (0..len).filter(|&k| !is_last(k)).map(|k| piece[k]))
+                .collect();
+            self.n -= 1;
+            dropped = next;
+        }
+        self.max_error += dropped.iter().fold(0.0f64, |acc, &sum| acc.max(sum));
+    }
+
+    /// Generates the source code.
+    pub fn generate(&self, language: Language, precision: Precision) -> String {
+        assert!(
+            (1..=2).contains(&self.dims()),
+            "Only one or two dimensions are supported"
+        );
+        assert_eq!(
+            self.coeffs.len(),
+            self.piece_len() * self.pieces.pow(self.dims() as u32),
+            "Wrong number of coefficients"
+        );
+        match language {
+            Language::Rust => self.generate_rust(precision),
+            Language::C => self.generate_c(precision),
+        }
+    }
+
+    fn header(&self, comment: &str) -> String {
+        let mut s = String::new();
+        let _ = writeln!(s, "{comment} Generated by ellip. Do not edit.");
+        let _ = writeln!(s, "{comment} Approximates {}.", self.description);
+        let _ = writeln!(
+            s,
+            "{comment} Pieces per dimension: {}, degree: {}, maximum absolute error: {:.2e}.",
+            self.pieces,
+            self.n - 1,
+            self.max_error
+        );
+        s
+    }
+
+    /// Writes the coefficients of each piece as rows of `open`..`close` delimited lists.
+    fn write_coeffs(
+        &self,
+        s: &mut String,
+        language: Language,
+        precision: Precision,
+        open: &str,
+        close: &str,
+    ) {
+        let len = self.piece_len();
+        for piece in self.coeffs.chunks(len) {
+            let _ = writeln!(s, "    {open}");
+            for row in piece.chunks(self.n) {
+                let values: Vec<String> = row
+                    .iter()
+                    .map(|&c| precision.literal(c, language))
+                    .collect();
+                let _ = writeln!(s, "        {},", values.join(", "));
+            }
+            let _ = writeln!(s, "    {close},");
+        }
+    }
+
+    fn generate_rust(&self, precision: Precision) -> String {
+        let ty = precision.rust_type();
+        let lit = |v: f64| precision.literal(v, Language::Rust);
+        let (n, len) = (self.n, self.piece_len());
+        let count = self.pieces.pow(self.dims() as u32);
+
+        let mut s = String::new();
+        let _ = writeln!(s, "const PIECES: usize = {};", self.pieces);
+        for (dim, &(lo, hi)) in self.domain.iter().enumerate() {
+            let _ = writeln!(s, "const LO{dim}: {ty} = {};", lit(lo));
+            let _ = writeln!(s, "const HI{dim}: {ty} = {};", lit(hi));
+            let _ = writeln!(s, "const SCALE{dim}: {ty} = {};", lit(self.scale(dim)));
+        }
+        let _ = writeln!(s, "const COEFFS: [[{ty}; {len}]; {count}] = [");
+        self.write_coeffs(&mut s, Language::Rust, precision, "[", "]");
+        let _ = writeln!(s, "];");
+        let _ = writeln!(s);
+        let _ = writeln!(s, "fn clenshaw(c: &[{ty}], t: {ty}) -> {ty} {{");
+        let _ = writeln!(s, "    let (mut b1, mut b2) = (0.0, 0.0);");
+        let _ = writeln!(s, "    for &cj in c[1..].iter().rev() {{");
+        let _ = writeln!(s, "        let b0 = 2.0 * t * b1 - b2 + cj;");
+        let _ = writeln!(s, "        b2 = b1;");
+        let _ = writeln!(s, "        b1 = b0;");
+        let _ = writeln!(s, "    }}");
+        let _ = writeln!(s, "    c[0] + t * b1 - b2");
+        let _ = writeln!(s, "}}");
+        let _ = writeln!(s);
+        let _ = writeln!(
+            s,
+            "/// Maps x in piece i of the dimension to (i, t) with t in [-1, 1]."
+        );
+        let _ = writeln!(
+            s,
+            "fn locate(x: {ty}, lo: {ty}, scale: {ty}) -> (usize, {ty}) {{"
+        );
+        let _ = writeln!(s, "    let u = (x - lo) * scale;");
+        let _ = writeln!(s, "    let mut i = u as usize;");
+        let _ = writeln!(s, "    if i > PIECES - 1 {{");
+        let _ = writeln!(s, "        i = PIECES - 1;");
+        let _ = writeln!(s, "    }}");
+        let _ = writeln!(s, "    (i, 2.0 * (u - i as {ty}) - 1.0)");
+        let _ = writeln!(s, "}}");
+        let _ = writeln!(s);
+        let _ = writeln!(s, "/// Returns NAN outside the domain.");
+        if self.dims() == 1 {
+            let _ = writeln!(s, "pub fn eval(x: {ty}) -> {ty} {{");
+            let _ = writeln!(s, "    if !(LO0..=HI0).contains(&x) {{");
+            let _ = writeln!(s, "        return {ty}::NAN;");
+            let _ = writeln!(s, "    }}");
+            let _ = writeln!(s, "    let (i, t) = locate(x, LO0, SCALE0);");
+            let _ = writeln!(s, "    clenshaw(&COEFFS[i], t)");
+        } else {
+            let _ = writeln!(s, "pub fn eval(x: {ty}, y: {ty}) -> {ty} {{");
+            let _ = writeln!(
+                s,
+                "    if !((LO0..=HI0).contains(&x) && (LO1..=HI1).contains(&y)) {{"
+            );
+            let _ = writeln!(s, "        return {ty}::NAN;");
+            let _ = writeln!(s, "    }}");
+            let _ = writeln!(s, "    let (ix, tx) = locate(x, LO0, SCALE0);");
+            let _ = writeln!(s, "    let (iy, ty) = locate(y, LO1, SCALE1);");
+            let _ = writeln!(s, "    let c = &COEFFS[iy * PIECES + ix];");
+            let _ = writeln!(s, "    let mut rows = [0.0; {n}];");
+            let _ = writeln!(s, "    for (i, row) in rows.iter_mut().enumerate() {{");
+            let _ = writeln!(
+                s,
+                "        *row = clenshaw(&c[i * {n}..(i + 1) * {n}], ty);"
+            );
+            let _ = writeln!(s, "    }}");
+            let _ = writeln!(s, "    clenshaw(&rows, tx)");
+        }
+        let _ = writeln!(s, "}}");
+
+        let mut out = self.header("//");
+        let _ = writeln!(out, "#[rustfmt::skip]");
+        let _ = writeln!(out, "#[allow(clippy::excessive_precision)]");
+        let _ = writeln!(out, "pub mod {} {{", self.name);
+        for line in s.lines() {
+            match line {
+                "" => {
+                    let _ = writeln!(out);
+                }
+                _ => {
+                    let _ = writeln!(out, "    {line}");
+                }
+            }
+        }
+        let _ = writeln!(out, "}}");
+        out
+    }
+
+    fn generate_c(&self, precision: Precision) -> String {
+        let ty = precision.c_type();
+        let lit = |v: f64| precision.literal(v, Language::C);
+        let name = &self.name;
+        let upper = name.to_uppercase();
+        let (n, len) = (self.n, self.piece_len());
+        let count = self.pieces.pow(self.dims() as u32);
+
+        let mut s = self.header("//");
+        let _ = writeln!(s, "#define {upper}_PIECES {}u", self.pieces);
+        // NAN without <math.h>
+        let _ = writeln!(s, "#define {upper}_NAN (0.0 / 0.0)");
+        for (dim, &(lo, hi)) in self.domain.iter().enumerate() {
+            let _ = writeln!(s, "#define {upper}_LO{dim} {}", lit(lo));
+            let _ = writeln!(s, "#define {upper}_HI{dim} {}", lit(hi));
+            let _ = writeln!(s, "#define {upper}_SCALE{dim} {}", lit(self.scale(dim)));
+        }
+        let _ = writeln!(s);
+        let _ = writeln!(s, "static const {ty} {name}_coeffs[{count}][{len}] = {{");
+        self.write_coeffs(&mut s, Language::C, precision, "{", "}");
+        let _ = writeln!(s, "}};");
+        let _ = writeln!(s);
+        let _ = writeln!(
+            s,
+            "static {ty} {name}_clenshaw(const {ty} *c, unsigned int n, {ty} t) {{"
+        );
+        let _ = writeln!(s, "    {ty} b1 = 0, b2 = 0;");
+        let _ = writeln!(s, "    for (unsigned int j = n - 1; j > 0; j--) {{");
+        let _ = writeln!(s, "        {ty} b0 = 2 * t * b1 - b2 + c[j];");
+        let _ = writeln!(s, "        b2 = b1;");
+        let _ = writeln!(s, "        b1 = b0;");
+        let _ = writeln!(s, "    }}");
+        let _ = writeln!(s, "    return c[0] + t * b1 - b2;");
+        let _ = writeln!(s, "}}");
+        let _ = writeln!(s);
+        let _ = writeln!(
+            s,
+            "static unsigned int {name}_locate({ty} x, {ty} lo, {ty} scale, {ty} *t) {{"
+        );
+        let _ = writeln!(s, "    {ty} u = (x - lo) * scale;");
+        let _ = writeln!(s, "    unsigned int i = (unsigned int)u;");
+        let _ = writeln!(s, "    if (i > {upper}_PIECES - 1) {{");
+        let _ = writeln!(s, "        i = {upper}_PIECES - 1;");
+        let _ = writeln!(s, "    }}");
+        let _ = writeln!(s, "    *t = 2 * (u - ({ty})i) - 1;");
+        let _ = writeln!(s, "    return i;");
+        let _ = writeln!(s, "}}");
+        let _ = writeln!(s);
+        let _ = writeln!(s, "/* Returns NAN outside the domain. */");
+        if self.dims() == 1 {
+            let _ = writeln!(s, "{ty} {name}({ty} x) {{");
+            let _ = writeln!(s, "    if (!(x >= {upper}_LO0 && x <= {upper}_HI0)) {{");
+            let _ = writeln!(s, "        return {upper}_NAN;");
+            let _ = writeln!(s, "    }}");
+            let _ = writeln!(s, "    {ty} t;");
+            let _ = writeln!(
+                s,
+                "    unsigned int i = {name}_locate(x, {upper}_LO0, {upper}_SCALE0, &t);"
+            );
+            let _ = writeln!(s, "    return {name}_clenshaw({name}_coeffs[i], {n}u, t);");
+        } else {
+            let _ = writeln!(s, "{ty} {name}({ty} x, {ty} y) {{");
+            let _ = writeln!(
+                s,
+                "    if (!(x >= {upper}_LO0 && x <= {upper}_HI0 && y >= {upper}_LO1 && y <= {upper}_HI1)) {{"
+            );
+            let _ = writeln!(s, "        return {upper}_NAN;");
+            let _ = writeln!(s, "    }}");
+            let _ = writeln!(s, "    {ty} tx, ty, rows[{n}];");
+            let _ = writeln!(
+                s,
+                "    unsigned int ix = {name}_locate(x, {upper}_LO0, {upper}_SCALE0, &tx);"
+            );
+            let _ = writeln!(
+                s,
+                "    unsigned int iy = {name}_locate(y, {upper}_LO1, {upper}_SCALE1, &ty);"
+            );
+            let _ = writeln!(
+                s,
+                "    const {ty} *c = {name}_coeffs[iy * {upper}_PIECES + ix];"
+            );
+            let _ = writeln!(s, "    for (unsigned int i = 0; i < {n}u; i++) {{");
+            let _ = writeln!(
+                s,
+                "        rows[i] = {name}_clenshaw(c + i * {n}u, {n}u, ty);"
+            );
+            let _ = writeln!(s, "    }}");
+            let _ = writeln!(s, "    return {name}_clenshaw(rows, {n}u, tx);");
+        }
+        let _ = writeln!(s, "}}");
+        s
+    }
+}
+
+fn clenshaw<T: Float>(c: &[T], t: T) -> T {
+    let t2 = T::from(2.0).unwrap() * t;
+    let (mut b1, mut b2) = (T::zero(), T::zero());
+    for &cj in c[1..].iter().rev() {
+        let b0 = t2 * b1 - b2 + cj;
+        b2 = b1;
+        b1 = b0;
+    }
+    c[0] + t * b1 - b2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// x² = T₀/2 + T₂/2 on [-1, 1], split into two pieces.
+    fn square() -> Approximation {
+        // On [-1, 0], x = (t - 1)/2, so x² = (t² - 2t + 1)/4 = 3/8 T₀ - 1/2 T₁ + 1/8 T₂.
+        // On [0, 1], x = (t + 1)/2, so x² = 3/8 T₀ + 1/2 T₁ + 1/8 T₂.
+        Approximation {
+            name: "square".to_string(),
+            description: "x²".to_string(),
+            domain: vec![(-1.0, 1.0)],
+            pieces: 2,
+            n: 3,
+            coeffs: vec![0.375, -0.5, 0.125, 0.375, 0.5, 0.125],
+            max_error: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_eval() {
+        let approx = square();
+        for x in [-1.0, -0.3, 0.0, 0.7, 1.0] {
+            assert!((approx.eval(&[x]) - x * x).abs() < 1e-15);
+        }
+        assert!(approx.eval(&[1.5]).is_nan());
+        assert!(approx.verify::<f32, ()>(8, |x| Ok(x[0] * x[0])).unwrap() < 1e-6);
+
+        // xy on [0, 1]², which is (T₀ + T₁)/2 per dimension with one piece.
+        let product = Approximation {
+            domain: vec![(0.0, 1.0), (0.0, 1.0)],
+            pieces: 1,
+            n: 2,
+            coeffs: vec![0.25, 0.25, 0.25, 0.25],
+            ..square()
+        };
+        assert!((product.eval(&[0.3, 0.6]) - 0.18).abs() < 1e-15);
+        assert!(product.verify::<f64, ()>(4, |x| Ok(x[0] * x[1])).unwrap() < 1e-15);
+    }
+
+    #[test]
+    fn test_truncate() {
+        // Negligible T₃ in f64 but not T₂
+        let mut approx = Approximation {
+            n: 4,
+            coeffs: vec![0.375, -0.5, 0.125, 1e-20, 0.375, 0.5, 0.125, -1e-20],
+            ..square()
+        };
+        approx.truncate(Precision::F64.epsilon());
+        assert_eq!(approx.n, 3);
+        assert_eq!(approx.coeffs, square().coeffs);
+        assert_eq!(approx.max_error, 1e-20);
+
+        // Drops the last row and column in two dimensions
+        let mut product = Approximation {
+            domain: vec![(0.0, 1.0), (0.0, 1.0)],
+            pieces: 1,
+            n: 3,
+            coeffs: vec![0.25, 0.25, 1e-9, 0.25, 0.25, 0.0, 0.0, -1e-9, 0.0],
+            ..square()
+        };
+        product.truncate(Precision::F64.epsilon());
+        assert_eq!(product.n, 3);
+        product.truncate(Precision::F32.epsilon());
+        assert_eq!(product.n, 2);
+        assert_eq!(product.coeffs, vec![0.25, 0.25, 0.25, 0.25]);
+        assert_eq!(product.max_error, 2e-9);
+    }
+
+    #[test]
+    fn test_generate() {
+        let rust = square().generate(Language::Rust, Precision::F64);
+        assert!(rust.contains("pub mod square {"));
+        assert!(rust.contains("    const COEFFS: [[f64; 3]; 2] = ["));
+        assert!(rust.contains("            0.375, -0.5, 0.125,"));
+        assert!(rust.contains("    pub fn eval(x: f64) -> f64 {"));
+
+        let c = square().generate(Language::C, Precision::F32);
+        assert!(c.contains("static const float square_coeffs[2][3] = {"));
+        assert!(c.contains("        0.375f, -0.5f, 0.125f,"));
+        assert!(c.contains("float square(float x) {"));
+        assert!(c.contains("        return SQUARE_NAN;"));
+        assert!(!c.contains("#include"));
+    }
+}
@@ -6,6 +6,7 @@
 type StrErr = &'static str;
 
 pub mod benchmark;
+pub mod codegen;
 pub mod env;
 pub mod file;
 pub mod parser;
@@ -36,7 +36,8 @@ pub fn linspace(start: usize, end: usize, n: usize) -> Vec<usize> {
     (0..=(n - 1)).map(|i| start + i * delta / (n - 1)).collect()
 }
 
-fn get_cases(test_paths: &Vec<PathBuf>, n: usize) -> Vec<Case<f64>> {
+fn get_cases(test_paths: &[PathBuf], n: usize) -> Vec<Case<f64>> {
+    #[allow(clippy::manual_is_multiple_of)] // usize::is_multiple_of requires Rust 1.87
     if n % STEP != 0 {
         panic!(concat!("n must be multiple of ", stringify!(MULTIPLE), "."));
     }
@@ -90,15 +91,15 @@ enum ExitCond {
     MaxThres,
 }
 
-impl ExitCond {
-    fn to_string(&self) -> String {
-        match self {
-            Self::None => "-".to_string(),
-            Self::Converged => "Converged".to_string(),
-            Self::MinStep => "Min step size".to_string(),
-            Self::MaxIter => "Max iteration".to_string(),
-            Self::MaxThres => "Max threshold".to_string(),
-        }
+impl std::fmt::Display for ExitCond {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::None => "-",
+            Self::Converged => "Converged",
+            Self::MinStep => "Min step size",
+            Self::MaxIter => "Max iteration",
+            Self::MaxThres => "Max threshold",
+        })
     }
 }
 
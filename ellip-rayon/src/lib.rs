@@ -73,13 +73,13 @@ macro_rules! impl_par {
     };
     ($fn:ident, [$arg:ident], 1) => {
         #[doc=concat!["Computes [", stringify!($fn), "](ellip::", stringify!($fn), ") in parallel."]]
-        pub fn $fn($arg: &[f64]) -> Result<Vec<f64>, StrErr> {
+        pub fn $fn($arg: &[f64]) -> Result<Vec<f64>, EllipError> {
             $arg.iter().map(|&a| ellip::$fn(a)).collect()
         }
     };
     ($fn:ident, [$arg:ident], 1, $threshold:expr) => {
         #[doc=concat!["Computes [", stringify!($fn), "](ellip::", stringify!($fn), ") in parallel."]]
-        pub fn $fn($arg: &[f64]) -> Result<Vec<f64>, StrErr> {
+        pub fn $fn($arg: &[f64]) -> Result<Vec<f64>, EllipError> {
             if $arg.len() < $threshold {
                 $arg.iter().map(|&a| ellip::$fn(a)).collect()
             } else {
@@ -89,10 +89,16 @@ macro_rules! impl_par {
     };
     ($fn:ident, [$first:ident, $($args:ident),*], $n_arg:tt) => {
         #[doc=concat!["Computes [", stringify!($fn), "](ellip::", stringify!($fn), ") in parallel."]]
-        pub fn $fn($first: &[f64], $($args: &[f64],)*) -> Result<Vec<f64>, StrErr> {
+        pub fn $fn($first: &[f64], $($args: &[f64],)*) -> Result<Vec<f64>, EllipError> {
             $(
                 if $first.len() != $args.len() {
-                    return Err(concat![stringify!($fn), ": All arguments must have the same length."]);
+                    return Err(EllipError::DomainError(ellip::error::ErrorInfo {
+                        func: stringify!($fn),
+                        arg: None,
+                        value: None,
+                        limit: None,
+                        message: concat![stringify!($fn), ": All arguments must have the same length."],
+                    }));
                 }
             )*
             izip!($first, $($args),*).map(impl_par!(@inner, $fn, $n_arg)).collect()
@@ -100,10 +106,16 @@ macro_rules! impl_par {
     };
     ($fn:ident, [$first:ident, $($args:ident),*], $n_arg:tt, $threshold:expr) => {
         #[doc=concat!["Computes [", stringify!($fn), "](ellip::", stringify!($fn), ") in parallel."]]
-        pub fn $fn($first: &[f64], $($args: &[f64],)*) -> Result<Vec<f64>, StrErr> {
+        pub fn $fn($first: &[f64], $($args: &[f64],)*) -> Result<Vec<f64>, EllipError> {
             $(
                 if $first.len() != $args.len() {
-                    return Err(concat![stringify!($fn), ": All arguments must have the same length."]);
+                    return Err(EllipError::DomainError(ellip::error::ErrorInfo {
+                        func: stringify!($fn),
+                        arg: None,
+                        value: None,
+                        limit: None,
+                        message: concat![stringify!($fn), ": All arguments must have the same length."],
+                    }));
                 }
             )*
             if $first.len() < $threshold {
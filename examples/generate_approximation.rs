@@ -0,0 +1,290 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Generates a dependency-free lookup table and evaluator for an ellip function.
+//!
+//! ```sh
+//! cargo run --example generate_approximation -- <function> <args>... --x <lo> <hi> [options]
+//! ```
+//!
+//! Each argument is a number or the variable `x` (or `y` for a second variable with `--y`).
+//! For example, `ellippiinc x 0.3 0.5 --x 0 1.2 --tol 1e-12` approximates Π(φ, 0.3 | 0.5)
+//! for φ ∈ [0, 1.2].
+//!
+//! Options:
+//! - `--y <lo> <hi>`: Domain of the second variable.
+//! - `--tol <tol>`: Absolute tolerance. Default is 1e-12.
+//! - `--lang <rust|c>`: Output language. Default is rust.
+//! - `--type <f64|f32>`: Floating-point type of the output. Default is f64.
+//! - `--name <name>`: Identifier of the generated module or function. Default is `<function>_approx`.
+//!
+//! The degree and the number of pieces are chosen to minimize the table size, and the trailing
+//! coefficients below the epsilon of the output type are dropped. The source is printed to
+//! stdout and the summary to stderr. Exits with an error without printing the source if the
+//! tolerance is not met in the output type.
+
+use ellip::{chebyshev::*, *};
+use ellip_dev_utils::codegen::{Approximation, Language, Precision};
+
+macro_rules! functions {
+    ($($f:ident($($i:tt),*)),* $(,)?) => {
+        fn call(name: &str, a: &[f64]) -> Result<f64, EllipError> {
+            match name {
+                $(stringify!($f) => $f($(a[$i]),*),)*
+                _ => unreachable!(),
+            }
+        }
+
+        fn arity(name: &str) -> Option<usize> {
+            match name {
+                $(stringify!($f) => Some([$($i),*].len()),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+functions!(
+    ellipk(0),
+    ellipe(0),
+    ellipf(0, 1),
+    ellipeinc(0, 1),
+    ellippi(0, 1),
+    ellippiinc(0, 1, 2),
+    ellippiinc_bulirsch(0, 1, 2),
+    ellipd(0),
+    ellipdinc(0, 1),
+    cel(0, 1, 2, 3),
+    cel1(0),
+    cel2(0, 1, 2),
+    el1(0, 1),
+    el2(0, 1, 2, 3),
+    el3(0, 1, 2),
+    elliprf(0, 1, 2),
+    elliprg(0, 1, 2),
+    elliprj(0, 1, 2, 3),
+    elliprc(0, 1),
+    elliprd(0, 1, 2),
+    jacobi_zeta(0, 1),
+    heuman_lambda(0, 1),
+);
+
+/// Maximum number of pieces per dimension.
+const MAX_PIECES_1D: usize = 256;
+const MAX_PIECES_2D: usize = 16;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Arg {
+    Value(f64),
+    X,
+    Y,
+}
+
+struct Options {
+    function: String,
+    args: Vec<Arg>,
+    x: (f64, f64),
+    y: Option<(f64, f64)>,
+    tol: f64,
+    language: Language,
+    precision: Precision,
+    name: String,
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "Usage: cargo run --example generate_approximation -- <function> <args>... --x <lo> <hi> \
+         [--y <lo> <hi>] [--tol <tol>] [--lang rust|c] [--type f64|f32] [--name <name>]"
+    );
+    std::process::exit(1)
+}
+
+fn parse_f64(s: Option<String>) -> f64 {
+    s.and_then(|s| s.parse().ok()).unwrap_or_else(|| usage())
+}
+
+fn parse_args() -> Options {
+    let mut argv = std::env::args().skip(1);
+    let function = argv.next().unwrap_or_else(|| usage());
+    let Some(n_args) = arity(&function) else {
+        eprintln!("Unknown function: {function}");
+        usage()
+    };
+
+    let mut options = Options {
+        name: format!("{function}_approx"),
+        function,
+        args: vec![],
+        x: (f64::NAN, f64::NAN),
+        y: None,
+        tol: 1e-12,
+        language: Language::Rust,
+        precision: Precision::F64,
+    };
+    while let Some(arg) = argv.next() {
+        match arg.as_str() {
+            "--x" => options.x = (parse_f64(argv.next()), parse_f64(argv.next())),
+            "--y" => options.y = Some((parse_f64(argv.next()), parse_f64(argv.next()))),
+            "--tol" => options.tol = parse_f64(argv.next()),
+            "--lang" => {
+                options.language = match argv.next().as_deref() {
+                    Some("rust") => Language::Rust,
+                    Some("c") => Language::C,
+                    _ => usage(),
+                }
+            }
+            "--type" => {
+                options.precision = match argv.next().as_deref() {
+                    Some("f64") => Precision::F64,
+                    Some("f32") => Precision::F32,
+                    _ => usage(),
+                }
+            }
+            "--name" => options.name = argv.next().unwrap_or_else(|| usage()),
+            "x" => options.args.push(Arg::X),
+            "y" => options.args.push(Arg::Y),
+            _ => options.args.push(Arg::Value(parse_f64(Some(arg)))),
+        }
+    }
+
+    let count = |var: Arg| options.args.iter().filter(|&&a| a == var).count();
+    let (n_x, n_y) = (count(Arg::X), count(Arg::Y));
+    if options.args.len() != n_args {
+        eprintln!("{} takes {n_args} arguments.", options.function);
+        usage()
+    }
+    if n_x != 1 || n_y != options.y.is_some() as usize || options.x.0.is_nan() {
+        eprintln!("Mark the variable x once, and y once if --y is given.");
+        usage()
+    }
+    options
+}
+
+impl Options {
+    fn eval(&self, x: f64, y: f64) -> Result<f64, EllipError> {
+        let args: Vec<f64> = self
+            .args
+            .iter()
+            .map(|&a| match a {
+                Arg::Value(a) => a,
+                Arg::X => x,
+                Arg::Y => y,
+            })
+            .collect();
+        call(&self.function, &args)
+    }
+
+    fn description(&self) -> String {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|&a| match a {
+                Arg::Value(a) => a.to_string(),
+                Arg::X => "x".to_string(),
+                Arg::Y => "y".to_string(),
+            })
+            .collect();
+        let mut domain = format!("x ∈ [{}, {}]", self.x.0, self.x.1);
+        if let Some(y) = self.y {
+            domain += &format!(", y ∈ [{}, {}]", y.0, y.1);
+        }
+        format!("{}({}) for {domain}", self.function, args.join(", "))
+    }
+
+    /// Builds the approximant with N coefficients per piece and dimension.
+    fn build<const N: usize>(&self) -> Option<Approximation> {
+        let (domain, pieces, coeffs, max_error) = match self.y {
+            None => {
+                let approx = Chebyshev::<f64, N, MAX_PIECES_1D>::builder(self.x.0, self.x.1)
+                    .tol(self.tol)
+                    .build(|x| self.eval(x, f64::NAN))
+                    .ok()?;
+                let coeffs = approx.coefficients().iter().flatten().copied().collect();
+                (vec![self.x], approx.pieces(), coeffs, approx.max_error())
+            }
+            Some(y) => {
+                // The table of N² × 256 coefficients is built on the heap.
+                let mut approx =
+                    Box::<Chebyshev2d<f64, N, { MAX_PIECES_2D * MAX_PIECES_2D }>>::default();
+                Chebyshev2d::builder(self.x, y)
+                    .tol(self.tol)
+                    .build_into(|x, y| self.eval(x, y), &mut approx)
+                    .ok()?;
+                let coeffs = approx.coefficients().iter().flatten().flatten().copied();
+                (
+                    vec![self.x, y],
+                    approx.pieces(),
+                    coeffs.collect(),
+                    approx.max_error(),
+                )
+            }
+        };
+        Some(Approximation {
+            name: self.name.clone(),
+            description: self.description(),
+            domain,
+            pieces,
+            n: N,
+            coeffs,
+            max_error,
+        })
+    }
+}
+
+fn main() {
+    let options = parse_args();
+
+    // Smallest table among the candidate degrees
+    let candidates = [
+        options.build::<4>(),
+        options.build::<6>(),
+        options.build::<8>(),
+        options.build::<10>(),
+        options.build::<12>(),
+        options.build::<16>(),
+        options.build::<20>(),
+        options.build::<24>(),
+    ];
+    let Some(mut approx) = candidates
+        .into_iter()
+        .flatten()
+        .min_by_key(|approx| approx.coeffs.len())
+    else {
+        eprintln!(
+            "The tolerance {:e} is not met. Narrow the domain or loosen the tolerance.",
+            options.tol
+        );
+        std::process::exit(1)
+    };
+
+    approx.truncate(options.precision.epsilon());
+
+    let f = |x: &[f64]| options.eval(x[0], x.get(1).copied().unwrap_or(f64::NAN));
+    let rounded_error = match options.precision {
+        Precision::F64 => approx.verify::<f64, _>(8, f),
+        Precision::F32 => approx.verify::<f32, _>(8, f),
+    };
+    let rounded_error = rounded_error.unwrap_or_else(|err| {
+        eprintln!("Failed to verify {}: {err}", approx.description);
+        std::process::exit(1)
+    });
+    eprintln!(
+        "{}: {} coefficients, {} pieces per dimension of degree {}, maximum error {:.2e} ({:.2e} in the output type).",
+        approx.description,
+        approx.coeffs.len(),
+        approx.pieces,
+        approx.n - 1,
+        approx.max_error,
+        rounded_error
+    );
+    if rounded_error > options.tol || rounded_error.is_nan() {
+        eprintln!(
+            "The tolerance {:e} is not met in the output type. Loosen the tolerance or use --type f64.",
+            options.tol
+        );
+        std::process::exit(1)
+    }
+    print!("{}", approx.generate(options.language, options.precision));
+}
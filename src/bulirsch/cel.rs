@@ -8,7 +8,8 @@ use num_traits::Float;
 use crate::{
     bulirsch::constants::BulirschConst,
     crate_util::{case, check, declare},
-    StrErr,
+    error::err,
+    EllipError,
 };
 
 /// Computes [complete elliptic integral in Bulirsch form](https://dlmf.nist.gov/19.2#iii).
@@ -65,15 +66,20 @@ use crate::{
 /// # References
 /// - Bulirsch, R. “Numerical Calculation of Elliptic Integrals and Elliptic Functions. III.” Numerische Mathematik 13, no. 4 (August 1, 1969): 305–15. <https://doi.org/10.1007/BF02165405>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
-pub fn cel<T: Float + BulirschConst<T>>(kc: T, p: T, a: T, b: T) -> Result<T, StrErr> {
+pub fn cel<T: Float + BulirschConst<T>>(kc: T, p: T, a: T, b: T) -> Result<T, EllipError> {
     cel_with_const::<T, T>(kc, p, a, b)
 }
 
 /// Computes [cel]. Control the precision using [BulirschConst].
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
 #[inline]
-pub fn cel_with_const<T: Float, C: BulirschConst<T>>(kc: T, p: T, a: T, b: T) -> Result<T, StrErr> {
-    check!(@zero, cel, [kc, p]);
+pub fn cel_with_const<T: Float, C: BulirschConst<T>>(
+    kc: T,
+    p: T,
+    a: T,
+    b: T,
+) -> Result<T, EllipError> {
+    check!(@zero, cel, [kc, p], limit: pole_limit(p, b));
 
     let mut kc = kc.abs();
     declare!(mut [pp = p, aa = a, bb = b, f, q, g]);
@@ -127,7 +133,7 @@ pub fn cel_with_const<T: Float, C: BulirschConst<T>>(kc: T, p: T, a: T, b: T) ->
     if b.is_infinite() {
         return Ok(b.signum() * inf!());
     }
-    Err("cel: Failed to converge.")
+    err!(NoConvergence, cel, "Failed to converge.")
 }
 
 /// Computes [complete elliptic integral of the first kind in Bulirsch's form](https://link.springer.com/article/10.1007/bf01397975).
@@ -172,14 +178,14 @@ pub fn cel_with_const<T: Float, C: BulirschConst<T>>(kc: T, p: T, a: T, b: T) ->
 /// # References
 /// - Bulirsch, Roland. “Numerical Calculation of Elliptic Integrals and Elliptic Functions.” Numerische Mathematik 7, no. 1 (February 1, 1965): 78–90. <https://doi.org/10.1007/BF01397975>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
-pub fn cel1<T: Float + BulirschConst<T>>(kc: T) -> Result<T, StrErr> {
+pub fn cel1<T: Float + BulirschConst<T>>(kc: T) -> Result<T, EllipError> {
     cel1_with_const::<T, T>(kc)
 }
 
 /// Computes [cel1]. Control the precision using [BulirschConst].
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
 #[inline]
-pub fn cel1_with_const<T: Float, C: BulirschConst<T>>(kc: T) -> Result<T, StrErr> {
+pub fn cel1_with_const<T: Float, C: BulirschConst<T>>(kc: T) -> Result<T, EllipError> {
     declare!(mut [kc = kc.abs(), m = T::one(), ans = T::nan(), h]);
     for _ in 0..MAX_ITERATION {
         h = m;
@@ -199,8 +205,8 @@ pub fn cel1_with_const<T: Float, C: BulirschConst<T>>(kc: T) -> Result<T, StrErr
         return Ok(ans);
     }
     check!(@nan, cel1, [kc]);
-    check!(@zero, cel1, [kc]);
-    Err("cel1: Failed to converge.")
+    check!(@zero, cel1, [kc], limit: inf!());
+    err!(NoConvergence, cel1, "Failed to converge.")
 }
 
 /// Computes [complete elliptic integral of the second kind in Bulirsch's form](https://link.springer.com/article/10.1007/bf01397975).
@@ -254,14 +260,14 @@ pub fn cel1_with_const<T: Float, C: BulirschConst<T>>(kc: T) -> Result<T, StrErr
 /// # References
 /// - Bulirsch, Roland. “Numerical Calculation of Elliptic Integrals and Elliptic Functions.” Numerische Mathematik 7, no. 1 (February 1, 1965): 78–90. <https://doi.org/10.1007/BF01397975>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
-pub fn cel2<T: Float + BulirschConst<T>>(kc: T, a: T, b: T) -> Result<T, StrErr> {
+pub fn cel2<T: Float + BulirschConst<T>>(kc: T, a: T, b: T) -> Result<T, EllipError> {
     cel2_with_const::<T, T>(kc, a, b)
 }
 
 /// Computes [cel2]. Control the precision using [BulirschConst].
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
 #[inline]
-pub fn cel2_with_const<T: Float, C: BulirschConst<T>>(kc: T, a: T, b: T) -> Result<T, StrErr> {
+pub fn cel2_with_const<T: Float, C: BulirschConst<T>>(kc: T, a: T, b: T) -> Result<T, EllipError> {
     declare!(mut [kc = kc.abs(), aa = a, bb = b, m = T::one(), c = aa, ans = T::nan(), m0]);
     aa = bb + aa;
 
@@ -285,7 +291,7 @@ pub fn cel2_with_const<T: Float, C: BulirschConst<T>>(kc: T, a: T, b: T) -> Resu
         return Ok(ans);
     }
     check!(@nan, cel2, [kc, a, b]);
-    check!(@zero, cel2, [kc]);
+    check!(@zero, cel2, [kc], limit: pole_limit(T::one(), b));
     check!(@multi, cel2, "infinite", is_infinite, [kc, a, b]);
     if kc.is_infinite() {
         return Ok(0.0);
@@ -296,14 +302,28 @@ pub fn cel2_with_const<T: Float, C: BulirschConst<T>>(kc: T, a: T, b: T) -> Resu
     if b.is_infinite() {
         return Ok(b.signum() * inf!());
     }
-    Err("cel2: Failed to converge.")
+    err!(NoConvergence, cel2, "Failed to converge.")
 }
 
 #[cfg(not(feature = "test_force_fail"))]
-const MAX_ITERATION: i16 = 10;
+const MAX_ITERATION: i16 = 16;
 #[cfg(feature = "test_force_fail")]
 const MAX_ITERATION: i16 = 1;
 
+/// Returns the limit of cel at kc = 0 or p = 0, where the integrand grows like b / (p |cos θ|)
+/// or b / cos²θ near θ = π/2. Returns NAN if b = 0, where the integral is finite.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub(crate) fn pole_limit<T: Float>(p: T, b: T) -> T {
+    if b == 0.0 {
+        return nan!();
+    }
+    if p < 0.0 {
+        -b.signum() * inf!()
+    } else {
+        b.signum() * inf!()
+    }
+}
+
 #[cfg(not(feature = "test_force_fail"))]
 #[cfg(test)]
 mod tests {
@@ -350,13 +370,35 @@ mod tests {
         assert_close! {cel(1e-1, -4.1, 1.2, 1.1).unwrap(), -6.7687378198360556e-1, 5e-16};
     }
 
+    #[test]
+    fn test_cel_small_kc() {
+        // K ≈ ln(4 / kc), the AGM needs more steps as kc decreases.
+        for (kc, expected) in [
+            (1e-80, 185.59310180064355),
+            (1e-300, 692.1618222593336),
+            (5e-324, 745.8263662825012),
+        ] {
+            assert_close!(expected, cel(kc, 1.0, 1.0, 1.0).unwrap(), 1e-15);
+            assert_close!(expected, cel1(kc).unwrap(), 1e-15);
+            assert_close!(expected, cel2(kc, 1.0, 1.0).unwrap(), 1e-15);
+        }
+        // E = 1 for kc = 0
+        assert_close!(1.0, cel2(1e-300, 1.0, 0.0).unwrap(), 1e-15);
+    }
+
     #[test]
     fn test_cel_special_cases() {
         use std::f64::{INFINITY, NAN, NEG_INFINITY};
         // kc = 0: should return Err
-        assert_eq!(cel(0.0, 1.0, 1.0, 1.0), Err("cel: kc cannot be zero."));
+        assert_eq!(
+            cel(0.0, 1.0, 1.0, 1.0).unwrap_err(),
+            "cel: kc cannot be zero."
+        );
         // p = 0: should return Err
-        assert_eq!(cel(0.5, 0.0, 1.0, 1.0), Err("cel: p cannot be zero."));
+        assert_eq!(
+            cel(0.5, 0.0, 1.0, 1.0).unwrap_err(),
+            "cel: p cannot be zero."
+        );
         // a = 0, b = 0: cel(kc, p, 0, 0) = 0
         assert_eq!(cel(0.5, 1.0, 0.0, 0.0).unwrap(), 0.0);
         // kc = inf: cel(inf, p, a, b) = 0
@@ -377,20 +419,20 @@ mod tests {
         assert_eq!(cel(0.5, 1.0, 1.0, NEG_INFINITY).unwrap(), NEG_INFINITY);
         // NANs: should return Err
         assert_eq!(
-            cel(NAN, 1.0, 1.0, 1.0),
-            Err("cel: Arguments cannot be NAN.")
+            cel(NAN, 1.0, 1.0, 1.0).unwrap_err(),
+            "cel: Arguments cannot be NAN."
         );
         assert_eq!(
-            cel(0.5, NAN, 1.0, 1.0),
-            Err("cel: Arguments cannot be NAN.")
+            cel(0.5, NAN, 1.0, 1.0).unwrap_err(),
+            "cel: Arguments cannot be NAN."
         );
         assert_eq!(
-            cel(0.5, 1.0, NAN, 1.0),
-            Err("cel: Arguments cannot be NAN.")
+            cel(0.5, 1.0, NAN, 1.0).unwrap_err(),
+            "cel: Arguments cannot be NAN."
         );
         assert_eq!(
-            cel(0.5, 1.0, 1.0, NAN),
-            Err("cel: Arguments cannot be NAN.")
+            cel(0.5, 1.0, 1.0, NAN).unwrap_err(),
+            "cel: Arguments cannot be NAN."
         );
     }
 
@@ -411,13 +453,13 @@ mod tests {
     fn test_cel1_special_cases() {
         use std::f64::{INFINITY, NAN, NEG_INFINITY};
         // kc = 0: should return Err
-        assert_eq!(cel1(0.0), Err("cel1: kc cannot be zero."));
+        assert_eq!(cel1(0.0).unwrap_err(), "cel1: kc cannot be zero.");
         // kc = inf: cel1(inf) = 0
         assert_eq!(cel1(INFINITY).unwrap(), 0.0);
         // kc = -inf: cel1(-inf) = 0
         assert_eq!(cel1(NEG_INFINITY).unwrap(), 0.0);
         // kc = NaN: should return Err
-        assert_eq!(cel1(NAN), Err("cel1: Arguments cannot be NAN."));
+        assert_eq!(cel1(NAN).unwrap_err(), "cel1: Arguments cannot be NAN.");
     }
 
     #[test]
@@ -437,7 +479,7 @@ mod tests {
     fn test_cel2_special_cases() {
         use std::f64::{INFINITY, NAN, NEG_INFINITY};
         // kc = 0: should return Err
-        assert_eq!(cel2(0.0, 1.0, 1.0), Err("cel2: kc cannot be zero."));
+        assert_eq!(cel2(0.0, 1.0, 1.0).unwrap_err(), "cel2: kc cannot be zero.");
         // kc = inf: cel2(inf, 1, 1) = 0
         assert_eq!(cel2(INFINITY, 1.0, 1.0).unwrap(), 0.0);
         // kc = -inf: cel2(-inf, 1, 1) = 0
@@ -453,15 +495,45 @@ mod tests {
         // b = inf: cel2(kc, a, -inf) = -inf
         assert_eq!(cel2(0.5, 1.0, NEG_INFINITY).unwrap(), NEG_INFINITY);
         // NANs: should return Err
-        assert_eq!(cel2(NAN, 1.0, 1.0), Err("cel2: Arguments cannot be NAN."));
-        assert_eq!(cel2(0.5, NAN, 1.0), Err("cel2: Arguments cannot be NAN."));
-        assert_eq!(cel2(0.5, 1.0, NAN), Err("cel2: Arguments cannot be NAN."));
+        assert_eq!(
+            cel2(NAN, 1.0, 1.0).unwrap_err(),
+            "cel2: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            cel2(0.5, NAN, 1.0).unwrap_err(),
+            "cel2: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            cel2(0.5, 1.0, NAN).unwrap_err(),
+            "cel2: Arguments cannot be NAN."
+        );
+    }
+
+    #[test]
+    fn test_cel_double_double() {
+        use crate::test_util::{assert_close_dd, dd};
+        let kc = dd("0.5");
+        assert_close_dd(
+            cel(kc, dd("3"), dd("1"), dd("2")).unwrap(),
+            "1.647904722303459329098833360696693",
+            1e-31,
+        );
+        assert_close_dd(
+            cel1(kc).unwrap(),
+            "2.156515647499643235438674998800322",
+            1e-31,
+        );
+        assert_close_dd(
+            cel2(kc, dd("1"), dd("0.25")).unwrap(),
+            "1.211056027568459524803562899548979",
+            1e-31,
+        );
     }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(cel(1e300, 0.2, 0.5, 0.5), Err("cel: Failed to converge."));
-    assert_eq!(cel1(1e300), Err("cel1: Failed to converge."));
-    assert_eq!(cel2(1e300, 0.5, 0.5), Err("cel2: Failed to converge."));
+    assert_eq!(cel(1e300, 0.2, 0.5, 0.5).unwrap_err(), "cel: Failed to converge.");
+    assert_eq!(cel1(1e300).unwrap_err(), "cel1: Failed to converge.");
+    assert_eq!(cel2(1e300, 0.5, 0.5).unwrap_err(), "cel2: Failed to converge.");
 }
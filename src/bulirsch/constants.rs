@@ -8,7 +8,7 @@ use num_traits::Float;
 pub trait BulirschConst<T: Float> {
     /// Number of significant figures.
     ///
-    /// **D** is `7` for `f32` and `16` for `f64`.
+    /// **D** is `7` for `f32`, `16` for `f64`, and `32` for [DoubleDouble](crate::DoubleDouble).
     #[allow(dead_code)]
     const D: i32;
     /// D-2
@@ -50,6 +50,7 @@ macro_rules! impl_bulirsch_const {
 
 impl_bulirsch_const!(@type f32, {D: 7, CA: 1e-3, CB: 1e-9, LIM: 1e-7});
 impl_bulirsch_const!(@type f64, {D: 16, CA: 1e-8, CB: 1e-18, LIM: 1e-12});
+impl_bulirsch_const!(@type crate::DoubleDouble, {D: 32, CA: 1e-16, CB: 1e-34, LIM: 1e-20});
 
 /// [f32] precision for Bulirsch's integrals.
 /// <div class="warning">⚠️ Unstable feature. May subject to changes.</div>
@@ -96,5 +97,7 @@ mod tests {
         assert_eq!(<f64 as BulirschConst<f64>>::ca(), 1e-8);
         assert_eq!(<f64 as BulirschConst<f64>>::cb(), 1e-18);
         assert_eq!(<f64 as BulirschConst<f64>>::lim_kc_p(), 1e-12);
+        assert_eq!(<crate::DoubleDouble as BulirschConst<f64>>::D, 32);
+        assert_eq!(<crate::DoubleDouble as BulirschConst<f64>>::ca(), 1e-16);
     }
 }
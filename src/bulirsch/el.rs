@@ -8,7 +8,9 @@ use num_traits::Float;
 use crate::{
     bulirsch::{cel::cel2_with_const, constants::BulirschConst},
     crate_util::{case, check, declare, let_mut},
-    ellipeinc, ellipf, ellippi, ellippiinc, StrErr,
+    ellipeinc, ellipf, ellippi, ellippiinc,
+    error::err,
+    EllipError,
 };
 
 /// Computes [incomplete elliptic integral of the first kind in Bulirsch's form](https://dlmf.nist.gov/19.2.E11_5).
@@ -59,22 +61,22 @@ use crate::{
 /// # References
 /// - Bulirsch, Roland. “Numerical Calculation of Elliptic Integrals and Elliptic Functions.” Numerische Mathematik 7, no. 1 (February 1, 1965): 78–90. <https://doi.org/10.1007/BF01397975>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
-pub fn el1<T: Float + BulirschConst<T>>(x: T, kc: T) -> Result<T, StrErr> {
+pub fn el1<T: Float + BulirschConst<T>>(x: T, kc: T) -> Result<T, EllipError> {
     el1_with_const::<T, T>(x, kc)
 }
 
 /// Computes [el1]. Control the precision using [BulirschConst].
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
 #[inline]
-pub fn el1_with_const<T: Float, C: BulirschConst<T>>(x: T, kc: T) -> Result<T, StrErr> {
+pub fn el1_with_const<T: Float, C: BulirschConst<T>>(x: T, kc: T) -> Result<T, EllipError> {
     let ans = el1_unchecked::<T, C>(x, kc);
     if ans.is_finite() {
         return Ok(ans);
     }
     check!(@nan, el1, [x, kc]);
-    check!(@zero, el1, [kc]);
+    check!(@zero DomainError, el1, [kc]);
     case!(kc == inf!(), T::zero());
-    Err("el1: Failed to converge.")
+    err!(NoConvergence, el1, "Failed to converge.")
 }
 
 /// Unsafe version of [el1].
@@ -168,27 +170,32 @@ pub fn el1_unchecked<T: Float, C: BulirschConst<T>>(x: T, kc: T) -> T {
 /// # References
 /// - Bulirsch, Roland. “Numerical Calculation of Elliptic Integrals and Elliptic Functions.” Numerische Mathematik 7, no. 1 (February 1, 1965): 78–90. <https://doi.org/10.1007/BF01397975>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
-pub fn el2<T: Float + BulirschConst<T>>(x: T, kc: T, a: T, b: T) -> Result<T, StrErr> {
+pub fn el2<T: Float + BulirschConst<T>>(x: T, kc: T, a: T, b: T) -> Result<T, EllipError> {
     el2_with_const::<T, T>(x, kc, a, b)
 }
 
 /// Computes [el2]. Control the precision using [BulirschConst].
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
 #[inline]
-pub fn el2_with_const<T: Float, C: BulirschConst<T>>(x: T, kc: T, a: T, b: T) -> Result<T, StrErr> {
+pub fn el2_with_const<T: Float, C: BulirschConst<T>>(
+    x: T,
+    kc: T,
+    a: T,
+    b: T,
+) -> Result<T, EllipError> {
     let ans = el2_unchecked::<T, C>(x, kc, a, b);
     if ans.is_finite() {
         return Ok(ans);
     }
     check!(@nan, el2, [x, kc, a, b]);
-    check!(@zero, el2, [kc]);
+    check!(@zero DomainError, el2, [kc]);
     case!(x == T::zero(), T::zero());
     if x == inf!() {
         // phi = π/2
         return cel2_with_const::<T, C>(kc, a, b);
     }
 
-    Err("el2: Failed to converge.")
+    err!(NoConvergence, el2, "Failed to converge.")
 }
 
 /// Unsafe version of [el2].
@@ -301,19 +308,19 @@ pub fn el2_unchecked<T: Float, C: BulirschConst<T>>(x: T, kc: T, a: T, b: T) ->
 /// # References
 /// - Bulirsch, R. “Numerical Calculation of Elliptic Integrals and Elliptic Functions. III.” Numerische Mathematik 13, no. 4 (August 1, 1969): 305–15. <https://doi.org/10.1007/BF02165405>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
-pub fn el3<T: Float + BulirschConst<T>>(x: T, kc: T, p: T) -> Result<T, StrErr> {
+pub fn el3<T: Float + BulirschConst<T>>(x: T, kc: T, p: T) -> Result<T, EllipError> {
     el3_with_const::<T, T>(x, kc, p)
 }
 
 /// Computes [el3]. Control the precision using [BulirschConst].
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
 #[inline]
-pub fn el3_with_const<T: Float, C: BulirschConst<T>>(x: T, kc: T, p: T) -> Result<T, StrErr> {
+pub fn el3_with_const<T: Float, C: BulirschConst<T>>(x: T, kc: T, p: T) -> Result<T, EllipError> {
     let m = 1.0 - kc * kc;
     let n = 1.0 - p;
 
     if kc.abs() < epsilon!() {
-        return Err("el3: kc must not be zero.");
+        return err!(DomainError, el3, "kc must not be zero.", kc);
     }
 
     if x == 0.0 {
@@ -330,11 +337,13 @@ pub fn el3_with_const<T: Float, C: BulirschConst<T>>(x: T, kc: T, p: T) -> Resul
         if n < 1.0 {
             let vcr = p.sqrt();
             return Ok((vcr * x).atan() / vcr);
+        } else if n == 1.0 {
+            return Ok(x);
         } else {
-            // v > 1:
+            // v > 1, taking the principal value for |vcr * x| > 1:
             let vcr = (-p).sqrt();
             let arg = vcr * x;
-            return Ok((arg.ln_1p() - (-arg).ln_1p()) / (2.0 * vcr));
+            return Ok(((1.0 + arg).abs().ln() - (1.0 - arg).abs().ln()) / (2.0 * vcr));
         }
     }
 
@@ -422,7 +431,7 @@ pub fn el3_with_const<T: Float, C: BulirschConst<T>>(x: T, kc: T, p: T) -> Resul
 
     w = 1.0 + f;
     if w == 0.0 {
-        return Err("el3: 1 + px² cannot be zero.");
+        return err!(Singularity, el3, "1 + px² cannot be zero.", [x, p], limit: x.signum() * inf!());
     }
 
     let p1 = p;
@@ -618,7 +627,7 @@ pub fn el3_with_const<T: Float, C: BulirschConst<T>>(x: T, kc: T, p: T) -> Resul
     if ans.is_ok() {
         return ans;
     }
-    Err("el3: Failed to converge.")
+    err!(NoConvergence, el3, "Failed to converge.")
 }
 
 const MAX_ND: usize = 50;
@@ -673,16 +682,16 @@ mod tests {
         // x = 0: el1(0, kc) = 0
         assert_eq!(el1(0.0, 0.5).unwrap(), 0.0);
         // kc = 0: should return Err
-        assert_eq!(el1(0.5, 0.0), Err("el1: kc cannot be zero."));
+        assert_eq!(el1(0.5, 0.0).unwrap_err(), "el1: kc cannot be zero.");
         // x = inf: el1(inf, kc) = cel1(kc)
         assert_eq!(el1(INFINITY, 0.5).unwrap(), cel1(0.5).unwrap());
         // kc = inf: el1(x, inf) = 0
         assert_eq!(el1(0.5, INFINITY).unwrap(), 0.0);
         // y = 0 branch in the loop
-        assert_close!(el1(1.0, 1.0).unwrap(), 0.7853981633974483, 1e-15);
+        assert_close!(el1(1.0, 1.0).unwrap(), core::f64::consts::FRAC_PI_4, 1e-15);
         // x = nan or kc = nan: should return Err
-        assert_eq!(el1(NAN, 0.5), Err("el1: Arguments cannot be NAN."));
-        assert_eq!(el1(0.5, NAN), Err("el1: Arguments cannot be NAN."));
+        assert_eq!(el1(NAN, 0.5).unwrap_err(), "el1: Arguments cannot be NAN.");
+        assert_eq!(el1(0.5, NAN).unwrap_err(), "el1: Arguments cannot be NAN.");
     }
 
     #[test]
@@ -697,7 +706,10 @@ mod tests {
         // x = 0: el2(0, kc, a, b) = 0
         assert_eq!(el2(0.0, 0.5, 1.0, 1.0).unwrap(), 0.0);
         // kc = 0: should return Err
-        assert_eq!(el2(0.5, 0.0, 1.0, 1.0), Err("el2: kc cannot be zero."));
+        assert_eq!(
+            el2(0.5, 0.0, 1.0, 1.0).unwrap_err(),
+            "el2: kc cannot be zero."
+        );
         // a = 0, b = 0: el2(x, kc, 0, 0) = 0
         assert_eq!(el2(0.5, 0.5, 0.0, 0.0).unwrap(), 0.0);
         // x = inf: el2(inf, kc, a, b) = cel2(kc, a, b)
@@ -707,12 +719,12 @@ mod tests {
         );
         // x = nan or kc = nan: should return Err
         assert_eq!(
-            el2(NAN, 0.5, 1.0, 1.0),
-            Err("el2: Arguments cannot be NAN.")
+            el2(NAN, 0.5, 1.0, 1.0).unwrap_err(),
+            "el2: Arguments cannot be NAN."
         );
         assert_eq!(
-            el2(0.5, NAN, 1.0, 1.0),
-            Err("el2: Arguments cannot be NAN.")
+            el2(0.5, NAN, 1.0, 1.0).unwrap_err(),
+            "el2: Arguments cannot be NAN."
         );
     }
 
@@ -762,7 +774,7 @@ mod tests {
         // x = 0: el3(0, kc, p) = 0
         assert_eq!(el3(0.0, 0.5, 0.5).unwrap(), 0.0);
         // kc = 0: should return Err
-        assert_eq!(el3(0.5, 0.0, 0.5), Err("el3: kc must not be zero."));
+        assert_eq!(el3(0.5, 0.0, 0.5).unwrap_err(), "el3: kc must not be zero.");
 
         let complete_el3 = el3(INFINITY, 0.5, 0.5).unwrap();
         // x = inf: el3(inf, kc, p) = cel(kc, p, 1, 1)
@@ -775,21 +787,35 @@ mod tests {
             el3(4.0, 1.0, 0.5).unwrap(),
             (0.5.sqrt() * 4.0).atan() / 0.5.sqrt()
         );
-        // kc = 1, p <= 0: el3(x, 1, p) = (ln(1+vx) - ln(1-vx)) / (2v); v = sqrt(-p)
-        assert_close!(el3(4.0, 1.0, -0.5).unwrap(), 5.0, 1e-15);
+        // kc = 1, p = 0: el3(x, 1, 0) = x
+        assert_eq!(el3(4.0, 1.0, 0.0).unwrap(), 4.0);
+        // kc = 1, p < 0: el3(x, 1, p) = (ln|1+vx| - ln|1-vx|) / (2v); v = sqrt(-p)
+        assert_close!(0.5225504573804798, el3(4.0, 1.0, -0.5).unwrap(), 1e-15);
         // 1 + px² = 0: should return Err
-        assert_eq!(el3(1.0, 0.5, -1.0), Err("el3: 1 + px² cannot be zero."));
+        assert_eq!(
+            el3(1.0, 0.5, -1.0).unwrap_err(),
+            "el3: 1 + px² cannot be zero."
+        );
         // x = nan, kc = nan, or p = nan: should return Err
-        assert_eq!(el3(NAN, 0.5, 0.5), Err("el3: Arguments cannot be NAN."));
-        assert_eq!(el3(0.5, NAN, 0.5), Err("el3: Arguments cannot be NAN."));
-        assert_eq!(el3(0.5, 0.5, NAN), Err("el3: Arguments cannot be NAN."));
+        assert_eq!(
+            el3(NAN, 0.5, 0.5).unwrap_err(),
+            "el3: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            el3(0.5, NAN, 0.5).unwrap_err(),
+            "el3: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            el3(0.5, 0.5, NAN).unwrap_err(),
+            "el3: Arguments cannot be NAN."
+        );
     }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
     use crate::bulirsch::constants::DefaultPrecision;
-    assert_eq!(el1_with_const::<f64, DefaultPrecision>(0.5, 0.5), Err("el1: Failed to converge."));
-    assert_eq!(el2_with_const::<f64, DefaultPrecision>(0.5, 0.5, 0.5, 0.5), Err("el2: Failed to converge."));
-    assert_eq!(el3_with_const::<f64, DefaultPrecision>(0.5, 0.5, 0.5), Err("el3: Failed to converge."));
+    assert_eq!(el1_with_const::<f64, DefaultPrecision>(0.5, 0.5).unwrap_err(), "el1: Failed to converge.");
+    assert_eq!(el2_with_const::<f64, DefaultPrecision>(0.5, 0.5, 0.5, 0.5).unwrap_err(), "el2: Failed to converge.");
+    assert_eq!(el3_with_const::<f64, DefaultPrecision>(0.5, 0.5, 0.5).unwrap_err(), "el3: Failed to converge.");
 }
@@ -9,6 +9,7 @@ mod cel;
 mod constants;
 pub(crate) mod el;
 
+pub(crate) use cel::pole_limit as cel_pole_limit;
 pub use cel::{cel, cel1, cel2};
 pub use cel::{cel1_with_const, cel2_with_const, cel_with_const};
 pub use el::{el1, el2, el3};
@@ -0,0 +1,85 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+use num_traits::Float;
+
+/// Trait for controling the convergence tolerance of the Carlson's integrals
+///
+/// The tolerance replaces the machine epsilon in the stopping criteria of the duplication
+/// and AGM iterations. The relative error of the result is roughly proportional to the tolerance,
+/// so a looser tolerance requires fewer duplication steps.
+///
+/// # Examples
+/// ```
+/// use ellip::{
+///     carlson::{elliprf_with_const, CarlsonConst},
+///     elliprf,
+///     util::assert_close,
+/// };
+///
+/// struct MonteCarlo;
+/// impl CarlsonConst<f64> for MonteCarlo {
+///     fn tol() -> f64 {
+///         1e-8
+///     }
+/// }
+///
+/// let rf = elliprf_with_const::<f64, MonteCarlo>(1.0, 0.5, 0.25).unwrap();
+/// assert_close(rf, elliprf(1.0, 0.5, 0.25).unwrap(), 1e-8);
+/// ```
+pub trait CarlsonConst<T: Float> {
+    /// Relative tolerance of the iterations.
+    ///
+    /// Values smaller than the machine epsilon of `T` are treated as the machine epsilon.
+    fn tol() -> T;
+}
+
+/// Default tolerance for Carlson's integrals.
+///
+/// Uses the machine epsilon of `T`, giving full precision.
+pub struct DefaultTolerance;
+impl<T: Float> CarlsonConst<T> for DefaultTolerance {
+    fn tol() -> T {
+        T::epsilon()
+    }
+}
+
+/// Reduced tolerance for Carlson's integrals.
+///
+/// Uses the tolerance of `1e-8`, giving results with about 8 significant digits.
+pub struct ReducedTolerance;
+impl<T: Float> CarlsonConst<T> for ReducedTolerance {
+    fn tol() -> T {
+        T::from(1e-8).unwrap()
+    }
+}
+
+/// Returns the tolerance of `C`, bounded below by the machine epsilon.
+#[inline]
+pub(crate) fn tol<T: Float, C: CarlsonConst<T>>() -> T {
+    C::tol().max(T::epsilon())
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TooTight;
+    impl CarlsonConst<f64> for TooTight {
+        fn tol() -> f64 {
+            0.0
+        }
+    }
+
+    #[test]
+    fn test_carlson_const() {
+        assert_eq!(<DefaultTolerance as CarlsonConst<f64>>::tol(), f64::EPSILON);
+        assert_eq!(<DefaultTolerance as CarlsonConst<f32>>::tol(), f32::EPSILON);
+        assert_eq!(<ReducedTolerance as CarlsonConst<f64>>::tol(), 1e-8);
+        assert_eq!(tol::<f64, ReducedTolerance>(), 1e-8);
+        assert_eq!(tol::<f32, ReducedTolerance>(), f32::EPSILON);
+        assert_eq!(tol::<f64, TooTight>(), f64::EPSILON);
+    }
+}
@@ -13,8 +13,10 @@
 use num_traits::Float;
 
 use crate::{
+    carlson::{CarlsonConst, DefaultTolerance},
     crate_util::{case, check, let_mut},
-    StrErr,
+    error::err,
+    EllipError,
 };
 
 /// Computes RC ([degenerate symmetric elliptic integral of RF](https://dlmf.nist.gov/19.16.E6)).
@@ -64,14 +66,23 @@ use crate::{
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed April 17, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
 /// - The SciPy Community. “SciPy: Special Functions - Elliprc.” Accessed April 17, 2025. <https://docs.scipy.org/doc/scipy/reference/generated/scipy.special.elliprc.html>.
+pub fn elliprc<T: Float>(x: T, y: T) -> Result<T, EllipError> {
+    elliprc_with_const::<T, DefaultTolerance>(x, y)
+}
+
+/// Computes [elliprc]. Control the tolerance using [CarlsonConst].
+///
+/// RC is evaluated in closed form, so the tolerance has no effect. This function is provided
+/// for generic code over the Carlson's integrals.
+#[allow(clippy::extra_unused_type_parameters)]
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn elliprc<T: Float>(x: T, y: T) -> Result<T, StrErr> {
+pub fn elliprc_with_const<T: Float, C: CarlsonConst<T>>(x: T, y: T) -> Result<T, EllipError> {
     if x < 0.0 {
-        return Err("elliprc: x must be non-negative.");
+        return err!(DomainError, elliprc, "x must be non-negative.", x);
     }
 
     if y == 0.0 {
-        return Err("elliprc: y must be non-zero.");
+        return err!(Singularity, elliprc, "y must be non-zero.", y, limit: inf!());
     }
 
     let ans = elliprc_unchecked(x, y);
@@ -80,7 +91,7 @@ pub fn elliprc<T: Float>(x: T, y: T) -> Result<T, StrErr> {
     }
     check!(@nan, elliprc, [x, y]);
     case!(@any [x, y] == inf!(), T::zero());
-    Err("elliprc: Unexpected error.")
+    err!(Unexpected, elliprc, "Unexpected error.")
 }
 
 /// Unsafe version of [elliprc].
@@ -145,9 +156,15 @@ mod tests {
     fn test_elliprc_special_cases() {
         use std::f64::{consts::PI, INFINITY, NAN};
         // x < 0: should return Err
-        assert_eq!(elliprc(-1.0, 1.0), Err("elliprc: x must be non-negative."));
+        assert_eq!(
+            elliprc(-1.0, 1.0).unwrap_err(),
+            "elliprc: x must be non-negative."
+        );
         // y == 0: should return Err
-        assert_eq!(elliprc(1.0, 0.0), Err("elliprc: y must be non-zero."));
+        assert_eq!(
+            elliprc(1.0, 0.0).unwrap_err(),
+            "elliprc: y must be non-zero."
+        );
         // RC(x, x) = 1/sqrt(x)
         assert_eq!(elliprc(1.0, 1.0).unwrap(), 1.0);
         assert_eq!(elliprc(4.0, 4.0).unwrap(), 0.5);
@@ -167,15 +184,57 @@ mod tests {
             ((3.0 + 8.0.sqrt()).ln() / 8.0.sqrt())
         );
         // NANs: should return Err
-        assert_eq!(elliprc(NAN, 1.0), Err("elliprc: Arguments cannot be NAN."));
-        assert_eq!(elliprc(1.0, NAN), Err("elliprc: Arguments cannot be NAN."));
+        assert_eq!(
+            elliprc(NAN, 1.0).unwrap_err(),
+            "elliprc: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            elliprc(1.0, NAN).unwrap_err(),
+            "elliprc: Arguments cannot be NAN."
+        );
         // Infs: should return 0
         assert_eq!(elliprc(INFINITY, 1.0).unwrap(), 0.0);
         assert_eq!(elliprc(1.0, INFINITY).unwrap(), 0.0);
     }
+
+    #[test]
+    fn test_elliprc_double_double() {
+        use crate::test_util::{assert_close_dd, dd};
+        assert_close_dd(
+            elliprc(dd("1"), dd("2")).unwrap(),
+            "0.7853981633974483096156608458198757",
+            1e-31,
+        );
+        assert_close_dd(
+            elliprc(dd("2"), dd("1")).unwrap(),
+            "0.8813735870195430252326093249797923",
+            1e-31,
+        );
+        assert_close_dd(
+            elliprc(dd("2"), dd("-1")).unwrap(),
+            "0.6617680207599845789670526126743406",
+            1e-31,
+        );
+    }
+
+    #[test]
+    fn test_elliprc_with_const() {
+        use crate::carlson::ReducedTolerance;
+
+        for (x, y) in [(0.0, 1.0), (1.0, 0.5), (1.0, 4.0), (4.0, 1.0), (1.0, -1.0)] {
+            assert_eq!(
+                elliprc_with_const::<f64, ReducedTolerance>(x, y).unwrap(),
+                elliprc(x, y).unwrap()
+            );
+        }
+        assert_eq!(
+            elliprc_with_const::<f64, ReducedTolerance>(-1.0, 1.0).unwrap_err(),
+            "elliprc: x must be non-negative."
+        );
+    }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(elliprc(2.0, 1.0), Err("elliprc: Unexpected error."));
+    assert_eq!(elliprc(2.0, 1.0).unwrap_err(), "elliprc: Unexpected error.");
 }
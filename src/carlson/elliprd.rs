@@ -15,8 +15,10 @@ use core::mem::swap;
 use num_traits::Float;
 
 use crate::{
+    carlson::constants::{tol, CarlsonConst, DefaultTolerance},
     crate_util::{case, check, let_mut},
-    StrErr,
+    error::err,
+    EllipError,
 };
 
 /// Computes RD ([degenerate symmetric elliptic integral of the third kind](https://dlmf.nist.gov/19.16.E5)).
@@ -66,25 +68,38 @@ use crate::{
 /// # References
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed April 17, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
+pub fn elliprd<T: Float>(x: T, y: T, z: T) -> Result<T, EllipError> {
+    elliprd_with_const::<T, DefaultTolerance>(x, y, z)
+}
+
+/// Computes [elliprd]. Control the tolerance using [CarlsonConst].
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn elliprd<T: Float>(x: T, y: T, z: T) -> Result<T, StrErr> {
+pub fn elliprd_with_const<T: Float, C: CarlsonConst<T>>(x: T, y: T, z: T) -> Result<T, EllipError> {
     if x.min(y) < 0.0 {
-        return Err("elliprd: x and y must be non-negative.");
+        return err!(
+            DomainError,
+            elliprd,
+            "x and y must be non-negative.",
+            [x, y]
+        );
     }
     if x == 0.0 && y == 0.0 {
-        return Err("elliprd: Both x and y cannot be zero.");
+        return err!(Singularity, elliprd, "Both x and y cannot be zero.", [x, y], limit: inf!());
+    }
+    if z < 0.0 {
+        return err!(DomainError, elliprd, "z must be positive", z);
     }
-    if z <= 0.0 {
-        return Err("elliprd: z must be positive");
+    if z == 0.0 {
+        return err!(Singularity, elliprd, "z must be positive", z, limit: inf!());
     }
 
-    let ans = elliprd_unchecked(x, y, z);
+    let ans = elliprd_unchecked_with_const::<T, C>(x, y, z);
     if ans.is_finite() {
         return Ok(ans);
     }
     check!(@nan, elliprd, [x, y, z]);
     case!(@any [x, y, z] == inf!(), T::zero());
-    Err("elliprd: Failed to converge.")
+    err!(NoConvergence, elliprd, "Failed to converge.")
 }
 
 /// Unsafe version of [elliprd](crate::elliprd).
@@ -95,9 +110,18 @@ pub fn elliprd<T: Float>(x: T, y: T, z: T) -> Result<T, StrErr> {
 /// - x < 0, y < 0, z ≤ 0
 /// - x = 0 and y = 0
 /// - x = ∞ or y = ∞
-#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
 #[inline]
 pub fn elliprd_unchecked<T: Float>(x: T, y: T, z: T) -> T {
+    elliprd_unchecked_with_const::<T, DefaultTolerance>(x, y, z)
+}
+
+/// Unsafe version of [elliprd_with_const](crate::carlson::elliprd_with_const).
+/// <div class="warning">⚠️ Unstable feature. May subject to changes.</div>
+///
+/// Undefined behavior with invalid arguments and edge cases.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+#[inline]
+pub fn elliprd_unchecked_with_const<T: Float, C: CarlsonConst<T>>(x: T, y: T, z: T) -> T {
     let_mut!(x, y);
 
     // Special cases
@@ -122,7 +146,7 @@ pub fn elliprd_unchecked<T: Float>(x: T, y: T, z: T) -> T {
         let mut sum = 0.0;
         let mut sum_pow = 0.25;
 
-        while (xn - yn).abs() >= 2.7 * epsilon!() * xn.abs() {
+        while (xn - yn).abs() >= 2.7 * tol::<T, C>() * xn.abs() {
             let t = (xn * yn).sqrt();
             xn = (xn + yn) / 2.0;
             yn = t;
@@ -141,7 +165,7 @@ pub fn elliprd_unchecked<T: Float>(x: T, y: T, z: T) -> T {
 
     let mut an = (x + y + 3.0 * z) / 5.0;
     let a0 = an;
-    let mut q = (epsilon!() / 4.0).powf(-1.0 / 8.0) * (an - x).max(an - y).max(an - z) * 1.2;
+    let mut q = (tol::<T, C>() / 4.0).powf(-1.0 / 8.0) * (an - x).max(an - y).max(an - z) * 1.2;
 
     let mut fn_val = 1.0;
     let mut rd_sum = 0.0;
@@ -240,42 +264,78 @@ mod tests {
         use std::f64::{INFINITY, NAN};
         // x < 0 or y < 0: should return Err
         assert_eq!(
-            elliprd(-1.0, 1.0, 1.0),
-            Err("elliprd: x and y must be non-negative.")
+            elliprd(-1.0, 1.0, 1.0).unwrap_err(),
+            "elliprd: x and y must be non-negative."
         );
         assert_eq!(
-            elliprd(1.0, -1.0, 1.0),
-            Err("elliprd: x and y must be non-negative.")
+            elliprd(1.0, -1.0, 1.0).unwrap_err(),
+            "elliprd: x and y must be non-negative."
         );
         // z <= 0: should return Err
-        assert_eq!(elliprd(1.0, 1.0, 0.0), Err("elliprd: z must be positive"));
-        assert_eq!(elliprd(1.0, 1.0, -1.0), Err("elliprd: z must be positive"));
+        assert_eq!(
+            elliprd(1.0, 1.0, 0.0).unwrap_err(),
+            "elliprd: z must be positive"
+        );
+        assert_eq!(
+            elliprd(1.0, 1.0, -1.0).unwrap_err(),
+            "elliprd: z must be positive"
+        );
         // both x and y zero: should return Err
         assert_eq!(
-            elliprd(0.0, 0.0, 1.0),
-            Err("elliprd: Both x and y cannot be zero.")
+            elliprd(0.0, 0.0, 1.0).unwrap_err(),
+            "elliprd: Both x and y cannot be zero."
         );
         // NANs: should return Err
         assert_eq!(
-            elliprd(NAN, 1.0, 1.0),
-            Err("elliprd: Arguments cannot be NAN.")
+            elliprd(NAN, 1.0, 1.0).unwrap_err(),
+            "elliprd: Arguments cannot be NAN."
         );
         assert_eq!(
-            elliprd(1.0, NAN, 1.0),
-            Err("elliprd: Arguments cannot be NAN.")
+            elliprd(1.0, NAN, 1.0).unwrap_err(),
+            "elliprd: Arguments cannot be NAN."
         );
         assert_eq!(
-            elliprd(1.0, 1.0, NAN),
-            Err("elliprd: Arguments cannot be NAN.")
+            elliprd(1.0, 1.0, NAN).unwrap_err(),
+            "elliprd: Arguments cannot be NAN."
         );
         // Infs: should return zero
         assert_eq!(elliprd(INFINITY, 1.0, 1.0).unwrap(), 0.0);
         assert_eq!(elliprd(1.0, INFINITY, 1.0).unwrap(), 0.0);
         assert_eq!(elliprd(1.0, 1.0, INFINITY).unwrap(), 0.0);
     }
+
+    #[test]
+    fn test_elliprd_double_double() {
+        use crate::test_util::{assert_close_dd, dd};
+        assert_close_dd(
+            elliprd(dd("1"), dd("2"), dd("3")).unwrap(),
+            "0.2904602810289906442326533856585405",
+            1e-31,
+        );
+        assert_close_dd(
+            elliprd(dd("0"), dd("0.5"), dd("1")).unwrap(),
+            "3.020584777522178495482053671139205",
+            1e-31,
+        );
+    }
+
+    #[test]
+    fn test_elliprd_with_const() {
+        use crate::{assert_close, carlson::ReducedTolerance, test_util::linspace};
+        use itertools::Itertools;
+
+        for (x, y, z) in linspace(0.0, 10.0, 6).into_iter().tuple_combinations() {
+            if y == 0.0 {
+                continue;
+            }
+            let expected = elliprd(x, y, z).unwrap();
+            let actual = elliprd_with_const::<f64, ReducedTolerance>(x, y, z).unwrap();
+            assert_close!(expected, actual, 1e-8);
+        }
+    }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(elliprd(0.2, 0.5, 1e300), Err("elliprd: Failed to converge."));
+    assert_eq!(elliprd(0.2, 0.5, 1e300).unwrap_err(), "elliprd: Failed to converge.");
 }
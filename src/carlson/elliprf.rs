@@ -15,9 +15,13 @@ use core::mem::swap;
 use num_traits::Float;
 
 use crate::{
-    carlson::elliprc_unchecked,
+    carlson::{
+        constants::{tol, CarlsonConst, DefaultTolerance},
+        elliprc_unchecked,
+    },
     crate_util::{case, check, declare},
-    StrErr,
+    error::err,
+    EllipError,
 };
 
 /// Computes RF ([symmetric elliptic integral of the first kind](https://dlmf.nist.gov/19.16.E1)).
@@ -67,16 +71,21 @@ use crate::{
 /// # References
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed April 17, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
-pub fn elliprf<T: Float>(x: T, y: T, z: T) -> Result<T, StrErr> {
-    let ans = elliprf_unchecked(x, y, z);
+pub fn elliprf<T: Float>(x: T, y: T, z: T) -> Result<T, EllipError> {
+    elliprf_with_const::<T, DefaultTolerance>(x, y, z)
+}
+
+/// Computes [elliprf]. Control the tolerance using [CarlsonConst].
+pub fn elliprf_with_const<T: Float, C: CarlsonConst<T>>(x: T, y: T, z: T) -> Result<T, EllipError> {
+    let ans = elliprf_unchecked_with_const::<T, C>(x, y, z);
     if ans.is_finite() {
         return Ok(ans);
     }
     check!(@nan, elliprf, [x, y, z]);
     check!(@neg, elliprf, [x, y, z]);
-    check!(@multi_zero, elliprf, [x, y, z]);
+    check!(@multi_zero, elliprf, [x, y, z], limit: inf!());
     case!(@any [x, y, z] == inf!(), T::zero());
-    Err("elliprf: Failed to converge.")
+    err!(NoConvergence, elliprf, "Failed to converge.")
 }
 
 /// Unsafe version of [elliprf](crate::elliprf).
@@ -87,9 +96,18 @@ pub fn elliprf<T: Float>(x: T, y: T, z: T) -> Result<T, StrErr> {
 /// - x < 0, y < 0, z < 0
 /// - More than one of x, y, and z are zero.
 /// - x = ∞ or y = ∞ or z = ∞
-#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
 #[inline]
 pub fn elliprf_unchecked<T: Float>(x: T, y: T, z: T) -> T {
+    elliprf_unchecked_with_const::<T, DefaultTolerance>(x, y, z)
+}
+
+/// Unsafe version of [elliprf_with_const](crate::carlson::elliprf_with_const).
+/// <div class="warning">⚠️ Unstable feature. May subject to changes.</div>
+///
+/// Undefined behavior with invalid arguments and edge cases.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+#[inline]
+pub fn elliprf_unchecked_with_const<T: Float, C: CarlsonConst<T>>(x: T, y: T, z: T) -> T {
     // Special cases from http://dlmf.nist.gov/19.20#i
     if x == y {
         if x == z {
@@ -140,7 +158,7 @@ pub fn elliprf_unchecked<T: Float>(x: T, y: T, z: T) -> T {
     if zn == 0.0 {
         declare!(mut [xn = xn.sqrt(), yn = yn.sqrt(), t]);
         for _ in 0..N_MAX_ITERATIONS {
-            if (xn - yn).abs() >= 2.7 * epsilon!() * xn.abs() {
+            if (xn - yn).abs() >= 2.7 * tol::<T, C>() * xn.abs() {
                 t = (xn * yn).sqrt();
                 xn = (xn + yn) / 2.0;
                 yn = t;
@@ -153,7 +171,7 @@ pub fn elliprf_unchecked<T: Float>(x: T, y: T, z: T) -> T {
 
     let mut an = (xn + yn + zn) / 3.0;
     let a0 = an;
-    let mut q = (3.0 * epsilon!()).powf(-1.0 / 8.0)
+    let mut q = (3.0 * tol::<T, C>()).powf(-1.0 / 8.0)
         * an.abs()
             .max((an - xn).abs())
             .max((an - yn).abs())
@@ -183,7 +201,7 @@ pub fn elliprf_unchecked<T: Float>(x: T, y: T, z: T) -> T {
 
             ans = (1.0
                 + e3 * (1.0 / 14.0 + 3.0 * e3 / 104.0)
-                + e2 * (-0.1 + e2 / 24.0 - (3.0 * e3) / 44.0 - 5.0 * e2 * e2 / 208.0
+                + e2 * (-1.0 / 10.0 + e2 / 24.0 - (3.0 * e3) / 44.0 - 5.0 * e2 * e2 / 208.0
                     + e2 * e3 / 16.0))
                 / an.sqrt();
             break;
@@ -251,51 +269,80 @@ mod tests {
         use std::f64::{INFINITY, NAN};
         // Negative arguments: should return Err
         assert_eq!(
-            elliprf(-1.0, 1.0, 2.0),
-            Err("elliprf: Arguments must be non-negative.")
+            elliprf(-1.0, 1.0, 2.0).unwrap_err(),
+            "elliprf: Arguments must be non-negative."
         );
         assert_eq!(
-            elliprf(1.0, -1.0, 1.0),
-            Err("elliprf: Arguments must be non-negative.")
+            elliprf(1.0, -1.0, 1.0).unwrap_err(),
+            "elliprf: Arguments must be non-negative."
         );
         assert_eq!(
-            elliprf(1.0, 1.0, -1.0),
-            Err("elliprf: Arguments must be non-negative.")
+            elliprf(1.0, 1.0, -1.0).unwrap_err(),
+            "elliprf: Arguments must be non-negative."
         );
         // More than one zero: should return Err
         assert_eq!(
-            elliprf(0.0, 0.0, 1.0),
-            Err("elliprf: At most one argument can be zero.")
+            elliprf(0.0, 0.0, 1.0).unwrap_err(),
+            "elliprf: At most one argument can be zero."
         );
         assert_eq!(
-            elliprf(0.0, 1.0, 0.0),
-            Err("elliprf: At most one argument can be zero.")
+            elliprf(0.0, 1.0, 0.0).unwrap_err(),
+            "elliprf: At most one argument can be zero."
         );
         assert_eq!(
-            elliprf(1.0, 0.0, 0.0),
-            Err("elliprf: At most one argument can be zero.")
+            elliprf(1.0, 0.0, 0.0).unwrap_err(),
+            "elliprf: At most one argument can be zero."
         );
         // NANs: should return Err
         assert_eq!(
-            elliprf(NAN, 1.0, 1.0),
-            Err("elliprf: Arguments cannot be NAN.")
+            elliprf(NAN, 1.0, 1.0).unwrap_err(),
+            "elliprf: Arguments cannot be NAN."
         );
         assert_eq!(
-            elliprf(1.0, NAN, 1.0),
-            Err("elliprf: Arguments cannot be NAN.")
+            elliprf(1.0, NAN, 1.0).unwrap_err(),
+            "elliprf: Arguments cannot be NAN."
         );
         assert_eq!(
-            elliprf(1.0, 1.0, NAN),
-            Err("elliprf: Arguments cannot be NAN.")
+            elliprf(1.0, 1.0, NAN).unwrap_err(),
+            "elliprf: Arguments cannot be NAN."
         );
         // Infs: should return zero
         assert_eq!(elliprf(INFINITY, 1.0, 1.0).unwrap(), 0.0);
         assert_eq!(elliprf(1.0, INFINITY, 1.0).unwrap(), 0.0);
         assert_eq!(elliprf(1.0, 1.0, INFINITY).unwrap(), 0.0);
     }
+
+    #[test]
+    fn test_elliprf_double_double() {
+        use crate::test_util::{assert_close_dd, dd};
+        assert_close_dd(
+            elliprf(dd("1"), dd("2"), dd("3")).unwrap(),
+            "0.7269459354689081985395706260198918",
+            1e-31,
+        );
+        assert_close_dd(
+            elliprf(dd("0"), dd("0.5"), dd("1")).unwrap(),
+            "1.854074677301371918433850347195260",
+            1e-31,
+        );
+    }
+
+    #[test]
+    fn test_elliprf_with_const() {
+        use crate::{carlson::ReducedTolerance, test_util::linspace};
+
+        for (x, y, z) in linspace(0.0, 10.0, 6).into_iter().tuple_combinations() {
+            if x == 0.0 && y == 0.0 {
+                continue;
+            }
+            let expected = elliprf(x, y, z).unwrap();
+            let actual = elliprf_with_const::<f64, ReducedTolerance>(x, y, z).unwrap();
+            assert_close!(expected, actual, 1e-8);
+        }
+    }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(elliprf(0.2, 0.5, 1e300), Err("elliprf: Failed to converge."));
+    assert_eq!(elliprf(0.2, 0.5, 1e300).unwrap_err(), "elliprf: Failed to converge.");
 }
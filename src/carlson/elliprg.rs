@@ -8,9 +8,13 @@ use core::mem::swap;
 use num_traits::Float;
 
 use crate::{
-    carlson::{elliprc_unchecked, elliprd_unchecked, elliprf_unchecked},
+    carlson::{
+        constants::{tol, CarlsonConst, DefaultTolerance},
+        elliprc_unchecked, elliprd_unchecked_with_const, elliprf_unchecked_with_const,
+    },
     crate_util::{check, let_mut},
-    StrErr,
+    error::err,
+    EllipError,
 };
 
 // Original header from Boost Math
@@ -65,15 +69,25 @@ use crate::{
 /// # References
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed April 17, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
-pub fn elliprg<T: Float>(x: T, y: T, z: T) -> Result<T, StrErr> {
+pub fn elliprg<T: Float>(x: T, y: T, z: T) -> Result<T, EllipError> {
+    elliprg_with_const::<T, DefaultTolerance>(x, y, z)
+}
+
+/// Computes [elliprg]. Control the tolerance using [CarlsonConst].
+pub fn elliprg_with_const<T: Float, C: CarlsonConst<T>>(x: T, y: T, z: T) -> Result<T, EllipError> {
     check!(@neg, elliprg, [x, y, z]);
 
-    let ans = elliprg_unchecked(x, y, z);
+    let ans = elliprg_unchecked_with_const::<T, C>(x, y, z);
     if ans.is_finite() {
         return Ok(ans);
     }
     check!(@nan, elliprg, [x, y, z]);
-    Err("elliprg: Arguments must be finite.")
+    err!(
+        InfiniteArgument,
+        elliprg,
+        "Arguments must be finite.",
+        [x, y, z]
+    )
 }
 
 /// Unsafe version of [elliprg](crate::elliprg).
@@ -83,9 +97,18 @@ pub fn elliprg<T: Float>(x: T, y: T, z: T) -> Result<T, StrErr> {
 /// # Known Invalid Cases
 /// - x < 0, y < 0, z < 0
 /// - x, y, or z are infinite.
-#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
 #[inline]
 pub fn elliprg_unchecked<T: Float>(x: T, y: T, z: T) -> T {
+    elliprg_unchecked_with_const::<T, DefaultTolerance>(x, y, z)
+}
+
+/// Unsafe version of [elliprg_with_const](crate::carlson::elliprg_with_const).
+/// <div class="warning">⚠️ Unstable feature. May subject to changes.</div>
+///
+/// Undefined behavior with invalid arguments and edge cases.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+#[inline]
+pub fn elliprg_unchecked_with_const<T: Float, C: CarlsonConst<T>>(x: T, y: T, z: T) -> T {
     let_mut!(x, y, z);
     if x < y {
         swap(&mut x, &mut y);
@@ -125,7 +148,7 @@ pub fn elliprg_unchecked<T: Float>(x: T, y: T, z: T) -> T {
         let mut sum = 0.0;
         let mut sum_pow = 0.25;
 
-        while (xn - yn).abs() >= 2.7 * epsilon!() * xn.abs() {
+        while (xn - yn).abs() >= 2.7 * tol::<T, C>() * xn.abs() {
             let t = (xn * yn).sqrt();
             xn = (xn + yn) / 2.0;
             yn = t;
@@ -136,7 +159,8 @@ pub fn elliprg_unchecked<T: Float>(x: T, y: T, z: T) -> T {
         return ((x0 + y0) * (x0 + y0) / 4.0 - sum) * rf / 2.0;
     }
 
-    (z * elliprf_unchecked(x, y, z) - (x - z) * (y - z) * elliprd_unchecked(x, y, z) / 3.0
+    (z * elliprf_unchecked_with_const::<T, C>(x, y, z)
+        - (x - z) * (y - z) * elliprd_unchecked_with_const::<T, C>(x, y, z) / 3.0
         + (x * y / z).sqrt())
         / 2.0
 }
@@ -191,42 +215,68 @@ mod tests {
         use std::f64::{INFINITY, NAN};
         // Negative arguments: should return Err
         assert_eq!(
-            elliprg(-1.0, 1.0, 1.0),
-            Err("elliprg: Arguments must be non-negative.")
+            elliprg(-1.0, 1.0, 1.0).unwrap_err(),
+            "elliprg: Arguments must be non-negative."
         );
         assert_eq!(
-            elliprg(1.0, -1.0, 1.0),
-            Err("elliprg: Arguments must be non-negative.")
+            elliprg(1.0, -1.0, 1.0).unwrap_err(),
+            "elliprg: Arguments must be non-negative."
         );
         assert_eq!(
-            elliprg(1.0, 1.0, -1.0),
-            Err("elliprg: Arguments must be non-negative.")
+            elliprg(1.0, 1.0, -1.0).unwrap_err(),
+            "elliprg: Arguments must be non-negative."
         );
         // NANs: should return Err
         assert_eq!(
-            elliprg(NAN, 1.0, 1.0),
-            Err("elliprg: Arguments cannot be NAN.")
+            elliprg(NAN, 1.0, 1.0).unwrap_err(),
+            "elliprg: Arguments cannot be NAN."
         );
         assert_eq!(
-            elliprg(1.0, NAN, 1.0),
-            Err("elliprg: Arguments cannot be NAN.")
+            elliprg(1.0, NAN, 1.0).unwrap_err(),
+            "elliprg: Arguments cannot be NAN."
         );
         assert_eq!(
-            elliprg(1.0, 1.0, NAN),
-            Err("elliprg: Arguments cannot be NAN.")
+            elliprg(1.0, 1.0, NAN).unwrap_err(),
+            "elliprg: Arguments cannot be NAN."
         );
         // Infinity arguments should return Err
         assert_eq!(
-            elliprg(INFINITY, 1.0, 1.0),
-            Err("elliprg: Arguments must be finite.")
+            elliprg(INFINITY, 1.0, 1.0).unwrap_err(),
+            "elliprg: Arguments must be finite."
         );
         assert_eq!(
-            elliprg(1.0, INFINITY, 1.0),
-            Err("elliprg: Arguments must be finite.")
+            elliprg(1.0, INFINITY, 1.0).unwrap_err(),
+            "elliprg: Arguments must be finite."
         );
         assert_eq!(
-            elliprg(1.0, 1.0, INFINITY),
-            Err("elliprg: Arguments must be finite.")
+            elliprg(1.0, 1.0, INFINITY).unwrap_err(),
+            "elliprg: Arguments must be finite."
         );
     }
+
+    #[test]
+    fn test_elliprg_double_double() {
+        use crate::test_util::{assert_close_dd, dd};
+        assert_close_dd(
+            elliprg(dd("1"), dd("2"), dd("3")).unwrap(),
+            "1.401847099990895099431352156261306",
+            1e-31,
+        );
+        assert_close_dd(
+            elliprg(dd("0"), dd("0.5"), dd("1")).unwrap(),
+            "0.6753219405238377512600873676693629",
+            1e-31,
+        );
+    }
+
+    #[test]
+    fn test_elliprg_with_const() {
+        use crate::{carlson::ReducedTolerance, test_util::linspace};
+
+        for (x, y, z) in linspace(0.0, 10.0, 6).into_iter().tuple_combinations() {
+            let expected = elliprg(x, y, z).unwrap();
+            let actual = elliprg_with_const::<f64, ReducedTolerance>(x, y, z).unwrap();
+            assert_close!(expected, actual, 1e-8);
+        }
+    }
 }
@@ -13,9 +13,13 @@
 use core::mem::swap;
 
 use crate::{
-    carlson::{elliprc_unchecked, elliprd_unchecked, elliprf_unchecked},
+    carlson::{
+        constants::{tol, CarlsonConst, DefaultTolerance},
+        elliprc_unchecked, elliprd_unchecked_with_const, elliprf_unchecked_with_const,
+    },
     crate_util::{case, check, declare, let_mut},
-    StrErr,
+    error::err,
+    EllipError,
 };
 use num_traits::Float;
 
@@ -71,19 +75,29 @@ use num_traits::Float;
 /// # References
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed April 17, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
+pub fn elliprj<T: Float>(x: T, y: T, z: T, p: T) -> Result<T, EllipError> {
+    elliprj_with_const::<T, DefaultTolerance>(x, y, z, p)
+}
+
+/// Computes [elliprj]. Control the tolerance using [CarlsonConst].
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn elliprj<T: Float>(x: T, y: T, z: T, p: T) -> Result<T, StrErr> {
-    let ans = elliprj_unchecked(x, y, z, p);
+pub fn elliprj_with_const<T: Float, C: CarlsonConst<T>>(
+    x: T,
+    y: T,
+    z: T,
+    p: T,
+) -> Result<T, EllipError> {
+    let ans = elliprj_unchecked_with_const::<T, C>(x, y, z, p);
 
     if ans.is_finite() {
         return Ok(ans);
     }
     check!(@nan, elliprj, [x, y, z, p]);
-    check!(@zero, elliprj, [p]);
+    check!(@zero, elliprj, [p], limit: inf!());
     check!(@neg, elliprj, "x, y, and z must be non-negative.", [x, y, z]);
-    check!(@multi_zero, elliprj, [x, y, z]);
+    check!(@multi_zero, elliprj, [x, y, z], limit: p.signum() * inf!());
     case!(@any [x, y, z, p] == inf!(), T::zero());
-    Err("elliprj: Failed to converge.")
+    err!(NoConvergence, elliprj, "Failed to converge.")
 }
 
 /// Calculate RC(1, 1 + x)
@@ -116,8 +130,17 @@ fn elliprc1p<T: Float>(y: T) -> T {
 /// - p = 0
 /// - x = ∞ or y = ∞ or z = ∞
 #[inline]
-#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
 pub fn elliprj_unchecked<T: Float>(x: T, y: T, z: T, p: T) -> T {
+    elliprj_unchecked_with_const::<T, DefaultTolerance>(x, y, z, p)
+}
+
+/// Unsafe version of [elliprj_with_const](crate::carlson::elliprj_with_const).
+/// <div class="warning">⚠️ Unstable feature. May subject to changes.</div>
+///
+/// Undefined behavior with invalid arguments and edge cases.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+#[inline]
+pub fn elliprj_unchecked_with_const<T: Float, C: CarlsonConst<T>>(x: T, y: T, z: T, p: T) -> T {
     let_mut!(x, y, z);
     // for p < 0, the integral is singular, return Cauchy principal value
     if p <= 0.0 {
@@ -138,12 +161,17 @@ pub fn elliprj_unchecked<T: Float>(x: T, y: T, z: T, p: T) -> T {
         let z_plus_q = z + q;
         let xy = x * y;
         let p = (z * (x + y + q) - xy) / z_plus_q;
+        // The transformed p is positive for valid x, y, and z.
+        // Prevent infinite recursion with invalid arguments.
+        if p <= 0.0 || p.is_nan() {
+            return nan!();
+        }
         let pq = p * q;
         let xy_plus_pq = xy + pq;
         let xyz = xy * z;
 
-        let rj = elliprj_unchecked(x, y, z, p);
-        let rf = elliprf_unchecked(x, y, z);
+        let rj = elliprj_unchecked_with_const::<T, C>(x, y, z, p);
+        let rf = elliprf_unchecked_with_const::<T, C>(x, y, z);
         let rc = elliprc_unchecked(xy_plus_pq, pq);
 
         let mut value = (p - z) * rj;
@@ -174,7 +202,7 @@ pub fn elliprj_unchecked<T: Float>(x: T, y: T, z: T, p: T) -> T {
     if y == z {
         if y == p {
             // RJ(x,y,y,y)
-            return elliprd_unchecked(x, y, y);
+            return elliprd_unchecked_with_const::<T, C>(x, y, y);
         }
         // This prevents division by zero.
         if p.max(y) / p.min(y) > 1.2 {
@@ -185,14 +213,14 @@ pub fn elliprj_unchecked<T: Float>(x: T, y: T, z: T, p: T) -> T {
 
     if z == p {
         // RJ(x,y,z,z)
-        return elliprd_unchecked(x, y, z);
+        return elliprd_unchecked_with_const::<T, C>(x, y, z);
     }
 
     declare!(mut [xn = x, yn = y, zn = z, pn = p]);
     let mut an = (x + y + z + 2.0 * p) / 5.0;
     let a0 = an;
     let mut delta = (p - x) * (p - y) * (p - z);
-    let q = (epsilon!() / 5.0).powf(-1.0 / 8.0)
+    let q = (tol::<T, C>() / 5.0).powf(-1.0 / 8.0)
         * (an - x)
             .abs()
             .max((an - y).abs())
@@ -313,51 +341,55 @@ mod tests {
         use std::f64::{INFINITY, NAN};
         // Negative arguments: should return Err
         assert_eq!(
-            elliprj(-1.0, 1.0, 1.0, 1.0),
-            Err("elliprj: x, y, and z must be non-negative.")
+            elliprj(-1.0, 1.0, 1.0, 1.0).unwrap_err(),
+            "elliprj: x, y, and z must be non-negative."
+        );
+        assert_eq!(
+            elliprj(1.0, -1.0, 1.0, 1.0).unwrap_err(),
+            "elliprj: x, y, and z must be non-negative."
         );
         assert_eq!(
-            elliprj(1.0, -1.0, 1.0, 1.0),
-            Err("elliprj: x, y, and z must be non-negative.")
+            elliprj(1.0, 1.0, -1.0, 1.0).unwrap_err(),
+            "elliprj: x, y, and z must be non-negative."
         );
         assert_eq!(
-            elliprj(1.0, 1.0, -1.0, 1.0),
-            Err("elliprj: x, y, and z must be non-negative.")
+            elliprj(-3.0, -3.0, -1.0, -3.0).unwrap_err(),
+            "elliprj: x, y, and z must be non-negative."
         );
         // More than one zero among x, y, z: should return Err
         assert_eq!(
-            elliprj(0.0, 0.0, 1.0, 1.0),
-            Err("elliprj: At most one argument can be zero.")
+            elliprj(0.0, 0.0, 1.0, 1.0).unwrap_err(),
+            "elliprj: At most one argument can be zero."
         );
         assert_eq!(
-            elliprj(0.0, 1.0, 0.0, 1.0),
-            Err("elliprj: At most one argument can be zero.")
+            elliprj(0.0, 1.0, 0.0, 1.0).unwrap_err(),
+            "elliprj: At most one argument can be zero."
         );
         assert_eq!(
-            elliprj(1.0, 0.0, 0.0, 1.0),
-            Err("elliprj: At most one argument can be zero.")
+            elliprj(1.0, 0.0, 0.0, 1.0).unwrap_err(),
+            "elliprj: At most one argument can be zero."
         );
         // p = 0: should return Err
         assert_eq!(
-            elliprj(1.0, 1.0, 1.0, 0.0),
-            Err("elliprj: p cannot be zero.")
+            elliprj(1.0, 1.0, 1.0, 0.0).unwrap_err(),
+            "elliprj: p cannot be zero."
         );
         // NANs: should return Err
         assert_eq!(
-            elliprj(NAN, 1.0, 1.0, 1.0),
-            Err("elliprj: Arguments cannot be NAN.")
+            elliprj(NAN, 1.0, 1.0, 1.0).unwrap_err(),
+            "elliprj: Arguments cannot be NAN."
         );
         assert_eq!(
-            elliprj(1.0, NAN, 1.0, 1.0),
-            Err("elliprj: Arguments cannot be NAN.")
+            elliprj(1.0, NAN, 1.0, 1.0).unwrap_err(),
+            "elliprj: Arguments cannot be NAN."
         );
         assert_eq!(
-            elliprj(1.0, 1.0, NAN, 1.0),
-            Err("elliprj: Arguments cannot be NAN.")
+            elliprj(1.0, 1.0, NAN, 1.0).unwrap_err(),
+            "elliprj: Arguments cannot be NAN."
         );
         assert_eq!(
-            elliprj(1.0, 1.0, 1.0, NAN),
-            Err("elliprj: Arguments cannot be NAN.")
+            elliprj(1.0, 1.0, 1.0, NAN).unwrap_err(),
+            "elliprj: Arguments cannot be NAN."
         );
         // Infs: should return zero
         assert_eq!(elliprj(INFINITY, 1.0, 1.0, 1.0).unwrap(), 0.0);
@@ -373,9 +405,40 @@ mod tests {
         // y < -1
         assert!(elliprc1p(-1.1).is_finite());
     }
+
+    #[test]
+    fn test_elliprj_double_double() {
+        use crate::test_util::{assert_close_dd, dd};
+        assert_close_dd(
+            elliprj(dd("1"), dd("2"), dd("3"), dd("4")).unwrap(),
+            "0.2398480997495677621758616710416392",
+            1e-31,
+        );
+        assert_close_dd(
+            elliprj(dd("0.5"), dd("1"), dd("2"), dd("3")).unwrap(),
+            "0.4669800994714269145684532776202563",
+            1e-31,
+        );
+    }
+
+    #[test]
+    fn test_elliprj_with_const() {
+        use crate::{carlson::ReducedTolerance, test_util::linspace};
+
+        for (x, y, z) in linspace(0.0, 10.0, 6).into_iter().tuple_combinations() {
+            if y == 0.0 {
+                continue;
+            }
+            for p in [-5.0, 0.5, 3.0, 7.0] {
+                let expected = elliprj(x, y, z, p).unwrap();
+                let actual = elliprj_with_const::<f64, ReducedTolerance>(x, y, z, p).unwrap();
+                assert_close!(expected, actual, 1e-8);
+            }
+        }
+    }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(elliprj(0.2, 0.5, 1e300, 1.0), Err("elliprj: Failed to converge."));
+    assert_eq!(elliprj(0.2, 0.5, 1e300, 1.0).unwrap_err(), "elliprj: Failed to converge.");
 }
@@ -5,6 +5,7 @@
 
 //! Elliptic integral functions in Carlson's form.
 
+mod constants;
 mod elliprc;
 mod elliprd;
 mod elliprf;
@@ -12,19 +13,33 @@ mod elliprg;
 mod elliprj;
 
 pub use elliprc::elliprc;
+pub use elliprc::elliprc_with_const;
 pub use elliprd::elliprd;
+pub use elliprd::elliprd_with_const;
 pub use elliprf::elliprf;
+pub use elliprf::elliprf_with_const;
 pub use elliprg::elliprg;
+pub use elliprg::elliprg_with_const;
 pub use elliprj::elliprj;
+pub use elliprj::elliprj_with_const;
+
+pub use constants::{CarlsonConst, DefaultTolerance, ReducedTolerance};
 
 #[cfg(not(feature = "unstable"))]
 pub(crate) use {
     elliprc::elliprc_unchecked, elliprd::elliprd_unchecked, elliprf::elliprf_unchecked,
     elliprg::elliprg_unchecked, elliprj::elliprj_unchecked,
 };
+#[cfg(not(feature = "unstable"))]
+pub(crate) use {elliprd::elliprd_unchecked_with_const, elliprf::elliprf_unchecked_with_const};
 
 #[cfg(feature = "unstable")]
 pub use {
     elliprc::elliprc_unchecked, elliprd::elliprd_unchecked, elliprf::elliprf_unchecked,
     elliprg::elliprg_unchecked, elliprj::elliprj_unchecked,
 };
+#[cfg(feature = "unstable")]
+pub use {
+    elliprd::elliprd_unchecked_with_const, elliprf::elliprf_unchecked_with_const,
+    elliprg::elliprg_unchecked_with_const, elliprj::elliprj_unchecked_with_const,
+};
@@ -0,0 +1,605 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Piecewise Chebyshev approximants of functions over a fixed domain.
+//!
+//! The builders sample a function at the Chebyshev nodes of uniformly spaced pieces,
+//! doubling the number of pieces until the approximant meets the requested absolute
+//! tolerance on a verification grid. The approximants evaluate by a table lookup and
+//! the Clenshaw recurrence, i.e., about 2N multiply-adds in one dimension and 2N² in two.
+//!
+//! The storage is fixed by the const parameters: `N` is the number of coefficients per
+//! piece (the degree plus one) and `P` is the maximum number of pieces. The approximants
+//! do not allocate, so they can be used in `no_std` and real-time contexts. To emit the
+//! tables as standalone Rust or C source, run the `generate_approximation` example.
+//!
+//! The reported [max_error](Chebyshev::max_error) is the largest absolute error observed on
+//! the verification grid, which samples each piece at `oversample × N` equally spaced points
+//! per dimension, including the boundaries of the piece.
+//!
+//! # Examples
+//! ```
+//! use ellip::{chebyshev::Chebyshev, ellippiinc};
+//!
+//! let approx = Chebyshev::<f64, 16, 32>::builder(0.0, 1.2)
+//!     .tol(1e-13)
+//!     .build(|phi| ellippiinc(phi, 0.3, 0.5))
+//!     .unwrap();
+//! assert!(approx.max_error() <= 1e-13);
+//! assert!((approx.eval(0.7) - ellippiinc(0.7, 0.3, 0.5).unwrap()).abs() <= 1e-13);
+//! ```
+//!
+//! # References
+//! - Trefethen, Lloyd N. Approximation Theory and Approximation Practice. Philadelphia: SIAM, 2013.
+
+use num_traits::Float;
+
+use crate::{error::err, EllipError};
+
+/// Default verification points per coefficient, per piece and dimension.
+const DEFAULT_OVERSAMPLE: usize = 4;
+
+/// Returns the k-th of the n Chebyshev nodes on [-1, 1].
+#[inline]
+fn node<T: Float>(k: usize, n: usize) -> T {
+    cos_table::<T>(1, k, n)
+}
+
+/// Returns cos(π j (k + 1/2) / n).
+#[inline]
+fn cos_table<T: Float>(j: usize, k: usize, n: usize) -> T {
+    let arg =
+        T::from(j).unwrap() * (T::from(k).unwrap() + T::from(0.5).unwrap()) / T::from(n).unwrap();
+    (pi!() * arg).cos()
+}
+
+/// Evaluates Σ cⱼ Tⱼ(t) by the Clenshaw recurrence.
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn clenshaw<T: Float, const N: usize>(c: &[T; N], t: T) -> T {
+    let t2 = 2.0 * t;
+    let mut b1 = 0.0;
+    let mut b2 = 0.0;
+    for &cj in c[1..].iter().rev() {
+        let b0 = t2 * b1 - b2 + cj;
+        b2 = b1;
+        b1 = b0;
+    }
+    c[0] + t * b1 - b2
+}
+
+/// Maps x in [lo, hi] split into `pieces` to the index of the piece and t in [-1, 1].
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn locate<T: Float>(x: T, lo: T, scale: T, pieces: usize) -> (usize, T) {
+    let u = (x - lo) * scale;
+    let i = u.floor().to_usize().unwrap_or(0).min(pieces - 1);
+    (i, 2.0 * (u - T::from(i).unwrap()) - 1.0)
+}
+
+/// Maps t in [-1, 1] of the piece `i` to x.
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn unlocate<T: Float>(i: usize, t: T, lo: T, scale: T) -> T {
+    lo + (T::from(i).unwrap() + 0.5 * (t + 1.0)) / scale
+}
+
+/// Samples `f` and checks that the value is finite.
+#[inline]
+fn sample<T: Float>(value: Result<T, EllipError>) -> Result<T, EllipError> {
+    let value = value?;
+    if !value.is_finite() {
+        return err!(
+            DomainError,
+            Chebyshev,
+            "The function must be finite on the domain."
+        );
+    }
+    Ok(value)
+}
+
+/// Checks the domain and the capacity.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn check_domain<T: Float>(lo: T, hi: T, tol: T, n: usize, p: usize) -> Result<(), EllipError> {
+    if lo.is_nan() || hi.is_nan() || tol.is_nan() {
+        return err!(
+            NanArgument,
+            Chebyshev,
+            "Arguments cannot be NAN.",
+            [lo, hi, tol]
+        );
+    }
+    if !(lo.is_finite() && hi.is_finite()) || lo >= hi {
+        return err!(
+            DomainError,
+            Chebyshev,
+            "The domain must be finite with lo < hi.",
+            [lo, hi]
+        );
+    }
+    if tol <= 0.0 {
+        return err!(DomainError, Chebyshev, "tol must be positive.", tol);
+    }
+    if n < 2 || p < 1 {
+        return err!(
+            DomainError,
+            Chebyshev,
+            "N must be at least 2 and P must be at least 1."
+        );
+    }
+    Ok(())
+}
+
+/// Returns the next number of pieces, or None if the capacity is exhausted.
+#[inline]
+fn refine(pieces: usize, max: usize) -> Option<usize> {
+    (pieces < max).then(|| (2 * pieces).min(max))
+}
+
+/// Piecewise Chebyshev approximant of a function of one variable.
+///
+/// Construct with [Chebyshev::builder]. See the [module documentation](self) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct Chebyshev<T: Float, const N: usize, const P: usize> {
+    lo: T,
+    hi: T,
+    scale: T,
+    pieces: usize,
+    coeffs: [[T; N]; P],
+    max_error: T,
+}
+
+/// Builder of [Chebyshev].
+#[derive(Debug, Clone, Copy)]
+pub struct ChebyshevBuilder<T: Float, const N: usize, const P: usize> {
+    lo: T,
+    hi: T,
+    tol: T,
+    oversample: usize,
+}
+
+impl<T: Float, const N: usize, const P: usize> Chebyshev<T, N, P> {
+    /// Returns a builder approximating a function over [lo, hi].
+    ///
+    /// The default tolerance is 1000ε.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn builder(lo: T, hi: T) -> ChebyshevBuilder<T, N, P> {
+        ChebyshevBuilder {
+            lo,
+            hi,
+            tol: 1000.0 * epsilon!(),
+            oversample: DEFAULT_OVERSAMPLE,
+        }
+    }
+
+    /// Evaluates the approximant. Returns NAN outside the domain.
+    #[inline]
+    pub fn eval(&self, x: T) -> T {
+        if !(x >= self.lo && x <= self.hi) {
+            return nan!();
+        }
+        let (i, t) = locate(x, self.lo, self.scale, self.pieces);
+        clenshaw(&self.coeffs[i], t)
+    }
+
+    /// Returns the domain [lo, hi].
+    pub fn domain(&self) -> (T, T) {
+        (self.lo, self.hi)
+    }
+
+    /// Returns the number of pieces.
+    pub fn pieces(&self) -> usize {
+        self.pieces
+    }
+
+    /// Returns the maximum absolute error on the verification grid.
+    pub fn max_error(&self) -> T {
+        self.max_error
+    }
+
+    /// Returns the coefficients of each piece, from lo to hi.
+    ///
+    /// The piece i covers [lo + i/s, lo + (i + 1)/s], where s = pieces/(hi - lo), and
+    /// approximates Σ cⱼ Tⱼ(t) with t = 2(x - lo)s - 2i - 1. The first coefficient is halved,
+    /// so that the constant term is c₀ rather than c₀/2.
+    pub fn coefficients(&self) -> &[[T; N]] {
+        &self.coeffs[..self.pieces]
+    }
+}
+
+impl<T: Float, const N: usize, const P: usize> ChebyshevBuilder<T, N, P> {
+    /// Sets the absolute tolerance.
+    pub fn tol(mut self, tol: T) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Sets the number of verification points per coefficient in each piece.
+    pub fn oversample(mut self, oversample: usize) -> Self {
+        self.oversample = oversample.max(1);
+        self
+    }
+
+    /// Builds the approximant of `f`.
+    ///
+    /// ## Domain
+    /// - Returns error if:
+    ///   - the domain is not finite or lo ≥ hi,
+    ///   - tol ≤ 0, N < 2, or P < 1,
+    ///   - `f` returns an error or a non-finite value on the domain,
+    ///   - or the tolerance is not met with P pieces.
+    pub fn build<F>(&self, f: F) -> Result<Chebyshev<T, N, P>, EllipError>
+    where
+        F: Fn(T) -> Result<T, EllipError>,
+    {
+        let (lo, hi) = (self.lo, self.hi);
+        check_domain(lo, hi, self.tol, N, P)?;
+
+        let mut approx = Chebyshev {
+            lo,
+            hi,
+            scale: T::one(),
+            pieces: 1,
+            coeffs: [[T::zero(); N]; P],
+            max_error: T::zero(),
+        };
+        loop {
+            approx.scale = T::from(approx.pieces).unwrap() / (hi - lo);
+            for i in 0..approx.pieces {
+                approx.coeffs[i] = fit(|t| f(unlocate(i, t, lo, approx.scale)))?;
+            }
+            approx.max_error = T::zero();
+            let m = self.oversample * N;
+            for i in 0..approx.pieces {
+                for j in 0..=m {
+                    let t = T::from(2 * j).unwrap() / T::from(m).unwrap() - T::one();
+                    let exact = sample(f(unlocate(i, t, lo, approx.scale)))?;
+                    let error = (clenshaw(&approx.coeffs[i], t) - exact).abs();
+                    approx.max_error = approx.max_error.max(error);
+                }
+            }
+            if approx.max_error <= self.tol {
+                return Ok(approx);
+            }
+            match refine(approx.pieces, P) {
+                Some(pieces) => approx.pieces = pieces,
+                None => {
+                    return err!(
+                        NoConvergence,
+                        Chebyshev,
+                        "The tolerance is not met with P pieces."
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Computes the Chebyshev coefficients of `f` on [-1, 1], with the first coefficient halved.
+fn fit<T: Float, const N: usize, F>(f: F) -> Result<[T; N], EllipError>
+where
+    F: Fn(T) -> Result<T, EllipError>,
+{
+    let mut values = [T::zero(); N];
+    for (k, value) in values.iter_mut().enumerate() {
+        *value = sample(f(node(k, N)))?;
+    }
+    Ok(coefficients(&values))
+}
+
+/// Computes the Chebyshev coefficients from the values at the nodes, with the first coefficient halved.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn coefficients<T: Float, const N: usize>(values: &[T; N]) -> [T; N] {
+    let mut c = [0.0; N];
+    for (j, cj) in c.iter_mut().enumerate() {
+        let sum = values
+            .iter()
+            .enumerate()
+            .fold(0.0, |acc, (k, &v)| acc + v * cos_table::<T>(j, k, N));
+        *cj = 2.0 * sum / T::from(N).unwrap();
+    }
+    c[0] = 0.5 * c[0];
+    c
+}
+
+/// Piecewise Chebyshev approximant of a function of two variables over a rectangle.
+///
+/// The rectangle is split into s × s pieces with s² ≤ P. Construct with [Chebyshev2d::builder].
+/// See the [module documentation](self) for details.
+#[derive(Debug, Clone, Copy)]
+pub struct Chebyshev2d<T: Float, const N: usize, const P: usize> {
+    lo: (T, T),
+    hi: (T, T),
+    scale: (T, T),
+    pieces: usize,
+    coeffs: [[[T; N]; N]; P],
+    max_error: T,
+}
+
+/// Builder of [Chebyshev2d].
+#[derive(Debug, Clone, Copy)]
+pub struct Chebyshev2dBuilder<T: Float, const N: usize, const P: usize> {
+    x: (T, T),
+    y: (T, T),
+    tol: T,
+    oversample: usize,
+}
+
+impl<T: Float, const N: usize, const P: usize> Chebyshev2d<T, N, P> {
+    /// Returns a builder approximating a function over the rectangle x × y,
+    /// where `x` and `y` are the intervals (lo, hi).
+    ///
+    /// The default tolerance is 1000ε.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn builder(x: (T, T), y: (T, T)) -> Chebyshev2dBuilder<T, N, P> {
+        Chebyshev2dBuilder {
+            x,
+            y,
+            tol: 1000.0 * epsilon!(),
+            oversample: DEFAULT_OVERSAMPLE,
+        }
+    }
+
+    /// Evaluates the approximant. Returns NAN outside the domain.
+    #[inline]
+    pub fn eval(&self, x: T, y: T) -> T {
+        if !(x >= self.lo.0 && x <= self.hi.0 && y >= self.lo.1 && y <= self.hi.1) {
+            return nan!();
+        }
+        let (ix, tx) = locate(x, self.lo.0, self.scale.0, self.pieces);
+        let (iy, ty) = locate(y, self.lo.1, self.scale.1, self.pieces);
+        eval_2d(&self.coeffs[iy * self.pieces + ix], tx, ty)
+    }
+
+    /// Returns the domain as the intervals (lo, hi) of x and y.
+    pub fn domain(&self) -> ((T, T), (T, T)) {
+        ((self.lo.0, self.hi.0), (self.lo.1, self.hi.1))
+    }
+
+    /// Returns the number of pieces along each dimension.
+    pub fn pieces(&self) -> usize {
+        self.pieces
+    }
+
+    /// Returns the maximum absolute error on the verification grid.
+    pub fn max_error(&self) -> T {
+        self.max_error
+    }
+
+    /// Returns the coefficients of each piece in row-major order, i.e., the piece (ix, iy) is
+    /// at the index iy × pieces + ix.
+    ///
+    /// Each piece approximates Σ cᵢⱼ Tᵢ(tx) Tⱼ(ty), with tx and ty mapped as in
+    /// [Chebyshev::coefficients]. The coefficients c₀ⱼ and cᵢ₀ are halved, so c₀₀ is quartered.
+    pub fn coefficients(&self) -> &[[[T; N]; N]] {
+        &self.coeffs[..self.pieces * self.pieces]
+    }
+}
+
+impl<T: Float, const N: usize, const P: usize> Default for Chebyshev2d<T, N, P> {
+    /// Returns an empty approximant to build into with [Chebyshev2dBuilder::build_into].
+    /// Evaluates to NAN everywhere.
+    fn default() -> Self {
+        Self {
+            lo: (T::nan(), T::nan()),
+            hi: (T::nan(), T::nan()),
+            scale: (T::one(), T::one()),
+            pieces: 0,
+            coeffs: [[[T::zero(); N]; N]; P],
+            max_error: T::nan(),
+        }
+    }
+}
+
+/// Evaluates Σ cᵢⱼ Tᵢ(tx) Tⱼ(ty).
+#[inline]
+fn eval_2d<T: Float, const N: usize>(c: &[[T; N]; N], tx: T, ty: T) -> T {
+    let mut rows = [T::zero(); N];
+    for (row, ci) in rows.iter_mut().zip(c) {
+        *row = clenshaw(ci, ty);
+    }
+    clenshaw(&rows, tx)
+}
+
+impl<T: Float, const N: usize, const P: usize> Chebyshev2dBuilder<T, N, P> {
+    /// Sets the absolute tolerance.
+    pub fn tol(mut self, tol: T) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Sets the number of verification points per coefficient in each piece and dimension.
+    pub fn oversample(mut self, oversample: usize) -> Self {
+        self.oversample = oversample.max(1);
+        self
+    }
+
+    /// Builds the approximant of `f`.
+    ///
+    /// ## Domain
+    /// - Returns error if:
+    ///   - the rectangle is not finite or lo ≥ hi in either dimension,
+    ///   - tol ≤ 0, N < 2, or P < 1,
+    ///   - `f` returns an error or a non-finite value on the rectangle,
+    ///   - or the tolerance is not met with P pieces.
+    pub fn build<F>(&self, f: F) -> Result<Chebyshev2d<T, N, P>, EllipError>
+    where
+        F: Fn(T, T) -> Result<T, EllipError>,
+    {
+        let mut approx = Chebyshev2d::default();
+        self.build_into(f, &mut approx)?;
+        Ok(approx)
+    }
+
+    /// Builds the approximant of `f` into `approx`, overwriting its contents.
+    ///
+    /// The table holds N²P coefficients, which may not fit on the stack. Build into a heap
+    /// allocation, e.g., `Box::<Chebyshev2d<_, N, P>>::default()`, to avoid the copies of
+    /// [Chebyshev2dBuilder::build].
+    ///
+    /// ## Domain
+    /// - Same as [Chebyshev2dBuilder::build]. On error, `approx` is left unspecified.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn build_into<F>(&self, f: F, approx: &mut Chebyshev2d<T, N, P>) -> Result<(), EllipError>
+    where
+        F: Fn(T, T) -> Result<T, EllipError>,
+    {
+        let (x, y) = (self.x, self.y);
+        check_domain(x.0, x.1, self.tol, N, P)?;
+        check_domain(y.0, y.1, self.tol, N, P)?;
+        // Largest s with s² ≤ P
+        let max_pieces = (1..=P).take_while(|s| s * s <= P).last().unwrap_or(1);
+
+        approx.lo = (x.0, y.0);
+        approx.hi = (x.1, y.1);
+        approx.pieces = 1;
+        loop {
+            let s = approx.pieces;
+            let scale = (
+                T::from(s).unwrap() / (x.1 - x.0),
+                T::from(s).unwrap() / (y.1 - y.0),
+            );
+            approx.scale = scale;
+            for (iy, ix) in (0..s).flat_map(|iy| (0..s).map(move |ix| (iy, ix))) {
+                let map = |tx: T, ty: T| {
+                    (
+                        unlocate(ix, tx, x.0, scale.0),
+                        unlocate(iy, ty, y.0, scale.1),
+                    )
+                };
+                // Fit along y at each node in x, then along x for each coefficient in y.
+                let mut partial = [[0.0; N]; N];
+                for (k, row) in partial.iter_mut().enumerate() {
+                    let tx = node(k, N);
+                    *row = fit(|ty| {
+                        let (x, y) = map(tx, ty);
+                        f(x, y)
+                    })?;
+                }
+                let mut c = [[0.0; N]; N];
+                for j in 0..N {
+                    let column: [T; N] = core::array::from_fn(|k| partial[k][j]);
+                    for (i, ci) in coefficients(&column).into_iter().enumerate() {
+                        c[i][j] = ci;
+                    }
+                }
+                approx.coeffs[iy * s + ix] = c;
+            }
+
+            approx.max_error = 0.0;
+            let m = self.oversample * N;
+            let grid = |j: usize| T::from(2 * j).unwrap() / T::from(m).unwrap() - 1.0;
+            for (iy, ix) in (0..s).flat_map(|iy| (0..s).map(move |ix| (iy, ix))) {
+                for (jx, jy) in (0..=m).flat_map(|jx| (0..=m).map(move |jy| (jx, jy))) {
+                    let (tx, ty) = (grid(jx), grid(jy));
+                    let exact = sample(f(
+                        unlocate(ix, tx, x.0, scale.0),
+                        unlocate(iy, ty, y.0, scale.1),
+                    ))?;
+                    let error = (eval_2d(&approx.coeffs[iy * s + ix], tx, ty) - exact).abs();
+                    approx.max_error = approx.max_error.max(error);
+                }
+            }
+            if approx.max_error <= self.tol {
+                return Ok(());
+            }
+            match refine(s, max_pieces) {
+                Some(pieces) => approx.pieces = pieces,
+                None => {
+                    return err!(
+                        NoConvergence,
+                        Chebyshev2d,
+                        "The tolerance is not met with P pieces."
+                    )
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ellipk, ellippiinc, elliprj};
+
+    #[test]
+    fn test_chebyshev() {
+        let f = |phi: f64| ellippiinc(phi, 0.3, 0.5);
+        let approx = Chebyshev::<f64, 16, 32>::builder(0.0, 1.2)
+            .tol(1e-13)
+            .build(f)
+            .unwrap();
+        assert!(approx.max_error() <= 1e-13);
+        assert_eq!(approx.domain(), (0.0, 1.2));
+        for i in 0..=997 {
+            let phi = 1.2 * i as f64 / 997.0;
+            assert!((approx.eval(phi) - f(phi).unwrap()).abs() <= 2e-13);
+        }
+        assert!(approx.eval(-0.1).is_nan());
+        assert!(approx.eval(f64::NAN).is_nan());
+
+        // Near the pole, more pieces are needed.
+        let approx = Chebyshev::<f64, 16, 64>::builder(0.5, 0.99)
+            .tol(1e-10)
+            .build(ellipk)
+            .unwrap();
+        assert!(approx.pieces() > 1);
+        assert_eq!(approx.coefficients().len(), approx.pieces());
+        assert!((approx.eval(0.985) - ellipk(0.985).unwrap()).abs() <= 2e-10);
+    }
+
+    #[test]
+    fn test_chebyshev_2d() {
+        let f = |x: f64, p: f64| elliprj(x, 1.0, 2.0, p);
+        let approx = Chebyshev2d::<f64, 12, 16>::builder((0.5, 0.7), (1.0, 1.5))
+            .tol(1e-12)
+            .build(f)
+            .unwrap();
+        assert!(approx.max_error() <= 1e-12);
+        for (x, p) in [(0.5, 1.0), (0.55, 1.33), (0.69, 1.01), (0.7, 1.5)] {
+            assert!((approx.eval(x, p) - f(x, p).unwrap()).abs() <= 2e-12);
+        }
+        assert!(approx.eval(0.4, 1.2).is_nan());
+
+        // Building into an existing table, e.g., on the heap
+        let mut boxed = Box::<Chebyshev2d<f64, 12, 16>>::default();
+        assert!(boxed.eval(0.55, 1.33).is_nan());
+        Chebyshev2d::builder((0.5, 0.7), (1.0, 1.5))
+            .tol(1e-12)
+            .build_into(f, &mut boxed)
+            .unwrap();
+        assert_eq!(boxed.pieces(), approx.pieces());
+        assert_eq!(boxed.coefficients(), approx.coefficients());
+        assert_eq!(boxed.eval(0.55, 1.33), approx.eval(0.55, 1.33));
+    }
+
+    #[test]
+    fn test_chebyshev_err() {
+        assert!(matches!(
+            Chebyshev::<f64, 16, 4>::builder(0.5, 1.5)
+                .build(ellipk)
+                .unwrap_err(),
+            EllipError::DomainError(_)
+        ));
+        assert_eq!(
+            Chebyshev::<f64, 4, 2>::builder(0.0, 0.99)
+                .tol(1e-15)
+                .build(ellipk)
+                .unwrap_err(),
+            "Chebyshev: The tolerance is not met with P pieces."
+        );
+        assert_eq!(
+            Chebyshev::<f64, 4, 2>::builder(1.0, 0.0)
+                .build(ellipk)
+                .unwrap_err(),
+            "Chebyshev: The domain must be finite with lo < hi."
+        );
+        assert!(Chebyshev2d::<f64, 4, 4>::builder((0.0, 1.0), (0.0, 1.0))
+            .tol(0.0)
+            .build(|x, y| Ok(x + y))
+            .is_err());
+    }
+}
@@ -0,0 +1,720 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Cnoidal waves of the Korteweg–de Vries (KdV) equation in shallow water.
+//!
+//! The periodic wave of the height H on the mean water depth h has the free-surface elevation
+//! above the mean water level
+//! ```text
+//!                      ⎛ 2K(m)            ⎞
+//! η(x, t) = η₂ + H cn² ⎜ ───── (x - ct), m ⎟
+//!                      ⎝   λ              ⎠
+//! ```
+//! to the first order in H / h (Fenton, 1999, §3), with
+//! ```text
+//! Wavelength:      λ = 4K h √(mh / 3H)
+//! Celerity:        c = √(gh) [1 + H / (mh) (1 - m/2 - 3E / 2K)]
+//! Period:          T = λ / c
+//! Trough:          η₂ = H / m (1 - m - E / K) = H (D / K - 1)
+//! Ursell number:   U = Hλ² / h³ = 16mK² / 3
+//! ```
+//! where K, E, and D are the complete elliptic integrals of the parameter m. The trough and
+//! the crest η₁ = η₂ + H are the corrections from the mean water level, on which the elevation
+//! averages to zero.
+//!
+//! Given the wavelength, m is the inverse of the Ursell number, [parameter_from_ursell]. Given
+//! the period, the dimensionless period T√(g / h) = √(U h / H) / [1 + ...] has a minimum in m,
+//! below which the celerity of the first-order theory vanishes, so m is taken on the branch of
+//! the longer waves beyond the minimum. Practical waves have m extremely close to 1, so the
+//! solution is carried in w = -ln(1 - m) and the elliptic functions are evaluated by
+//! [EllipticModulus::from_kc] from the complementary modulus.
+//!
+//! # Examples
+//! ```
+//! use ellip::{cnoidal::CnoidalWave, util::assert_close};
+//!
+//! // Wave of 2 m height and 10 s period on 5 m depth
+//! let wave = CnoidalWave::from_period(2.0, 5.0, 10.0, 9.81).unwrap();
+//! assert_close(wave.wavelength(), 73.64747236067157, 1e-14);
+//! assert_close(wave.celerity(), 7.364747236067156, 1e-14);
+//! assert_close(wave.crest() - wave.trough(), 2.0, 1e-15);
+//! assert_close(wave.elevation(0.0, 0.0), wave.crest(), 1e-15);
+//! ```
+//!
+//! # References
+//! - Fenton, John D. “The Cnoidal Theory of Water Waves.” In Developments in Offshore Engineering, edited by J. B. Herbich, 55–100. Houston: Gulf, 1999.
+//! - Wiegel, R. L. “A Presentation of Cnoidal Wave Theory for Practical Application.” Journal of Fluid Mechanics 7, no. 2 (1960): 273–86. <https://doi.org/10.1017/S0022112060001481>.
+
+use num_traits::Float;
+
+use crate::{
+    crate_util::{check, solve_increasing},
+    error::err,
+    EllipError, EllipticModulus,
+};
+
+/// Maximum number of iterations of the bisection for the shortest period.
+const MAX_ITERATIONS: usize = 200;
+
+/// Periodic wave of the KdV equation, see [mod@crate::cnoidal].
+///
+/// The parameter m and the derived quantities are computed at construction. The methods return
+/// NAN for NAN arguments.
+///
+/// ## Parameters
+/// - height: wave height from trough to crest (H). H ∈ ℝ, H > 0, H is finite.
+/// - depth: mean water depth (h). h ∈ ℝ, h > 0, h is finite.
+/// - gravity: gravitational acceleration (g). g ∈ ℝ, g > 0, g is finite.
+#[derive(Debug, Clone, Copy)]
+pub struct CnoidalWave<T: Float> {
+    height: T,
+    depth: T,
+    gravity: T,
+    wavelength: T,
+    celerity: T,
+    trough: T,
+    ursell: T,
+    modulus: EllipticModulus<T>,
+}
+
+/// Quantities of the wave at w = -ln(1 - m).
+struct Shape<T: Float> {
+    modulus: EllipticModulus<T>,
+    m: T,
+    /// 1 - m
+    m1: T,
+    k: T,
+    e: T,
+}
+
+impl<T: Float> Shape<T> {
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn new(w: T) -> Result<Self, EllipError> {
+        let m = -(-w).exp_m1();
+        let modulus = if m < 0.5 {
+            EllipticModulus::new(m)?
+        } else {
+            EllipticModulus::from_kc((-0.5 * w).exp())?
+        };
+        Ok(Self {
+            m,
+            m1: (-w).exp(),
+            k: modulus.ellipk(),
+            e: modulus.ellipe(),
+            modulus,
+        })
+    }
+
+    /// Returns U = 16mK² / 3 and dU/dw = 16KE / 3.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn ursell(&self) -> (T, T) {
+        (
+            16.0 / 3.0 * self.m * self.k * self.k,
+            16.0 / 3.0 * self.k * self.e,
+        )
+    }
+
+    /// Returns c / √(gh) = 1 + ε G / m with G = 1 - m/2 - 3E / 2K, and its derivative in w,
+    /// for ε = H / h.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn celerity(&self, ratio: T) -> (T, T) {
+        let Self { m, m1, k, e, .. } = *self;
+        let g = 1.0 - 0.5 * m - 1.5 * e / k;
+        // dG/dw = m₁ dG/dm, with d(E/K)/dm = (2EKm₁ - K²m₁ - E²) / (2mm₁K²)
+        let dg = -0.5 * m1 - 0.75 * (2.0 * e * k * m1 - k * k * m1 - e * e) / (m * k * k);
+        (1.0 + ratio * g / m, ratio * (m * dg - m1 * g) / (m * m))
+    }
+
+    /// Returns ln(T√(g / h)) = ln(√(U / ε) / C) and its derivative in w, or None if C ≤ 0.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn log_period(&self, ratio: T) -> Option<(T, T)> {
+        let (u, du) = self.ursell();
+        let (c, dc) = self.celerity(ratio);
+        if c <= 0.0 {
+            return None;
+        }
+        Some((0.5 * (u / ratio).ln() - c.ln(), 0.5 * du / u - dc / c))
+    }
+}
+
+/// Largest w, where the complementary modulus e^(-w/2) is the smallest normal number.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn max_w<T: Float>() -> T {
+    -2.0 * T::min_positive_value().ln()
+}
+
+/// Returns the largest Ursell number, at w = [max_w].
+fn max_ursell<T: Float>() -> Result<T, EllipError> {
+    Ok(Shape::new(max_w())?.ursell().0)
+}
+
+/// Solves U(w) = ursell for w = -ln(1 - m), for 0 < ursell ≤ [max_ursell]. Returns None if
+/// not converged.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn solve_ursell<T: Float>(ursell: T) -> Result<Option<T>, EllipError> {
+    let hi = max_w();
+    // U ≈ 4π²m / 3 for small m, and K ≈ √(3U / 16) with 1 - m ≈ 16e^(-2K) near m = 1.
+    let small = 3.0 * ursell / (4.0 * pi!() * pi!());
+    let w0 = if small < 0.5 {
+        -(-small).ln_1p()
+    } else {
+        2.0 * (3.0 * ursell / 16.0).sqrt() - 16.0.ln()
+    };
+    solve_increasing(0.0, hi, w0.max(T::min_positive_value()).min(hi), |w| {
+        let (u, du) = Shape::new(w)?.ursell();
+        Ok((u - ursell, du))
+    })
+}
+
+impl<T: Float> CnoidalWave<T> {
+    /// Constructs the wave of the height H on the mean depth h with the wavelength λ.
+    ///
+    /// ## Domain
+    /// - Returns error if H ≤ 0, h ≤ 0, λ ≤ 0, or g ≤ 0.
+    /// - Returns error if the celerity c ≤ 0, where the first-order theory does not apply.
+    /// - Returns error if the Ursell number Hλ² / h³ is too large for m to be represented.
+    /// - Returns error if any argument is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn from_wavelength(
+        height: T,
+        depth: T,
+        wavelength: T,
+        gravity: T,
+    ) -> Result<Self, EllipError> {
+        check!(@nan, CnoidalWave, [height, depth, wavelength, gravity]);
+        if height.min(depth).min(wavelength).min(gravity) <= 0.0 {
+            return err!(
+                DomainError,
+                CnoidalWave,
+                "Arguments must be positive.",
+                [height, depth, wavelength, gravity]
+            );
+        }
+        check!(@inf, CnoidalWave, [height, depth, wavelength, gravity]);
+
+        let ursell = height / depth * (wavelength / depth) * (wavelength / depth);
+        if ursell > max_ursell()? {
+            return err!(
+                DomainError,
+                CnoidalWave,
+                "The Ursell number is too large.",
+                [height, depth, wavelength]
+            );
+        }
+        let w = match solve_ursell(ursell)? {
+            Some(w) => w,
+            None => return err!(NoConvergence, CnoidalWave, "Failed to converge."),
+        };
+        let wave = Self::from_shape(height, depth, gravity, &Shape::new(w)?);
+        if wave.celerity <= 0.0 {
+            return err!(
+                DomainError,
+                CnoidalWave,
+                "The celerity must be positive.",
+                [height, depth, wavelength]
+            );
+        }
+        Ok(wave)
+    }
+
+    /// Constructs the wave of the height H on the mean depth h with the period T.
+    ///
+    /// The solution is the longest wave with the period T, see [mod@crate::cnoidal].
+    ///
+    /// ## Domain
+    /// - Returns error if H ≤ 0, h ≤ 0, T ≤ 0, or g ≤ 0.
+    /// - Returns error if T is shorter than the minimum period of the first-order theory at
+    ///   the ratio H / h.
+    /// - Returns error if T is too long for m to be represented.
+    /// - Returns error if any argument is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn from_period(height: T, depth: T, period: T, gravity: T) -> Result<Self, EllipError> {
+        check!(@nan, CnoidalWave, [height, depth, period, gravity]);
+        if height.min(depth).min(period).min(gravity) <= 0.0 {
+            return err!(
+                DomainError,
+                CnoidalWave,
+                "Arguments must be positive.",
+                [height, depth, period, gravity]
+            );
+        }
+        check!(@inf, CnoidalWave, [height, depth, period, gravity]);
+
+        let ratio = height / depth;
+        let target = (period * (gravity / depth).sqrt()).ln();
+        let slope = |w: T| -> Result<T, EllipError> {
+            Ok(Shape::new(w)?
+                .log_period(ratio)
+                .map_or(-1.0, |(_, slope)| slope))
+        };
+
+        // Bracket the minimum of the period, where the slope changes sign, and bisect.
+        let w_max = max_w();
+        let (mut lo, mut hi) = (0.0, 1.0);
+        while slope(hi)? <= 0.0 && hi < w_max {
+            lo = hi;
+            hi = (2.0 * hi).min(w_max);
+        }
+        for _ in 0..MAX_ITERATIONS {
+            let mid = 0.5 * (lo + hi);
+            if slope(mid)? <= 0.0 {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+            if hi - lo <= epsilon!() * hi {
+                break;
+            }
+        }
+
+        let log_period = |w: T| -> Result<(T, T), EllipError> {
+            let (value, slope) = Shape::new(w)?.log_period(ratio).unwrap_or((nan!(), nan!()));
+            Ok((value - target, slope))
+        };
+        let (shortest, _) = log_period(hi)?;
+        if shortest > 0.0 {
+            return err!(
+                DomainError,
+                CnoidalWave,
+                "The period is shorter than the minimum period of the cnoidal wave.",
+                [height, depth, period]
+            );
+        }
+        let (longest, _) = log_period(w_max)?;
+        if longest < 0.0 {
+            return err!(
+                DomainError,
+                CnoidalWave,
+                "The period is too long.",
+                [height, depth, period]
+            );
+        }
+        let w = match solve_increasing(hi, w_max, hi, log_period)? {
+            Some(w) => w,
+            None => return err!(NoConvergence, CnoidalWave, "Failed to converge."),
+        };
+        Ok(Self::from_shape(height, depth, gravity, &Shape::new(w)?))
+    }
+
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn from_shape(height: T, depth: T, gravity: T, shape: &Shape<T>) -> Self {
+        let ratio = height / depth;
+        let (ursell, _) = shape.ursell();
+        let (c, _) = shape.celerity(ratio);
+        let modulus = shape.modulus;
+        Self {
+            height,
+            depth,
+            gravity,
+            wavelength: depth * (ursell / ratio).sqrt(),
+            celerity: (gravity * depth).sqrt() * c,
+            // η₂ = H (D / K - 1) without the cancellation of 1 - m - E / K for small m
+            trough: height * (modulus.ellipd() / shape.k - 1.0),
+            ursell,
+            modulus,
+        }
+    }
+
+    /// Returns the wave height H.
+    pub fn height(&self) -> T {
+        self.height
+    }
+
+    /// Returns the mean water depth h.
+    pub fn depth(&self) -> T {
+        self.depth
+    }
+
+    /// Returns the gravitational acceleration g.
+    pub fn gravity(&self) -> T {
+        self.gravity
+    }
+
+    /// Returns the parameter m of the Jacobi elliptic function cn.
+    pub fn m(&self) -> T {
+        self.modulus.m()
+    }
+
+    /// Returns the complementary modulus kc = √(1 - m), which keeps the digits of 1 - m near
+    /// the solitary wave.
+    pub fn kc(&self) -> T {
+        self.modulus.kc()
+    }
+
+    /// Returns the Ursell number U = Hλ² / h³.
+    pub fn ursell_number(&self) -> T {
+        self.ursell
+    }
+
+    /// Returns the wavelength λ.
+    pub fn wavelength(&self) -> T {
+        self.wavelength
+    }
+
+    /// Returns the celerity c.
+    pub fn celerity(&self) -> T {
+        self.celerity
+    }
+
+    /// Returns the period T = λ / c.
+    pub fn period(&self) -> T {
+        self.wavelength / self.celerity
+    }
+
+    /// Returns the elevation of the trough above the mean water level, η₂ ≤ 0.
+    pub fn trough(&self) -> T {
+        self.trough
+    }
+
+    /// Returns the elevation of the crest above the mean water level, η₁ = η₂ + H.
+    pub fn crest(&self) -> T {
+        self.trough + self.height
+    }
+
+    /// Returns the elevation η(x, t) of the free surface above the mean water level.
+    ///
+    /// The crest passes x = 0 at t = 0.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn elevation(&self, x: T, t: T) -> T {
+        let u = 2.0 * self.modulus.ellipk() * (x - self.celerity * t) / self.wavelength;
+        let (_, cn, _) = self.modulus.sn_cn_dn(u);
+        self.trough + self.height * cn * cn
+    }
+}
+
+/// Computes the Ursell number of the cnoidal wave with the parameter m.
+/// ```text
+///       Hλ²    16
+/// U  =  ─── =  ── m K²(m)
+///        h³     3
+/// ```
+///
+/// ## Parameters
+/// - m: elliptic parameter. m ∈ ℝ, 0 ≤ m < 1.
+///
+/// ## Domain
+/// - Returns error if m < 0 or m ≥ 1.
+/// - Returns error if m is NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{cnoidal::ursell_number, util::assert_close};
+///
+/// assert_close(ursell_number(0.9).unwrap(), 31.90348293555864, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ursell_number<T: Float>(m: T) -> Result<T, EllipError> {
+    check!(@nan, ursell_number, [m]);
+    if m < 0.0 {
+        return err!(DomainError, ursell_number, "m cannot be negative.", m);
+    }
+    if m >= 1.0 {
+        return err!(DomainError, ursell_number, "m must be less than 1.", m);
+    }
+    let modulus = EllipticModulus::new(m)?;
+    Ok(16.0 / 3.0 * m * modulus.ellipk() * modulus.ellipk())
+}
+
+/// Computes the parameter m of the cnoidal wave with the Ursell number U = Hλ² / h³, the
+/// inverse of [ursell_number].
+///
+/// Solved by Newton's method safeguarded by bisection in w = -ln(1 - m), for which
+/// dU/dw = 16KE / 3. For large U, m rounds to 1 although 1 - m ≈ 16e^(-2K) with
+/// K ≈ √(3U / 16) remains representable; use [CnoidalWave::kc] to keep these digits.
+///
+/// ## Parameters
+/// - ursell: Ursell number (U). U ∈ ℝ, U ≥ 0.
+///
+/// ## Domain
+/// - Returns error if U < 0.
+/// - Returns error if U is too large for 1 - m to be represented, or U is NAN.
+///
+/// ## Special Cases
+/// - m = 0 if U = 0.
+///
+/// # Examples
+/// ```
+/// use ellip::{cnoidal::parameter_from_ursell, util::assert_close};
+///
+/// assert_close(parameter_from_ursell(31.90348293555864).unwrap(), 0.9, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn parameter_from_ursell<T: Float>(ursell: T) -> Result<T, EllipError> {
+    check!(@nan, parameter_from_ursell, [ursell]);
+    if ursell < 0.0 {
+        return err!(
+            DomainError,
+            parameter_from_ursell,
+            "ursell cannot be negative.",
+            ursell
+        );
+    }
+    if ursell == 0.0 {
+        return Ok(0.0);
+    }
+    if ursell > max_ursell()? {
+        return err!(
+            DomainError,
+            parameter_from_ursell,
+            "The Ursell number is too large.",
+            ursell
+        );
+    }
+    match solve_ursell(ursell)? {
+        Some(w) => Ok(-(-w).exp_m1()),
+        None => err!(NoConvergence, parameter_from_ursell, "Failed to converge."),
+    }
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+
+    /// mpmath references of the first-order relations solved with findroot,
+    /// (H, h, T, g, kc, λ, c, η₂).
+    const PERIOD_REFERENCES: [[f64; 8]; 4] = [
+        [
+            2.0,
+            5.0,
+            10.0,
+            9.81,
+            0.07038341679338873,
+            73.64747236067157,
+            7.364747236067156,
+            -0.4914464436823229,
+        ],
+        [
+            1.0,
+            10.0,
+            20.0,
+            9.81,
+            0.2529243471943491,
+            197.13364986257534,
+            9.856682493128767,
+            -0.3427679086413214,
+        ],
+        [
+            0.5,
+            2.0,
+            30.0,
+            9.81,
+            5.26828716493494e-7,
+            146.34845259054526,
+            4.878281753018175,
+            -0.031560307415273395,
+        ],
+        [
+            0.3,
+            8.0,
+            12.0,
+            9.81,
+            0.7932986014122893,
+            102.09862974986316,
+            8.508219145821931,
+            -0.14134080483094238,
+        ],
+    ];
+
+    #[test]
+    fn test_from_period() {
+        for [height, depth, period, gravity, kc, wavelength, celerity, trough] in PERIOD_REFERENCES
+        {
+            let wave = CnoidalWave::from_period(height, depth, period, gravity).unwrap();
+            assert_close!(kc, wave.kc(), 1e-13);
+            assert_close!(wavelength, wave.wavelength(), 1e-14);
+            assert_close!(celerity, wave.celerity(), 1e-14);
+            assert_close!(trough, wave.trough(), 1e-14);
+            assert_close!(period, wave.period(), 1e-14);
+            assert_close!(height + trough, wave.crest(), 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_from_wavelength() {
+        // mpmath references, (H, h, λ, g, m, c, T, η₂)
+        for [height, depth, wavelength, gravity, m, celerity, period, trough] in [
+            [
+                1.0,
+                5.0,
+                100.0,
+                9.81,
+                0.9931945454231611,
+                7.162899317251081,
+                13.960827253171162,
+                -0.25521712783542416,
+            ],
+            [
+                0.2,
+                4.0,
+                20.0,
+                9.81,
+                0.09060859151949607,
+                4.616911131041438,
+                4.331900578620969,
+                -0.09881289336679115,
+            ],
+        ] {
+            let wave = CnoidalWave::from_wavelength(height, depth, wavelength, gravity).unwrap();
+            assert_close!(m, wave.m(), 1e-14);
+            assert_close!(celerity, wave.celerity(), 1e-14);
+            assert_close!(period, wave.period(), 1e-14);
+            assert_close!(trough, wave.trough(), 1e-14);
+            assert_close!(
+                height * wavelength * wavelength / depth.powi(3),
+                wave.ursell_number(),
+                1e-14
+            );
+        }
+
+        // Both constructors give the same wave on the branch of the long waves.
+        for [height, depth, period, gravity, ..] in PERIOD_REFERENCES {
+            let wave = CnoidalWave::from_period(height, depth, period, gravity).unwrap();
+            let same =
+                CnoidalWave::from_wavelength(height, depth, wave.wavelength(), gravity).unwrap();
+            assert_close!(wave.kc(), same.kc(), 1e-12);
+            assert_close!(period, same.period(), 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_elevation() {
+        // mpmath references, (x, t, η(x, t)) of the waves in PERIOD_REFERENCES
+        let references = [
+            [
+                [18.411868090167893, 0.0, -0.35993577995354326],
+                [22.09424170820147, 1.7, 0.282952517796604],
+            ],
+            [
+                [49.283412465643835, 0.0, -0.14090069474231942],
+                [59.14009495877261, 1.7, -0.05001816097804858],
+            ],
+            [
+                [36.587113147636316, 0.0, -0.031560044001053916],
+                [43.90453577716358, 1.7, -0.03155990551687476],
+            ],
+            [
+                [25.52465743746579, 0.0, -0.008630290120084337],
+                [30.62958892495895, 1.7, 0.07759544663816494],
+            ],
+        ];
+        for ([height, depth, period, gravity, ..], points) in
+            PERIOD_REFERENCES.iter().zip(references)
+        {
+            let wave = CnoidalWave::from_period(*height, *depth, *period, *gravity).unwrap();
+            for [x, t, expected] in points {
+                assert_close!(expected, wave.elevation(x, t), 1e-13);
+            }
+
+            // The crest at x = 0 and the trough half a wavelength away
+            let lambda = wave.wavelength();
+            assert_close!(wave.crest(), wave.elevation(0.0, 0.0), 1e-15);
+            assert_close!(wave.trough(), wave.elevation(0.5 * lambda, 0.0), 1e-13);
+            assert_close!(
+                wave.elevation(0.2 * lambda, 0.0),
+                wave.elevation(0.2 * lambda + wave.celerity(), 1.0),
+                1e-13
+            );
+
+            // The elevation averages to zero over a wavelength.
+            let n = 4000;
+            let mean = (0..n)
+                .map(|i| wave.elevation(lambda * i as f64 / n as f64, 0.0))
+                .sum::<f64>()
+                / n as f64;
+            assert!(mean.abs() < 1e-14 * height, "{mean}");
+        }
+    }
+
+    #[test]
+    fn test_ursell_number() {
+        // mpmath references of 16mK²/3
+        for (m, expected) in [
+            (0.1, 1.3866491216334293),
+            (0.5, 9.166914424027164),
+            (0.9, 31.90348293555864),
+            (0.999999, 366.88651141105726),
+        ] {
+            assert_close!(expected, ursell_number(m).unwrap(), 1e-15);
+            assert_close!(m, parameter_from_ursell(expected).unwrap(), 1e-14);
+        }
+        assert_eq!(ursell_number(0.0), Ok(0.0));
+        assert_eq!(parameter_from_ursell(0.0), Ok(0.0));
+        // U ≈ 4π²m / 3 for small m
+        assert_close!(
+            3e-12 / (4.0 * std::f64::consts::PI.powi(2)),
+            parameter_from_ursell(1e-12).unwrap(),
+            1e-11
+        );
+        assert_close!(
+            3e-300 / (4.0 * std::f64::consts::PI.powi(2)),
+            parameter_from_ursell(1e-300).unwrap(),
+            1e-15
+        );
+        assert_eq!(parameter_from_ursell(1e6), Ok(1.0));
+
+        assert_eq!(
+            ursell_number(-0.1).unwrap_err(),
+            "ursell_number: m cannot be negative."
+        );
+        assert_eq!(
+            ursell_number(1.0).unwrap_err(),
+            "ursell_number: m must be less than 1."
+        );
+        assert_eq!(
+            ursell_number(f64::INFINITY).unwrap_err(),
+            "ursell_number: m must be less than 1."
+        );
+        assert_eq!(
+            parameter_from_ursell(-1.0).unwrap_err(),
+            "parameter_from_ursell: ursell cannot be negative."
+        );
+        assert_eq!(
+            parameter_from_ursell(1e7).unwrap_err(),
+            "parameter_from_ursell: The Ursell number is too large."
+        );
+        assert_eq!(
+            parameter_from_ursell(f64::INFINITY).unwrap_err(),
+            "parameter_from_ursell: The Ursell number is too large."
+        );
+    }
+
+    #[test]
+    fn test_cnoidal_errors() {
+        assert_eq!(
+            CnoidalWave::from_period(0.3, 8.0, 6.0, 9.81).unwrap_err(),
+            "CnoidalWave: The period is shorter than the minimum period of the cnoidal wave."
+        );
+        assert_eq!(
+            CnoidalWave::from_period(1.0, 10.0, 1e6, 9.81).unwrap_err(),
+            "CnoidalWave: The period is too long."
+        );
+        assert_eq!(
+            CnoidalWave::from_wavelength(1.0, 2.0, 3.0, 9.81).unwrap_err(),
+            "CnoidalWave: The celerity must be positive."
+        );
+        assert_eq!(
+            CnoidalWave::from_wavelength(1.0, 1.0, 1e4, 9.81).unwrap_err(),
+            "CnoidalWave: The Ursell number is too large."
+        );
+        assert_eq!(
+            CnoidalWave::from_period(0.0, 1.0, 1.0, 9.81).unwrap_err(),
+            "CnoidalWave: Arguments must be positive."
+        );
+        assert_eq!(
+            CnoidalWave::from_wavelength(1.0, 1.0, 1.0, -9.81).unwrap_err(),
+            "CnoidalWave: Arguments must be positive."
+        );
+        assert_eq!(
+            CnoidalWave::from_period(1.0, f64::NAN, 1.0, 9.81).unwrap_err(),
+            "CnoidalWave: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            CnoidalWave::from_period(1.0, 1.0, f64::INFINITY, 9.81).unwrap_err(),
+            "CnoidalWave: period cannot be infinite."
+        );
+    }
+}
@@ -5,52 +5,49 @@
 
 /// Macro to conditionally return error.
 macro_rules! check {
-    (@return Err, $fn_name:ident, $var:ident, $value_name:expr) => {
-        return Err(concat![stringify!($fn_name), ": ", stringify!($var), " cannot be ", $value_name, "."])
-    };
-    ($fn_name:ident, $check_method:ident, $value_name:expr, [$($var:ident),* $(,)?] $(,)?) => {
+    ($kind:ident, $fn_name:ident, $check_method:ident, $value_name:expr, [$($var:ident),* $(,)?] $(,)?) => {
         $(
             if $var.$check_method() {
-                check!(@return Err, $fn_name, $var, $value_name);
-            }
-        )*
-    };
-    ($fn_name:ident, ($predicate:expr), $value_name:expr, [$($var:ident),* $(,)?] $(,)?) => {
-        $(
-            if $predicate {
-                check!(@return Err, $fn_name, $var, $value_name);
+                return $crate::error::err!($kind, $fn_name, concat![stringify!($var), " cannot be ", $value_name, "."], $var);
             }
         )*
     };
     (@nan, $fn_name:ident, [$($var:ident),* $(,)?] $(,)?) => {{
         $(
             if $var.is_nan() {
-                return Err(concat![stringify!($fn_name), ": Arguments cannot be NAN."])
+                return $crate::error::err!(NanArgument, $fn_name, "Arguments cannot be NAN.", [$var]);
             }
         )*
 
     }};
-    (@zero, $fn_name:ident, [$($var:ident),* $(,)?] $(,)?) => {
-        check!($fn_name, is_zero, "zero", [$($var),*])
+    (@zero, $fn_name:ident, [$($var:ident),* $(,)?], limit: $limit:expr $(,)?) => {
+        $(
+            if $var.is_zero() {
+                return $crate::error::err!(Singularity, $fn_name, concat![stringify!($var), " cannot be zero."], $var, limit: $limit);
+            }
+        )*
+    };
+    (@zero $kind:ident, $fn_name:ident, [$($var:ident),* $(,)?] $(,)?) => {
+        check!($kind, $fn_name, is_zero, "zero", [$($var),*])
     };
     (@inf, $fn_name:ident, [$($var:ident),* $(,)?] $(,)?) => {
-        check!($fn_name, is_infinite, "infinite", [$($var),*])
+        check!(InfiniteArgument, $fn_name, is_infinite, "infinite", [$($var),*])
     };
     (@neg, $fn_name:ident, $msg:expr, [$first:ident, $($var:ident),* $(,)?] $(,)?) => {
         if $first$(.min($var))* < T::zero() {
-            return Err(concat![stringify!($fn_name), ": ", $msg]);
+            return $crate::error::err!(DomainError, $fn_name, $msg, [$first, $($var),*]);
         }
     };
     (@neg, $fn_name:ident, [$first:ident, $($var:ident),* $(,)?] $(,)?) => {
         check!(@neg, $fn_name, "Arguments must be non-negative.", [$first, $($var),*])
     };
-    (@multi_zero, $fn_name:ident, [$($var:ident),* $(,)?] $(,)?) => {{
+    (@multi_zero, $fn_name:ident, [$($var:ident),* $(,)?], limit: $limit:expr $(,)?) => {{
         let mut count: u8 = 0;
         $(
             count += $var.is_zero() as u8;
         )*
         if count > 1 {
-            return Err(concat![stringify!($fn_name), ": At most one argument can be zero."]);
+            return $crate::error::err!(Singularity, $fn_name, "At most one argument can be zero.", [$($var),*], limit: $limit);
         }
     }};
     (@multi, $fn_name:ident, $value_name:expr, $check_method:ident, [$first:ident, $($var:ident),* $(,)?] $(,)?) => {{
@@ -62,22 +59,97 @@ macro_rules! check {
                 count += 1;
             }
             if count >= 2 {
-                return Err(
+                return $crate::error::err!(
+                    InfiniteArgument,
+                    $fn_name,
                     concat![
-                        stringify!($fn_name),
-                        ": More than one argument in ",
+                        "More than one argument in ",
                         stringify!($first), $(", ", stringify!($var), )*
                         " cannot be ",
                         $value_name,
                         "."
-                    ]
-                )
+                    ],
+                    [$first, $($var),*]
+                );
             }
         }
     }};
 }
 pub(crate) use check;
 
+/// Returns true if T is more precise than f64.
+///
+/// The polynomial approximations are tuned for f64, so they are skipped for such types.
+#[inline]
+pub(crate) fn is_extended_precision<T: num_traits::Float>() -> bool {
+    T::epsilon() < T::from(f64::EPSILON).unwrap()
+}
+
+/// Defines the test module reaching otherwise unreachable code with `test_force_fail`.
+/// Defined outside of `test_util` so the feature also builds without `cfg(test)`.
+#[cfg(feature = "test_force_fail")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! test_force_unreachable {
+    ($($inner:tt)*) => {
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #[test]
+            fn force_unreachable() {
+                $($inner)*
+            }
+        }
+    };
+}
+
+/// Returns a power of two within a factor of four of |x| > 0, so that dividing by it is exact.
+/// The exponent is rounded toward zero, so the power itself never overflows or underflows.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub(crate) fn pow2_scale<T: num_traits::Float>(x: T) -> T {
+    let exp = x.abs().log2().floor().to_i32().unwrap();
+    if exp < 0 {
+        0.5.powi(-exp - 1)
+    } else {
+        2.0.powi((exp - 1).max(0))
+    }
+}
+
+/// Solves f(w) = 0 for increasing f on [lo, hi] by Newton's method safeguarded by bisection,
+/// where f returns the value and the slope. Newton's step may land on lo, so that a root at
+/// lo = 0 is found. Returns None if not converged after 200 iterations, for the caller to
+/// report.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub(crate) fn solve_increasing<T: num_traits::Float>(
+    mut lo: T,
+    mut hi: T,
+    mut w: T,
+    f: impl Fn(T) -> Result<(T, T), crate::EllipError>,
+) -> Result<Option<T>, crate::EllipError> {
+    for _ in 0..200 {
+        let (value, slope) = f(w)?;
+        if value == 0.0 {
+            return Ok(Some(w));
+        }
+        if value < 0.0 {
+            lo = w;
+        } else {
+            hi = w;
+        }
+        let mut next = w - value / slope;
+        if !(next >= lo && next < hi) {
+            next = 0.5 * (lo + hi);
+        }
+        let done = (next - w).abs() <= epsilon!() * next;
+        w = next;
+        if done || hi - lo <= epsilon!() * hi {
+            return Ok(Some(w));
+        }
+    }
+    Ok(None)
+}
+
 macro_rules! case {
     ($predicate:expr, $res:expr) => {
         if $predicate {
@@ -113,3 +185,55 @@ macro_rules! let_mut {
     };
 }
 pub(crate) use let_mut;
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pow2_scale() {
+        for x in [
+            1.0,
+            3.0,
+            0.3,
+            -1e-200,
+            1e200,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+        ] {
+            let scale = pow2_scale(x);
+            assert_eq!(scale.log2().fract(), 0.0);
+            assert!(x.abs() / scale >= 0.25 && x.abs() / scale <= 4.0);
+        }
+        assert_eq!(pow2_scale(1e20f32), 2f32.powi(65));
+    }
+
+    #[test]
+    fn test_solve_increasing() {
+        // w³ + w - 10 = 0 at w = 2
+        let w = solve_increasing(0.0, 10.0, 1.0, |w: f64| {
+            Ok((w * w * w + w - 10.0, 3.0 * w * w + 1.0))
+        });
+        assert_eq!(w, Ok(Some(2.0)));
+        // Roots at and near the lower bound
+        assert_eq!(
+            solve_increasing(0.0, 1.0, 0.5, |w: f64| Ok((w, 1.0))),
+            Ok(Some(0.0))
+        );
+        assert_eq!(
+            solve_increasing(0.0, 1.0, 0.5, |w: f64| Ok((w * w + w, 2.0 * w + 1.0))),
+            Ok(Some(0.0))
+        );
+        assert_eq!(
+            solve_increasing(0.0, 1.0, 0.5, |w: f64| Ok((w - 1e-300, 1.0))),
+            Ok(Some(1e-300))
+        );
+        // Bisection alone does not reach the root.
+        assert_eq!(
+            solve_increasing(0.0, 1.0, 0.5, |w: f64| Ok((w - 1e-300, 1e-300))),
+            Ok(None)
+        );
+    }
+}
@@ -0,0 +1,728 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Magnetic fields of finite solenoids and uniformly magnetized cylinders.
+//!
+//! The cylinder of radius a and length l is centered at the origin with its axis along the
+//! z-axis, and the field point is given in cylindrical coordinates (ρ, φ, z). Each end of the
+//! cylinder at z = ±l/2 contributes terms of the distances
+//! ```text
+//! ζ± = z ± l/2,  α± = 1 / √(ζ±² + (a + ρ)²),  γ = (a - ρ) / (a + ρ),
+//! kc± = √(ζ±² + (a - ρ)²) α±
+//! ```
+//! and the field of the ideal solenoid is (Derby and Olbert, 2010)
+//! ```text
+//!         μ₀nI
+//! B_ρ  =  ──── [α₊ cel(kc₊, 1, 1, -1) - α₋ cel(kc₋, 1, 1, -1)]
+//!          π
+//!
+//!         μ₀nI    a
+//! B_z  =  ──── ───── [ζ₊α₊ cel(kc₊, γ², 1, γ) - ζ₋α₋ cel(kc₋, γ², 1, γ)]
+//!          π   a + ρ
+//! ```
+//! The axially magnetized cylinder has the same B-field with μ₀nI replaced by the polarization
+//! J = μ₀M. The diametrically magnetized cylinder (Caciagli et al., 2018) is expressed by the
+//! vector potential and the field of the solenoid and the vector potential of its end loops.
+//!
+//! cel(kc, 1, 1, -1) is evaluated by the descending Landen transformation as in [mod@crate::ring],
+//! and the vector potential divided by ρ by a power series near the axis and far from the
+//! cylinder, so the fields keep their relative precision on the axis, at the walls, and away
+//! from the cylinder up to the cancellation between the two ends, which loses about
+//! log₁₀(r / l) digits at the distance r ≫ l.
+//!
+//! The fields are discontinuous across the surface of the cylinder and return the mean of both
+//! sides there. They diverge logarithmically at the edges, where ρ = a and z = ±l/2.
+//!
+//! # Examples
+//! ```
+//! use ellip::{cylinder::*, util::assert_close};
+//!
+//! // At the center of the solenoid, B_z = μ₀nI l / √(l² + 4a²).
+//! let (b_rho, b_z) = solenoid_field(1.0, 4.0, 0.0, 0.0).unwrap();
+//! assert_eq!(b_rho, 0.0);
+//! assert_close(b_z, 4.0 / 20.0f64.sqrt(), 1e-15);
+//!
+//! // Inside a long diametrically magnetized cylinder, H ≈ -M/2 along the magnetization.
+//! let (h_rho, h_phi, h_z) = diametric_cylinder_h_field(1.0, 1e4, 0.5, 0.0, 0.0f64).unwrap();
+//! assert_close(h_rho, -0.5, 1e-7);
+//! assert!(h_phi.abs() < 1e-15 && h_z.abs() < 1e-15);
+//! ```
+//!
+//! # References
+//! - Caciagli, Alessio, Roel J. Baars, Albert P. Philipse, and Bonny W. M. Kuipers. “Exact Expression for the Magnetic Field of a Finite Cylinder with Arbitrary Uniform Magnetization.” Journal of Magnetism and Magnetic Materials 456 (2018): 423–32. <https://doi.org/10.1016/j.jmmm.2018.02.003>.
+//! - Derby, Norman, and Stanislaw Olbert. “Cylindrical Magnets and Ideal Solenoids.” American Journal of Physics 78, no. 3 (2010): 229–35. <https://doi.org/10.1119/1.3256157>.
+
+use num_traits::Float;
+
+use crate::{
+    bulirsch::BulirschConst,
+    cel,
+    crate_util::{check, pow2_scale},
+    error::err,
+    ring::current_loop_vector_potential,
+    EllipError,
+};
+
+/// Maximum number of terms of the series of the vector potential.
+const MAX_TERMS: usize = 100;
+
+/// Validates the arguments and returns early for field points at infinity.
+macro_rules! check_cylinder {
+    ($fn_name:ident, $a:ident, $l:ident, $rho:ident, $z:ident, $at_infinity:expr) => {
+        check!(@nan, $fn_name, [$a, $l, $rho, $z]);
+        check!(@inf, $fn_name, [$a, $l]);
+        if $a <= T::zero() {
+            return err!(DomainError, $fn_name, "a must be positive.", $a);
+        }
+        if $l <= T::zero() {
+            return err!(DomainError, $fn_name, "l must be positive.", $l);
+        }
+        if $rho < T::zero() {
+            return err!(DomainError, $fn_name, "rho cannot be negative.", $rho);
+        }
+        if $rho.is_infinite() || $z.is_infinite() {
+            return Ok($at_infinity);
+        }
+    };
+}
+
+/// Field of the unit solenoid (B_ρ, B_z) and its vector potential divided by ρ, A_φ / ρ.
+struct Solenoid<T> {
+    b_rho: T,
+    b_z: T,
+    a_rho: T,
+}
+
+impl<T: Float + BulirschConst<T>> Solenoid<T> {
+    /// Computes the solenoid of radius one and half-length h at (x, z), or returns None at the
+    /// edges.
+    fn new(x: T, h: T, z: T) -> Result<Option<Self>, EllipError> {
+        if x.is_infinite() {
+            return Ok(Some(Solenoid {
+                b_rho: T::zero(),
+                b_z: T::zero(),
+                a_rho: T::zero(),
+            }));
+        }
+        let (Some(upper), Some(lower)) = (Self::end(x, z + h)?, Self::end(x, z - h)?) else {
+            return Ok(None);
+        };
+        Ok(Some(Solenoid {
+            b_rho: upper.b_rho - lower.b_rho,
+            b_z: upper.b_z - lower.b_z,
+            a_rho: upper.a_rho - lower.a_rho,
+        }))
+    }
+
+    /// Computes the terms of the end of the solenoid at the axial distance ζ.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn end(x: T, zeta: T) -> Result<Option<Self>, EllipError> {
+        // kc, 1 / √p, ζ / √p, and m = 4x / p, where the distances are scaled by a power of two
+        // so that p = ζ² + (1 + x)² does not overflow or underflow.
+        let (kc, alpha, zeta_alpha, m) = if zeta.is_infinite() {
+            (1.0, 0.0, zeta.signum(), 0.0)
+        } else {
+            let s = pow2_scale(zeta.abs().max(1.0 + x));
+            let sp = (zeta / s).hypot((1.0 + x) / s);
+            let sq = (zeta / s).hypot((1.0 - x) / s);
+            if sq == 0.0 {
+                return Ok(None);
+            }
+            (
+                sq / sp,
+                1.0 / (sp * s),
+                zeta / s / sp,
+                4.0 * (x / s) / (s * sp * sp),
+            )
+        };
+        let gamma = (1.0 - x) / (1.0 + x);
+
+        // cel(kc, 1, 1, -1) = -2(1 - kc) / (1 + kc)² cel(λc, 1, 0, 1)
+        let landen = 2.0 * kc.sqrt() / (1.0 + kc);
+        let one_minus_kc = m / (1.0 + kc);
+        let c = -2.0 * one_minus_kc / ((1.0 + kc) * (1.0 + kc)) * cel(landen, 1.0, 0.0, 1.0)?;
+
+        let b_z = if gamma == 0.0 {
+            cel(kc, 1.0, 1.0, 1.0)?
+        } else {
+            cel(kc, gamma * gamma, 1.0, gamma)?
+        };
+
+        let j = sin2_cos2_integral(4.0 * x / (1.0 + x) / (1.0 + x), m, kc, gamma)?;
+
+        Ok(Some(Solenoid {
+            b_rho: alpha * c / pi!(),
+            b_z: zeta_alpha * b_z / (pi!() * (1.0 + x)),
+            a_rho: 4.0 * zeta_alpha * j / (pi!() * (1.0 + x) * (1.0 + x)),
+        }))
+    }
+}
+
+/// Computes the integral of sin²θ cos²θ / (1 - n sin²θ) √(1 - m sin²θ) over [0, π/2] for
+/// 0 ≤ m ≤ n ≤ 1 or n = 0, where kc² = 1 - m and γ² = 1 - n.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub(crate) fn sin2_cos2_integral<T: Float + BulirschConst<T>>(
+    n: T,
+    m: T,
+    kc: T,
+    gamma: T,
+) -> Result<T, EllipError> {
+    if n.max(m) < 0.1 {
+        return Ok(sin2_cos2_series(n, m, 0.5));
+    }
+    if n == 0.0 {
+        // (1 + kc²)E - 2kc²K = m cel(kc, 1, 1, -kc²)
+        return Ok(cel(kc, 1.0, 1.0, -kc * kc)? / (3.0 * m));
+    }
+
+    // Divided difference of cel(kc, p, 1, 0) between p = γ² and 1
+    let c = if gamma == 0.0 {
+        cel(kc, 1.0, 1.0, 1.0)?
+    } else {
+        cel(kc, gamma * gamma, 1.0, 0.0)?
+    };
+    Ok((c - cel(kc, 1.0, 1.0, 0.0)?) / n)
+}
+
+/// Sums the series of the integral of sin²θ cos²θ / (1 - n sin²θ) (1 - m sin²θ)ˢ over
+/// [0, π/2] for 0 ≤ m ≤ n < 1 or n = 0, which converges like max(n, m)ⁱ.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub(crate) fn sin2_cos2_series<T: Float>(n: T, m: T, s: T) -> T {
+    // Σ W_{i+1} Σ c_j m^j n^(i-j), where W_i is the integral of sin^(2i)θ cos²θ and c_j are
+    // the coefficients of (1 - t)^(-s).
+    let mut w = pi!() / 16.0;
+    let (mut c, mut mj) = (1.0, 1.0);
+    let mut sum = 1.0;
+    let mut ans = w;
+    for i in 1..MAX_TERMS {
+        let i = T::from(i).unwrap();
+        w = w * (i + 0.5) / (i + 2.0);
+        c = c * (i - 1.0 + s) / i;
+        mj = mj * m;
+        sum = n * sum + c * mj;
+        let term = w * sum;
+        ans = ans + term;
+        if term.abs() <= epsilon!() * ans {
+            break;
+        }
+    }
+    ans
+}
+
+/// Returns 1 inside the cylinder of radius one and half-length h, 1/2 on its surface, and 0
+/// outside.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn interior<T: Float>(x: T, h: T, z: T) -> T {
+    if x > 1.0 || z.abs() > h {
+        0.0
+    } else if x == 1.0 || z.abs() == h {
+        0.5
+    } else {
+        1.0
+    }
+}
+
+/// Computes the H-field of the diametrically magnetized cylinder of radius one and half-length
+/// h at (x, φ, z), or returns None at the edges.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn diametric_h_field<T: Float + BulirschConst<T>>(
+    x: T,
+    h: T,
+    phi: T,
+    z: T,
+) -> Result<Option<(T, T, T)>, EllipError> {
+    let Some(s) = Solenoid::new(x, h, z)? else {
+        return Ok(None);
+    };
+    let (sin, cos) = phi.sin_cos();
+    let h_z = current_loop_vector_potential(1.0, x, z - h)?
+        - current_loop_vector_potential(1.0, x, z + h)?;
+    Ok(Some((cos * (s.a_rho - s.b_z), sin * s.a_rho, cos * h_z)))
+}
+
+/// Computes the magnetic field (B_ρ, B_z) of the finite ideal solenoid per unit μ₀nI.
+///
+/// The solenoid of radius a and length l is centered at the origin with its axis along the
+/// z-axis, and its surface current nI flows counterclockwise about the z-axis. See
+/// [mod@crate::cylinder] for the formulas.
+///
+/// ## Parameters
+/// - a: radius. a ∈ ℝ, a > 0.
+/// - l: length. l ∈ ℝ, l > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, l ≤ 0, rho < 0, a or l is infinite, or any argument is NAN.
+/// - Returns error at the edges, where rho = a and z = ±l/2.
+///
+/// ## Special Cases
+/// - B_ρ = 0 for rho = 0 or z = 0.
+/// - B = (0, 0) for rho or z = ±∞.
+///
+/// # Examples
+/// ```
+/// use ellip::{cylinder::solenoid_field, util::assert_close};
+///
+/// let (b_rho, b_z) = solenoid_field(1.0, 2.0, 0.5, 0.8).unwrap();
+/// assert_close(b_rho, 0.11377497140609214, 1e-15);
+/// assert_close(b_z, 0.5608419045839395, 1e-15);
+/// ```
+pub fn solenoid_field<T: Float + BulirschConst<T>>(
+    a: T,
+    l: T,
+    rho: T,
+    z: T,
+) -> Result<(T, T), EllipError> {
+    check_cylinder!(solenoid_field, a, l, rho, z, (T::zero(), T::zero()));
+
+    let h = l / (a + a);
+    let Some(s) = Solenoid::new(rho / a, h, z / a)? else {
+        return err!(
+            Singularity,
+            solenoid_field,
+            "The point must not lie on the edges.",
+            [rho, z]
+        );
+    };
+    Ok((s.b_rho, s.b_z))
+}
+
+/// Computes the magnetic flux density (B_ρ, B_z) of the axially magnetized cylinder per unit
+/// polarization J = μ₀M.
+///
+/// The cylinder of radius a and length l is centered at the origin and magnetized along the
+/// z-axis. Its field equals the field of the solenoid with μ₀nI = J, see [solenoid_field].
+///
+/// ## Parameters
+/// - a: radius. a ∈ ℝ, a > 0.
+/// - l: length. l ∈ ℝ, l > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, l ≤ 0, rho < 0, a or l is infinite, or any argument is NAN.
+/// - Returns error at the edges, where rho = a and z = ±l/2.
+///
+/// # Examples
+/// ```
+/// use ellip::{cylinder::axial_cylinder_field, util::assert_close};
+///
+/// // Far away on the axis, the field approaches the dipole field J a² l / 2z³.
+/// let (_, b_z) = axial_cylinder_field(1.0, 2.0, 0.0, 1e3).unwrap();
+/// assert_close(b_z, 1e-9, 1e-5);
+/// ```
+pub fn axial_cylinder_field<T: Float + BulirschConst<T>>(
+    a: T,
+    l: T,
+    rho: T,
+    z: T,
+) -> Result<(T, T), EllipError> {
+    check_cylinder!(axial_cylinder_field, a, l, rho, z, (T::zero(), T::zero()));
+
+    let Some(s) = Solenoid::new(rho / a, l / (a + a), z / a)? else {
+        return err!(
+            Singularity,
+            axial_cylinder_field,
+            "The point must not lie on the edges.",
+            [rho, z]
+        );
+    };
+    Ok((s.b_rho, s.b_z))
+}
+
+/// Computes the magnetic field (H_ρ, H_z) of the axially magnetized cylinder per unit
+/// magnetization M.
+///
+/// The cylinder of radius a and length l is centered at the origin and magnetized along the
+/// z-axis. The field is H = B / μ₀ - M, where B is given by [axial_cylinder_field].
+///
+/// ## Parameters
+/// - a: radius. a ∈ ℝ, a > 0.
+/// - l: length. l ∈ ℝ, l > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, l ≤ 0, rho < 0, a or l is infinite, or any argument is NAN.
+/// - Returns error at the edges, where rho = a and z = ±l/2.
+///
+/// # Examples
+/// ```
+/// use ellip::{cylinder::axial_cylinder_h_field, util::assert_close};
+///
+/// // The demagnetizing field at the center of a thin disk approaches -M.
+/// let (_, h_z) = axial_cylinder_h_field(1.0, 1e-6, 0.0, 0.0).unwrap();
+/// assert_close(h_z, -1.0, 1e-6);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn axial_cylinder_h_field<T: Float + BulirschConst<T>>(
+    a: T,
+    l: T,
+    rho: T,
+    z: T,
+) -> Result<(T, T), EllipError> {
+    check_cylinder!(axial_cylinder_h_field, a, l, rho, z, (0.0, 0.0));
+
+    let h = l / (a + a);
+    let Some(s) = Solenoid::new(rho / a, h, z / a)? else {
+        return err!(
+            Singularity,
+            axial_cylinder_h_field,
+            "The point must not lie on the edges.",
+            [rho, z]
+        );
+    };
+    Ok((s.b_rho, s.b_z - interior(rho / a, h, z / a)))
+}
+
+/// Computes the magnetic field (H_ρ, H_φ, H_z) of the diametrically magnetized cylinder per
+/// unit magnetization M.
+/// ```text
+/// H_ρ  =  cos(φ) (A_φ / ρ - B_z)
+/// H_φ  =  sin(φ) A_φ / ρ
+/// H_z  =  cos(φ) (A(z - l/2) - A(z + l/2))
+/// ```
+/// where (B_ρ, B_z) and A_φ are the field and the vector potential of the solenoid with
+/// μ₀nI = 1, see [solenoid_field], and A(z) is the vector potential of its end loop, see
+/// [current_loop_vector_potential].
+///
+/// The cylinder of radius a and length l is centered at the origin with its axis along the
+/// z-axis and magnetized along the x-axis, from which the azimuth φ is measured.
+///
+/// ## Parameters
+/// - a: radius. a ∈ ℝ, a > 0.
+/// - l: length. l ∈ ℝ, l > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - phi: azimuth. phi ∈ ℝ.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, l ≤ 0, rho < 0, a, l, or phi is infinite, or any argument is NAN.
+/// - Returns error at the edges, where rho = a and z = ±l/2.
+///
+/// # Examples
+/// ```
+/// use ellip::{cylinder::diametric_cylinder_h_field, util::assert_close};
+///
+/// let (h_rho, h_phi, h_z) = diametric_cylinder_h_field(1.0, 2.0, 0.5, 0.3, 0.8).unwrap();
+/// assert_close(h_rho, -0.2744042115428237, 1e-15);
+/// assert_close(h_phi, 0.08085694587124232, 1e-15);
+/// assert_close(h_z, 0.10869338173346228, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn diametric_cylinder_h_field<T: Float + BulirschConst<T>>(
+    a: T,
+    l: T,
+    rho: T,
+    phi: T,
+    z: T,
+) -> Result<(T, T, T), EllipError> {
+    check_cylinder!(diametric_cylinder_h_field, a, l, rho, z, (0.0, 0.0, 0.0));
+    check!(@nan, diametric_cylinder_h_field, [phi]);
+    check!(@inf, diametric_cylinder_h_field, [phi]);
+
+    let Some(h) = diametric_h_field(rho / a, l / (a + a), phi, z / a)? else {
+        return err!(
+            Singularity,
+            diametric_cylinder_h_field,
+            "The point must not lie on the edges.",
+            [rho, z]
+        );
+    };
+    Ok(h)
+}
+
+/// Computes the magnetic flux density (B_ρ, B_φ, B_z) of the diametrically magnetized cylinder
+/// per unit polarization J = μ₀M.
+///
+/// The cylinder of radius a and length l is centered at the origin with its axis along the
+/// z-axis and magnetized along the x-axis, from which the azimuth φ is measured. The flux
+/// density is B = μ₀(H + M), where H is given by [diametric_cylinder_h_field].
+///
+/// ## Parameters
+/// - a: radius. a ∈ ℝ, a > 0.
+/// - l: length. l ∈ ℝ, l > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - phi: azimuth. phi ∈ ℝ.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, l ≤ 0, rho < 0, a, l, or phi is infinite, or any argument is NAN.
+/// - Returns error at the edges, where rho = a and z = ±l/2.
+///
+/// # Examples
+/// ```
+/// use ellip::{cylinder::diametric_cylinder_field, util::assert_close};
+///
+/// let (b_rho, b_phi, b_z) = diametric_cylinder_field(1.0, 2.0, 0.5, 0.3, 0.8).unwrap();
+/// assert_close(b_rho, 0.6809322775827823, 1e-15);
+/// assert_close(b_phi, -0.21466326079009726, 1e-15);
+/// assert_close(b_z, 0.10869338173346228, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn diametric_cylinder_field<T: Float + BulirschConst<T>>(
+    a: T,
+    l: T,
+    rho: T,
+    phi: T,
+    z: T,
+) -> Result<(T, T, T), EllipError> {
+    check_cylinder!(diametric_cylinder_field, a, l, rho, z, (0.0, 0.0, 0.0));
+    check!(@nan, diametric_cylinder_field, [phi]);
+    check!(@inf, diametric_cylinder_field, [phi]);
+
+    let (x, h, z) = (rho / a, l / (a + a), z / a);
+    let Some((h_rho, h_phi, h_z)) = diametric_h_field(x, h, phi, z)? else {
+        return err!(
+            Singularity,
+            diametric_cylinder_field,
+            "The point must not lie on the edges.",
+            [rho, z]
+        );
+    };
+    let m = interior(x, h, z);
+    let (sin, cos) = phi.sin_cos();
+    Ok((h_rho + m * cos, h_phi - m * sin, h_z))
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+
+    /// rho, z, B_ρ, B_z of the solenoid and H_ρ, H_φ, H_z of the diametrically magnetized
+    /// cylinder at φ = 0.3 for a = 1 and l = 2, computed by integrating the fields of the loops
+    /// over the length using mpmath.
+    const REFERENCES: [[f64; 7]; 9] = [
+        [
+            0.5,
+            0.8,
+            0.11377497140609214,
+            0.5608419045839395,
+            -0.2744042115428237,
+            0.080856945871242319,
+            0.10869338173346228,
+        ],
+        [
+            0.0,
+            0.3,
+            0.0,
+            0.68304316673396422,
+            -0.32626803041443065,
+            0.10092652889591846,
+            0.0,
+        ],
+        [
+            1e-7,
+            0.3,
+            8.079045136911356e-9,
+            0.68304316673396559,
+            -0.32626803041443164,
+            0.10092652889591856,
+            7.7182066165841957e-9,
+        ],
+        [
+            0.999,
+            0.2,
+            0.037404289945600084,
+            0.81780157693381634,
+            -0.4181066778164315,
+            0.11234133937097682,
+            0.03573368303486579,
+        ],
+        [
+            1.001,
+            0.2,
+            0.037396325482889793,
+            -0.18180381993626324,
+            0.53600895943065106,
+            0.1120802988280488,
+            0.035726074293022368,
+        ],
+        [
+            1.5,
+            1.0,
+            0.11642041096699072,
+            -0.031383665299384983,
+            0.11664242132934965,
+            0.02680722190434198,
+            0.11122066667576512,
+        ],
+        [
+            0.3,
+            1.0,
+            0.071109686901880039,
+            0.44840356429999319,
+            -0.21447096077370546,
+            0.06616867133370459,
+            0.067933678627663169,
+        ],
+        [
+            0.05,
+            0.05,
+            0.00066300031227045698,
+            0.70677560478544603,
+            -0.33768352853118029,
+            0.10440871651860876,
+            0.00063338839061363882,
+        ],
+        [
+            3.0,
+            0.0,
+            0.0,
+            -0.017405732941669126,
+            0.034006705187355908,
+            0.0053757608482226501,
+            0.0,
+        ],
+    ];
+
+    /// Compares magnitudes, since assert_close! divides by the signed value.
+    fn check(expected: f64, actual: f64, rtol: f64) {
+        assert_close!(expected.abs(), actual.abs(), rtol);
+        assert!(expected == 0.0 || expected.signum() == actual.signum());
+    }
+
+    #[test]
+    fn test_references() {
+        for [rho, z, b_rho, b_z, h_rho, h_phi, h_z] in REFERENCES {
+            let actual = solenoid_field(1.0, 2.0, rho, z).unwrap();
+            check(b_rho, actual.0, 5e-15);
+            check(b_z, actual.1, 5e-15);
+            assert_eq!(axial_cylinder_field(1.0, 2.0, rho, z), Ok(actual));
+
+            let actual = diametric_cylinder_h_field(1.0, 2.0, rho, 0.3, z).unwrap();
+            check(h_rho, actual.0, 5e-15);
+            check(h_phi, actual.1, 5e-15);
+            check(h_z, actual.2, 5e-15);
+        }
+
+        // Scaled cylinder
+        let (b_rho, b_z) = solenoid_field(3.0, 6.0, 1.5, 2.4).unwrap();
+        assert_close!(0.11377497140609214, b_rho, 5e-15);
+        assert_close!(0.5608419045839395, b_z, 5e-15);
+    }
+
+    #[test]
+    fn test_b_and_h() {
+        // B = μ₀(H + M) inside and B = μ₀H outside
+        for (rho, z, m) in [
+            (0.5, 0.8, 1.0),
+            (1.0, 0.2, 0.5),
+            (0.5, 1.0, 0.5),
+            (1.5, 0.8, 0.0),
+        ] {
+            let (b_rho, b_z) = axial_cylinder_field(1.0, 2.0, rho, z).unwrap();
+            let (h_rho, h_z) = axial_cylinder_h_field(1.0, 2.0, rho, z).unwrap();
+            assert_eq!((b_rho, b_z - m), (h_rho, h_z));
+
+            let phi = 2.0;
+            let (b_rho, b_phi, b_z) = diametric_cylinder_field(1.0, 2.0, rho, phi, z).unwrap();
+            let (h_rho, h_phi, h_z) = diametric_cylinder_h_field(1.0, 2.0, rho, phi, z).unwrap();
+            assert_close!(h_rho.abs(), (b_rho - m * phi.cos()).abs(), 1e-15);
+            assert_close!(h_phi.abs(), (b_phi + m * phi.sin()).abs(), 1e-15);
+            assert_eq!(h_z, b_z);
+        }
+
+        // The normal component of B is continuous across the wall.
+        let phi = 0.7;
+        let inner = diametric_cylinder_field(1.0, 2.0, 1.0 - 1e-12, phi, 0.4).unwrap();
+        let outer = diametric_cylinder_field(1.0, 2.0, 1.0 + 1e-12, phi, 0.4).unwrap();
+        assert_close!(inner.0, outer.0, 1e-10);
+        let (h_rho, ..) = diametric_cylinder_h_field(1.0, 2.0, 1.0 - 1e-12, phi, 0.4).unwrap();
+        assert!(h_rho < 0.0);
+    }
+
+    #[test]
+    fn test_limits() {
+        // Long solenoid and cylinder
+        let (_, b_z) = solenoid_field(1.0, 1e6, 0.7, 0.0).unwrap();
+        assert_close!(1.0, b_z, 1e-11);
+        let (h_rho, h_phi, _) = diametric_cylinder_h_field(1.0, 1e6, 0.7, 0.4, 0.0).unwrap();
+        assert_close!(0.5 * 0.4f64.cos(), -h_rho, 1e-11);
+        assert_close!(0.5 * 0.4f64.sin(), h_phi, 1e-11);
+
+        // Thin disk
+        let (_, h_z) = axial_cylinder_h_field(1.0, 1e-9, 0.3, 0.0).unwrap();
+        assert_close!(1.0, -h_z, 1e-8);
+        let (h_rho, ..) = diametric_cylinder_h_field(1.0, 1e-9, 0.3, 0.0, 0.0).unwrap();
+        assert!(h_rho.abs() < 1e-8);
+
+        // Dipole far field, H = (3(m·r̂)r̂ - m) / 4πr³ with m = πa²l M
+        let m = std::f64::consts::PI * 2.0 / (4.0 * std::f64::consts::PI);
+        let (rho, z) = (600.0, 800.0);
+        let r = 1e3f64;
+        let (b_rho, b_z) = axial_cylinder_field(1.0, 2.0, rho, z).unwrap();
+        assert_close!(m * 3.0 * rho * z / r.powi(5), b_rho, 1e-5);
+        assert_close!(m * (3.0 * z * z - r * r) / r.powi(5), b_z, 1e-5);
+        let (h_rho, h_phi, h_z) = diametric_cylinder_h_field(1.0, 2.0, rho, 0.0, z).unwrap();
+        assert_close!(m * (3.0 * rho * rho - r * r) / r.powi(5), h_rho, 1e-5);
+        assert!(h_phi.abs() < 1e-20);
+        assert_close!(m * 3.0 * rho * z / r.powi(5), h_z, 1e-5);
+    }
+
+    #[test]
+    fn test_special_cases() {
+        assert_eq!(
+            solenoid_field(1.0, 2.0, 1.0, 1.0).unwrap_err(),
+            "solenoid_field: The point must not lie on the edges."
+        );
+        assert_eq!(
+            diametric_cylinder_field(1.0, 2.0, 1.0, 0.0, -1.0).unwrap_err(),
+            "diametric_cylinder_field: The point must not lie on the edges."
+        );
+        assert_eq!(solenoid_field(1.0, 2.0, f64::INFINITY, 0.0), Ok((0.0, 0.0)));
+        assert_eq!(
+            diametric_cylinder_h_field(1.0, 2.0, 0.0, 0.0, f64::NEG_INFINITY),
+            Ok((0.0, 0.0, 0.0))
+        );
+
+        // Mean of both sides on the wall
+        let inner = solenoid_field(1.0, 2.0, 1.0 - 1e-13, 0.2).unwrap().1;
+        let outer = solenoid_field(1.0, 2.0, 1.0 + 1e-13, 0.2).unwrap().1;
+        let (_, b_z) = solenoid_field(1.0, 2.0, 1.0, 0.2).unwrap();
+        assert_close!((inner + outer) / 2.0, b_z, 1e-11);
+
+        // Beyond the reach of the squared distances
+        assert_eq!(solenoid_field(1.0, 2.0, 0.0, 1e200), Ok((0.0, 0.0)));
+        assert_eq!(solenoid_field(1.0, 1e300, 0.0, 0.0), Ok((0.0, 1.0)));
+        assert_eq!(
+            axial_cylinder_field(1e-300, 1e300, 0.0, 0.0),
+            Ok((0.0, 1.0))
+        );
+        assert_eq!(solenoid_field(1e-300, 2.0, 1e300, 0.0), Ok((0.0, 0.0)));
+        assert_eq!(solenoid_field(1.0f32, 2.0, 0.0, 1e20), Ok((0.0, 0.0)));
+        let (b_rho, b_phi, b_z) = diametric_cylinder_field(1.0, 1e300, 2.0, 0.3, 3.0).unwrap();
+        assert_close!(0.125 * 0.3f64.cos(), b_rho, 1e-15);
+        assert_close!(0.125 * 0.3f64.sin(), b_phi, 1e-15);
+        assert_eq!(b_z, 0.0);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            solenoid_field(1.0, f64::NAN, 0.0, 0.0).unwrap_err(),
+            "solenoid_field: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            axial_cylinder_field(-1.0, 2.0, 0.0, 0.0).unwrap_err(),
+            "axial_cylinder_field: a must be positive."
+        );
+        assert_eq!(
+            axial_cylinder_h_field(1.0, 0.0, 0.0, 0.0).unwrap_err(),
+            "axial_cylinder_h_field: l must be positive."
+        );
+        assert_eq!(
+            diametric_cylinder_h_field(1.0, 2.0, -0.5, 0.0, 0.0).unwrap_err(),
+            "diametric_cylinder_h_field: rho cannot be negative."
+        );
+        assert_eq!(
+            diametric_cylinder_field(1.0, 2.0, 0.5, f64::INFINITY, 0.0).unwrap_err(),
+            "diametric_cylinder_field: phi cannot be infinite."
+        );
+    }
+}
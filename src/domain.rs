@@ -0,0 +1,630 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Domain classification of the arguments before evaluation.
+//!
+//! Each function in this module takes the same arguments as its counterpart at the crate root
+//! and reports whether the arguments are valid, on a pole, or out of the domain, along with the
+//! violated condition. The conditions follow the "Domain" section of each function.
+//!
+//! # Examples
+//! ```
+//! use ellip::domain::{self, Domain};
+//!
+//! assert_eq!(domain::ellipf(0.5, 0.5), Domain::Valid);
+//! assert_eq!(domain::ellipf(1.5, 2.0), Domain::OutOfDomain("m sin²φ ≥ 1"));
+//! assert_eq!(domain::ellipk(1.0), Domain::Pole("m = 1"));
+//!
+//! if let Some(condition) = domain::ellipk(2.0).condition() {
+//!     println!("Invalid input: {condition}");
+//! }
+//! ```
+
+use num_traits::Float;
+
+/// Classification of the arguments of a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Domain {
+    /// The arguments are in the domain.
+    Valid,
+    /// The function returns the Cauchy principal value.
+    PrincipalValue,
+    /// The arguments lie on a pole. The function returns ±∞ or an error.
+    Pole(&'static str),
+    /// The arguments are outside the domain. The function returns an error.
+    OutOfDomain(&'static str),
+}
+
+impl Domain {
+    /// Returns true if the function returns a finite value (barring infinite arguments).
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid | Self::PrincipalValue)
+    }
+
+    /// Returns the violated condition.
+    pub fn condition(&self) -> Option<&'static str> {
+        match self {
+            Self::Pole(condition) | Self::OutOfDomain(condition) => Some(*condition),
+            _ => None,
+        }
+    }
+}
+
+/// Case of the elliptic integral of the third kind, see [classify_pi].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PiCase {
+    /// n(n - m)(n - 1) < 0, i.e., m < n < 1 or n < 0.
+    Circular,
+    /// n(n - m)(n - 1) > 0, i.e., 0 < n < m or n > 1 with n sin²φ < 1.
+    Hyperbolic,
+    /// n = 0 or n = m, where Π reduces to F or E.
+    Degenerate,
+    /// n sin²φ > 1, where the Cauchy principal value is returned.
+    PrincipalValue,
+    /// n sin²φ = 1, where Π is infinite.
+    Singular,
+    /// The arguments are outside the domain, with the violated condition.
+    OutOfDomain(&'static str),
+}
+
+/// Classifies the arguments of [ellippiinc](crate::ellippiinc) into the cases of [DLMF 19.7.iii](https://dlmf.nist.gov/19.7.iii).
+///
+/// Use φ = π/2 for the complete integral [ellippi](crate::ellippi).
+///
+/// # Examples
+/// ```
+/// use ellip::domain::{classify_pi, PiCase};
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// assert_eq!(classify_pi(FRAC_PI_2, 0.8, 0.5), PiCase::Circular);
+/// assert_eq!(classify_pi(FRAC_PI_2, 0.2, 0.5), PiCase::Hyperbolic);
+/// assert_eq!(classify_pi(FRAC_PI_2, 2.0, 0.5), PiCase::PrincipalValue);
+/// assert_eq!(classify_pi(FRAC_PI_2, 1.0, 0.5), PiCase::Singular);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn classify_pi<T: Float>(phi: T, n: T, m: T) -> PiCase {
+    match ellippiinc(phi, n, m) {
+        Domain::OutOfDomain(condition) => return PiCase::OutOfDomain(condition),
+        Domain::PrincipalValue => return PiCase::PrincipalValue,
+        Domain::Pole("n sin²φ = 1") => return PiCase::Singular,
+        _ => (),
+    }
+
+    let c = n * (n - m) * (n - 1.0);
+    if c < 0.0 {
+        PiCase::Circular
+    } else if c > 0.0 {
+        PiCase::Hyperbolic
+    } else {
+        PiCase::Degenerate
+    }
+}
+
+/// Supremum of sin²θ for θ in [0, |φ|].
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn sup_sin2<T: Float>(phi: T) -> T {
+    if phi.abs() >= pi_2!() {
+        1.0
+    } else {
+        phi.sin() * phi.sin()
+    }
+}
+
+/// Returns true if φ is within `tol` of an integer multiple of π/2.
+#[inline]
+fn is_multiple_of_pi_2<T: Float>(phi: T, tol: T) -> bool {
+    let n = (phi / pi_2!()).round();
+    (phi - n * pi_2!()).abs() < tol
+}
+
+/// Sorts three values in ascending order.
+#[inline]
+fn sort3<T: Float>(x: T, y: T, z: T) -> (T, T, T) {
+    let (a, b) = if x < y { (x, y) } else { (y, x) };
+    if z < a {
+        (z, a, b)
+    } else if z < b {
+        (a, z, b)
+    } else {
+        (a, b, z)
+    }
+}
+
+macro_rules! check_nan {
+    ($($var:ident),+) => {
+        if false $(|| $var.is_nan())+ {
+            return Domain::OutOfDomain("argument is NAN");
+        }
+    };
+}
+
+/// Classifies the arguments of [ellipk](crate::ellipk).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellipk<T: Float>(m: T) -> Domain {
+    check_nan!(m);
+    if m > 1.0 {
+        return Domain::OutOfDomain("m > 1");
+    }
+    if m == 1.0 {
+        return Domain::Pole("m = 1");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [ellipe](crate::ellipe).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellipe<T: Float>(m: T) -> Domain {
+    check_nan!(m);
+    if m > 1.0 {
+        return Domain::OutOfDomain("m > 1");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [ellippi](crate::ellippi).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellippi<T: Float>(n: T, m: T) -> Domain {
+    check_nan!(n, m);
+    if m > 1.0 {
+        return Domain::OutOfDomain("m > 1");
+    }
+    if n == 1.0 {
+        return Domain::Pole("n = 1");
+    }
+    if m == 1.0 {
+        return Domain::Pole("m = 1");
+    }
+    if n > 1.0 {
+        return Domain::PrincipalValue;
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [ellipd](crate::ellipd).
+pub fn ellipd<T: Float>(m: T) -> Domain {
+    ellipk(m)
+}
+
+/// Classifies the arguments of [ellipf](crate::ellipf).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellipf<T: Float>(phi: T, m: T) -> Domain {
+    check_nan!(phi, m);
+    if m * sup_sin2(phi) >= 1.0 {
+        return Domain::OutOfDomain("m sin²φ ≥ 1");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [ellipeinc](crate::ellipeinc).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellipeinc<T: Float>(phi: T, m: T) -> Domain {
+    check_nan!(phi, m);
+    if m > 1.0 && m * sup_sin2(phi) >= 1.0 {
+        return Domain::OutOfDomain("m sin²φ ≥ 1");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [ellippiinc](crate::ellippiinc).
+///
+/// The case n = m = 1 is not supported and reported as out of domain.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellippiinc<T: Float>(phi: T, n: T, m: T) -> Domain {
+    check_nan!(phi, n, m);
+    let sp2 = phi.sin() * phi.sin();
+    if m * sp2 > 1.0 {
+        return Domain::OutOfDomain("m sin²φ > 1");
+    }
+    if n * sp2 == 1.0 {
+        return Domain::Pole("n sin²φ = 1");
+    }
+    if m >= 1.0 && phi.abs() >= pi_2!() {
+        return Domain::OutOfDomain("m ≥ 1 and |φ| ≥ π/2");
+    }
+    if n == 1.0 && m == 1.0 {
+        return Domain::OutOfDomain("n = m = 1");
+    }
+    if n * sup_sin2(phi) > 1.0 {
+        return Domain::PrincipalValue;
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [ellippiinc_bulirsch](crate::ellippiinc_bulirsch).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellippiinc_bulirsch<T: Float>(phi: T, n: T, m: T) -> Domain {
+    if n == 1.0 && m == 0.0 && phi.is_finite() {
+        // Π(φ, 1, 0) = tan φ
+        return Domain::Valid;
+    }
+    ellippiinc(phi, n, m)
+}
+
+/// Classifies the arguments of [ellipdinc](crate::ellipdinc).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellipdinc<T: Float>(phi: T, m: T) -> Domain {
+    check_nan!(phi, m);
+    let s2 = sup_sin2(phi);
+    if m > 1.0 && m * s2 > 1.0 {
+        return Domain::OutOfDomain("m sin²φ > 1");
+    }
+    if m == 1.0 && s2 == 1.0 && phi.is_finite() {
+        return Domain::Pole("m sin²φ = 1");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [cel](crate::cel).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn cel<T: Float>(kc: T, p: T, a: T, b: T) -> Domain {
+    check_nan!(kc, p, a, b);
+    if kc == 0.0 {
+        return Domain::Pole("kc = 0");
+    }
+    if p == 0.0 {
+        return Domain::Pole("p = 0");
+    }
+    let n_inf = [kc, p, a, b].iter().filter(|x| x.is_infinite()).count();
+    if n_inf > 1 {
+        return Domain::OutOfDomain("more than one argument is infinite");
+    }
+    if p < 0.0 {
+        return Domain::PrincipalValue;
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [cel1](crate::cel1).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn cel1<T: Float>(kc: T) -> Domain {
+    check_nan!(kc);
+    if kc == 0.0 {
+        return Domain::Pole("kc = 0");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [cel2](crate::cel2).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn cel2<T: Float>(kc: T, a: T, b: T) -> Domain {
+    check_nan!(kc, a, b);
+    if kc == 0.0 {
+        return Domain::Pole("kc = 0");
+    }
+    let n_inf = [kc, a, b].iter().filter(|x| x.is_infinite()).count();
+    if n_inf > 1 {
+        return Domain::OutOfDomain("more than one argument is infinite");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [el1](crate::el1).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn el1<T: Float>(x: T, kc: T) -> Domain {
+    check_nan!(x, kc);
+    if kc == 0.0 {
+        return Domain::OutOfDomain("kc = 0");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [el2](crate::el2).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn el2<T: Float>(x: T, kc: T, a: T, b: T) -> Domain {
+    check_nan!(x, kc, a, b);
+    if kc == 0.0 {
+        return Domain::OutOfDomain("kc = 0");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [el3](crate::el3).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn el3<T: Float>(x: T, kc: T, p: T) -> Domain {
+    check_nan!(x, kc, p);
+    if kc.abs() < T::epsilon() {
+        return Domain::OutOfDomain("kc = 0");
+    }
+    let w = 1.0 + p * x * x;
+    if w == 0.0 {
+        return Domain::Pole("1 + px² = 0");
+    }
+    if w < 0.0 {
+        return Domain::PrincipalValue;
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [elliprf](crate::elliprf).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn elliprf<T: Float>(x: T, y: T, z: T) -> Domain {
+    check_nan!(x, y, z);
+    if x.min(y).min(z) < 0.0 {
+        // RF(x, y, y) = RC(x, y)
+        let (a, b, c) = sort3(x, y, z);
+        if a == b && c > 0.0 {
+            return Domain::PrincipalValue;
+        }
+        return Domain::OutOfDomain("x, y, or z is negative");
+    }
+    if [x, y, z].iter().filter(|v| v.is_zero()).count() > 1 {
+        return Domain::Pole("more than one of x, y, and z are zero");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [elliprg](crate::elliprg).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn elliprg<T: Float>(x: T, y: T, z: T) -> Domain {
+    check_nan!(x, y, z);
+    if x.min(y).min(z) < 0.0 {
+        return Domain::OutOfDomain("x, y, or z is negative");
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() {
+        return Domain::OutOfDomain("x, y, or z is infinite");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [elliprj](crate::elliprj).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn elliprj<T: Float>(x: T, y: T, z: T, p: T) -> Domain {
+    check_nan!(x, y, z, p);
+    if p == 0.0 {
+        return Domain::Pole("p = 0");
+    }
+    if x.min(y).min(z) < 0.0 {
+        return Domain::OutOfDomain("x, y, or z is negative");
+    }
+    if [x, y, z].iter().filter(|v| v.is_zero()).count() > 1 {
+        return Domain::Pole("more than one of x, y, and z are zero");
+    }
+    if p < 0.0 {
+        return Domain::PrincipalValue;
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [elliprc](crate::elliprc).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn elliprc<T: Float>(x: T, y: T) -> Domain {
+    check_nan!(x, y);
+    if x < 0.0 {
+        return Domain::OutOfDomain("x < 0");
+    }
+    if y == 0.0 {
+        return Domain::Pole("y = 0");
+    }
+    if y < 0.0 {
+        return Domain::PrincipalValue;
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [elliprd](crate::elliprd).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn elliprd<T: Float>(x: T, y: T, z: T) -> Domain {
+    check_nan!(x, y, z);
+    if x.min(y) < 0.0 {
+        return Domain::OutOfDomain("x or y is negative");
+    }
+    if x == 0.0 && y == 0.0 {
+        return Domain::Pole("x = y = 0");
+    }
+    if z < 0.0 {
+        return Domain::OutOfDomain("z < 0");
+    }
+    if z == 0.0 {
+        return Domain::Pole("z = 0");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [jacobi_zeta](crate::jacobi_zeta).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn jacobi_zeta<T: Float>(phi: T, m: T) -> Domain {
+    check_nan!(phi, m);
+    if phi.is_infinite() || m.is_infinite() {
+        return Domain::OutOfDomain("φ or m is infinite");
+    }
+    if is_multiple_of_pi_2(phi, epsilon!().sqrt()) {
+        // Z(nπ/2, m) = 0
+        return Domain::Valid;
+    }
+    if m > 1.0 {
+        return Domain::OutOfDomain("m > 1");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [heuman_lambda](crate::heuman_lambda).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn heuman_lambda<T: Float>(phi: T, m: T) -> Domain {
+    check_nan!(phi, m);
+    if m < 0.0 {
+        return Domain::OutOfDomain("m < 0 or m ≥ 1");
+    }
+    if m > 0.0 && is_multiple_of_pi_2(phi, epsilon!()) {
+        // Λ0(nπ/2, m) = n
+        return Domain::Valid;
+    }
+    if m >= 1.0 {
+        return Domain::OutOfDomain("m < 0 or m ≥ 1");
+    }
+    if phi.is_infinite() {
+        return Domain::OutOfDomain("φ is infinite");
+    }
+    Domain::Valid
+}
+
+/// Classifies the arguments of [ellipk_ratio](crate::ellipk_ratio).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellipk_ratio<T: Float>(m: T) -> Domain {
+    check_nan!(m);
+    if m < 0.0 || m > 1.0 {
+        return Domain::OutOfDomain("m < 0 or m > 1");
+    }
+    if m == 1.0 {
+        return Domain::Pole("m = 1");
+    }
+    Domain::Valid
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EllipError;
+    use core::f64::consts::FRAC_PI_2;
+    use itertools::iproduct;
+
+    const VALUES: [f64; 11] = [
+        -3.0,
+        -1.0,
+        -0.5,
+        0.0,
+        0.3,
+        0.5,
+        1.0,
+        1.5,
+        2.0,
+        4.0,
+        f64::NAN,
+    ];
+    const PHIS: [f64; 8] = [-2.0, 0.0, 0.3, 0.9, FRAC_PI_2, 2.0, 4.0, f64::NAN];
+
+    /// Asserts that the classification agrees with the evaluation.
+    fn agree(domain: Domain, result: Result<f64, EllipError>, args: &[f64]) {
+        // The order of the checks differs between functions, so NAN may not be reported first.
+        if args.iter().any(|x| x.is_nan()) {
+            assert_eq!(domain, Domain::OutOfDomain("argument is NAN"));
+            return;
+        }
+        let ok = match domain {
+            Domain::Valid | Domain::PrincipalValue => matches!(result, Ok(ans) if ans.is_finite()),
+            Domain::Pole(_) => match result {
+                Ok(ans) => ans.is_infinite(),
+                Err(err) => matches!(err, EllipError::Singularity(_)),
+            },
+            Domain::OutOfDomain(_) => {
+                matches!(result, Err(err) if !matches!(err, EllipError::Singularity(_)))
+            }
+        };
+        assert!(
+            ok,
+            "args = {args:?}, domain = {domain:?}, result = {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_domain_legendre() {
+        for m in VALUES {
+            agree(ellipk(m), crate::ellipk(m), &[m]);
+            agree(ellipe(m), crate::ellipe(m), &[m]);
+            agree(ellipd(m), crate::ellipd(m), &[m]);
+        }
+        for (n, m) in iproduct!(VALUES, VALUES) {
+            agree(ellippi(n, m), crate::ellippi(n, m), &[n, m]);
+        }
+        for (phi, m) in iproduct!(PHIS, VALUES) {
+            agree(ellipf(phi, m), crate::ellipf(phi, m), &[phi, m]);
+            agree(ellipeinc(phi, m), crate::ellipeinc(phi, m), &[phi, m]);
+            agree(ellipdinc(phi, m), crate::ellipdinc(phi, m), &[phi, m]);
+        }
+        for (phi, n, m) in iproduct!(PHIS, VALUES, VALUES) {
+            let args = [phi, n, m];
+            agree(ellippiinc(phi, n, m), crate::ellippiinc(phi, n, m), &args);
+            agree(
+                ellippiinc_bulirsch(phi, n, m),
+                crate::ellippiinc_bulirsch(phi, n, m),
+                &args,
+            );
+        }
+    }
+
+    #[test]
+    fn test_domain_bulirsch() {
+        for kc in VALUES {
+            agree(cel1(kc), crate::cel1(kc), &[kc]);
+        }
+        for (kc, a, b) in iproduct!(VALUES, VALUES, VALUES) {
+            agree(cel2(kc, a, b), crate::cel2(kc, a, b), &[kc, a, b]);
+            agree(el1(a, kc), crate::el1(a, kc), &[a, kc]);
+            agree(el3(a, kc, b), crate::el3(a, kc, b), &[a, kc, b]);
+        }
+        for (kc, p, a, b) in iproduct!(VALUES, VALUES, VALUES, VALUES) {
+            agree(cel(kc, p, a, b), crate::cel(kc, p, a, b), &[kc, p, a, b]);
+            agree(el2(kc, p, a, b), crate::el2(kc, p, a, b), &[kc, p, a, b]);
+        }
+    }
+
+    #[test]
+    fn test_domain_carlson() {
+        for (x, y) in iproduct!(VALUES, VALUES) {
+            agree(elliprc(x, y), crate::elliprc(x, y), &[x, y]);
+        }
+        for (x, y, z) in iproduct!(VALUES, VALUES, VALUES) {
+            agree(elliprf(x, y, z), crate::elliprf(x, y, z), &[x, y, z]);
+            agree(elliprg(x, y, z), crate::elliprg(x, y, z), &[x, y, z]);
+            agree(elliprd(x, y, z), crate::elliprd(x, y, z), &[x, y, z]);
+        }
+        for (x, y, z, p) in iproduct!(VALUES, VALUES, VALUES, VALUES) {
+            agree(
+                elliprj(x, y, z, p),
+                crate::elliprj(x, y, z, p),
+                &[x, y, z, p],
+            );
+        }
+    }
+
+    #[test]
+    fn test_domain_misc() {
+        for (phi, m) in iproduct!(PHIS, VALUES) {
+            agree(jacobi_zeta(phi, m), crate::jacobi_zeta(phi, m), &[phi, m]);
+            agree(
+                heuman_lambda(phi, m),
+                crate::heuman_lambda(phi, m),
+                &[phi, m],
+            );
+        }
+        for m in VALUES {
+            agree(ellipk_ratio(m), crate::ellipk_ratio(m), &[m]);
+        }
+        assert_eq!(
+            jacobi_zeta(f64::INFINITY, 0.5),
+            Domain::OutOfDomain("φ or m is infinite")
+        );
+    }
+
+    #[test]
+    fn test_classify_pi() {
+        assert_eq!(classify_pi(FRAC_PI_2, 0.8, 0.5), PiCase::Circular);
+        assert_eq!(classify_pi(FRAC_PI_2, -0.5, 0.5), PiCase::Circular);
+        assert_eq!(classify_pi(FRAC_PI_2, 0.2, 0.5), PiCase::Hyperbolic);
+        assert_eq!(classify_pi(0.5, 1.5, 0.5), PiCase::Hyperbolic);
+        assert_eq!(classify_pi(FRAC_PI_2, 0.0, 0.5), PiCase::Degenerate);
+        assert_eq!(classify_pi(FRAC_PI_2, 0.5, 0.5), PiCase::Degenerate);
+        assert_eq!(classify_pi(FRAC_PI_2, 2.0, 0.5), PiCase::PrincipalValue);
+        assert_eq!(classify_pi(FRAC_PI_2, 1.0, 0.5), PiCase::Singular);
+        assert_eq!(
+            classify_pi(FRAC_PI_2, 0.5, 2.0),
+            PiCase::OutOfDomain("m sin²φ > 1")
+        );
+        assert_eq!(
+            classify_pi(f64::NAN, 0.5, 0.5),
+            PiCase::OutOfDomain("argument is NAN")
+        );
+    }
+
+    #[test]
+    fn test_domain_methods() {
+        assert!(ellipk(0.5).is_valid());
+        assert!(ellippi(2.0, 0.5).is_valid());
+        assert!(!ellipk(1.0).is_valid());
+        assert_eq!(ellipk(0.5).condition(), None);
+        assert_eq!(ellipk(2.0).condition(), Some("m > 1"));
+    }
+}
@@ -0,0 +1,991 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Double-double floating-point type for extended precision.
+//!
+//! [DoubleDouble] represents a number as the unevaluated sum of two [f64], giving about
+//! 32 significant digits (ε = 2⁻¹⁰⁴ ≈ 4.93e-32) with the exponent range of [f64]. It implements
+//! [Float], so it can be used with the functions in this crate to compute reference values.
+//!
+//! When the precision of the type is higher than [f64], the functions skip the approximations
+//! tuned for [f64] and use the duplication theorem and the arithmetic-geometric mean with
+//! tolerances derived from [Float::epsilon]. The Bulirsch's integrals use [BulirschConst](crate::bulirsch::BulirschConst)
+//! with D = 32.
+//!
+//! # Examples
+//! ```
+//! use ellip::{ellipk, DoubleDouble};
+//!
+//! let m: DoubleDouble = "0.5".parse().unwrap();
+//! let k = ellipk(m).unwrap();
+//! let expected: DoubleDouble = "1.8540746773013719184338503471953".parse().unwrap();
+//! assert!((k - expected).hi().abs() < 1e-31);
+//! ```
+//!
+//! # Notes
+//! The elementary functions are accurate to a few units of ε for moderate arguments.
+//! The argument reduction of the trigonometric functions uses a double-double π, so
+//! the accuracy degrades for arguments much larger than 1e3.
+//!
+//! # References
+//! - Hida, Yozo, Xiaoye S. Li, and David H. Bailey. “Library for Double-Double and Quad-Double Arithmetic.” Lawrence Berkeley National Laboratory, 2007. <https://www.davidhbailey.com/dhbpapers/qd.pdf>.
+//! - Dekker, T. J. “A Floating-Point Technique for Extending the Available Precision.” Numerische Mathematik 18, no. 3 (June 1, 1971): 224–42. <https://doi.org/10.1007/BF01397083>.
+
+use core::{
+    cmp::Ordering,
+    f64::consts,
+    fmt,
+    num::FpCategory,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use num_traits::{Float, Num, NumCast, One, ToPrimitive, Zero};
+
+use crate::StrErr;
+
+/// Double-double floating-point number with about 32 significant digits.
+///
+/// The value is hi + lo, where |lo| ≤ ulp(hi)/2.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+const PI: DoubleDouble = DoubleDouble::from_parts(consts::PI, 1.2246467991473532e-16);
+const PI_2: DoubleDouble = DoubleDouble::from_parts(consts::FRAC_PI_2, 6.123233995736766e-17);
+const PI_4: DoubleDouble = DoubleDouble::from_parts(consts::FRAC_PI_4, 3.061616997868383e-17);
+const LN_2: DoubleDouble = DoubleDouble::from_parts(consts::LN_2, 2.3190468138462996e-17);
+const LN_10: DoubleDouble = DoubleDouble::from_parts(consts::LN_10, -2.1707562233822494e-16);
+const EPSILON: f64 = 4.930380657631324e-32;
+
+/// Number of significant digits printed by [fmt::Display].
+const DIGITS: usize = 32;
+
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+#[inline]
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+#[inline]
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+impl DoubleDouble {
+    /// Creates a double-double from the sum hi + lo.
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+        Self::from_parts(hi, lo)
+    }
+
+    /// Creates a double-double from an [f64].
+    pub const fn from_f64(x: f64) -> Self {
+        Self::from_parts(x, 0.0)
+    }
+
+    /// Creates a double-double from normalized parts without renormalization.
+    const fn from_parts(hi: f64, lo: f64) -> Self {
+        Self { hi, lo }
+    }
+
+    /// Creates a double-double from the result of an error-free transformation.
+    #[inline]
+    fn normalized(hi: f64, lo: f64) -> Self {
+        if !hi.is_finite() {
+            return Self::from_parts(hi, 0.0);
+        }
+        let (hi, lo) = quick_two_sum(hi, lo);
+        Self::from_parts(hi, lo)
+    }
+
+    /// Returns the leading component.
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the trailing component.
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    #[inline]
+    fn mul_f64(self, b: f64) -> Self {
+        let (p1, p2) = two_prod(self.hi, b);
+        Self::normalized(p1, p2 + self.lo * b)
+    }
+
+    /// Multiplies by 2ⁿ exactly, unless the result overflows or underflows.
+    fn ldexp(self, n: i32) -> Self {
+        // Split the scaling to avoid overflowing the scale factor itself.
+        let half = n / 2;
+        let a = 2f64.powi(half);
+        let b = 2f64.powi(n - half);
+        Self::from_parts(self.hi * a * b, self.lo * a * b)
+    }
+
+    /// Computes exp(x) - 1 for |x| ≤ ln(2)/2.
+    fn expm1_reduced(x: Self) -> Self {
+        // Scale down, sum the Taylor series, then square back up using (1+u)² - 1 = u(2+u).
+        const SQUARINGS: i32 = 10;
+        let s = x.ldexp(-SQUARINGS);
+        let mut u = s;
+        let mut term = s;
+        let mut n = 1.0;
+        loop {
+            n += 1.0;
+            term *= s / Self::from_f64(n);
+            u += term;
+            if term.hi.abs() <= EPSILON * 1e-3 * u.hi.abs() {
+                break;
+            }
+        }
+        for _ in 0..SQUARINGS {
+            u *= u + Self::from_f64(2.0);
+        }
+        u
+    }
+
+    /// Computes sin(x) and cos(x) for |x| ≤ π/4 using the Taylor series.
+    fn sin_cos_reduced(x: Self) -> (Self, Self) {
+        let x2 = x * x;
+        let mut sin = x;
+        let mut cos = Self::one();
+        let mut s_term = x;
+        let mut c_term = Self::one();
+        let mut n = 0.0;
+        loop {
+            n += 2.0;
+            c_term = -c_term * x2 / Self::from_f64(n * (n - 1.0));
+            s_term = -s_term * x2 / Self::from_f64(n * (n + 1.0));
+            cos += c_term;
+            sin += s_term;
+            if c_term.hi.abs() <= EPSILON * 1e-3 {
+                break;
+            }
+        }
+        (sin, cos)
+    }
+
+    /// Parses a decimal string such as "-1.25e-3".
+    fn parse_decimal(s: &str) -> Result<Self, StrErr> {
+        const ERR: StrErr = "DoubleDouble: Invalid number.";
+        let s = s.trim();
+        let (neg, body) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let sign = |x: Self| if neg { -x } else { x };
+
+        if body.eq_ignore_ascii_case("inf") || body.eq_ignore_ascii_case("infinity") {
+            return Ok(sign(Self::infinity()));
+        }
+        if body.eq_ignore_ascii_case("nan") {
+            return Ok(Self::nan());
+        }
+
+        let (mantissa, exponent) = match body.find(['e', 'E']) {
+            Some(i) => (&body[..i], body[i + 1..].parse::<i32>().map_err(|_| ERR)?),
+            None => (body, 0),
+        };
+
+        let mut value = Self::zero();
+        let mut scale = exponent;
+        let mut n_digits = 0;
+        let mut seen_point = false;
+        for c in mantissa.chars() {
+            match c {
+                '0'..='9' => {
+                    value = value * Self::from_f64(10.0) + Self::from_f64((c as u8 - b'0') as f64);
+                    n_digits += 1;
+                    if seen_point {
+                        scale -= 1;
+                    }
+                }
+                '.' if !seen_point => seen_point = true,
+                _ => return Err(ERR),
+            }
+        }
+        if n_digits == 0 {
+            return Err(ERR);
+        }
+
+        let ten = Self::from_f64(10.0);
+        let value = match scale.cmp(&0) {
+            Ordering::Less => value / ten.powi(-scale),
+            Ordering::Greater => value * ten.powi(scale),
+            Ordering::Equal => value,
+        };
+        Ok(sign(value))
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(x: f64) -> Self {
+        Self::from_f64(x)
+    }
+}
+
+impl From<f32> for DoubleDouble {
+    fn from(x: f32) -> Self {
+        Self::from_f64(x as f64)
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (s1, s2) = two_sum(self.hi, rhs.hi);
+        if !s1.is_finite() {
+            return Self::from_parts(s1, 0.0);
+        }
+        let (t1, t2) = two_sum(self.lo, rhs.lo);
+        let (s1, s2) = quick_two_sum(s1, s2 + t1);
+        Self::normalized(s1, s2 + t2)
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let (p1, p2) = two_prod(self.hi, rhs.hi);
+        Self::normalized(p1, p2 + (self.hi * rhs.lo + self.lo * rhs.hi))
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let q1 = self.hi / rhs.hi;
+        if !q1.is_finite() || q1 == 0.0 {
+            return Self::from_f64(q1);
+        }
+        let r = self - rhs.mul_f64(q1);
+        let q2 = r.hi / rhs.hi;
+        let r = r - rhs.mul_f64(q2);
+        let q3 = r.hi / rhs.hi;
+        let (q1, q2) = quick_two_sum(q1, q2);
+        Self::from_parts(q1, q2) + Self::from_f64(q3)
+    }
+}
+
+impl Rem for DoubleDouble {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        self - rhs * (self / rhs).trunc()
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from_parts(-self.hi, -self.lo)
+    }
+}
+
+macro_rules! impl_assign_op {
+    ($($trait:ident, $method:ident, $op:tt);* $(;)?) => {
+        $(
+            impl $trait for DoubleDouble {
+                fn $method(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_assign_op! {
+    AddAssign, add_assign, +;
+    SubAssign, sub_assign, -;
+    MulAssign, mul_assign, *;
+    DivAssign, div_assign, /;
+    RemAssign, rem_assign, %;
+}
+
+impl Zero for DoubleDouble {
+    fn zero() -> Self {
+        Self::from_f64(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.hi == 0.0
+    }
+}
+
+impl One for DoubleDouble {
+    fn one() -> Self {
+        Self::from_f64(1.0)
+    }
+}
+
+impl Num for DoubleDouble {
+    type FromStrRadixErr = StrErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 {
+            return Err("DoubleDouble: Only radix 10 is supported.");
+        }
+        Self::parse_decimal(str)
+    }
+}
+
+impl FromStr for DoubleDouble {
+    type Err = StrErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_decimal(s)
+    }
+}
+
+impl ToPrimitive for DoubleDouble {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128()?.try_into().ok()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_i128()?.try_into().ok()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        let t = self.trunc();
+        if !(-1.7014118346046923e38..1.7014118346046923e38).contains(&t.hi) {
+            return None;
+        }
+        (t.hi as i128).checked_add(t.lo as i128)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.hi)
+    }
+}
+
+impl NumCast for DoubleDouble {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        let f = n.to_f64()?;
+        // Integers beyond 2⁵³ are not exactly representable by f64.
+        if f.abs() >= 9007199254740992.0 && f.fract() == 0.0 {
+            if let Some(i) = n.to_i128() {
+                let hi = i as f64;
+                return Some(Self::new(hi, (i - hi as i128) as f64));
+            }
+        }
+        Some(Self::from_f64(f))
+    }
+}
+
+impl Float for DoubleDouble {
+    fn nan() -> Self {
+        Self::from_f64(f64::NAN)
+    }
+
+    fn infinity() -> Self {
+        Self::from_f64(f64::INFINITY)
+    }
+
+    fn neg_infinity() -> Self {
+        Self::from_f64(f64::NEG_INFINITY)
+    }
+
+    fn neg_zero() -> Self {
+        Self::from_f64(-0.0)
+    }
+
+    fn min_value() -> Self {
+        Self::from_f64(f64::MIN)
+    }
+
+    fn min_positive_value() -> Self {
+        Self::from_f64(f64::MIN_POSITIVE)
+    }
+
+    fn epsilon() -> Self {
+        Self::from_f64(EPSILON)
+    }
+
+    fn max_value() -> Self {
+        Self::from_f64(f64::MAX)
+    }
+
+    fn is_nan(self) -> bool {
+        self.hi.is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.hi.is_infinite()
+    }
+
+    fn is_finite(self) -> bool {
+        self.hi.is_finite()
+    }
+
+    fn is_normal(self) -> bool {
+        self.hi.is_normal()
+    }
+
+    fn classify(self) -> FpCategory {
+        self.hi.classify()
+    }
+
+    fn floor(self) -> Self {
+        let hi = self.hi.floor();
+        if hi == self.hi {
+            Self::normalized(hi, self.lo.floor())
+        } else {
+            Self::from_f64(hi)
+        }
+    }
+
+    fn ceil(self) -> Self {
+        let hi = self.hi.ceil();
+        if hi == self.hi {
+            Self::normalized(hi, self.lo.ceil())
+        } else {
+            Self::from_f64(hi)
+        }
+    }
+
+    fn round(self) -> Self {
+        let t = self.trunc();
+        if (self - t).abs() >= Self::from_f64(0.5) {
+            t + self.signum()
+        } else {
+            t
+        }
+    }
+
+    fn trunc(self) -> Self {
+        if self.is_sign_negative() {
+            self.ceil()
+        } else {
+            self.floor()
+        }
+    }
+
+    fn fract(self) -> Self {
+        self - self.trunc()
+    }
+
+    fn abs(self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn signum(self) -> Self {
+        Self::from_f64(self.hi.signum())
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.hi.is_sign_positive()
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.hi.is_sign_negative()
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    fn powi(self, n: i32) -> Self {
+        let mut base = self;
+        let mut exp = n.unsigned_abs();
+        let mut ans = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ans *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        if n < 0 {
+            ans.recip()
+        } else {
+            ans
+        }
+    }
+
+    fn powf(self, n: Self) -> Self {
+        if n.fract().is_zero() && n.abs().hi < i32::MAX as f64 {
+            return self.powi(n.hi as i32);
+        }
+        if self.is_zero() {
+            return if n.is_sign_negative() {
+                Self::infinity()
+            } else {
+                Self::zero()
+            };
+        }
+        (n * self.ln()).exp()
+    }
+
+    fn sqrt(self) -> Self {
+        if self.hi <= 0.0 || !self.is_finite() {
+            return Self::from_f64(self.hi.sqrt());
+        }
+        let y = self.hi.sqrt();
+        let (p1, p2) = two_prod(y, y);
+        let r = self - Self::from_parts(p1, p2);
+        Self::normalized(y, r.hi / (2.0 * y))
+    }
+
+    fn exp(self) -> Self {
+        if self.hi > 709.782712893384 {
+            return Self::infinity();
+        }
+        if self.hi < -745.1332191019412 {
+            return Self::zero();
+        }
+        if self.is_nan() {
+            return self;
+        }
+        let k = (self.hi / LN_2.hi).round();
+        let r = self - LN_2.mul_f64(k);
+        let u = Self::expm1_reduced(r);
+        (u + Self::one()).ldexp(k as i32)
+    }
+
+    fn exp2(self) -> Self {
+        (self * LN_2).exp()
+    }
+
+    fn ln(self) -> Self {
+        if self.hi <= 0.0 || !self.is_finite() {
+            return Self::from_f64(self.hi.ln());
+        }
+        let mut y = Self::from_f64(self.hi.ln());
+        for _ in 0..2 {
+            y += self * (-y).exp() - Self::one();
+        }
+        y
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.ln() / LN_2
+    }
+
+    fn log10(self) -> Self {
+        self.ln() / LN_10
+    }
+
+    fn max(self, other: Self) -> Self {
+        if self.is_nan() || self < other {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if self.is_nan() || self > other {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        if self <= other {
+            Self::zero()
+        } else {
+            self - other
+        }
+    }
+
+    fn cbrt(self) -> Self {
+        if self.is_zero() || !self.is_finite() {
+            return self;
+        }
+        let mut y = Self::from_f64(self.hi.cbrt());
+        for _ in 0..2 {
+            y -= (y * y * y - self) / (Self::from_f64(3.0) * y * y);
+        }
+        y
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        let (a, b) = (self.abs(), other.abs());
+        let (big, small) = if a > b { (a, b) } else { (b, a) };
+        if big.is_zero() || big.is_infinite() {
+            return big;
+        }
+        let r = small / big;
+        big * (Self::one() + r * r).sqrt()
+    }
+
+    fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    fn tan(self) -> Self {
+        let (s, c) = self.sin_cos();
+        s / c
+    }
+
+    fn asin(self) -> Self {
+        let one = Self::one();
+        self.atan2(((one - self) * (one + self)).sqrt())
+    }
+
+    fn acos(self) -> Self {
+        let one = Self::one();
+        ((one - self) * (one + self)).sqrt().atan2(self)
+    }
+
+    fn atan(self) -> Self {
+        if self.is_nan() || self.is_zero() {
+            return self;
+        }
+        if self.abs() > Self::one() {
+            return PI_2 * self.signum() - self.recip().atan();
+        }
+        let mut y = Self::from_f64(self.hi.atan());
+        for _ in 0..2 {
+            let (s, c) = y.sin_cos();
+            y -= (s - self * c) / (c + self * s);
+        }
+        y
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self, other);
+        if y.is_nan() || x.is_nan() {
+            return Self::nan();
+        }
+        if x.is_zero() {
+            if y.is_zero() {
+                return if x.is_sign_negative() {
+                    PI * y.signum()
+                } else {
+                    y
+                };
+            }
+            return PI_2 * y.signum();
+        }
+        let a = (y / x).atan();
+        if x.is_sign_positive() {
+            a
+        } else if y.is_sign_negative() {
+            a - PI
+        } else {
+            a + PI
+        }
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        if !self.is_finite() {
+            return (Self::nan(), Self::nan());
+        }
+        if self.abs() <= PI_4 {
+            return Self::sin_cos_reduced(self);
+        }
+        let k = (self / PI_2).round();
+        let (s, c) = Self::sin_cos_reduced(self - PI_2 * k);
+        match k.to_i64().map(|k| k.rem_euclid(4)) {
+            Some(0) => (s, c),
+            Some(1) => (c, -s),
+            Some(2) => (-s, -c),
+            Some(3) => (-c, s),
+            _ => (Self::nan(), Self::nan()),
+        }
+    }
+
+    fn exp_m1(self) -> Self {
+        if self.abs() <= LN_2 * Self::from_f64(0.5) {
+            return Self::expm1_reduced(self);
+        }
+        self.exp() - Self::one()
+    }
+
+    fn ln_1p(self) -> Self {
+        if self.abs() > Self::from_f64(0.5) || !self.is_finite() {
+            return (self + Self::one()).ln();
+        }
+        if self.is_zero() {
+            return self;
+        }
+        let mut y = Self::from_f64(self.hi.ln_1p());
+        for _ in 0..2 {
+            let u = y.exp_m1();
+            y -= (u - self) / (u + Self::one());
+        }
+        y
+    }
+
+    fn sinh(self) -> Self {
+        if self.abs() > Self::one() {
+            let e = self.exp();
+            return (e - e.recip()) * Self::from_f64(0.5);
+        }
+        let u = self.exp_m1();
+        (u + u / (u + Self::one())) * Self::from_f64(0.5)
+    }
+
+    fn cosh(self) -> Self {
+        let e = self.exp();
+        (e + e.recip()) * Self::from_f64(0.5)
+    }
+
+    fn tanh(self) -> Self {
+        if self.abs().hi > 40.0 {
+            return self.signum();
+        }
+        let u = (self * Self::from_f64(2.0)).exp_m1();
+        u / (u + Self::from_f64(2.0))
+    }
+
+    fn asinh(self) -> Self {
+        let a = self.abs();
+        let one = Self::one();
+        let ans = if a.hi > 1e150 {
+            a.ln() + LN_2
+        } else {
+            let a2 = a * a;
+            (a + a2 / (one + (one + a2).sqrt())).ln_1p()
+        };
+        if self.is_sign_negative() {
+            -ans
+        } else {
+            ans
+        }
+    }
+
+    fn acosh(self) -> Self {
+        let one = Self::one();
+        if self.hi > 1e150 {
+            return self.ln() + LN_2;
+        }
+        let t = self - one;
+        (t + (t * (self + one)).sqrt()).ln_1p()
+    }
+
+    fn atanh(self) -> Self {
+        let one = Self::one();
+        (self * Self::from_f64(2.0) / (one - self)).ln_1p() * Self::from_f64(0.5)
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.hi.integer_decode()
+    }
+}
+
+impl fmt::Display for DoubleDouble {
+    /// Formats the number in scientific notation with 32 significant digits, or
+    /// with the number of decimal places given by the precision.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_finite() {
+            return write!(f, "{}", self.hi);
+        }
+        if self.is_sign_negative() {
+            write!(f, "-")?;
+        }
+        let x = self.abs();
+        let n_digits = f.precision().map_or(DIGITS, |p| p + 1).min(40);
+        if x.is_zero() {
+            write!(f, "0")?;
+            if n_digits > 1 {
+                write!(f, ".{:0<1$}", "", n_digits - 1)?;
+            }
+            return write!(f, "e0");
+        }
+
+        // Normalize to [1, 10).
+        let ten = Self::from_f64(10.0);
+        let mut exp10 = x.hi.log10().floor() as i32;
+        let mut y = if exp10 < 0 {
+            x * ten.powi(-exp10)
+        } else {
+            x / ten.powi(exp10)
+        };
+        if y >= ten {
+            y /= ten;
+            exp10 += 1;
+        } else if y < Self::one() {
+            y *= ten;
+            exp10 -= 1;
+        }
+
+        // Extract one more digit than needed for rounding.
+        let mut digits = [0u8; 41];
+        for d in digits.iter_mut().take(n_digits + 1) {
+            let v = y.hi.floor().clamp(0.0, 9.0);
+            *d = v as u8;
+            y = (y - Self::from_f64(v)) * ten;
+        }
+        if digits[n_digits] >= 5 {
+            let mut i = n_digits;
+            while i > 0 {
+                i -= 1;
+                if digits[i] < 9 {
+                    digits[i] += 1;
+                    break;
+                }
+                digits[i] = 0;
+                if i == 0 {
+                    digits[0] = 1;
+                    exp10 += 1;
+                }
+            }
+        }
+
+        write!(f, "{}", digits[0])?;
+        if n_digits > 1 {
+            write!(f, ".")?;
+            for d in &digits[1..n_digits] {
+                write!(f, "{d}")?;
+            }
+        }
+        write!(f, "e{exp10}")
+    }
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_util::{assert_close_dd, dd};
+
+    type DD = DoubleDouble;
+
+    #[test]
+    fn test_arithmetic() {
+        let third = DD::one() / DD::from_f64(3.0);
+        assert_close_dd(third, "0.33333333333333333333333333333333333", 1e-31);
+        assert_eq!(third * DD::from_f64(3.0), DD::one());
+        let x = dd("1.2345678901234567890123456789012");
+        assert_close_dd(x * x, "1.5241578753238836750495351562566", 1e-31);
+        assert_close_dd(x - DD::one(), "0.2345678901234567890123456789012", 1e-31);
+        assert_eq!(DD::new(1.0, 1e-20).lo(), 1e-20);
+        assert_eq!(DD::from_f64(7.5) % DD::from_f64(2.0), DD::from_f64(1.5));
+        assert!(DD::one() < DD::new(1.0, 1e-30));
+        assert!((DD::one() / DD::zero()).is_infinite());
+    }
+
+    #[test]
+    fn test_num_cast() {
+        let big = <DD as NumCast>::from(u64::MAX).unwrap();
+        assert_eq!(big.to_u64(), Some(u64::MAX));
+        let neg = <DD as NumCast>::from(i64::MIN + 1).unwrap();
+        assert_eq!(neg.to_i64(), Some(i64::MIN + 1));
+        assert_eq!(dd("-2.75").to_i64(), Some(-2));
+        assert_eq!(<DD as NumCast>::from(0.5).unwrap(), DD::from_f64(0.5));
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(dd("2.5").round(), DD::from_f64(3.0));
+        assert_eq!(dd("-2.5").round(), DD::from_f64(-3.0));
+        assert_eq!(dd("-2.25").floor(), DD::from_f64(-3.0));
+        assert_eq!(dd("-2.25").trunc(), DD::from_f64(-2.0));
+        assert_eq!(DD::new(1.0, -1e-20).floor(), DD::zero());
+        assert_eq!(DD::new(1.0, 1e-20).ceil(), DD::from_f64(2.0));
+    }
+
+    #[test]
+    fn test_elementary_functions() {
+        // Reference values from mpmath
+        let two = DD::from_f64(2.0);
+        let half = DD::from_f64(0.5);
+        assert_close_dd(two.sqrt(), "1.4142135623730950488016887242097", 4e-32);
+        assert_close_dd(two.cbrt(), "1.2599210498948731647672106072782", 4e-32);
+        assert_close_dd(DD::one().exp(), "2.7182818284590452353602874713527", 1e-31);
+        assert_close_dd(
+            DD::from_f64(-20.5).exp(),
+            "1.2501528663867426289375531192312e-9",
+            2e-31,
+        );
+        assert_close_dd(two.ln(), "0.69314718055994530941723212145818", 1e-31);
+        assert_close_dd(
+            dd("1e-10").ln_1p(),
+            "9.9999999995000000000333333333308e-11",
+            1e-31,
+        );
+        assert_close_dd(
+            dd("1e-10").exp_m1(),
+            "1.0000000000500000000016666666667e-10",
+            1e-31,
+        );
+        assert_close_dd(DD::from_f64(10.0).log10(), "1", 1e-31);
+        assert_close_dd(
+            two.powf(dd("-1.5")),
+            "0.35355339059327376220042218105242",
+            1e-31,
+        );
+        assert_close_dd(half.sin(), "0.47942553860420300027328793521557", 1e-31);
+        assert_close_dd(half.cos(), "0.87758256189037271611628158260383", 1e-31);
+        assert_close_dd(
+            DD::from_f64(100.0).sin(),
+            "-0.50636564110975879365655761045979",
+            1e-30,
+        );
+        assert_close_dd(half.tan(), "0.54630248984379051325517946578029", 1e-31);
+        assert_close_dd(half.atan(), "0.46364760900080611621425623146121", 1e-31);
+        assert_close_dd(
+            DD::from_f64(3.0).atan(),
+            "1.2490457723982544258299170772811",
+            1e-31,
+        );
+        assert_close_dd(half.asin(), "0.52359877559829887307710723054658", 1e-31);
+        assert_close_dd(half.acos(), "1.0471975511965977461542144610932", 1e-31);
+        assert_close_dd(
+            DD::from_f64(-1.0).atan2(DD::from_f64(-1.0)),
+            "-2.3561944901923449288469825374596",
+            1e-31,
+        );
+        assert_close_dd(half.sinh(), "0.52109530549374736162242562641149", 1e-31);
+        assert_close_dd(half.cosh(), "1.1276259652063807852262251614027", 1e-31);
+        assert_close_dd(half.tanh(), "0.46211715726000975850231848364367", 1e-31);
+        assert_close_dd(half.asinh(), "0.48121182505960344749775891342437", 1e-31);
+        assert_close_dd(two.acosh(), "1.3169578969248167086250463473080", 1e-31);
+        assert_close_dd(half.atanh(), "0.54930614433405484569762261846126", 1e-31);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", DD::one()),
+            "1.0000000000000000000000000000000e0"
+        );
+        assert_eq!(format!("{:.3}", dd("-1234.56789")), "-1.235e3");
+        assert_eq!(format!("{:.2}", dd("9.999")), "1.00e1");
+        assert_eq!(format!("{:.1}", DD::zero()), "0.0e0");
+        assert_eq!(format!("{}", DD::infinity()), "inf");
+        assert_eq!("1.5x".parse::<DD>(), Err("DoubleDouble: Invalid number."));
+    }
+}
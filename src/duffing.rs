@@ -0,0 +1,681 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Exact motion of the undamped Duffing oscillator and periods in quartic potentials.
+//!
+//! The Duffing oscillator x'' + αx + βx³ = 0 conserves the energy
+//! ```text
+//!       x'²    αx²    βx⁴
+//! E  =  ─── + ──── + ────
+//!        2      2      4
+//! ```
+//! Every bounded motion is a Jacobi elliptic function of ωt + u₀ (Kovacic and Brennan, 2011,
+//! §2):
+//! ```text
+//! Hardening (β > 0) and over both wells:   x = A cn(ωt + u₀, m),    ω² = α + βA²
+//! Within one well (α < 0 < β, E < 0):      x = ±A dn(ωt + u₀, m),   ω² = βA² / 2
+//! Softening (β < 0 < α):                   x = A sn(ωt + u₀, m),    ω² = α + βA² / 2
+//! Separatrix (α < 0 < β, E = 0):           x = ±A sech(ωt + u₀),    ω² = -α
+//! ```
+//! with the amplitude A at the outer turning point. The parameters are formed from
+//! D = √(α² + 4βE) without cancellation, and [EllipticModulus::from_kc] is constructed from
+//! the complementary modulus, so the motion near the separatrix of the double well and near
+//! the barrier of the softening potential remains accurate.
+//!
+//! The period of the motion between the turning points in any quartic potential is given by
+//! [quartic_period].
+//!
+//! # Examples
+//! ```
+//! use ellip::{duffing::{quartic_period, Duffing, Solution}, util::assert_close};
+//!
+//! // Released from rest at x = 1.5 in the double well x'' - x + x³ = 0, over both wells
+//! let duffing = Duffing::new(-1.0, 1.0, 1.5, 0.0).unwrap();
+//! assert_eq!(duffing.solution(), Solution::Cn);
+//! assert_close(duffing.period(), 9.223662748324173, 1e-15);
+//! assert_close(duffing.position(duffing.period() / 2.0), -1.5, 1e-15);
+//!
+//! // The same period from the turning points of V(x) = -x²/2 + x⁴/4
+//! assert_close(quartic_period(-1.5, 1.5, -0.5, 0.0, 0.25).unwrap(), duffing.period(), 1e-15);
+//! ```
+//!
+//! # References
+//! - Kovacic, Ivana, and Michael J. Brennan, eds. The Duffing Equation: Nonlinear Oscillators and Their Behaviour. Chichester: John Wiley & Sons, 2011. <https://doi.org/10.1002/9780470977859>.
+//! - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19.29>.
+
+use num_traits::Float;
+
+use crate::{
+    crate_util::{check, pow2_scale},
+    elliprf,
+    error::err,
+    EllipError, EllipticModulus,
+};
+
+/// Form of the solution of the Duffing oscillator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Solution {
+    /// x = A cn(ωt + u₀, m), the oscillation through x = 0 for β ≥ 0.
+    Cn,
+    /// x = ±A dn(ωt + u₀, m), the oscillation within one well of the double well.
+    Dn,
+    /// x = A sn(ωt + u₀, m), the oscillation inside the barriers of the softening potential.
+    Sn,
+    /// x = ±A sech(ωt + u₀), the approach to the hump of the double well.
+    Sech,
+}
+
+/// Undamped Duffing oscillator x'' + αx + βx³ = 0, see [mod@crate::duffing].
+///
+/// The phase and the period are computed at construction. The methods return NAN for NAN
+/// arguments.
+///
+/// ## Parameters
+/// - alpha: linear stiffness (α). α ∈ ℝ, α is finite.
+/// - beta: cubic stiffness (β). β ∈ ℝ, β is finite.
+/// - x0: initial position (x(0)). x(0) ∈ ℝ, x(0) is finite.
+/// - v0: initial velocity (x'(0)). x'(0) ∈ ℝ, x'(0) is finite.
+#[derive(Debug, Clone, Copy)]
+pub struct Duffing<T: Float> {
+    alpha: T,
+    beta: T,
+    energy: T,
+    solution: Solution,
+    amplitude: T,
+    /// Side of the well for [Solution::Dn] and [Solution::Sech].
+    sign: T,
+    omega: T,
+    /// Phase at t = 0.
+    u0: T,
+    period: T,
+    modulus: Option<EllipticModulus<T>>,
+}
+
+impl<T: Float> Duffing<T> {
+    /// Constructs the oscillator with the initial position x(0) and velocity x'(0).
+    ///
+    /// ## Domain
+    /// - Returns error if the motion is unbounded, which is the case for α ≤ 0 with β ≤ 0,
+    ///   and for β < 0 < α unless x(0)² < -α/β and E < -α²/4β.
+    /// - Returns error if any argument is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(alpha: T, beta: T, x0: T, v0: T) -> Result<Self, EllipError> {
+        check!(@nan, Duffing, [alpha, beta, x0, v0]);
+        check!(@inf, Duffing, [alpha, beta, x0, v0]);
+
+        // E = x'²/2 + x²(βx² + 2α)/4, with the rounding error of x² carried through the
+        // factor, which cancels near the hump of the double well.
+        let x2 = x0 * x0;
+        let x2_err = x0.mul_add(x0, -x2);
+        let factor = beta.mul_add(x2, 2.0 * alpha) + beta * x2_err;
+        let energy = 0.5 * v0 * v0 + 0.25 * x2 * factor;
+        let d2 = alpha * alpha + 4.0 * beta * energy;
+        let bounded = if beta > 0.0 {
+            true
+        } else if beta == 0.0 {
+            alpha > 0.0
+        } else {
+            alpha > 0.0 && x2 < -alpha / beta && d2 > 0.0
+        };
+        if !bounded {
+            return err!(
+                DomainError,
+                Duffing,
+                "The motion must be bounded.",
+                [alpha, beta, x0, v0]
+            );
+        }
+        let d = d2.sqrt();
+
+        let mut duffing = Self {
+            alpha,
+            beta,
+            energy,
+            solution: Solution::Cn,
+            amplitude: 0.0,
+            sign: if x0 < 0.0 { -1.0 } else { 1.0 },
+            omega: 0.0,
+            u0: 0.0,
+            period: inf!(),
+            modulus: None,
+        };
+
+        if alpha < 0.0 && energy == 0.0 {
+            duffing.solution = Solution::Sech;
+            duffing.amplitude = (-2.0 * alpha / beta).sqrt();
+            duffing.omega = (-alpha).sqrt();
+            // x'/x = -ω tanh(u₀), or at rest on the hump
+            duffing.u0 = if x0 == 0.0 {
+                inf!()
+            } else {
+                (-v0 / (duffing.omega * x0)).max(-1.0).min(1.0).atanh()
+            };
+            return Ok(duffing);
+        }
+
+        // A, ω², kc², and m of each solution, with D + α = 4βE / (D - α) for α < 0
+        let (a2, omega2, kc2, m) = if beta < 0.0 {
+            duffing.solution = Solution::Sn;
+            let a2 = 4.0 * energy / (d + alpha);
+            (
+                a2,
+                0.5 * (alpha + d),
+                2.0 * d / (alpha + d),
+                (alpha - d) / (alpha + d),
+            )
+        } else if alpha < 0.0 && energy < 0.0 {
+            duffing.solution = Solution::Dn;
+            let kc = 2.0 * (-beta * energy).sqrt() / (d - alpha);
+            (
+                (d - alpha) / beta,
+                0.5 * (d - alpha),
+                kc * kc,
+                2.0 * d / (d - alpha),
+            )
+        } else if alpha == 0.0 {
+            // The pure quartic oscillator has m = 1/2 at any amplitude, which is found below.
+            (0.0, 0.0, 0.5, 0.5)
+        } else {
+            let (a2, d_plus_alpha) = if alpha > 0.0 {
+                (4.0 * energy / (d + alpha), d + alpha)
+            } else {
+                ((d - alpha) / beta, 4.0 * beta * energy / (d - alpha))
+            };
+            (a2, d, 0.5 * d_plus_alpha / d, 0.5 * (d - alpha) / d)
+        };
+        let modulus = EllipticModulus::from_kc(kc2.sqrt())?;
+        let (a, omega) = if alpha != 0.0 {
+            (a2.sqrt(), omega2.sqrt())
+        } else if x0 == 0.0 && v0 == 0.0 {
+            (0.0, 0.0)
+        } else {
+            // A⁴ = x(0)⁴ + 2x'(0)² / β and ω = √β A, from the turning point rather than the
+            // energy, which underflows for small amplitudes. The distances are scaled by a
+            // power of two so that A⁴ does not underflow or overflow.
+            let c = (2.0 / beta).sqrt().sqrt() * v0.abs().sqrt();
+            let s = pow2_scale(x0.abs().max(c));
+            let a = s * ((x0 / s) * (x0 / s)).hypot((c / s) * (c / s)).sqrt();
+            (a, beta.sqrt() * a)
+        };
+        duffing.amplitude = a;
+        duffing.omega = omega;
+
+        // sn, cn, and dn at t = 0. Near the turning points, the velocity determines the small
+        // functions more accurately than the position.
+        // At rest at the bottom of the well, m = 0 and any phase is valid.
+        if a > 0.0 && !(duffing.solution == Solution::Dn && m == 0.0) {
+            let (sn, cn, dn) = match duffing.solution {
+                Solution::Cn => {
+                    // sn dn = -w
+                    let (cn, w) = ((x0 / a).max(-1.0).min(1.0), v0 / a / omega);
+                    let dn = (kc2 + m * cn * cn).sqrt();
+                    let sn = if cn * cn >= 0.5 {
+                        w.abs() / dn
+                    } else {
+                        ((1.0 - cn.abs()) * (1.0 + cn.abs())).sqrt()
+                    };
+                    (-w.signum() * sn, cn, dn)
+                }
+                Solution::Dn => {
+                    // sn cn = -w, with cn ≥ 0 for u₀ ∈ [-K, K]. The larger of sn and cn follows
+                    // from the position, and the smaller from the velocity.
+                    let kc = modulus.kc();
+                    let (dn, w) = (
+                        (x0.abs() / a).max(kc).min(1.0),
+                        v0 / (duffing.sign * a * omega * m),
+                    );
+                    let sn = ((1.0 - dn) * (1.0 + dn) / m).sqrt();
+                    let cn = ((dn - kc) * (dn + kc) / m).sqrt();
+                    let (sn, cn) = if sn <= cn {
+                        (w.abs() / cn, cn)
+                    } else {
+                        (sn, w.abs() / sn)
+                    };
+                    (-w.signum() * sn, cn, dn)
+                }
+                _ => {
+                    // cn dn = w
+                    let (sn, w) = ((x0 / a).max(-1.0).min(1.0), v0 / (a * omega));
+                    let cn = if sn * sn >= 0.5 {
+                        (2.0 * w * w / (kc2 + (kc2 * kc2 + 4.0 * m * w * w).sqrt())).sqrt()
+                    } else {
+                        ((1.0 - sn.abs()) * (1.0 + sn.abs())).sqrt()
+                    };
+                    (sn, w.signum() * cn, (kc2 + m * cn * cn).sqrt())
+                }
+            };
+            duffing.u0 = modulus.inverse_sn_cn_dn(sn, cn, dn);
+        }
+
+        let quarter = if duffing.solution == Solution::Dn {
+            0.5
+        } else {
+            1.0
+        };
+        duffing.period = 4.0 * quarter * modulus.ellipk() / omega;
+        duffing.modulus = Some(modulus);
+        Ok(duffing)
+    }
+
+    /// Returns the linear stiffness α.
+    pub fn alpha(&self) -> T {
+        self.alpha
+    }
+
+    /// Returns the cubic stiffness β.
+    pub fn beta(&self) -> T {
+        self.beta
+    }
+
+    /// Returns the energy E = x'²/2 + αx²/2 + βx⁴/4.
+    pub fn energy(&self) -> T {
+        self.energy
+    }
+
+    /// Returns the form of the solution.
+    pub fn solution(&self) -> Solution {
+        self.solution
+    }
+
+    /// Returns the amplitude A, the largest |x| along the motion.
+    pub fn amplitude(&self) -> T {
+        self.amplitude
+    }
+
+    /// Returns the angular frequency ω of the argument ωt + u₀.
+    pub fn omega(&self) -> T {
+        self.omega
+    }
+
+    /// Returns the parameter m of the Jacobi elliptic functions.
+    ///
+    /// Returns 1 on the separatrix.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn m(&self) -> T {
+        self.modulus.map_or(1.0, |modulus| modulus.m())
+    }
+
+    /// Returns the period.
+    ///
+    /// Returns ∞ on the separatrix and at rest for α = 0.
+    pub fn period(&self) -> T {
+        self.period
+    }
+
+    /// Returns the turning points (x₁, x₂) with x₁ ≤ x₂.
+    ///
+    /// On the separatrix, the inner turning point is the hump at x = 0, which is approached
+    /// but not reached.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn turning_points(&self) -> (T, T) {
+        let a = self.amplitude;
+        let inner = match (self.solution, self.modulus) {
+            (Solution::Dn, Some(modulus)) => a * modulus.kc(),
+            (Solution::Sech, _) => 0.0,
+            _ => return (-a, a),
+        };
+        if self.sign > 0.0 {
+            (inner, a)
+        } else {
+            (-a, -inner)
+        }
+    }
+
+    /// Returns the position x(t) and the velocity x'(t).
+    pub fn state(&self, t: T) -> (T, T) {
+        let (a, omega) = (self.amplitude, self.omega);
+        let u = omega * t + self.u0;
+        let Some(modulus) = self.modulus else {
+            let (sech, tanh) = (u.cosh().recip(), u.tanh());
+            return (self.sign * a * sech, -self.sign * a * omega * sech * tanh);
+        };
+        let (sn, cn, dn) = modulus.sn_cn_dn(u);
+        match self.solution {
+            Solution::Cn => (a * cn, -a * omega * sn * dn),
+            Solution::Dn => (
+                self.sign * a * dn,
+                -self.sign * a * omega * modulus.m() * sn * cn,
+            ),
+            _ => (a * sn, a * omega * cn * dn),
+        }
+    }
+
+    /// Returns the position x(t).
+    pub fn position(&self, t: T) -> T {
+        self.state(t).0
+    }
+
+    /// Returns the velocity x'(t).
+    pub fn velocity(&self, t: T) -> T {
+        self.state(t).1
+    }
+}
+
+/// Computes the period of the motion between the turning points in a quartic potential.
+/// ```text
+///         x₂
+///        ⌠         dx
+/// T = 2  │  ────────────── = 2√2 RF(0, (2√(Q(x₁)Q(x₂)) + N) / 4, √(Q(x₁)Q(x₂)))
+///        ⌡  √(2(E - V(x)))
+///        x₁
+/// ```
+///
+/// The unit mass moves in the potential V(x) = c₀ + c₁x + c₂x² + c₃x³ + c₄x⁴ between the
+/// turning points x₁ and x₂, where V(x₁) = V(x₂) = E. Dividing out the turning points leaves
+/// E - V(x) = (x - x₁)(x₂ - x) Q(x) with Q(x) = c₄x² + gx + f, g = c₃ + c₄(x₁ + x₂), and
+/// f = c₂ + g(x₁ + x₂) - c₄x₁x₂. The integral with the remaining roots of Q, real or
+/// complex, reduces to RF (Carlson, 2025, §19.29.4), where N = 2c₄x₁x₂ + g(x₁ + x₂) + 2f.
+/// The energy and the coefficients c₀ and c₁ follow from the turning points, so they do not
+/// enter the period.
+///
+/// ## Parameters
+/// - x1, x2: turning points. x1, x2 ∈ ℝ, x1 < x2.
+/// - c2, c3, c4: coefficients of x², x³, and x⁴ in the potential. c2, c3, c4 ∈ ℝ.
+///
+/// ## Domain
+/// - Returns error if x1 ≥ x2.
+/// - Returns error if V(x) > E somewhere between the turning points.
+/// - Returns error if any argument is infinite or NAN.
+///
+/// ## Special Cases
+/// - T = ∞ if Q(x₁) = 0 or Q(x₂) = 0, where the turning point is a maximum of V.
+///
+/// # Examples
+/// ```
+/// use ellip::{duffing::quartic_period, util::assert_close};
+/// use std::f64::consts::PI;
+///
+/// // Harmonic oscillator V(x) = x², with ω = √2
+/// assert_close(quartic_period(-1.0, 1.0, 1.0, 0.0, 0.0).unwrap(), PI * 2.0f64.sqrt(), 1e-15);
+/// // Asymmetric well V(x) = -x / 16 + x² - x³ + x⁴ / 2, where V(-1/2) = V(1)
+/// assert_close(quartic_period(-0.5, 1.0, 1.0, -1.0, 0.5).unwrap(), 4.974698993998626, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn quartic_period<T: Float>(x1: T, x2: T, c2: T, c3: T, c4: T) -> Result<T, EllipError> {
+    check!(@nan, quartic_period, [x1, x2, c2, c3, c4]);
+    check!(@inf, quartic_period, [x1, x2, c2, c3, c4]);
+    if x1 >= x2 {
+        return err!(
+            DomainError,
+            quartic_period,
+            "x1 must be less than x2.",
+            [x1, x2]
+        );
+    }
+
+    let (s, p) = (x1 + x2, x1 * x2);
+    let g = c3 + c4 * s;
+    let f = c2 + g * s - c4 * p;
+    let q = |x: T| (c4 * x + g) * x + f;
+    let (q1, q2) = (q(x1), q(x2));
+    // Q is convex for c₄ > 0 and may dip below zero between the turning points.
+    let vertex = -g / (2.0 * c4);
+    if q1 < 0.0 || q2 < 0.0 || (c4 > 0.0 && vertex > x1 && vertex < x2 && q(vertex) < 0.0) {
+        return err!(
+            DomainError,
+            quartic_period,
+            "The potential must lie below the energy between the turning points.",
+            [x1, x2, c2, c3, c4]
+        );
+    }
+
+    let pq = (q1 * q2).sqrt();
+    if pq == 0.0 {
+        return Ok(inf!());
+    }
+    let n = 2.0 * c4 * p + g * s + 2.0 * f;
+    Ok(2.0 * 2.0.sqrt() * elliprf(0.0, 0.25 * (2.0 * pq + n), pq)?)
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+    use std::f64::consts::PI;
+
+    /// mpmath references by integrating x'' = -αx - βx³ with the Taylor method,
+    /// (α, β, x(0), x'(0), t, x(t), x'(t)).
+    const REFERENCES: [[f64; 7]; 7] = [
+        [
+            1.0,
+            1.0,
+            1.0,
+            0.5,
+            3.0,
+            -0.8527615114096468,
+            0.8708537697015877,
+        ],
+        [
+            0.0,
+            2.0,
+            -0.7,
+            0.0,
+            5.0,
+            0.302097025940352,
+            -0.4814261474550838,
+        ],
+        [
+            1.0,
+            -1.0,
+            0.5,
+            0.2,
+            4.0,
+            -0.5503376632653452,
+            -0.04176173314764399,
+        ],
+        [
+            -1.0,
+            1.0,
+            0.0,
+            1.0,
+            6.0,
+            -0.8502987701957618,
+            1.2089820989490379,
+        ],
+        [
+            -1.0,
+            1.0,
+            1.0,
+            0.1,
+            7.0,
+            0.9610873394856178,
+            -0.08419207821065192,
+        ],
+        [
+            -1.0,
+            1.0,
+            -1.2,
+            -0.3,
+            2.5,
+            -0.6475998118688593,
+            0.13506817048368092,
+        ],
+        [
+            -2.0,
+            1.0,
+            2.0,
+            0.0,
+            1.5,
+            0.4727004853685476,
+            -0.6495594633224938,
+        ],
+    ];
+
+    #[test]
+    fn test_duffing() {
+        for [alpha, beta, x0, v0, t, x, v] in REFERENCES {
+            let duffing = Duffing::new(alpha, beta, x0, v0).unwrap();
+            let state = duffing.state(t);
+            assert_close!(x.abs(), state.0.abs(), 1e-13);
+            assert_close!(v.abs(), state.1.abs(), 1e-13);
+            assert_eq!(x.signum(), state.0.signum());
+            assert_eq!(v.signum(), state.1.signum());
+
+            let start = duffing.state(0.0);
+            assert!((x0 - start.0).abs() < 1e-14);
+            assert!((v0 - start.1).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_duffing_period() {
+        for [alpha, beta, x0, v0, ..] in REFERENCES {
+            let duffing = Duffing::new(alpha, beta, x0, v0).unwrap();
+            let (x1, x2) = duffing.turning_points();
+            if duffing.solution() == Solution::Sech {
+                assert_eq!(duffing.period(), f64::INFINITY);
+                continue;
+            }
+            assert_close!(
+                quartic_period(x1, x2, alpha / 2.0, 0.0, beta / 4.0).unwrap(),
+                duffing.period(),
+                1e-14
+            );
+            let t = 0.3;
+            assert_close!(
+                duffing.position(t),
+                duffing.position(t + duffing.period()),
+                1e-13
+            );
+        }
+
+        // Linear oscillator
+        let duffing = Duffing::new(4.0, 0.0, 1.0, 0.0).unwrap();
+        assert_close!(PI, duffing.period(), 1e-15);
+        assert_close!(1.5f64.cos(), duffing.position(0.75), 1e-15);
+        // Small amplitudes, T = 2π/√α (1 - 3βA²/8α)
+        let a: f64 = 1e-4;
+        let duffing = Duffing::new(1.0, 1.0, a, 0.0).unwrap();
+        assert_close!(
+            2.0 * PI * (1.0 - 3.0 * a * a / 8.0),
+            duffing.period(),
+            1e-15
+        );
+    }
+
+    #[test]
+    fn test_duffing_quartic() {
+        // x'' + βx³ = 0 with A⁴ = x(0)⁴ + 2x'(0)²/β and T = 4K(1/2) / √β A
+        let k = 1.8540746773013719;
+        let duffing = Duffing::new(0.0, 2.0, 0.5, 0.3).unwrap();
+        let a = (0.0625f64 + 0.09).powf(0.25);
+        assert_close!(a, duffing.amplitude(), 1e-15);
+        assert_close!(4.0 * k / (2.0f64.sqrt() * a), duffing.period(), 1e-15);
+        let (x, v) = duffing.state(0.0);
+        assert_close!(0.5, x, 1e-15);
+        assert_close!(0.3, v, 1e-15);
+
+        let duffing = Duffing::new(0.0, 1.0, 1e-300, 0.0).unwrap();
+        assert_eq!(duffing.amplitude(), 1e-300);
+        assert_close!(4.0 * k * 1e300, duffing.period(), 1e-15);
+        assert_close!(1e-300, duffing.position(0.0), 1e-15);
+        let duffing = Duffing::new(0.0, 1.0, 0.0, 1e-300).unwrap();
+        assert_close!(2.0f64.powf(0.25) * 1e-150, duffing.amplitude(), 1e-15);
+
+        // At rest
+        let duffing = Duffing::new(0.0, 1.0, 0.0, 0.0).unwrap();
+        assert_eq!(duffing.amplitude(), 0.0);
+        assert_eq!(duffing.period(), f64::INFINITY);
+        assert_eq!(duffing.state(1.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_duffing_separatrix() {
+        // mpmath references of the double well near E = 0, x'' - x + x³ = 0 released from rest
+        // at x = √2 ∓ δ.
+        for (x0, solution, expected) in [
+            (2.0f64.sqrt() - 1e-10, Solution::Dn, 25.451866948412416),
+            (2.0f64.sqrt() + 1e-10, Solution::Cn, 50.90373001981662),
+        ] {
+            let duffing = Duffing::new(-1.0, 1.0, x0, 0.0).unwrap();
+            assert_eq!(duffing.solution(), solution);
+            assert_close!(expected, duffing.period(), 1e-12);
+        }
+
+        // At rest at the bottom of the well, with the small-oscillation period 2π/√(-2α)
+        let duffing = Duffing::new(-1.0, 1.0, 1.0, 0.0).unwrap();
+        assert_eq!(duffing.solution(), Solution::Dn);
+        assert_eq!(duffing.state(2.0), (1.0, 0.0));
+        assert_close!(PI * 2.0f64.sqrt(), duffing.period(), 1e-15);
+
+        // Homoclinic orbit, x = 2 sech(√2 t)
+        let duffing = Duffing::new(-2.0, 1.0, 2.0, 0.0).unwrap();
+        assert_eq!(duffing.solution(), Solution::Sech);
+        assert_eq!(duffing.m(), 1.0);
+        assert_eq!(duffing.turning_points(), (0.0, 2.0));
+        assert_close!(2.0 / 2.0f64.sqrt().cosh(), duffing.position(1.0), 1e-15);
+        // At rest on the hump
+        let duffing = Duffing::new(-2.0, 1.0, 0.0, 0.0).unwrap();
+        assert_eq!(duffing.state(5.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_duffing_energy() {
+        for [alpha, beta, x0, v0, ..] in REFERENCES {
+            let duffing = Duffing::new(alpha, beta, x0, v0).unwrap();
+            for t in [0.1, 1.0, 3.0, 30.0] {
+                let (x, v) = duffing.state(t);
+                let energy = 0.5 * v * v + 0.5 * alpha * x * x + 0.25 * beta * x.powi(4);
+                assert!((duffing.energy() - energy).abs() < 1e-14 * (1.0 + alpha.abs()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_quartic_period() {
+        // mpmath references by quadrature, (x₁, x₂, c₂, c₃, c₄, T)
+        for [x1, x2, c2, c3, c4, expected] in [
+            [-0.5, 1.0, 1.0, -1.0, 0.5, 4.974698993998626],
+            [0.5, 1.5, -1.0, 0.0, 0.5, 3.1324665804331095],
+            [-1.0, 2.0, 1.0, 0.3, -0.05, 4.098721303209948],
+            [-1.0, 0.5, 1.0, 0.5, 0.0, 6.099547352327584],
+        ] {
+            assert_close!(expected, quartic_period(x1, x2, c2, c3, c4).unwrap(), 1e-14);
+        }
+
+        assert_eq!(quartic_period(0.0, 2.0, -1.0, 0.0, 0.25), Ok(f64::INFINITY));
+        assert_eq!(
+            quartic_period(1.0, 1.0, 1.0, 0.0, 0.0).unwrap_err(),
+            "quartic_period: x1 must be less than x2."
+        );
+        assert_eq!(
+            quartic_period(-1.0, 1.0, -0.5, 0.0, 0.125).unwrap_err(),
+            "quartic_period: The potential must lie below the energy between the turning points."
+        );
+        assert_eq!(
+            quartic_period(-1.0, 1.0, f64::NAN, 0.0, 0.0).unwrap_err(),
+            "quartic_period: Arguments cannot be NAN."
+        );
+    }
+
+    #[test]
+    fn test_duffing_errors() {
+        assert_eq!(
+            Duffing::new(-1.0, -1.0, 0.5, 0.0).unwrap_err(),
+            "Duffing: The motion must be bounded."
+        );
+        assert_eq!(
+            Duffing::new(1.0, -1.0, 1.5, 0.0).unwrap_err(),
+            "Duffing: The motion must be bounded."
+        );
+        assert_eq!(
+            Duffing::new(1.0, -1.0, 0.0, 1.0).unwrap_err(),
+            "Duffing: The motion must be bounded."
+        );
+        assert_eq!(
+            Duffing::new(0.0, 0.0, 0.0, 0.0).unwrap_err(),
+            "Duffing: The motion must be bounded."
+        );
+        assert_eq!(
+            Duffing::new(1.0, f64::NAN, 0.0, 0.0).unwrap_err(),
+            "Duffing: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            Duffing::new(1.0, 1.0, f64::INFINITY, 0.0).unwrap_err(),
+            "Duffing: x0 cannot be infinite."
+        );
+    }
+}
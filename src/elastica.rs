@@ -0,0 +1,722 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Euler's elastica, the large deflection of an inextensible rod loaded at its ends.
+//!
+//! The tangent angle θ(s) of a rod with the bending stiffness EI under the compressive end
+//! load P, measured from the line of action of the load, satisfies
+//! ```text
+//! θ'' + α² sin θ = 0,     α = √(P / EI),
+//! ```
+//! which is the equation of the [pendulum](mod@crate::pendulum) with the arc length s in place
+//! of time (Kirchhoff's kinetic analogy). The rod is inflectional when the curvature θ'
+//! changes sign (libration, m < 1) and non-inflectional when it forms loops (rotation, m > 1),
+//! with the energy parameter m = θ'² / 4α² + sin²(θ/2). The position along the rod follows
+//! from ∫cos θ ds and ∫sin θ ds, which reduce to the Jacobi amplitude and
+//! [ellipdinc](crate::ellipdinc) (Love, 1944, §263).
+//!
+//! Two classical boundary value problems are solved for the modulus:
+//! - [Cantilever]: a rod clamped horizontally at s = 0 and loaded at the free tip by the
+//!   transverse force P, where αL = K(k) - F(φ₁, k) with k² = (1 + sin φ₀) / 2,
+//!   sin φ₁ = 1 / (√2 k), and the tip rotation φ₀ (Bisshopp and Drucker, 1945).
+//! - [BuckledColumn]: a pinned-pinned column beyond the critical load π²EI / L², where
+//!   αL = 2K(k) with the end rotation 2 arcsin k.
+//!
+//! The load equations are solved by Newton's method in w ≈ -ln(1 - m), so that the moduli
+//! remain accurate for the large loads and the rod shapes approaching the transition between
+//! the inflectional and the non-inflectional elastica at m = 1.
+//!
+//! # Examples
+//! ```
+//! use ellip::{elastica::{BuckledColumn, Cantilever}, util::assert_close};
+//!
+//! // Cantilever of unit length and stiffness under the load parameter PL²/EI = 2
+//! let cantilever = Cantilever::new(1.0, 1.0, 2.0).unwrap();
+//! assert_close(cantilever.tip_rotation(), 0.78174983155654304, 1e-14);
+//! assert_close(cantilever.tip_deflection(), 0.49345748039671182, 1e-14);
+//!
+//! // Pinned-pinned column at twice the critical load
+//! let column = BuckledColumn::new(1.0, 1.0, 2.0 * std::f64::consts::PI.powi(2)).unwrap();
+//! assert_close(column.end_rotation(), 2.1738542409403358, 1e-14);
+//! ```
+//!
+//! # References
+//! - Love, A. E. H. A Treatise on the Mathematical Theory of Elasticity. 4th ed. New York: Dover, 1944.
+//! - Bisshopp, K. E., and D. C. Drucker. “Large Deflection of Cantilever Beams.” Quarterly of Applied Mathematics 3, no. 3 (1945): 272–75. <https://doi.org/10.1090/qam/13360>.
+//! - Timoshenko, Stephen P., and James M. Gere. Theory of Elastic Stability. 2nd ed. New York: McGraw-Hill, 1961.
+
+use num_traits::Float;
+
+use crate::{
+    carlson::{elliprd_unchecked, elliprf_unchecked},
+    crate_util::{check, solve_increasing},
+    error::err,
+    pendulum::{self, Pendulum},
+    EllipError, EllipticModulus,
+};
+
+/// Shape of the elastica.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Regime {
+    /// Wavy rod whose curvature changes sign, for m < 1.
+    Inflectional,
+    /// Single loop approaching the line of action asymptotically, for m = 1.
+    Separatrix,
+    /// Looped rod whose curvature keeps its sign, for m > 1.
+    NonInflectional,
+}
+
+/// Planar elastica θ'' + α² sin θ = 0, see [mod@crate::elastica].
+///
+/// The coordinates (x, y) are measured from the point at s = 0, with the x-axis along the
+/// line of action of the compressive load, and the angle θ from the x-axis. The methods return
+/// NAN for NAN arguments.
+///
+/// ## Parameters
+/// - alpha: load parameter (α = √(P / EI)). α ∈ ℝ, α > 0, α is finite.
+/// - theta0: tangent angle at s = 0 (θ(0)). θ(0) ∈ ℝ, θ(0) is finite.
+/// - curvature0: curvature at s = 0 (θ'(0)). θ'(0) ∈ ℝ, θ'(0) is finite.
+#[derive(Debug, Clone, Copy)]
+pub struct Elastica<T: Float> {
+    pendulum: Pendulum<T>,
+}
+
+impl<T: Float> Elastica<T> {
+    /// Constructs the elastica with the tangent angle θ(0) and the curvature θ'(0).
+    ///
+    /// ## Domain
+    /// - Returns error if α ≤ 0, or any argument is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(alpha: T, theta0: T, curvature0: T) -> Result<Self, EllipError> {
+        check!(@nan, Elastica, [alpha, theta0, curvature0]);
+        if alpha <= 0.0 {
+            return err!(DomainError, Elastica, "alpha must be positive.", alpha);
+        }
+        check!(@inf, Elastica, [alpha, theta0, curvature0]);
+        Ok(Self {
+            pendulum: Pendulum::new(alpha, theta0, curvature0)?,
+        })
+    }
+
+    /// Returns the load parameter α.
+    pub fn alpha(&self) -> T {
+        self.pendulum.omega0()
+    }
+
+    /// Returns the shape of the elastica.
+    pub fn regime(&self) -> Regime {
+        match self.pendulum.regime() {
+            pendulum::Regime::Libration => Regime::Inflectional,
+            pendulum::Regime::Separatrix => Regime::Separatrix,
+            pendulum::Regime::Rotation => Regime::NonInflectional,
+        }
+    }
+
+    /// Returns the energy parameter m = θ'² / 4α² + sin²(θ/2).
+    pub fn m(&self) -> T {
+        self.pendulum.m()
+    }
+
+    /// Returns the arc length of a period of the curvature.
+    ///
+    /// Returns ∞ on the separatrix.
+    pub fn wavelength(&self) -> T {
+        self.pendulum.period()
+    }
+
+    /// Returns the tangent angle θ(s).
+    pub fn angle(&self, s: T) -> T {
+        self.pendulum.angle(s)
+    }
+
+    /// Returns the curvature θ'(s), which is the bending moment divided by EI.
+    pub fn curvature(&self, s: T) -> T {
+        self.pendulum.angular_velocity(s)
+    }
+
+    /// Returns the position (x(s), y(s)) relative to the point at s = 0.
+    pub fn position(&self, s: T) -> (T, T) {
+        self.pendulum.displacement(s)
+    }
+}
+
+/// Cantilever under a transverse tip load, see [mod@crate::elastica].
+///
+/// The rod is clamped at the origin along the x-axis and the load acts at the free tip in the
+/// direction of -y.
+///
+/// ## Parameters
+/// - length: length of the rod (L). L ∈ ℝ, L > 0, L is finite.
+/// - stiffness: bending stiffness (EI). EI ∈ ℝ, EI > 0, EI is finite.
+/// - load: tip load (P). P ∈ ℝ, P > 0, P is finite.
+#[derive(Debug, Clone, Copy)]
+pub struct Cantilever<T: Float> {
+    length: T,
+    /// sin φ₀ and cos φ₀ of the tip rotation φ₀
+    sin_tip: T,
+    cos_tip: T,
+    tip_deflection: T,
+    tip_projection: T,
+    elastica: Elastica<T>,
+}
+
+/// Returns αL = K(k) - F(φ₁, k) of the cantilever and its derivative in z = ln(1 + sin φ₀)
+/// - ln(1 - sin φ₀), together with kc² = 1 - k² and sin φ₀.
+///
+/// With kc² = (1 - sin φ₀) / 2, the integral reads
+/// √(sin φ₀ / (1 + sin φ₀)) RF(kc² / (1 + sin φ₀), 1/2, kc²).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn cantilever_load<T: Float>(z: T) -> (T, T, T, T) {
+    // 1 - sin φ₀ = 2e^(-z) / (1 + e^(-z))
+    let e = (-z).exp();
+    let sin_tip = -(-z).exp_m1() / (1.0 + e);
+    let kc2 = e / (1.0 + e);
+    let (a, g) = (kc2 / (1.0 + sin_tip), sin_tip / (1.0 + sin_tip));
+    let sqrt_g = g.sqrt();
+    let rf = elliprf_unchecked(a, 0.5, kc2);
+    let slope = rf / (2.0 * sqrt_g * (1.0 + sin_tip) * (1.0 + sin_tip))
+        + sqrt_g
+            * (elliprd_unchecked(0.5, kc2, a) / (6.0 * (1.0 + sin_tip) * (1.0 + sin_tip))
+                + elliprd_unchecked(a, 0.5, kc2) / 12.0);
+    // d sin φ₀ / dz = (1 - sin²φ₀) / 2 = kc² (1 + sin φ₀)
+    let dsin = kc2 * (1.0 + sin_tip);
+    (sqrt_g * rf, slope * dsin, kc2, sin_tip)
+}
+
+/// Largest w = -ln(1 - m), where 1 - m is the square root of the smallest normal number, below
+/// which the Carlson integrals lose convergence.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn max_w<T: Float>() -> T {
+    -0.5 * T::min_positive_value().ln()
+}
+
+impl<T: Float> Cantilever<T> {
+    /// Constructs the cantilever of the length L and the bending stiffness EI under the tip
+    /// load P.
+    ///
+    /// ## Domain
+    /// - Returns error if L ≤ 0, EI ≤ 0, or P ≤ 0.
+    /// - Returns error if PL² / EI is too large for the modulus to be represented.
+    /// - Returns error if any argument is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(length: T, stiffness: T, load: T) -> Result<Self, EllipError> {
+        check!(@nan, Cantilever, [length, stiffness, load]);
+        if length.min(stiffness).min(load) <= 0.0 {
+            return err!(
+                DomainError,
+                Cantilever,
+                "Arguments must be positive.",
+                [length, stiffness, load]
+            );
+        }
+        check!(@inf, Cantilever, [length, stiffness, load]);
+
+        let alpha = (load / stiffness).sqrt();
+        let alpha_l = alpha * length;
+        // kc² ≈ e^(-z)
+        let hi = max_w();
+        if alpha_l >= cantilever_load(hi).0 {
+            return err!(
+                DomainError,
+                Cantilever,
+                "The load is too large.",
+                [length, stiffness, load]
+            );
+        }
+        // φ₀ ≈ (αL)² / 2 for small loads, and kc² ≈ 16e^(-2αL) / (1 + √2)² for large loads.
+        let z0 = if alpha_l < 1.5 {
+            alpha_l * alpha_l
+        } else {
+            2.0 * (alpha_l - 4.0.ln() + 2.0.sqrt().ln_1p())
+        };
+        let z = match solve_increasing(0.0, hi, z0.max(T::min_positive_value()).min(hi), |z| {
+            let (value, slope, _, _) = cantilever_load(z);
+            Ok((value - alpha_l, slope))
+        })? {
+            Some(z) => z,
+            None => return err!(NoConvergence, Cantilever, "Failed to converge."),
+        };
+
+        let (_, _, kc2, sin_tip) = cantilever_load(z);
+        let cos_tip = (2.0 * kc2 * (1.0 + sin_tip)).sqrt();
+        let a = kc2 / (1.0 + sin_tip);
+        // δ = L sin φ₀ (1 - kc² RD / 3RF), from ∫sin φ ds with sin φ = sin φ₀ - 2k² sin²τ
+        let rf = elliprf_unchecked(a, 0.5, kc2);
+        let ratio = kc2 * elliprd_unchecked(a, 0.5, kc2) / (3.0 * rf);
+        let tip_deflection = length * sin_tip * (1.0 - ratio);
+        // x(L) = √(2 sin φ₀) / α, with √(sin φ₀ / (1 + sin φ₀)) RF = αL
+        let tip_projection = length * (2.0 * (1.0 + sin_tip)).sqrt() / rf;
+
+        // In the frame of the load, θ(0) = -π/2 and the tip is at the phase -K.
+        let modulus = EllipticModulus::from_kc(kc2.sqrt())?;
+        let u0 = -(modulus.ellipk() + alpha_l);
+        Ok(Self {
+            length,
+            sin_tip,
+            cos_tip,
+            tip_deflection,
+            tip_projection,
+            elastica: Elastica {
+                pendulum: Pendulum::from_libration(alpha, modulus, u0),
+            },
+        })
+    }
+
+    /// Returns the rotation of the tip φ₀.
+    pub fn tip_rotation(&self) -> T {
+        self.sin_tip.atan2(self.cos_tip)
+    }
+
+    /// Returns the vertical deflection of the tip δ.
+    pub fn tip_deflection(&self) -> T {
+        self.tip_deflection
+    }
+
+    /// Returns the horizontal projection of the rod, L√(2 sin φ₀) / αL.
+    pub fn tip_projection(&self) -> T {
+        self.tip_projection
+    }
+
+    /// Returns the dimensionless load PL² / EI.
+    pub fn load_parameter(&self) -> T {
+        let alpha_l = self.elastica.alpha() * self.length;
+        alpha_l * alpha_l
+    }
+
+    /// Returns the deformed position (x(s), y(s)) at the arc length s from the clamp.
+    ///
+    /// The shape loses relative accuracy as PL² / EI → 0, where it approaches the straight rod.
+    pub fn shape(&self, s: T) -> (T, T) {
+        // The x-axis of the elastica points along +y and its y-axis along -x.
+        let (x, y) = self.elastica.position(s);
+        (-y, x)
+    }
+
+    /// Returns the elastica of the rod, with the x-axis pointing against the load.
+    pub fn elastica(&self) -> Elastica<T> {
+        self.elastica
+    }
+}
+
+/// Computes the Euler critical load of a pinned-pinned column.
+/// ```text
+///         π² EI
+/// P  =  ───────
+///  cr      L²
+/// ```
+///
+/// The column clamped at one end and free at the other buckles as half of the pinned-pinned
+/// column of the length 2L.
+///
+/// ## Parameters
+/// - length: length of the column (L). L ∈ ℝ, L > 0.
+/// - stiffness: bending stiffness (EI). EI ∈ ℝ, EI > 0.
+///
+/// ## Domain
+/// - Returns error if L ≤ 0 or EI ≤ 0, or any argument is NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{elastica::critical_load, util::assert_close};
+///
+/// assert_close(critical_load(2.0, 1.0).unwrap(), 2.4674011002723395, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn critical_load<T: Float>(length: T, stiffness: T) -> Result<T, EllipError> {
+    check!(@nan, critical_load, [length, stiffness]);
+    if length.min(stiffness) <= 0.0 {
+        return err!(
+            DomainError,
+            critical_load,
+            "Arguments must be positive.",
+            [length, stiffness]
+        );
+    }
+    Ok(pi!() * pi!() * stiffness / (length * length))
+}
+
+/// Pinned-pinned column in the post-buckling range, see [mod@crate::elastica].
+///
+/// The column lies between the pins on the x-axis, with the load applied at s = L in the
+/// direction of -x, and buckles toward +y.
+///
+/// ## Parameters
+/// - length: length of the column (L). L ∈ ℝ, L > 0, L is finite.
+/// - stiffness: bending stiffness (EI). EI ∈ ℝ, EI > 0, EI is finite.
+/// - load: axial load (P). P ∈ ℝ, P > π²EI / L², P is finite.
+#[derive(Debug, Clone, Copy)]
+pub struct BuckledColumn<T: Float> {
+    length: T,
+    modulus: EllipticModulus<T>,
+    elastica: Elastica<T>,
+}
+
+impl<T: Float> BuckledColumn<T> {
+    /// Constructs the column of the length L and the bending stiffness EI under the load P.
+    ///
+    /// ## Domain
+    /// - Returns error if L ≤ 0 or EI ≤ 0.
+    /// - Returns error if P does not exceed the critical load π²EI / L².
+    /// - Returns error if PL² / EI is too large for the modulus to be represented.
+    /// - Returns error if any argument is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(length: T, stiffness: T, load: T) -> Result<Self, EllipError> {
+        check!(@nan, BuckledColumn, [length, stiffness, load]);
+        if length.min(stiffness) <= 0.0 {
+            return err!(
+                DomainError,
+                BuckledColumn,
+                "Arguments must be positive.",
+                [length, stiffness]
+            );
+        }
+        check!(@inf, BuckledColumn, [length, stiffness, load]);
+
+        let alpha = (load / stiffness).sqrt();
+        // K(m) = αL / 2
+        let target = 0.5 * alpha * length;
+        if load <= 0.0 || target <= pi_2!() {
+            return err!(
+                DomainError,
+                BuckledColumn,
+                "The load must exceed the critical load.",
+                [length, stiffness, load]
+            );
+        }
+        let hi = max_w();
+        let modulus_at = |w: T| EllipticModulus::from_kc((-0.5 * w).exp());
+        if target >= modulus_at(hi)?.ellipk() {
+            return err!(
+                DomainError,
+                BuckledColumn,
+                "The load is too large.",
+                [length, stiffness, load]
+            );
+        }
+        // K ≈ π/2 (1 + m/4) for small m, and K ≈ ln(4 / kc) near m = 1.
+        let small = 4.0 * (target / pi_2!() - 1.0);
+        let w0 = if small < 0.5 {
+            -(-small).ln_1p()
+        } else {
+            2.0 * (target - 4.0.ln())
+        };
+        let w = match solve_increasing(0.0, hi, w0.max(T::min_positive_value()).min(hi), |w| {
+            let modulus = modulus_at(w)?;
+            // dK/dw = (K - D) / 2
+            Ok((
+                modulus.ellipk() - target,
+                0.5 * (modulus.ellipk() - modulus.ellipd()),
+            ))
+        })? {
+            Some(w) => w,
+            None => return err!(NoConvergence, BuckledColumn, "Failed to converge."),
+        };
+
+        // At s = 0, θ = 2 arcsin k and θ' = 0, at the phase K.
+        let modulus = modulus_at(w)?;
+        Ok(Self {
+            length,
+            modulus,
+            elastica: Elastica {
+                pendulum: Pendulum::from_libration(alpha, modulus, modulus.ellipk()),
+            },
+        })
+    }
+
+    /// Returns the parameter m = k².
+    pub fn m(&self) -> T {
+        self.modulus.m()
+    }
+
+    /// Returns the rotation at the pins, 2 arcsin k.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn end_rotation(&self) -> T {
+        2.0 * self.m().sqrt().atan2(self.modulus.kc())
+    }
+
+    /// Returns the deflection at the midspan, kL / K(k).
+    pub fn midspan_deflection(&self) -> T {
+        self.m().sqrt() * self.length / self.modulus.ellipk()
+    }
+
+    /// Returns the approach of the pins, 2mL D(k) / K(k).
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn end_shortening(&self) -> T {
+        2.0 * self.m() * self.length * self.modulus.ellipd() / self.modulus.ellipk()
+    }
+
+    /// Returns the deformed position (x(s), y(s)) at the arc length s from the pin at s = 0.
+    pub fn shape(&self, s: T) -> (T, T) {
+        self.elastica.position(s)
+    }
+
+    /// Returns the elastica of the column.
+    pub fn elastica(&self) -> Elastica<T> {
+        self.elastica
+    }
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+    use std::f64::consts::PI;
+
+    /// mpmath references by integrating θ'' = -α² sin θ, x' = cos θ, and y' = sin θ with the
+    /// Taylor method, (α, θ(0), θ'(0), s, θ(s), θ'(s), x(s), y(s)).
+    const REFERENCES: [[f64; 8]; 4] = [
+        [
+            2.0,
+            1.0,
+            0.5,
+            1.7,
+            -1.0078769101044589,
+            -0.44366758371171639,
+            1.2645285281673688,
+            0.2359168959279291,
+        ],
+        // Inflectional, m = 1 - 1e-8
+        [
+            1.5,
+            0.4,
+            2.940199718218642,
+            2.0,
+            2.9788530769287137,
+            0.2438398931964771,
+            -0.93597068111043184,
+            1.1983821444542956,
+        ],
+        [
+            1.0,
+            0.0,
+            2.0,
+            2.0,
+            2.6035206720920302,
+            0.53160445766815938,
+            -0.071944839848366232,
+            1.4683955423318406,
+        ],
+        // Non-inflectional, m = 1 + 1e-8
+        [
+            0.8,
+            -1.2,
+            -1.3205369935485118,
+            3.0,
+            -2.9503268117152015,
+            -0.15277963037608717,
+            -1.9230295727879077,
+            -1.8246208799569134,
+        ],
+    ];
+
+    #[test]
+    fn test_elastica() {
+        for [alpha, theta0, curvature0, s, theta, curvature, x, y] in REFERENCES {
+            let elastica = Elastica::new(alpha, theta0, curvature0).unwrap();
+            assert_close!(theta, elastica.angle(s), 1e-13);
+            assert_close!(curvature, elastica.curvature(s), 1e-13);
+            let position = elastica.position(s);
+            assert_close!(x, position.0, 1e-13);
+            assert_close!(y, position.1, 1e-13);
+            assert_eq!(elastica.position(0.0), (0.0, 0.0));
+        }
+        assert_eq!(
+            Elastica::new(1.0, 0.3, -2.5).unwrap().regime(),
+            Regime::NonInflectional
+        );
+        assert_eq!(
+            Elastica::new(2.0, 1.0, 0.5).unwrap().regime(),
+            Regime::Inflectional
+        );
+    }
+
+    #[test]
+    fn test_elastica_separatrix() {
+        // x = 2 tanh s - s and y = 2(1 - sech s) from θ = 2gd(s)
+        let elastica = Elastica::new(1.0, 0.0, 2.0).unwrap();
+        assert_eq!(elastica.regime(), Regime::Separatrix);
+        assert_eq!(elastica.wavelength(), f64::INFINITY);
+        let position = elastica.position(1.5);
+        assert_close!(0.31029650728973288, position.0, 1e-15);
+        assert_close!(1.1498079301154391, position.1, 1e-15);
+    }
+
+    #[test]
+    fn test_cantilever() {
+        // mpmath references from αL = K(k) - F(φ₁, k) solved by bisection,
+        // (αL, φ₀, δ / L, x(L) / L).
+        for [alpha_l, rotation, deflection, projection] in [
+            [1e-5, 5.0e-11, 3.3333333333333333e-11, 1.0],
+            [
+                0.1,
+                0.0049999541675597495,
+                0.0033332952389090696,
+                0.99999333346031431,
+            ],
+            [
+                1.0,
+                0.46135194971187896,
+                0.30172077379981353,
+                0.94356676371662312,
+            ],
+            [
+                3.0,
+                1.4054653336239528,
+                0.79905552747084022,
+                0.46817943732747275,
+            ],
+            [
+                10.0,
+                1.5706458846600332,
+                0.94142135086201098,
+                0.14142135543711792,
+            ],
+            [100.0, PI / 2.0, 0.99414213562373095, 0.01414213562373095],
+            [150.0, PI / 2.0, 0.99609475708248730, 0.0094280904158206337],
+        ] {
+            let cantilever = Cantilever::new(2.0, 3.0, 0.75 * alpha_l * alpha_l).unwrap();
+            assert_close!(alpha_l * alpha_l, cantilever.load_parameter(), 1e-15);
+            assert_close!(rotation, cantilever.tip_rotation(), 5e-15);
+            assert_close!(2.0 * deflection, cantilever.tip_deflection(), 5e-15);
+            assert_close!(2.0 * projection, cantilever.tip_projection(), 5e-15);
+            // The shape is formed by differences of the phase, which cancel as αL → 0.
+            if alpha_l >= 0.1 {
+                let tip = cantilever.shape(2.0);
+                assert_close!(2.0 * projection, tip.0, 1e-12);
+                assert_close!(-2.0 * deflection, tip.1, 1e-12);
+            }
+        }
+
+        // φ₀ ≈ PL² / 2EI and δ / L ≈ PL² / 3EI for tiny loads
+        let cantilever = Cantilever::new(1.0, 1.0, 1e-300).unwrap();
+        assert_close!(5e-301, cantilever.tip_rotation(), 1e-15);
+        assert_close!(1e-300 / 3.0, cantilever.tip_deflection(), 1e-15);
+        let cantilever = Cantilever::new(1e-200, 1e-200, 1e-200).unwrap();
+        assert_eq!(cantilever.tip_rotation(), 0.0);
+        assert_eq!(cantilever.tip_projection(), 1e-200);
+    }
+
+    #[test]
+    fn test_cantilever_shape() {
+        // mpmath references by integrating φ'' = -α² cos φ from the clamp at αL = √2
+        let cantilever = Cantilever::new(1.0, 1.0, 2.0).unwrap();
+        let (x, y) = cantilever.shape(0.3);
+        assert_close!(0.29052676764894565, x, 1e-14);
+        assert_close!(-0.065628007479451624, y, 1e-14);
+        let elastica = cantilever.elastica();
+        assert_close!(-PI / 2.0, elastica.angle(0.0), 1e-15);
+        assert_close!(-PI / 2.0 - 0.4151271594083946, elastica.angle(0.3), 1e-14);
+        assert!(elastica.curvature(1.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_cantilever_err() {
+        assert_eq!(
+            Cantilever::new(1.0, 0.0, 1.0).unwrap_err(),
+            "Cantilever: Arguments must be positive."
+        );
+        assert_eq!(
+            Cantilever::new(1.0, 1.0, -1.0).unwrap_err(),
+            "Cantilever: Arguments must be positive."
+        );
+        assert_eq!(
+            Cantilever::new(1.0, 1.0, 1e6).unwrap_err(),
+            "Cantilever: The load is too large."
+        );
+        assert_eq!(
+            Cantilever::new(f64::NAN, 1.0, 1.0).unwrap_err(),
+            "Cantilever: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            Cantilever::new(1.0, 1.0, f64::INFINITY).unwrap_err(),
+            "Cantilever: load cannot be infinite."
+        );
+    }
+
+    #[test]
+    fn test_buckled_column() {
+        // mpmath references from K(m) = αL / 2 solved by bisection,
+        // (PL² / EI, m, 2 arcsin k, kL / K, 2mLD / K).
+        for [load, m, rotation, deflection, shortening] in [
+            [
+                2.0 * PI * PI,
+                0.783581817487485,
+                2.1738542409403358,
+                0.39848070775997743,
+                0.92913818359829044,
+            ],
+            [
+                10.0,
+                0.025951179437044452,
+                0.32359744872924194,
+                0.10188460028295631,
+                0.026036473230779548,
+            ],
+            [
+                100.0,
+                0.9992725432844904,
+                3.0876433002391727,
+                0.19992724109380296,
+                1.5993452393267203,
+            ],
+        ] {
+            let column = BuckledColumn::new(1.0, 1.0, load).unwrap();
+            assert_close!(m, column.m(), 1e-13);
+            assert_close!(rotation, column.end_rotation(), 1e-14);
+            assert_close!(deflection, column.midspan_deflection(), 1e-14);
+            assert_close!(shortening, column.end_shortening(), 1e-13);
+            let end = column.shape(1.0);
+            assert_close!(1.0 - shortening, end.0, 1e-13);
+            assert!(end.1.abs() < 1e-14);
+            assert_close!(deflection, column.shape(0.5).1, 1e-14);
+        }
+
+        // mpmath references by integrating θ'' = -α² sin θ at twice the critical load
+        let column = BuckledColumn::new(1.0, 1.0, 2.0 * PI * PI).unwrap();
+        let (x, y) = column.shape(0.3);
+        assert_close!(-0.10019369033158756, x, 1e-14);
+        assert_close!(0.2743464039321099, y, 1e-14);
+        assert_close!(1.3941983244024599, column.elastica().angle(0.3), 1e-14);
+    }
+
+    #[test]
+    fn test_buckled_column_err() {
+        assert_eq!(
+            BuckledColumn::new(1.0, 1.0, 9.0).unwrap_err(),
+            "BuckledColumn: The load must exceed the critical load."
+        );
+        assert_eq!(
+            BuckledColumn::new(1.0, 1.0, PI * PI).unwrap_err(),
+            "BuckledColumn: The load must exceed the critical load."
+        );
+        assert_eq!(
+            BuckledColumn::new(1.0, 1.0, -20.0).unwrap_err(),
+            "BuckledColumn: The load must exceed the critical load."
+        );
+        assert_eq!(
+            BuckledColumn::new(-1.0, 1.0, 20.0).unwrap_err(),
+            "BuckledColumn: Arguments must be positive."
+        );
+        assert_eq!(
+            BuckledColumn::new(1.0, 1e-300, 1e300).unwrap_err(),
+            "BuckledColumn: The load is too large."
+        );
+        assert_eq!(
+            BuckledColumn::new(1.0, f64::NAN, 20.0).unwrap_err(),
+            "BuckledColumn: Arguments cannot be NAN."
+        );
+    }
+
+    #[test]
+    fn test_critical_load() {
+        assert_close!(PI * PI, critical_load(1.0, 1.0).unwrap(), 1e-15);
+        assert_close!(PI * PI / 4.0, critical_load(2.0, 1.0).unwrap(), 1e-15);
+        assert_eq!(
+            critical_load(0.0, 1.0).unwrap_err(),
+            "critical_load: Arguments must be positive."
+        );
+    }
+}
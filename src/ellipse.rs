@@ -0,0 +1,462 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Perimeter, arc length, and arc-length parametrization of ellipses.
+//!
+//! The ellipse with semi-axes a and b is parametrized by the eccentric angle t as
+//! (a cos t, b sin t). The arc length from t = 0 to φ is
+//! ```text
+//!          φ
+//!         ⌠   _________________________
+//! s(φ) =  │ ╲╱ a² sin²t + b² cos²t   dt = b E(φ, 1 - a²/b²)
+//!         ⌡
+//!        0
+//! ```
+//! Instead of evaluating [ellipeinc](crate::ellipeinc) with the parameter 1 - a²/b², which
+//! loses digits as the eccentricity approaches one, the arc length is computed from the
+//! symmetric integrals in a form where every term is positive (Carlson, 2025, §19.25.9–10).
+//!
+//! # Examples
+//! ```
+//! use ellip::{ellipeinc, util::assert_close, Ellipse};
+//!
+//! let ellipse = Ellipse::new(5.0, 3.0).unwrap();
+//! assert_close(ellipse.perimeter(), 25.526998863398124, 1e-15);
+//! assert_close(ellipse.arc_length(0.0, 0.5), 3.0 * ellipeinc(0.5, 1.0 - 25.0 / 9.0).unwrap(), 1e-15);
+//!
+//! // Eight points equally spaced in arc length around the ellipse
+//! let angles: Vec<f64> = ellipse.samples(0.0, 2.0 * std::f64::consts::PI, 8).collect();
+//! let quarter = ellipse.perimeter() / 4.0;
+//! assert_close(ellipse.arc_length(angles[0], angles[2]), quarter, 1e-14);
+//! ```
+//!
+//! # References
+//! - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19.25>.
+//! - Carlson, B. C. “DLMF: §19.30 Lengths of Plane Curves.” Accessed February 19, 2025. <https://dlmf.nist.gov/19.30>.
+
+use num_traits::Float;
+
+use crate::{
+    carlson::{elliprd_unchecked, elliprf_unchecked, elliprg_unchecked},
+    error::err,
+    EllipError,
+};
+
+/// Maximum number of iterations of the safeguarded Newton's method in [Ellipse::angle_at].
+const MAX_ITERATIONS: usize = 100;
+
+/// Ellipse with the semi-axes a and b.
+///
+/// The perimeter is computed at construction. The methods return NAN for NAN arguments.
+///
+/// ## Parameters
+/// - a ∈ ℝ, a > 0, a is finite.
+/// - b ∈ ℝ, b > 0, b is finite.
+#[derive(Debug, Clone, Copy)]
+pub struct Ellipse<T: Float> {
+    a: T,
+    b: T,
+    /// Larger semi-axis, used to normalize the integrals.
+    scale: T,
+    perimeter: T,
+}
+
+impl<T: Float> Ellipse<T> {
+    /// Constructs the ellipse with the semi-axes a and b.
+    ///
+    /// ## Domain
+    /// - Returns error if a ≤ 0, b ≤ 0, or a or b is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(a: T, b: T) -> Result<Self, EllipError> {
+        if a.is_nan() || b.is_nan() {
+            return err!(NanArgument, Ellipse, "Arguments cannot be NAN.", [a, b]);
+        }
+        if a <= 0.0 || b <= 0.0 {
+            return err!(
+                DomainError,
+                Ellipse,
+                "The semi-axes must be positive.",
+                [a, b]
+            );
+        }
+        if a.is_infinite() || b.is_infinite() {
+            return err!(
+                InfiniteArgument,
+                Ellipse,
+                "The semi-axes must be finite.",
+                [a, b]
+            );
+        }
+
+        let scale = a.max(b);
+        let (an, bn) = (a / scale, b / scale);
+        Ok(Self {
+            a,
+            b,
+            scale,
+            perimeter: 8.0 * scale * elliprg_unchecked(0.0, an * an, bn * bn),
+        })
+    }
+
+    /// Returns the semi-axis along x.
+    pub fn a(&self) -> T {
+        self.a
+    }
+
+    /// Returns the semi-axis along y.
+    pub fn b(&self) -> T {
+        self.b
+    }
+
+    /// Returns the eccentricity.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn eccentricity(&self) -> T {
+        let r = self.a.min(self.b) / self.scale;
+        ((1.0 - r) * (1.0 + r)).sqrt()
+    }
+
+    /// Returns the perimeter.
+    pub fn perimeter(&self) -> T {
+        self.perimeter
+    }
+
+    /// Returns the partial derivatives of the perimeter with respect to the semi-axes
+    /// (∂P/∂a, ∂P/∂b).
+    ///
+    /// ```text
+    /// ∂P/∂a = 4/3 a b² RD(0, a², b²)
+    /// ∂P/∂b = 4/3 a² b RD(0, b², a²)
+    /// ```
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn perimeter_derivative(&self) -> (T, T) {
+        let (a, b) = (self.a / self.scale, self.b / self.scale);
+        let (a2, b2) = (a * a, b * b);
+        // The limits of the degenerate ellipse, when the smaller squared semi-axis underflows
+        let dp_da = if b2 == 0.0 {
+            4.0
+        } else {
+            4.0 / 3.0 * a * b2 * elliprd_unchecked(0.0, a2, b2)
+        };
+        let dp_db = if a2 == 0.0 {
+            4.0
+        } else {
+            4.0 / 3.0 * a2 * b * elliprd_unchecked(0.0, b2, a2)
+        };
+        (dp_da, dp_db)
+    }
+
+    /// Returns the point (a cos t, b sin t) at the eccentric angle t.
+    pub fn point(&self, t: T) -> (T, T) {
+        let (s, c) = t.sin_cos();
+        (self.a * c, self.b * s)
+    }
+
+    /// Returns the arc length from 0 to the eccentric angle φ, for 0 ≤ φ ≤ π/2.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn quarter_arc(&self, phi: T) -> T {
+        let (a, b) = (self.a / self.scale, self.b / self.scale);
+        let (s, c) = phi.sin_cos();
+        if s == 0.0 {
+            return 0.0;
+        }
+        let (a2, b2) = (a * a, b * b);
+        let (s2, c2) = (s * s, c.max(0.0) * c.max(0.0));
+        let x = b2 * c2;
+        let y = a2 * s2 + x;
+
+        let arc = if a >= b {
+            if b2 == 0.0 {
+                // Degenerate to the segment along x
+                1.0 - c
+            } else {
+                b2 * s * elliprf_unchecked(x, y, b2)
+                    + (a2 - b2) * b2 * s * s2 / 3.0 * elliprd_unchecked(x, y, b2)
+            }
+        } else if a2 == 0.0 {
+            // Degenerate to the segment along y
+            s
+        } else {
+            a2 * s * elliprf_unchecked(x, y, b2)
+                + (b2 - a2) * a2 * s * s2 / 3.0 * elliprd_unchecked(x, b2, y)
+                + (b2 - a2) * s * c.max(0.0) / y.sqrt()
+        };
+        self.scale * arc
+    }
+
+    /// Returns the arc length from 0 to the eccentric angle φ.
+    ///
+    /// The arc length is odd in φ and increases by half the perimeter every π.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn arc_length_from_zero(&self, phi: T) -> T {
+        if phi.is_infinite() {
+            return phi;
+        }
+        // s(φ + jπ) = s(φ) + jP/2
+        let j = (phi / pi!()).round();
+        let r = phi - j * pi!();
+        let arc = self.quarter_arc(r.abs());
+        j * 0.5 * self.perimeter + arc.copysign(r)
+    }
+
+    /// Returns the arc length from the eccentric angle t₀ to t₁, which is negative if t₁ < t₀.
+    pub fn arc_length(&self, t0: T, t1: T) -> T {
+        self.arc_length_from_zero(t1) - self.arc_length_from_zero(t0)
+    }
+
+    /// Returns the eccentric angle φ at which [arc_length_from_zero](Ellipse::arc_length_from_zero)
+    /// equals s. This is the inverse of the arc length.
+    ///
+    /// Solved by Newton's method safeguarded by bisection.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn angle_at(&self, s: T) -> T {
+        if s.is_nan() {
+            return nan!();
+        }
+        if s.is_infinite() {
+            return s;
+        }
+        let half = 0.5 * self.perimeter;
+        let j = (s / half).round();
+        let target = s - j * half;
+        let goal = target.abs();
+
+        // s(φ) on [0, π/2] is increasing from 0 to P/4.
+        let (mut lo, mut hi) = (0.0, pi_2!());
+        let mut phi = (pi_2!() * goal / (0.5 * half)).min(pi_2!());
+        for _ in 0..MAX_ITERATIONS {
+            let f = self.quarter_arc(phi) - goal;
+            if f == 0.0 {
+                break;
+            }
+            if f < 0.0 {
+                lo = phi;
+            } else {
+                hi = phi;
+            }
+            let (sin, cos) = phi.sin_cos();
+            let (a, b) = (self.a * sin, self.b * cos);
+            let slope = (a * a + b * b).sqrt();
+            let mut next = phi - f / slope;
+            if !(next > lo && next < hi) {
+                next = 0.5 * (lo + hi);
+            }
+            let done = (next - phi).abs() <= epsilon!() * next.max(epsilon!());
+            phi = next;
+            if done || hi - lo <= epsilon!() * hi {
+                break;
+            }
+        }
+        j * pi!() + phi.copysign(target)
+    }
+
+    /// Returns n + 1 eccentric angles from t₀ to t₁ (inclusive) that divide the arc into n
+    /// pieces of equal length.
+    ///
+    /// Use t₁ = t₀ + 2π to sample the whole ellipse. The first and last angles are exactly
+    /// t₀ and t₁. Returns an empty iterator if n = 0.
+    pub fn samples(&self, t0: T, t1: T, n: usize) -> ArcLengthSamples<T> {
+        let s0 = self.arc_length_from_zero(t0);
+        let step = (self.arc_length_from_zero(t1) - s0) / T::from(n.max(1)).unwrap();
+        ArcLengthSamples {
+            ellipse: *self,
+            t0,
+            t1,
+            s0,
+            step,
+            i: 0,
+            n: if n == 0 { 0 } else { n + 1 },
+        }
+    }
+}
+
+/// Iterator over eccentric angles equally spaced in arc length, see [Ellipse::samples].
+#[derive(Debug, Clone, Copy)]
+pub struct ArcLengthSamples<T: Float> {
+    ellipse: Ellipse<T>,
+    t0: T,
+    t1: T,
+    s0: T,
+    step: T,
+    i: usize,
+    /// Number of angles.
+    n: usize,
+}
+
+impl<T: Float> Iterator for ArcLengthSamples<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.i >= self.n {
+            return None;
+        }
+        let i = self.i;
+        self.i += 1;
+        Some(match i {
+            0 => self.t0,
+            i if i + 1 == self.n => self.t1,
+            i => self
+                .ellipse
+                .angle_at(self.s0 + T::from(i).unwrap() * self.step),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.n - self.i;
+        (len, Some(len))
+    }
+}
+
+impl<T: Float> ExactSizeIterator for ArcLengthSamples<T> {}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_close, ellipe, ellipeinc};
+    use core::f64::consts::{FRAC_PI_2, PI};
+    use itertools::iproduct;
+
+    const AXES: [(f64, f64); 7] = [
+        (1.0, 1.0),
+        (5.0, 3.0),
+        (3.0, 5.0),
+        (1.0, 1e-3),
+        (1e-3, 1.0),
+        (2.0, 1e-9),
+        (1e-9, 2.0),
+    ];
+    const PHIS: [f64; 9] = [-7.0, -1.2, 0.0, 1e-3, 0.5, 1.0, FRAC_PI_2, 2.5, 40.0];
+
+    #[test]
+    fn test_ellipse() {
+        for ((a, b), phi) in iproduct!(AXES, PHIS) {
+            let ellipse = Ellipse::new(a, b).unwrap();
+            let e2 = 1.0 - (a.min(b) / a.max(b)).powi(2);
+            assert_close!(
+                4.0 * a.max(b) * ellipe(e2).unwrap(),
+                ellipse.perimeter(),
+                5e-15
+            );
+
+            // b E(φ, 1 - a²/b²) is well-conditioned for moderate eccentricities.
+            if (a / b).max(b / a) <= 5.0 {
+                let expected = b * ellipeinc(phi, 1.0 - a * a / (b * b)).unwrap();
+                let actual = ellipse.arc_length_from_zero(phi);
+                if expected != 0.0 {
+                    assert_close!(expected.abs(), actual.abs(), 5e-15);
+                }
+                assert_eq!(expected.signum(), actual.signum());
+            }
+            // The inverse is ill-conditioned where the curve is flat, so check the residual.
+            let s = ellipse.arc_length_from_zero(phi);
+            let residual = ellipse.arc_length_from_zero(ellipse.angle_at(s)) - s;
+            assert!(residual.abs() <= 5e-15 * ellipse.perimeter());
+        }
+    }
+
+    #[test]
+    fn test_ellipse_high_eccentricity() {
+        // Quarter arc of a thin ellipse along x: a(1 - cos φ) + O(b²)
+        let ellipse = Ellipse::new(1.0, 1e-9).unwrap();
+        assert_close!(4.0, ellipse.perimeter(), 1e-15);
+        assert_close!(1.0 - 1.0f64.cos(), ellipse.arc_length_from_zero(1.0), 1e-15);
+        assert_close!(1.0, ellipse.eccentricity(), 1e-15);
+
+        // Along y: b sin φ + O(a²)
+        let ellipse = Ellipse::new(1e-9, 1.0).unwrap();
+        assert_close!(0.5f64.sin(), ellipse.arc_length_from_zero(0.5), 1e-15);
+
+        // 1 - e² = 1e-8, where 4 E(1 - 10⁻⁸) = 4.0000002019326953754
+        let ellipse = Ellipse::new(1.0, 1e-4).unwrap();
+        assert_close!(4.0000002019326953754, ellipse.perimeter(), 2e-15);
+        let ellipse = Ellipse::new(1e-300, 1.0).unwrap();
+        assert_close!(4.0, ellipse.perimeter(), 1e-15);
+        assert_close!(1.0, ellipse.arc_length_from_zero(FRAC_PI_2), 1e-15);
+    }
+
+    #[test]
+    fn test_ellipse_derivative() {
+        for (a, b) in AXES {
+            let ellipse = Ellipse::new(a, b).unwrap();
+            let (dp_da, dp_db) = ellipse.perimeter_derivative();
+            // Euler's theorem for the homogeneous function of degree one
+            assert_close!(ellipse.perimeter(), a * dp_da + b * dp_db, 5e-15);
+
+            if (a / b).max(b / a) <= 5.0 {
+                let h = 1e-6 * a;
+                let fd = (Ellipse::new(a + h, b).unwrap().perimeter()
+                    - Ellipse::new(a - h, b).unwrap().perimeter())
+                    / (2.0 * h);
+                assert_close!(fd, dp_da, 1e-7);
+            }
+        }
+        // Reference values from the numerical derivative at 40 digits
+        let (dp_da, dp_db) = Ellipse::new(1e-3, 1.0).unwrap().perimeter_derivative();
+        assert_close!(0.029176219442576514, dp_da, 5e-15);
+        assert_close!(3.9999864118852457, dp_db, 5e-15);
+        // Circle: P = 2πr, so ∂P/∂a = ∂P/∂b = π
+        let (dp_da, dp_db) = Ellipse::new(2.0, 2.0).unwrap().perimeter_derivative();
+        assert_close!(PI, dp_da, 5e-16);
+        assert_close!(PI, dp_db, 5e-16);
+    }
+
+    #[test]
+    fn test_ellipse_samples() {
+        for (a, b) in AXES {
+            let ellipse = Ellipse::new(a, b).unwrap();
+            let (t0, t1) = (-0.3, 5.0);
+            let angles: Vec<f64> = ellipse.samples(t0, t1, 10).collect();
+            assert_eq!(angles.len(), 11);
+            assert_eq!((angles[0], angles[10]), (t0, t1));
+            let step = ellipse.arc_length(t0, t1) / 10.0;
+            for w in angles.windows(2) {
+                assert_close!(step, ellipse.arc_length(w[0], w[1]), 1e-12);
+            }
+        }
+        let ellipse = Ellipse::new(2.0, 1.0).unwrap();
+        assert_eq!(ellipse.samples(0.0, 1.0, 0).count(), 0);
+        assert_eq!(ellipse.samples(0.0, 1.0, 1).collect::<Vec<_>>(), [0.0, 1.0]);
+        // Reversed direction
+        let angles: Vec<f64> = ellipse.samples(1.0, -1.0, 4).collect();
+        assert_close!(0.0, angles[2], 1e-15);
+        assert!(angles.windows(2).all(|w| w[1] < w[0]));
+    }
+
+    #[test]
+    fn test_ellipse_special_cases() {
+        use std::f64::{INFINITY, NAN};
+        assert_eq!(
+            Ellipse::new(0.0, 1.0).unwrap_err(),
+            "Ellipse: The semi-axes must be positive."
+        );
+        assert!(matches!(
+            Ellipse::new(NAN, 1.0).unwrap_err(),
+            EllipError::NanArgument(_)
+        ));
+        assert!(matches!(
+            Ellipse::new(1.0, INFINITY).unwrap_err(),
+            EllipError::InfiniteArgument(_)
+        ));
+
+        let ellipse = Ellipse::new(3.0, 2.0).unwrap();
+        assert!(ellipse.arc_length_from_zero(NAN).is_nan());
+        assert_eq!(ellipse.arc_length_from_zero(INFINITY), INFINITY);
+        assert!(ellipse.angle_at(NAN).is_nan());
+        assert_eq!(ellipse.angle_at(0.0), 0.0);
+        assert_eq!(ellipse.point(0.0), (3.0, 0.0));
+        assert_close!(
+            ellipse.perimeter(),
+            ellipse.arc_length(0.0, 2.0 * PI),
+            5e-16
+        );
+
+        let ellipse = Ellipse::new(3.0f32, 2.0).unwrap();
+        assert_close!(
+            ellipse.perimeter() as f64,
+            Ellipse::new(3.0, 2.0).unwrap().perimeter(),
+            1e-7
+        );
+    }
+}
@@ -0,0 +1,995 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Surface areas, demagnetizing factors, and potentials of ellipsoids.
+//!
+//! The surface area of the ellipsoid with semi-axes a, b, and c is (Carlson, 2025, §19.33.1)
+//! ```text
+//! S = 4π RG(b²c², c²a², a²b²)
+//! ```
+//! which is symmetric in the semi-axes and reduces to RC for spheroids. Unlike the classical
+//! formulas in arcsin e / e or ln((1 + e)/(1 - e)) / e, it stays accurate when two semi-axes are
+//! nearly equal and includes the degenerate disk c = 0.
+//!
+//! Caps and zones are cut by planes normal to the c-axis. For spheroids with the polar axis c,
+//! the area reduces to elementary functions and RC. For triaxial ellipsoids, each section
+//! contributes a complete elliptic integral whose modulus varies with the height, which is
+//! integrated numerically.
+//!
+//! The demagnetizing (depolarization) factors and the Newtonian potential of a homogeneous
+//! ellipsoid are expressed by RF and RD of the squared semi-axes shifted by the confocal
+//! parameter λ (Kellogg, 1929, §VII.6; Osborn, 1945).
+//!
+//! # Examples
+//! ```
+//! use ellip::{ellipsoid::*, util::assert_close};
+//! use std::f64::consts::PI;
+//!
+//! // Sphere and disk limits
+//! assert_close(ellipsoid_surface_area(2.0, 2.0, 2.0).unwrap(), 16.0 * PI, 1e-15);
+//! assert_close(ellipsoid_surface_area(3.0, 2.0, 0.0).unwrap(), 12.0 * PI, 1e-15);
+//!
+//! // Earth (WGS 84) in km²
+//! let area = spheroid_surface_area(6378.137, 6356.752314245).unwrap();
+//! assert_close(area, 510065621.7240789, 1e-14);
+//! ```
+//!
+//! # References
+//! - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19.33>.
+//! - Kellogg, Oliver Dimon. Foundations of Potential Theory. Berlin: Springer, 1929. <https://doi.org/10.1007/978-3-642-90850-7>.
+//! - Osborn, J. A. “Demagnetizing Factors of the General Ellipsoid.” Physical Review 67, no. 11–12 (1945): 351–57. <https://doi.org/10.1103/PhysRev.67.351>.
+//! - Piessens, Robert, Elise de Doncker-Kapenga, Christoph W. Überhuber, and David K. Kahaner. QUADPACK: A Subroutine Package for Automatic Integration. Berlin: Springer, 1983. <https://doi.org/10.1007/978-3-642-61786-7>.
+
+use num_traits::Float;
+
+use crate::{
+    carlson::{elliprc_unchecked, elliprd_unchecked, elliprf_unchecked, elliprg_unchecked},
+    crate_util::check,
+    error::err,
+    EllipError,
+};
+
+/// Computes the surface area of the ellipsoid with the semi-axes a, b, and c.
+/// ```text
+/// x²   y²   z²
+/// ── + ── + ── = 1
+/// a²   b²   c²
+/// ```
+///
+/// The spheroids, where two semi-axes are equal, are computed by [spheroid_surface_area].
+///
+/// ## Parameters
+/// - a, b, c ∈ ℝ, a, b, c ≥ 0.
+///
+/// ## Domain
+/// - Returns error if a, b, or c is negative, infinite, or NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{ellipsoid::ellipsoid_surface_area, util::assert_close};
+///
+/// assert_close(ellipsoid_surface_area(3.0, 2.0, 1.0).unwrap(), 48.88214630258206, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellipsoid_surface_area<T: Float>(a: T, b: T, c: T) -> Result<T, EllipError> {
+    check!(@nan, ellipsoid_surface_area, [a, b, c]);
+    check!(@neg, ellipsoid_surface_area, "The semi-axes must be non-negative.", [a, b, c]);
+    check!(@inf, ellipsoid_surface_area, [a, b, c]);
+
+    if a == b {
+        return spheroid_surface_area(a, c);
+    }
+    if b == c {
+        return spheroid_surface_area(b, a);
+    }
+    if c == a {
+        return spheroid_surface_area(c, b);
+    }
+
+    let scale = a.max(b).max(c);
+    let (a2, b2, c2) = (
+        (a / scale).powi(2),
+        (b / scale).powi(2),
+        (c / scale).powi(2),
+    );
+    Ok(4.0 * pi!() * scale * scale * elliprg_unchecked(b2 * c2, c2 * a2, a2 * b2))
+}
+
+/// Computes the surface area of the spheroid with the equatorial semi-axis a and the polar
+/// semi-axis c.
+/// ```text
+/// S = 2π (a² + a c² RC(a², c²))
+/// ```
+///
+/// The spheroid is oblate if c < a and prolate if c > a.
+///
+/// ## Parameters
+/// - a ∈ ℝ, a ≥ 0.
+/// - c ∈ ℝ, c ≥ 0.
+///
+/// ## Domain
+/// - Returns error if a or c is negative, infinite, or NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{ellipsoid::spheroid_surface_area, util::assert_close};
+///
+/// assert_close(spheroid_surface_area(2.0, 5.0).unwrap(), 104.60734552427736, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn spheroid_surface_area<T: Float>(a: T, c: T) -> Result<T, EllipError> {
+    check!(@nan, spheroid_surface_area, [a, c]);
+    check!(@neg, spheroid_surface_area, "The semi-axes must be non-negative.", [a, c]);
+    check!(@inf, spheroid_surface_area, [a, c]);
+
+    if a == 0.0 {
+        return Ok(0.0);
+    }
+    let scale = a.max(c);
+    let (a, c) = (a / scale, c / scale);
+    let area = if c == 0.0 {
+        // Both faces of the disk
+        2.0 * a * a
+    } else {
+        2.0 * (a * a + a * c * c * elliprc_unchecked(a * a, c * c))
+    };
+    Ok(pi!() * scale * scale * area)
+}
+
+/// Validates the semi-axes and the finite arguments of the zones and caps.
+macro_rules! check_zone {
+    ($fn_name:ident, [$($axis:ident),+], $c:ident, [$($var:ident),+]) => {
+        check!(@nan, $fn_name, [$($axis,)+ $($var),+]);
+        check!(@neg, $fn_name, "The semi-axes must be non-negative.", [$($axis),+]);
+        check!(@inf, $fn_name, [$($axis,)+ $($var),+]);
+        if $c == T::zero() {
+            return err!(DomainError, $fn_name, "c must be positive.", $c);
+        }
+    };
+}
+
+/// Validates the planes z₁ ≤ z₂ within [-c, c] of the zones.
+macro_rules! check_planes {
+    ($fn_name:ident, $c:ident, $z1:ident, $z2:ident) => {
+        if $z1 > $z2 {
+            return err!(
+                DomainError,
+                $fn_name,
+                "z1 must not be greater than z2.",
+                [$z1, $z2]
+            );
+        }
+        if $z1 < -$c || $z2 > $c {
+            return err!(
+                DomainError,
+                $fn_name,
+                "z1 and z2 must be within [-c, c].",
+                [$z1, $z2]
+            );
+        }
+    };
+}
+
+/// Validates the height 0 ≤ h ≤ 2c of the caps.
+macro_rules! check_height {
+    ($fn_name:ident, $c:ident, $h:ident) => {
+        if $h < T::zero() || $h > $c + $c {
+            return err!(DomainError, $fn_name, "h must be within [0, 2c].", $h);
+        }
+    };
+}
+
+/// Computes the area of the zone of the spheroid between the planes z = z₁ and z = z₂
+/// perpendicular to the polar axis.
+///
+/// The spheroid has the equatorial semi-axis a and the polar semi-axis c, so the zone from
+/// -c to c is the whole surface. The zone area is
+/// ```text
+///         2πa    z₂ ┌──────────────────────┐
+/// A = ─────────  ∫  ╲╱ c⁴ - (c² - a²) z²  dz = 2πac [G(z₂/c) - G(z₁/c)]
+///         c²   z₁
+/// ```
+/// where G(t) = t/2 (w + RC(w², 1)) with w² = 1 - t² + (a/c)² t².
+///
+/// ## Parameters
+/// - a ∈ ℝ, a ≥ 0.
+/// - c ∈ ℝ, c > 0.
+/// - z₁, z₂ ∈ ℝ, -c ≤ z₁ ≤ z₂ ≤ c.
+///
+/// ## Domain
+/// - Returns error if:
+///   - a < 0 or c ≤ 0,
+///   - z₁ > z₂, or z₁ or z₂ is outside [-c, c],
+///   - or any argument is infinite or NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{ellipsoid::spheroid_zone_area, util::assert_close};
+/// use std::f64::consts::PI;
+///
+/// // Archimedes' hat-box theorem: the zone of a sphere has the area 2πr(z₂ - z₁).
+/// assert_close(spheroid_zone_area(2.0, 2.0, -0.5, 1.0).unwrap(), 6.0 * PI, 1e-15);
+/// ```
+pub fn spheroid_zone_area<T: Float>(a: T, c: T, z1: T, z2: T) -> Result<T, EllipError> {
+    check_zone!(spheroid_zone_area, [a, c], c, [z1, z2]);
+    check_planes!(spheroid_zone_area, c, z1, z2);
+    Ok(spheroid_zone(a, c, z1, z2))
+}
+
+/// Computes the area of the cap of height h at the pole of the spheroid.
+///
+/// The spheroid has the equatorial semi-axis a and the polar semi-axis c. The cap is the
+/// zone from c - h to c, see [spheroid_zone_area].
+///
+/// ## Parameters
+/// - a ∈ ℝ, a ≥ 0.
+/// - c ∈ ℝ, c > 0.
+/// - h ∈ ℝ, 0 ≤ h ≤ 2c.
+///
+/// ## Domain
+/// - Returns error if a < 0, c ≤ 0, h is outside [0, 2c], or any argument is infinite or NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{ellipsoid::{spheroid_cap_area, spheroid_surface_area}, util::assert_close};
+///
+/// // Half of an oblate spheroid
+/// let half = spheroid_cap_area(3.0, 1.0, 1.0).unwrap();
+/// assert_close(2.0 * half, spheroid_surface_area(3.0, 1.0).unwrap(), 1e-15);
+/// ```
+pub fn spheroid_cap_area<T: Float>(a: T, c: T, h: T) -> Result<T, EllipError> {
+    check_zone!(spheroid_cap_area, [a, c], c, [h]);
+    check_height!(spheroid_cap_area, c, h);
+    Ok(spheroid_zone(a, c, c - h, c))
+}
+
+/// Computes the area of the zone of the ellipsoid with the semi-axes a, b, and c between the
+/// planes z = z₁ and z = z₂ perpendicular to the c-axis.
+/// ```text
+///        z₂/c
+///       ⌠
+/// A  =  │  8 RG(0, b²(c²(1 - u²) + a²u²), a²(c²(1 - u²) + b²u²)) du
+///       ⌡
+///      z₁/c
+/// ```
+/// The integrand is the integral over the azimuth of the section at z = cu, a complete
+/// elliptic integral of the second kind whose modulus varies with u. The integral over u is
+/// evaluated by the adaptive Gauss–Kronrod rule (Piessens et al., 1983) to the rounding error.
+/// Spheroids with a = b are computed in closed form by [spheroid_zone_area].
+///
+/// ## Parameters
+/// - a, b ∈ ℝ, a, b ≥ 0.
+/// - c ∈ ℝ, c > 0.
+/// - z₁, z₂ ∈ ℝ, -c ≤ z₁ ≤ z₂ ≤ c.
+///
+/// ## Domain
+/// - Returns error if:
+///   - a < 0, b < 0, or c ≤ 0,
+///   - z₁ > z₂, or z₁ or z₂ is outside [-c, c],
+///   - or any argument is infinite or NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{ellipsoid::{ellipsoid_surface_area, ellipsoid_zone_area}, util::assert_close};
+///
+/// assert_close(ellipsoid_zone_area(3.0, 2.0, 1.0, -0.3, 0.6).unwrap(), 16.834241281676875, 1e-15);
+/// // The zone from -c to c is the whole surface.
+/// let total = ellipsoid_zone_area(3.0, 2.0, 1.0, -1.0, 1.0).unwrap();
+/// assert_close(total, ellipsoid_surface_area(3.0, 2.0, 1.0).unwrap(), 1e-15);
+/// ```
+pub fn ellipsoid_zone_area<T: Float>(a: T, b: T, c: T, z1: T, z2: T) -> Result<T, EllipError> {
+    check_zone!(ellipsoid_zone_area, [a, b, c], c, [z1, z2]);
+    check_planes!(ellipsoid_zone_area, c, z1, z2);
+    Ok(ellipsoid_zone(a, b, c, z1, z2))
+}
+
+/// Computes the area of the cap of height h at the pole z = c of the ellipsoid with the
+/// semi-axes a, b, and c.
+///
+/// The cap is the zone from c - h to c, see [ellipsoid_zone_area].
+///
+/// ## Parameters
+/// - a, b ∈ ℝ, a, b ≥ 0.
+/// - c ∈ ℝ, c > 0.
+/// - h ∈ ℝ, 0 ≤ h ≤ 2c.
+///
+/// ## Domain
+/// - Returns error if a < 0, b < 0, c ≤ 0, h is outside [0, 2c], or any argument is infinite
+///   or NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{ellipsoid::ellipsoid_cap_area, util::assert_close};
+///
+/// assert_close(ellipsoid_cap_area(3.0, 2.0, 1.0, 0.1).unwrap(), 3.6164231100209213, 1e-15);
+/// ```
+pub fn ellipsoid_cap_area<T: Float>(a: T, b: T, c: T, h: T) -> Result<T, EllipError> {
+    check_zone!(ellipsoid_cap_area, [a, b, c], c, [h]);
+    check_height!(ellipsoid_cap_area, c, h);
+    Ok(ellipsoid_zone(a, b, c, c - h, c))
+}
+
+/// Computes the zone of the spheroid with validated arguments.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn spheroid_zone<T: Float>(a: T, c: T, z1: T, z2: T) -> T {
+    let r = a / c;
+    let g = |t: T| {
+        let w2 = (1.0 - t) * (1.0 + t) + r * r * t * t;
+        0.5 * t * (w2.sqrt() + elliprc_unchecked(w2, 1.0))
+    };
+    2.0 * pi!() * a * c * (g(z2 / c) - g(z1 / c))
+}
+
+/// Computes the zone of the ellipsoid with validated arguments.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn ellipsoid_zone<T: Float>(a: T, b: T, c: T, z1: T, z2: T) -> T {
+    if a == b {
+        return spheroid_zone(a, c, z1, z2);
+    }
+    let scale = a.max(b).max(c);
+    let (a2, b2, c2) = (
+        (a / scale).powi(2),
+        (b / scale).powi(2),
+        (c / scale).powi(2),
+    );
+    // u = sin θ removes the square root of 1 - u² at the poles from the integrand.
+    let section = |theta: T| {
+        let (sin, cos) = theta.sin_cos();
+        let (sin2, cos2) = (sin * sin, cos * cos);
+        8.0 * cos
+            * elliprg_unchecked(
+                0.0,
+                b2 * (c2 * cos2 + a2 * sin2),
+                a2 * (c2 * cos2 + b2 * sin2),
+            )
+    };
+    let (t1, t2) = ((z1 / c).asin(), (z2 / c).asin());
+    integrate(section, t1, t2) * scale * scale
+}
+
+/// Nodes of the 15-point Kronrod rule on [-1, 1] from the largest to the center, where the
+/// odd-indexed nodes and the center are the nodes of the 7-point Gauss rule.
+const KRONROD_NODES: [f64; 8] = [
+    0.991455371120812639206854697526329,
+    0.949107912342758524526189684047851,
+    0.864864423359769072789712788640926,
+    0.741531185599394439863864773280788,
+    0.586087235467691130294144845693013,
+    0.405845151377397166906606412076961,
+    0.207784955007898467600689403773245,
+    0.0,
+];
+
+/// Weights of the 15-point Kronrod rule.
+const KRONROD_WEIGHTS: [f64; 8] = [
+    0.022935322010529224963732008058970,
+    0.063092092629978553290700663189204,
+    0.104790010322250183839876322541518,
+    0.140653259715525918745189590510238,
+    0.169004726639267902826583426598550,
+    0.190350578064785409913256402421014,
+    0.204432940075298892414161999234649,
+    0.209482141084727828012999174891714,
+];
+
+/// Weights of the 7-point Gauss rule.
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.129484966168869693270611432679082,
+    0.279705391489276667901467771423780,
+    0.381830050505118944950369775488975,
+    0.417959183673469387755102040816327,
+];
+
+/// Maximum depth of the bisection of the adaptive quadrature.
+const MAX_DEPTH: usize = 64;
+
+/// Integrates the positive function f over [lo, hi] by the adaptive Gauss–Kronrod rule,
+/// bisecting until the 7-point Gauss and the 15-point Kronrod rules agree to the rounding
+/// error on every subinterval.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn integrate<T: Float>(f: impl Fn(T) -> T, lo: T, hi: T) -> T {
+    let weight = |weights: &[f64], i: usize| T::from(weights[i]).unwrap();
+    let tolerance = 50.0 * epsilon!();
+
+    // Subintervals pending in depth-first order, at most one per level besides the current.
+    let mut pending = [(lo, hi, 0); MAX_DEPTH + 1];
+    let mut len = 1;
+    let mut sum = 0.0;
+    while len > 0 {
+        len -= 1;
+        let (lo, hi, depth) = pending[len];
+        let (center, half) = (0.5 * (lo + hi), 0.5 * (hi - lo));
+        let fc = f(center);
+        let mut kronrod = weight(&KRONROD_WEIGHTS, 7) * fc;
+        let mut gauss = weight(&GAUSS_WEIGHTS, 3) * fc;
+        for i in 0..7 {
+            let dx = half * weight(&KRONROD_NODES, i);
+            let fs = f(center - dx) + f(center + dx);
+            kronrod = kronrod + weight(&KRONROD_WEIGHTS, i) * fs;
+            if i % 2 == 1 {
+                gauss = gauss + weight(&GAUSS_WEIGHTS, i / 2) * fs;
+            }
+        }
+        if depth < MAX_DEPTH && (kronrod - gauss).abs() > tolerance * kronrod {
+            pending[len] = (center, hi, depth + 1);
+            pending[len + 1] = (lo, center, depth + 1);
+            len += 2;
+        } else {
+            sum = sum + half * kronrod;
+        }
+    }
+    sum
+}
+
+/// Maximum number of Newton iterations for the confocal parameter.
+const MAX_ITERATIONS: usize = 100;
+
+/// Computes the demagnetizing factors (Na, Nb, Nc) of the ellipsoid with the semi-axes a, b,
+/// and c.
+/// ```text
+///      abc
+/// Na = ─── RD(b², c², a²)
+///       3
+/// ```
+/// and cyclically for Nb and Nc. The factors sum to one. The same factors are the
+/// depolarization factors of a dielectric ellipsoid.
+///
+/// The degenerate ellipsoids are supported: the disk c = 0 gives (0, 0, 1) and the needle
+/// b = c = 0 gives (0, 1/2, 1/2).
+///
+/// ## Parameters
+/// - a, b, c ∈ ℝ, a, b, c ≥ 0.
+///
+/// ## Domain
+/// - Returns error if:
+///   - a, b, or c is negative, infinite, or NAN,
+///   - or a = b = c = 0.
+///
+/// # Examples
+/// ```
+/// use ellip::{ellipsoid::demagnetizing_factors, util::assert_close};
+///
+/// let (na, nb, nc) = demagnetizing_factors(3.0, 2.0, 1.0).unwrap();
+/// assert_close(na + nb + nc, 1.0, 1e-15);
+/// assert_close(nc, 0.5765452609087245, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn demagnetizing_factors<T: Float>(a: T, b: T, c: T) -> Result<(T, T, T), EllipError> {
+    check!(@nan, demagnetizing_factors, [a, b, c]);
+    check!(@neg, demagnetizing_factors, "The semi-axes must be non-negative.", [a, b, c]);
+    check!(@inf, demagnetizing_factors, [a, b, c]);
+
+    let scale = a.max(b).max(c);
+    if scale == 0.0 {
+        return err!(
+            DomainError,
+            demagnetizing_factors,
+            "At least one semi-axis must be positive.",
+            [a, b, c]
+        );
+    }
+    let (a, b, c) = (a / scale, b / scale, c / scale);
+    let (a2, b2, c2) = (a * a, b * b, c * c);
+    let degenerate = a2 == 0.0 || b2 == 0.0 || c2 == 0.0;
+    // Nz, where z is the squared semi-axis along the factor
+    let n = |x: T, y: T, z: T| {
+        if !degenerate {
+            a * b * c / 3.0 * elliprd_unchecked(x, y, z)
+        } else if z != 0.0 {
+            0.0
+        } else if x == 0.0 || y == 0.0 {
+            // Transverse to the needle
+            0.5
+        } else {
+            // Normal to the disk
+            1.0
+        }
+    };
+    Ok((n(b2, c2, a2), n(c2, a2, b2), n(a2, b2, c2)))
+}
+
+/// Returns the largest root λ of x²/(a² + λ) + y²/(b² + λ) + z²/(c² + λ) = 1 for a point
+/// outside the ellipsoid, or zero inside.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn confocal_parameter<T: Float>(a2: T, b2: T, c2: T, x2: T, y2: T, z2: T) -> Option<T> {
+    let f = |l: T| x2 / (a2 + l) + y2 / (b2 + l) + z2 / (c2 + l) - 1.0;
+    if f(0.0) <= 0.0 {
+        return Some(0.0);
+    }
+    // f is convex and decreasing, and f(r² - max(a², b², c²)) ≥ 0, so Newton's method
+    // started there increases monotonically to the root.
+    let mut l = (x2 + y2 + z2 - a2.max(b2).max(c2)).max(0.0);
+    for _ in 0..MAX_ITERATIONS {
+        let value = f(l);
+        let slope = -(x2 / (a2 + l).powi(2) + y2 / (b2 + l).powi(2) + z2 / (c2 + l).powi(2));
+        let step = value / slope;
+        if step >= 0.0 || step.is_nan() {
+            return Some(l);
+        }
+        l = l - step;
+        if -step <= epsilon!() * l {
+            return Some(l);
+        }
+    }
+    None
+}
+
+/// Computes the Newtonian potential of the homogeneous ellipsoid with the semi-axes a, b, and c
+/// and unit density at the point (x, y, z).
+/// ```text
+///                ∞
+///               ⌠  ⎛     x²      y²      z²  ⎞      ds
+/// ψ = π a b c   │  ⎜1 - ──── - ──── - ────⎟ ─────────────────────────
+///               ⌡  ⎝    a²+s    b²+s    c²+s ⎠  √((a²+s)(b²+s)(c²+s))
+///              λ
+///   = π a b c [2 RF(A, B, C) - 2/3 (x² RD(B, C, A) + y² RD(C, A, B) + z² RD(A, B, C))]
+/// ```
+/// where A = a² + λ, B = b² + λ, and C = c² + λ. The confocal parameter λ is zero inside the
+/// ellipsoid and the largest root of x²/A + y²/B + z²/C = 1 outside.
+///
+/// The potential is ψ = ∫ dV'/|r - r'| over the ellipsoid, so ψ → V/r far away, where V is
+/// the volume. Beyond the distance max(a, b, c) / ε, ψ = V/r is returned. Multiply by -Gρ for the gravitational potential, or by ρ/(4πε₀) for the
+/// electrostatic potential of a uniform charge density ρ.
+///
+/// ## Parameters
+/// - a, b, c ∈ ℝ, a, b, c > 0.
+/// - x, y, z ∈ ℝ.
+///
+/// ## Domain
+/// - Returns error if:
+///   - a, b, or c is not positive,
+///   - or any argument is infinite or NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{ellipsoid::ellipsoid_potential, util::assert_close};
+/// use std::f64::consts::PI;
+///
+/// // Outside a sphere of radius 2, the potential is V/r.
+/// let volume = 4.0 / 3.0 * PI * 8.0;
+/// assert_close(ellipsoid_potential(2.0, 2.0, 2.0, 3.0, 0.0, 4.0).unwrap(), volume / 5.0, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellipsoid_potential<T: Float>(a: T, b: T, c: T, x: T, y: T, z: T) -> Result<T, EllipError> {
+    check!(@nan, ellipsoid_potential, [a, b, c, x, y, z]);
+    if a.min(b).min(c) <= 0.0 {
+        return err!(
+            DomainError,
+            ellipsoid_potential,
+            "The semi-axes must be positive.",
+            [a, b, c]
+        );
+    }
+    check!(@inf, ellipsoid_potential, [a, b, c, x, y, z]);
+
+    let scale = a.max(b).max(c);
+    let (a, b, c) = (a / scale, b / scale, c / scale);
+    let (x, y, z) = (x / scale, y / scale, z / scale);
+
+    // Far away, ψ = V/r up to the quadrupole term, smaller by about 1/r², which is below the
+    // rounding error and spares the squares of the coordinates from overflow.
+    let far = x.abs().max(y.abs()).max(z.abs());
+    if far >= 1.0 / epsilon!() {
+        let r = far * ((x / far).powi(2) + (y / far).powi(2) + (z / far).powi(2)).sqrt();
+        return Ok(4.0 / 3.0 * pi!() * a * b * c / r * scale * scale);
+    }
+
+    let (a2, b2, c2) = (a * a, b * b, c * c);
+    let (x2, y2, z2) = (x * x, y * y, z * z);
+    let Some(l) = confocal_parameter(a2, b2, c2, x2, y2, z2) else {
+        return err!(
+            NoConvergence,
+            ellipsoid_potential,
+            "Failed to converge for the confocal parameter."
+        );
+    };
+    let (aa, bb, cc) = (a2 + l, b2 + l, c2 + l);
+    let psi = 2.0 * elliprf_unchecked(aa, bb, cc)
+        - 2.0 / 3.0
+            * (x2 * elliprd_unchecked(bb, cc, aa)
+                + y2 * elliprd_unchecked(cc, aa, bb)
+                + z2 * elliprd_unchecked(aa, bb, cc));
+    Ok(pi!() * a * b * c * psi * scale * scale)
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_close, ellipeinc, ellipf};
+    use std::f64::consts::PI;
+
+    /// Legendre's form for a > b > c (Carlson, 2025, §19.33.2)
+    fn surface_area_legendre(a: f64, b: f64, c: f64) -> f64 {
+        let phi = (c / a).acos();
+        let m = a * a * (b * b - c * c) / (b * b * (a * a - c * c));
+        let (s, cs) = phi.sin_cos();
+        2.0 * PI * c * c
+            + 2.0 * PI * a * b / s
+                * (ellipeinc(phi, m).unwrap() * s * s + ellipf(phi, m).unwrap() * cs * cs)
+    }
+
+    #[test]
+    fn test_ellipsoid_surface_area() {
+        for (a, b, c) in [
+            (3.0, 2.0, 1.0),
+            (1.0, 0.9, 0.1),
+            (10.0, 1.0, 0.5),
+            (2.0, 1.5, 1.4),
+        ] {
+            let expected = surface_area_legendre(a, b, c);
+            // Symmetric in the semi-axes
+            for (x, y, z) in [(a, b, c), (b, c, a), (c, a, b), (b, a, c)] {
+                assert_close!(expected, ellipsoid_surface_area(x, y, z).unwrap(), 5e-15);
+            }
+        }
+
+        // Sphere and the nearly spherical limit
+        assert_close!(
+            4.0 * PI,
+            ellipsoid_surface_area(1.0, 1.0, 1.0).unwrap(),
+            5e-16
+        );
+        let d = 1e-10;
+        assert_close!(
+            4.0 * PI + 8.0 * PI / 3.0 * d,
+            ellipsoid_surface_area(1.0, 1.0, 1.0 + d).unwrap(),
+            5e-16
+        );
+        assert_close!(
+            4.0 * PI + 8.0 * PI / 3.0 * (2.0 * d),
+            ellipsoid_surface_area(1.0 + d, 1.0 - d, 1.0 + 2.0 * d).unwrap(),
+            5e-16
+        );
+
+        // Degenerate disk, needle, and point
+        assert_close!(
+            12.0 * PI,
+            ellipsoid_surface_area(3.0, 2.0, 0.0).unwrap(),
+            5e-16
+        );
+        assert_close!(
+            18.0 * PI,
+            ellipsoid_surface_area(3.0, 0.0, 3.0).unwrap(),
+            5e-16
+        );
+        assert_eq!(ellipsoid_surface_area(3.0, 0.0, 0.0).unwrap(), 0.0);
+        assert_eq!(ellipsoid_surface_area(0.0, 0.0, 0.0).unwrap(), 0.0);
+
+        // Thin disk: 2πab + O(c)
+        let area = ellipsoid_surface_area(3.0, 2.0, 1e-12).unwrap();
+        assert_close!(12.0 * PI, area, 1e-11);
+
+        // Large and small scales
+        assert_close!(
+            surface_area_legendre(3.0, 2.0, 1.0) * 1e300,
+            ellipsoid_surface_area(3e150, 2e150, 1e150).unwrap(),
+            5e-15
+        );
+        assert_close!(
+            surface_area_legendre(3.0, 2.0, 1.0) * 1e-300,
+            ellipsoid_surface_area(3e-150, 2e-150, 1e-150).unwrap(),
+            5e-15
+        );
+    }
+
+    #[test]
+    fn test_spheroid_surface_area() {
+        // Classical formulas are accurate away from the sphere.
+        let (a, c) = (2.0, 5.0);
+        let e = (1.0 - a * a / (c * c)).sqrt();
+        let prolate = 2.0 * PI * a * a * (1.0 + c / (a * e) * e.asin());
+        assert_close!(prolate, spheroid_surface_area(a, c).unwrap(), 5e-15);
+
+        let (a, c) = (5.0, 2.0);
+        let e = (1.0 - c * c / (a * a)).sqrt();
+        let oblate = 2.0 * PI * a * a + PI * c * c / e * ((1.0 + e) / (1.0 - e)).ln();
+        assert_close!(oblate, spheroid_surface_area(a, c).unwrap(), 5e-15);
+
+        assert_close!(2.0 * PI, spheroid_surface_area(1.0, 0.0).unwrap(), 5e-16);
+        assert_eq!(spheroid_surface_area(0.0, 1.0).unwrap(), 0.0);
+        // Needle: π² a c
+        assert_close!(
+            PI * PI * 1e-12,
+            spheroid_surface_area(1e-12, 1.0).unwrap(),
+            1e-11
+        );
+    }
+
+    #[test]
+    fn test_spheroid_zone_area() {
+        for (a, c) in [(2.0, 5.0), (5.0, 2.0), (1.0, 1.0), (1.0, 1.0 + 1e-12)] {
+            let total = spheroid_surface_area(a, c).unwrap();
+            assert_close!(total, spheroid_zone_area(a, c, -c, c).unwrap(), 5e-15);
+            assert_close!(total, spheroid_cap_area(a, c, 2.0 * c).unwrap(), 5e-15);
+
+            let (z1, z2) = (-0.3 * c, 0.6 * c);
+            let zone = spheroid_zone_area(a, c, z1, z2).unwrap();
+            let caps =
+                spheroid_cap_area(a, c, c - z2).unwrap() + spheroid_cap_area(a, c, c + z1).unwrap();
+            assert_close!(total, zone + caps, 5e-15);
+        }
+        // Reference by numerical quadrature at 30 digits
+        assert_close!(
+            31.104922642601423,
+            spheroid_zone_area(2.0, 5.0, -1.0, 1.5).unwrap(),
+            5e-15
+        );
+        // Archimedes' hat-box theorem
+        assert_close!(
+            2.0 * PI * 3.0 * 1e-6,
+            spheroid_cap_area(3.0, 3.0, 1e-6).unwrap(),
+            1e-9
+        );
+        assert_eq!(spheroid_zone_area(2.0, 1.0, 0.5, 0.5).unwrap(), 0.0);
+        assert_eq!(spheroid_cap_area(0.0, 1.0, 1.0).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_ellipsoid_zone_area() {
+        // References by numerical quadrature at 30 digits
+        assert_close!(
+            16.834241281676875,
+            ellipsoid_zone_area(3.0, 2.0, 1.0, -0.3, 0.6).unwrap(),
+            5e-15
+        );
+        assert_close!(
+            3.6164231100209213,
+            ellipsoid_cap_area(3.0, 2.0, 1.0, 0.1).unwrap(),
+            5e-15
+        );
+        assert_close!(
+            23.955707073196069,
+            ellipsoid_zone_area(1.0, 2.0, 5.0, -1.0, 1.5).unwrap(),
+            5e-15
+        );
+        assert_close!(
+            16.834241f32,
+            ellipsoid_zone_area(3.0f32, 2.0, 1.0, -0.3, 0.6).unwrap(),
+            1e-6
+        );
+
+        for (a, b, c) in [
+            (3.0, 2.0, 1.0),
+            (1.0, 1e-3, 1e-6),
+            (1e-6, 1e-3, 1.0),
+            (1.0, 1e-10, 1.0),
+            (1.0, 0.0, 0.5),
+            (2.0, 1.5, 1.4),
+        ] {
+            let total = ellipsoid_surface_area(a, b, c).unwrap();
+            assert_close!(total, ellipsoid_zone_area(a, b, c, -c, c).unwrap(), 5e-15);
+            assert_close!(total, ellipsoid_cap_area(a, b, c, 2.0 * c).unwrap(), 5e-15);
+
+            let (z1, z2) = (-0.3 * c, 0.6 * c);
+            let zone = ellipsoid_zone_area(a, b, c, z1, z2).unwrap();
+            let caps = ellipsoid_cap_area(a, b, c, c - z2).unwrap()
+                + ellipsoid_cap_area(b, a, c, c + z1).unwrap();
+            assert_close!(total, zone + caps, 5e-15);
+        }
+
+        // Spheroids in closed form, and continuity with the triaxial case
+        assert_eq!(
+            ellipsoid_zone_area(2.0, 2.0, 5.0, -1.0, 1.5),
+            spheroid_zone_area(2.0, 5.0, -1.0, 1.5)
+        );
+        // The area grows by 7.806 δ for b = 2 + δ.
+        assert_close!(
+            31.104922642601423 + 7.806e-12,
+            ellipsoid_zone_area(2.0, 2.0 + 1e-12, 5.0, -1.0, 1.5).unwrap(),
+            5e-15
+        );
+        assert_eq!(ellipsoid_zone_area(3.0, 2.0, 1.0, 0.5, 0.5).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_demagnetizing_factors() {
+        for (a, b, c) in [
+            (3.0, 2.0, 1.0),
+            (1.0, 1e-3, 1e-6),
+            (5.0, 5.0, 1.0),
+            (2.0, 1.0, 2.0),
+        ] {
+            let (na, nb, nc) = demagnetizing_factors(a, b, c).unwrap();
+            assert_close!(1.0, na + nb + nc, 5e-15);
+            // Largest factor along the shortest axis
+            assert!(nc >= nb || c >= b);
+        }
+        let (na, nb, nc) = demagnetizing_factors(3.0, 2.0, 1.0).unwrap();
+        assert_close!(0.15630069882927098, na, 5e-15);
+        assert_close!(0.57654526090872450, nc, 5e-15);
+        assert_close!(1.0 - na - nc, nb, 5e-15);
+
+        // Sphere and the nearly spherical limit
+        let (na, nb, nc) = demagnetizing_factors(2.0, 2.0, 2.0).unwrap();
+        assert_close!(1.0 / 3.0, na, 5e-16);
+        assert_eq!((na, na), (nb, nc));
+        let (_, _, nc) = demagnetizing_factors(1.0, 1.0, 1.0 + 1e-9).unwrap();
+        // Nc ≈ 1/3 - 4/15 δ for c = 1 + δ
+        assert_close!(1.0 / 3.0 - 4.0 / 15.0 * 1e-9, nc, 5e-16);
+
+        // Prolate spheroid along a: Na = (1 - e²)/e³ (atanh e - e)
+        let e: f64 = (1.0 - 0.25f64).sqrt();
+        let (na, nb, nc) = demagnetizing_factors(2.0, 1.0, 1.0).unwrap();
+        assert_close!((1.0 - e * e) / e.powi(3) * (e.atanh() - e), na, 5e-15);
+        assert_close!(nb, nc, 5e-16);
+        // Oblate spheroid along c: Nc = (1 - √(1 - e²) asin(e)/e)/e²
+        let (_, _, nc) = demagnetizing_factors(2.0, 2.0, 1.0).unwrap();
+        assert_close!(
+            (1.0 - (1.0 - e * e).sqrt() * e.asin() / e) / (e * e),
+            nc,
+            5e-15
+        );
+
+        // Degenerate disk and needle
+        assert_eq!(
+            demagnetizing_factors(3.0, 2.0, 0.0).unwrap(),
+            (0.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            demagnetizing_factors(0.0, 2.0, 0.0).unwrap(),
+            (0.5, 0.0, 0.5)
+        );
+        let (na, nb, nc) = demagnetizing_factors(1e-300, 1.0, 1.0).unwrap();
+        assert_close!(1.0, na, 1e-15);
+        assert!(nb < 1e-15 && nc < 1e-15);
+    }
+
+    #[test]
+    fn test_ellipsoid_potential() {
+        // Sphere of radius 2: 2π(R² - r²/3) inside and V/r outside
+        let volume = 4.0 / 3.0 * PI * 8.0;
+        assert_close!(
+            2.0 * PI * (4.0 - 1.0 / 3.0),
+            ellipsoid_potential(2.0, 2.0, 2.0, 0.0, 1.0, 0.0).unwrap(),
+            5e-16
+        );
+        assert_close!(
+            volume / 2.0,
+            ellipsoid_potential(2.0, 2.0, 2.0, 0.0, 0.0, 2.0).unwrap(),
+            5e-16
+        );
+        assert_close!(
+            volume / 1e10,
+            ellipsoid_potential(2.0, 2.0, 2.0, 6e9, 0.0, 8e9).unwrap(),
+            5e-16
+        );
+
+        // Triaxial reference values by quadrature at 30 digits
+        assert_close!(
+            6.3139117317460521,
+            ellipsoid_potential(3.0, 2.0, 1.0, 4.0, 1.0, 1.0).unwrap(),
+            5e-15
+        );
+        assert_close!(
+            18.365387586631979,
+            ellipsoid_potential(3.0, 2.0, 1.0, 0.5, 0.5, 0.2).unwrap(),
+            5e-15
+        );
+        // Scale invariance: ψ scales with the square of the length.
+        assert_close!(
+            6.3139117317460521e-200,
+            ellipsoid_potential(3e-100, 2e-100, 1e-100, 4e-100, 1e-100, 1e-100).unwrap(),
+            5e-15
+        );
+
+        // Continuity at the surface, and far field V/r
+        let (a, b, c) = (3.0, 2.0, 1.0);
+        let (x, y, z) = (3.0 * 0.6, 2.0 * 0.0, 1.0 * 0.8);
+        let inside = ellipsoid_potential(a, b, c, x * (1.0 - 1e-9), y, z * (1.0 - 1e-9)).unwrap();
+        let outside = ellipsoid_potential(a, b, c, x * (1.0 + 1e-9), y, z * (1.0 + 1e-9)).unwrap();
+        assert_close!(inside, outside, 1e-8);
+        let r = 1e8;
+        assert_close!(
+            4.0 / 3.0 * PI * a * b * c / r,
+            ellipsoid_potential(a, b, c, 0.0, r, 0.0).unwrap(),
+            1e-14
+        );
+        // Across the far-field threshold and beyond the reach of the squared coordinates
+        let volume = 4.0 / 3.0 * PI * a * b * c;
+        for r in [1e15, 1e16, 1e200] {
+            assert_close!(
+                volume / r,
+                ellipsoid_potential(a, b, c, 0.6 * r, 0.0, 0.8 * r).unwrap(),
+                1e-14
+            );
+        }
+        assert_close!(
+            volume / 1e200,
+            ellipsoid_potential(1.0, 2.0, 3.0, 1e200, 0.0, 0.0).unwrap(),
+            1e-15
+        );
+        assert_close!(
+            (4.0 / 3.0 * std::f32::consts::PI * 6.0) / 1e20,
+            ellipsoid_potential(1.0f32, 2.0, 3.0, 1e20, 0.0, 0.0).unwrap(),
+            1e-6
+        );
+
+        // Poisson's equation: ∇²ψ = -4π inside and 0 outside
+        let laplacian = |x: f64, y: f64, z: f64| {
+            let h = 1e-3;
+            let f = |x, y, z| ellipsoid_potential(a, b, c, x, y, z).unwrap();
+            (f(x + h, y, z)
+                + f(x - h, y, z)
+                + f(x, y + h, z)
+                + f(x, y - h, z)
+                + f(x, y, z + h)
+                + f(x, y, z - h)
+                - 6.0 * f(x, y, z))
+                / (h * h)
+        };
+        assert_close!(-4.0 * PI, laplacian(1.0, 0.5, 0.3), 1e-6);
+        assert!(laplacian(2.0, 2.0, 1.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_ellipsoid_err() {
+        assert_eq!(
+            ellipsoid_surface_area(-1.0, 1.0, 1.0).unwrap_err(),
+            "ellipsoid_surface_area: The semi-axes must be non-negative."
+        );
+        assert!(matches!(
+            ellipsoid_surface_area(f64::NAN, 1.0, 1.0).unwrap_err(),
+            EllipError::NanArgument(_)
+        ));
+        assert!(matches!(
+            spheroid_surface_area(f64::INFINITY, 1.0).unwrap_err(),
+            EllipError::InfiniteArgument(_)
+        ));
+        assert_eq!(
+            spheroid_zone_area(1.0, 1.0, 0.5, 0.2).unwrap_err(),
+            "spheroid_zone_area: z1 must not be greater than z2."
+        );
+        assert_eq!(
+            spheroid_zone_area(1.0, 1.0, -2.0, 0.2).unwrap_err(),
+            "spheroid_zone_area: z1 and z2 must be within [-c, c]."
+        );
+        assert_eq!(
+            spheroid_zone_area(1.0, 0.0, 0.0, 0.0).unwrap_err(),
+            "spheroid_zone_area: c must be positive."
+        );
+        assert_eq!(
+            spheroid_cap_area(1.0, 1.0, 3.0).unwrap_err(),
+            "spheroid_cap_area: h must be within [0, 2c]."
+        );
+        // The semi-axes are validated before the height.
+        assert_eq!(
+            spheroid_cap_area(-1.0, 1.0, 5.0).unwrap_err(),
+            "spheroid_cap_area: The semi-axes must be non-negative."
+        );
+        assert_eq!(
+            spheroid_cap_area(1.0, f64::NAN, 1.0).unwrap_err(),
+            "spheroid_cap_area: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            ellipsoid_zone_area(3.0, 2.0, 1.0, 0.5, 0.2).unwrap_err(),
+            "ellipsoid_zone_area: z1 must not be greater than z2."
+        );
+        assert_eq!(
+            ellipsoid_zone_area(3.0, -2.0, 1.0, 0.0, 0.2).unwrap_err(),
+            "ellipsoid_zone_area: The semi-axes must be non-negative."
+        );
+        assert_eq!(
+            ellipsoid_cap_area(3.0, 2.0, 0.0, 0.0).unwrap_err(),
+            "ellipsoid_cap_area: c must be positive."
+        );
+        assert_eq!(
+            ellipsoid_cap_area(3.0, 2.0, 1.0, -0.1).unwrap_err(),
+            "ellipsoid_cap_area: h must be within [0, 2c]."
+        );
+        assert_eq!(
+            demagnetizing_factors(0.0, 0.0, 0.0).unwrap_err(),
+            "demagnetizing_factors: At least one semi-axis must be positive."
+        );
+        assert_eq!(
+            ellipsoid_potential(1.0, 0.0, 1.0, 0.0, 0.0, 0.0).unwrap_err(),
+            "ellipsoid_potential: The semi-axes must be positive."
+        );
+        assert!(matches!(
+            ellipsoid_potential(1.0, 1.0, 1.0, f64::INFINITY, 0.0, 0.0).unwrap_err(),
+            EllipError::InfiniteArgument(_)
+        ));
+    }
+}
@@ -0,0 +1,580 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Precomputed context for repeated evaluation at a fixed parameter.
+//!
+//! [EllipticModulus] computes K(m), E(m), D(m), kc, and the arithmetic-geometric mean (AGM)
+//! sequence once. The incomplete integrals are then evaluated by the descending Landen
+//! transformation, which only needs one arctangent per AGM step, without the Carlson's
+//! integrals. Reversing the transformation gives the Jacobi amplitude and the Jacobi
+//! elliptic functions sn, cn, and dn.
+//!
+//! # Examples
+//! ```
+//! use ellip::{ellipf, jacobi_zeta, util::assert_close, EllipticModulus};
+//!
+//! let modulus = EllipticModulus::new(0.5).unwrap();
+//! for phi in [0.1, 0.5, 1.0, 2.0] {
+//!     assert_close(modulus.f(phi), ellipf(phi, 0.5).unwrap(), 1e-14);
+//!     assert_close(modulus.zeta(phi), jacobi_zeta(phi, 0.5).unwrap(), 1e-14);
+//! }
+//! ```
+//!
+//! # References
+//! - Abramowitz, Milton, and Irene A. Stegun. Handbook of Mathematical Functions with Formulas, Graphs, and Mathematical Tables. 10th ed. Applied Mathematics Series 55. Washington, D.C.: National Bureau of Standards, 1972. §16.4, §17.5–17.6.
+//! - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19.8>.
+//! - Reinhardt, W. P., and P. L. Walker. “DLMF: Chapter 22 Jacobian Elliptic Functions.” Accessed August 31, 2025. <https://dlmf.nist.gov/22.4>.
+
+use num_traits::Float;
+
+use crate::{carlson::elliprd_unchecked, ellipd, ellipe, ellipk, error::err, EllipError};
+
+/// Maximum number of AGM steps. The AGM converges quadratically, so the limit is only
+/// reached when kc is subnormal.
+const MAX_AGM: usize = 40;
+
+/// AGM sequence aₙ and bₙ, starting from a₀ = 1 and b₀ = kc.
+#[derive(Debug, Clone, Copy)]
+struct Agm<T: Float> {
+    a: [T; MAX_AGM],
+    b: [T; MAX_AGM],
+    /// Index of the last term.
+    n: usize,
+}
+
+impl<T: Float> Agm<T> {
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn new(kc: T) -> Self {
+        let mut agm = Self {
+            a: [0.0; MAX_AGM],
+            b: [0.0; MAX_AGM],
+            n: 0,
+        };
+        agm.a[0] = 1.0;
+        agm.b[0] = kc;
+        while agm.n + 1 < MAX_AGM {
+            let (a, b) = (agm.a[agm.n], agm.b[agm.n]);
+            if (a - b).abs() <= epsilon!() * a {
+                break;
+            }
+            agm.n += 1;
+            agm.a[agm.n] = 0.5 * (a + b);
+            agm.b[agm.n] = (a * b).sqrt();
+        }
+        agm
+    }
+
+    /// Returns F(φ) and Z(φ) = Σ cₙ sin φₙ for |φ| ≤ π/2 using the descending Landen transformation.
+    ///
+    /// The amplitudes satisfy tan(φₙ₊₁ - φₙ) = (bₙ / aₙ) tan φₙ, where φₙ₊₁ - 2φₙ is taken
+    /// from the principal branch.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn landen(&self, phi: T) -> (T, T) {
+        let mut phi_n = phi;
+        let mut zeta = 0.0;
+        for i in 0..self.n {
+            let (a, b) = (self.a[i], self.b[i]);
+            let (s, c) = phi_n.sin_cos();
+            phi_n = 2.0 * phi_n - ((a - b) * s * c / (a * c * c + b * s * s)).atan();
+            zeta = zeta + 0.5 * (a - b) * phi_n.sin();
+        }
+        let scale = 2.0.powi(self.n as i32) * self.a[self.n];
+        (phi_n / scale, zeta)
+    }
+
+    /// Returns the amplitude φ with F(φ) = u for |u| ≤ K by reversing [Agm::landen].
+    ///
+    /// The amplitudes satisfy sin(2φₙ - φₙ₊₁) = (cₙ₊₁ / aₙ₊₁) sin φₙ₊₁, where
+    /// cₙ₊₁ = (aₙ - bₙ) / 2 (Abramowitz and Stegun, 1972, §16.4).
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn inverse_landen(&self, u: T) -> T {
+        let mut phi_n = 2.0.powi(self.n as i32) * self.a[self.n] * u;
+        for i in (0..self.n).rev() {
+            let c = 0.5 * (self.a[i] - self.b[i]) / self.a[i + 1];
+            phi_n = 0.5 * (phi_n + (c * phi_n.sin()).asin());
+        }
+        phi_n
+    }
+}
+
+/// Precomputed context for the elliptic integrals and the Jacobi elliptic functions at a fixed
+/// parameter m.
+///
+/// Construct once and evaluate at many amplitudes. The methods return NAN for NAN arguments.
+///
+/// The incomplete integral of the third kind depends on n as well as m, so nothing is shared
+/// between evaluations. Use [ellippiinc](crate::ellippiinc), or [raw::ellippiinc](crate::raw::ellippiinc)
+/// with [m](EllipticModulus::m) for a plain value.
+///
+/// ## Parameters
+/// - m ∈ ℝ, m < 1, m is finite.
+///
+/// # Examples
+/// ```
+/// use ellip::{ellipeinc, heuman_lambda, util::assert_close, EllipticModulus};
+///
+/// let modulus = EllipticModulus::new(0.8).unwrap();
+/// assert_eq!(modulus.ellipk(), ellip::ellipk(0.8).unwrap());
+///
+/// let phis: Vec<f64> = (0..100).map(|i| i as f64 * 0.05).collect();
+/// let e: Vec<f64> = phis.iter().map(|&phi| modulus.e(phi)).collect();
+/// assert_close(e[10], ellipeinc(0.5, 0.8).unwrap(), 1e-14);
+/// assert_close(modulus.lambda(0.5), heuman_lambda(0.5, 0.8).unwrap(), 1e-14);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct EllipticModulus<T: Float> {
+    m: T,
+    kc: T,
+    k: T,
+    e: T,
+    d: T,
+    agm: Agm<T>,
+    /// K(1 - m) and the AGM sequence of the complementary parameter for Heuman's Lambda.
+    k_mc: T,
+    agm_mc: Option<Agm<T>>,
+}
+
+impl<T: Float> EllipticModulus<T> {
+    /// Precomputes the context for the parameter m.
+    ///
+    /// ## Domain
+    /// - Returns error if m ≥ 1, or m is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(m: T) -> Result<Self, EllipError> {
+        if m.is_nan() {
+            return err!(NanArgument, EllipticModulus, "m cannot be NAN.", [m]);
+        }
+        if m >= 1.0 {
+            return err!(DomainError, EllipticModulus, "m must be less than 1.", m);
+        }
+        if m.is_infinite() {
+            return err!(InfiniteArgument, EllipticModulus, "m must be finite.", m);
+        }
+
+        let kc = (1.0 - m).sqrt();
+        let (k_mc, agm_mc) = if m > 0.0 {
+            (ellipk(1.0 - m)?, Some(Agm::new(m.sqrt())))
+        } else {
+            (nan!(), None)
+        };
+        Ok(Self {
+            m,
+            kc,
+            k: ellipk(m)?,
+            e: ellipe(m)?,
+            d: ellipd(m)?,
+            agm: Agm::new(kc),
+            k_mc,
+            agm_mc,
+        })
+    }
+
+    /// Precomputes the context for the complementary modulus kc = √(1 - m).
+    ///
+    /// Near m = 1, the parameter m cannot carry the digits of 1 - m, which determine K(m) and
+    /// the Jacobi elliptic functions. Here, K(m) = π / (2 AGM(1, kc)) is computed from kc.
+    ///
+    /// ## Domain
+    /// - Returns error if kc ≤ 0, or kc is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn from_kc(kc: T) -> Result<Self, EllipError> {
+        if kc.is_nan() {
+            return err!(NanArgument, EllipticModulus, "kc cannot be NAN.", [kc]);
+        }
+        if kc <= 0.0 {
+            return err!(DomainError, EllipticModulus, "kc must be positive.", kc);
+        }
+        if kc.is_infinite() {
+            return err!(InfiniteArgument, EllipticModulus, "kc must be finite.", kc);
+        }
+
+        let m = (1.0 - kc) * (1.0 + kc);
+        let agm = Agm::new(kc);
+        let k = pi_2!() / agm.a[agm.n];
+        let e = ellipe(m)?;
+        let (k_mc, agm_mc) = if m > 0.0 {
+            (ellipk(kc * kc)?, Some(Agm::new(m.sqrt())))
+        } else {
+            (nan!(), None)
+        };
+        Ok(Self {
+            m,
+            kc,
+            k,
+            e,
+            // D = (K - E) / m cancels for small m, where m is accurate.
+            d: if m < 0.5 { ellipd(m)? } else { (k - e) / m },
+            agm,
+            k_mc,
+            agm_mc,
+        })
+    }
+
+    /// Returns the parameter m.
+    pub fn m(&self) -> T {
+        self.m
+    }
+
+    /// Returns the complementary modulus kc = √(1 - m).
+    pub fn kc(&self) -> T {
+        self.kc
+    }
+
+    /// Returns K(m).
+    pub fn ellipk(&self) -> T {
+        self.k
+    }
+
+    /// Returns E(m).
+    pub fn ellipe(&self) -> T {
+        self.e
+    }
+
+    /// Returns D(m).
+    pub fn ellipd(&self) -> T {
+        self.d
+    }
+
+    /// Reduces φ to φ = jπ + r with |r| ≤ π/2. Returns (j, F(r), Z(r)).
+    fn reduce(&self, phi: T) -> (T, T, T) {
+        let j = (phi / pi!()).round();
+        let (f, zeta) = self.agm.landen(phi - j * pi!());
+        (j, f, zeta)
+    }
+
+    /// Computes [ellipf](crate::ellipf)(φ, m).
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn f(&self, phi: T) -> T {
+        if phi.is_infinite() {
+            return phi;
+        }
+        let (j, f, _) = self.reduce(phi);
+        2.0 * j * self.k + f
+    }
+
+    /// Computes [ellipeinc](crate::ellipeinc)(φ, m).
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn e(&self, phi: T) -> T {
+        if phi.is_infinite() {
+            return phi;
+        }
+        let (j, f, zeta) = self.reduce(phi);
+        2.0 * j * self.e + self.e / self.k * f + zeta
+    }
+
+    /// Computes [ellipdinc](crate::ellipdinc)(φ, m).
+    ///
+    /// The argument 1 - m sin²φ = cos²φ + kc² sin²φ of [elliprd](crate::elliprd) is formed from
+    /// kc, so that D(φ, m) remains finite near m = 1 for |φ| < π/2.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn d(&self, phi: T) -> T {
+        if phi.is_infinite() {
+            return phi;
+        }
+        let j = (phi / pi!()).round();
+        let (s, c) = (phi - j * pi!()).sin_cos();
+        let (s2, c2) = (s * s, c * c);
+        let kc2 = self.kc * self.kc;
+        2.0 * j * self.d + s * s2 * elliprd_unchecked(c2, c2 + kc2 * s2, 1.0) / 3.0
+    }
+
+    /// Computes [jacobi_zeta](crate::jacobi_zeta)(φ, m).
+    ///
+    /// Returns NAN if φ is infinite.
+    pub fn zeta(&self, phi: T) -> T {
+        if phi.is_infinite() {
+            return nan!();
+        }
+        self.reduce(phi).2
+    }
+
+    /// Computes [heuman_lambda](crate::heuman_lambda)(φ, m).
+    ///
+    /// Returns NAN if m < 0 or φ is infinite.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn lambda(&self, phi: T) -> T {
+        if phi.is_infinite() {
+            return nan!();
+        }
+        if self.m == 0.0 {
+            return phi.sin();
+        }
+        let Some(agm_mc) = self.agm_mc else {
+            return nan!();
+        };
+        // Λ0(φ + jπ) = Λ0(φ) + 2j
+        let j = (phi / pi!()).round();
+        let (f, zeta) = agm_mc.landen(phi - j * pi!());
+        2.0 * j + f / self.k_mc + self.k * zeta / pi_2!()
+    }
+
+    /// Computes the Jacobi amplitude am(u, m), the inverse of [ellipf](crate::ellipf)(φ, m).
+    ///
+    /// Returns ±∞ if u is ±∞.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn am(&self, u: T) -> T {
+        if u.is_infinite() {
+            return u;
+        }
+        // am(u + 2jK) = am(u) + jπ
+        let j = (u / (2.0 * self.k)).round();
+        j * pi!() + self.agm.inverse_landen(u - 2.0 * j * self.k)
+    }
+
+    /// Computes the Jacobi elliptic functions (sn(u, m), cn(u, m), dn(u, m)).
+    ///
+    /// For K/2 < |u| ≤ K, the functions are computed from K - |u| by sn(u) = cd(K - u),
+    /// cn(u) = kc sd(K - u), and dn(u) = kc nd(K - u) (Reinhardt and Walker, 2025, §22.4.3),
+    /// so cn and dn keep their relative accuracy near their minima as m approaches 1.
+    ///
+    /// Returns NAN if u is infinite.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn sn_cn_dn(&self, u: T) -> (T, T, T) {
+        if u.is_infinite() {
+            return (nan!(), nan!(), nan!());
+        }
+        // sn(u + 2jK) = (-1)ʲ sn(u), cn(u + 2jK) = (-1)ʲ cn(u), and dn(u + 2jK) = dn(u)
+        let j = (u / (2.0 * self.k)).round();
+        let r = u - 2.0 * j * self.k;
+        let parity = if (0.5 * j).fract() == 0.0 { 1.0 } else { -1.0 };
+        if r.abs() <= 0.5 * self.k {
+            let (sn, cn, dn) = self.unreduced_sn_cn_dn(r);
+            return (parity * sn, parity * cn, dn);
+        }
+        let (sn, cn, dn) = self.unreduced_sn_cn_dn(self.k - r.abs());
+        (
+            parity * r.signum() * cn / dn,
+            parity * self.kc * sn / dn,
+            self.kc / dn,
+        )
+    }
+
+    /// Computes u ∈ [-2K, 2K] from the Jacobi elliptic functions sn(u, m), cn(u, m), and
+    /// dn(u, m), the inverse of [EllipticModulus::sn_cn_dn].
+    ///
+    /// Near u = ±K, F is ill-conditioned as m approaches 1, so u = ±(K - F(ψ)) with
+    /// tan ψ = cn / (kc |sn|), from sn(u) = cd(K - u) and cn(u) = kc sd(K - u). Only the ratio
+    /// of sn and cn is used, so they may share a positive factor.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn inverse_sn_cn_dn(&self, sn: T, cn: T, dn: T) -> T {
+        if dn * dn >= self.kc {
+            return self.f(sn.atan2(cn));
+        }
+        sn.signum() * (self.k - self.f(cn.atan2(self.kc * sn.abs())))
+    }
+
+    /// Returns (sn(u), cn(u), dn(u)) for |u| ≤ K.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn unreduced_sn_cn_dn(&self, u: T) -> (T, T, T) {
+        let (sn, cn) = self.agm.inverse_landen(u).sin_cos();
+        // Sum of the positive terms, dn² = kc² + m cn² for m ≥ 0
+        let dn2 = if self.m < 0.0 {
+            1.0 - self.m * sn * sn
+        } else {
+            self.kc * self.kc + self.m * cn * cn
+        };
+        (sn, cn, dn2.sqrt())
+    }
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_close, ellipdinc, ellipeinc, ellipf, heuman_lambda, jacobi_zeta};
+    use itertools::iproduct;
+
+    const MS: [f64; 8] = [-5.0, -0.5, 0.0, 0.1, 0.5, 0.9, 0.99, 0.999999];
+    const PHIS: [f64; 9] = [-7.0, -1.2, 0.0, 0.05, 0.5, 1.0, 1.5, 2.5, 40.0];
+
+    #[test]
+    fn test_elliptic_modulus() {
+        for (m, phi) in iproduct!(MS, PHIS) {
+            let modulus = EllipticModulus::new(m).unwrap();
+            assert_eq!(modulus.ellipk(), crate::ellipk(m).unwrap());
+            assert_eq!(modulus.ellipe(), crate::ellipe(m).unwrap());
+            assert_eq!(modulus.ellipd(), crate::ellipd(m).unwrap());
+            assert_eq!(modulus.kc(), (1.0 - m).sqrt());
+
+            assert_close!(ellipf(phi, m).unwrap(), modulus.f(phi), 5e-15);
+            assert_close!(ellipeinc(phi, m).unwrap(), modulus.e(phi), 5e-15);
+            assert_close!(ellipdinc(phi, m).unwrap(), modulus.d(phi), 5e-14);
+            let zeta = jacobi_zeta(phi, m).unwrap();
+            if zeta.abs() > 1e-12 {
+                assert_close!(zeta, modulus.zeta(phi), 5e-14);
+            } else {
+                assert!(modulus.zeta(phi).abs() < 1e-14);
+            }
+            if m >= 0.0 {
+                assert_close!(heuman_lambda(phi, m).unwrap(), modulus.lambda(phi), 5e-14);
+            } else {
+                assert!(modulus.lambda(phi).is_nan());
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobi_elliptic_functions() {
+        // mpmath references (m, u, [am, sn, cn, dn])
+        const REFERENCES: [(f64, f64, [f64; 4]); 6] = [
+            (
+                0.5,
+                0.7,
+                [
+                    0.6742864586034127,
+                    0.6243400909662173,
+                    0.7811526424536344,
+                    0.8972734953213249,
+                ],
+            ),
+            (
+                0.9,
+                3.0,
+                [
+                    1.7077931710728238,
+                    0.9906305999378325,
+                    -0.13656871701385334,
+                    0.34173953973769106,
+                ],
+            ),
+            (
+                0.99,
+                -10.0,
+                [
+                    -4.58130465216949,
+                    0.9914207448606821,
+                    -0.1307092447376634,
+                    0.16405504440062638,
+                ],
+            ),
+            (
+                -2.0,
+                1.3,
+                [
+                    1.7922899924351343,
+                    0.9755703987306549,
+                    -0.21968704358817148,
+                    1.7039586866350305,
+                ],
+            ),
+            (
+                0.999999,
+                5.0,
+                [
+                    1.5573391706718211,
+                    0.999909453841007,
+                    0.013456749956024364,
+                    0.013493848164807216,
+                ],
+            ),
+            (
+                0.1,
+                40.0,
+                [
+                    38.97436791824973,
+                    0.956644930021061,
+                    0.29125672157908966,
+                    0.9531437707851318,
+                ],
+            ),
+        ];
+        for (m, u, [am, sn, cn, dn]) in REFERENCES {
+            let modulus = EllipticModulus::new(m).unwrap();
+            let (s, c, d) = modulus.sn_cn_dn(u);
+            assert_close!(am, modulus.am(u), 1e-14);
+            assert_close!(sn, s, 1e-14);
+            assert_close!(cn.abs(), c.abs(), 5e-14);
+            assert_eq!(cn.signum(), c.signum());
+            assert_close!(dn, d, 5e-14);
+        }
+
+        for (m, phi) in iproduct!(MS, PHIS) {
+            let modulus = EllipticModulus::new(m).unwrap();
+            assert_close!(phi.abs(), modulus.am(modulus.f(phi)).abs(), 1e-14);
+        }
+
+        for (m, u) in iproduct!(MS, [-4.0, -1.5, 0.0, 0.3, 1.0, 2.0, 5.0]) {
+            let modulus = EllipticModulus::new(m).unwrap();
+            let k = modulus.ellipk();
+            // Reduce to [-2K, 2K].
+            let u = u - (u / (4.0 * k)).round() * 4.0 * k;
+            let (sn, cn, dn) = modulus.sn_cn_dn(u);
+            let inverse = modulus.inverse_sn_cn_dn(sn, cn, dn);
+            assert!((u - inverse).abs() <= 1e-14 * k, "{m} {u} {inverse}");
+        }
+    }
+
+    #[test]
+    fn test_elliptic_modulus_from_kc() {
+        for m in MS {
+            let modulus = EllipticModulus::new(m).unwrap();
+            let from_kc = EllipticModulus::from_kc((1.0 - m).sqrt()).unwrap();
+            assert_close!(modulus.ellipk(), from_kc.ellipk(), 5e-15);
+            assert_close!(modulus.ellipe(), from_kc.ellipe(), 5e-15);
+            assert_close!(modulus.ellipd(), from_kc.ellipd(), 5e-15);
+            for phi in PHIS {
+                assert_close!(modulus.f(phi), from_kc.f(phi), 5e-15);
+            }
+        }
+
+        // mpmath references, K(1 - kc²) with m rounded to 1
+        let modulus = EllipticModulus::from_kc(1e-10).unwrap();
+        assert_eq!(modulus.m(), 1.0);
+        assert_close!(24.412145291060348, modulus.ellipk(), 5e-15);
+        assert_close!(23.412145291060348, modulus.ellipd(), 5e-15);
+        assert_close!(2.3431825561942566, modulus.d(1.5), 5e-15);
+        assert_close!(47.056176477791827, modulus.d(4.0), 5e-15);
+        let (sn, cn, dn) = modulus.sn_cn_dn(20.0);
+        assert_eq!(1.0, sn);
+        assert_close!(4.121700788382853e-9, cn, 5e-14);
+        assert_close!(4.1229137013713775e-9, dn, 5e-14);
+        assert_close!(20.0, modulus.inverse_sn_cn_dn(sn, cn, dn), 5e-15);
+
+        assert_eq!(
+            EllipticModulus::from_kc(0.0).unwrap_err(),
+            "EllipticModulus: kc must be positive."
+        );
+        assert!(matches!(
+            EllipticModulus::from_kc(f64::INFINITY).unwrap_err(),
+            EllipError::InfiniteArgument(_)
+        ));
+    }
+
+    #[test]
+    fn test_elliptic_modulus_special_cases() {
+        use std::f64::{INFINITY, NAN, NEG_INFINITY};
+        assert_eq!(
+            EllipticModulus::new(1.0).unwrap_err(),
+            "EllipticModulus: m must be less than 1."
+        );
+        assert!(matches!(
+            EllipticModulus::new(NAN).unwrap_err(),
+            EllipError::NanArgument(_)
+        ));
+        assert!(matches!(
+            EllipticModulus::new(NEG_INFINITY).unwrap_err(),
+            EllipError::InfiniteArgument(_)
+        ));
+
+        let modulus = EllipticModulus::new(0.5).unwrap();
+        assert!(modulus.f(NAN).is_nan());
+        assert_eq!(modulus.f(INFINITY), INFINITY);
+        assert_eq!(modulus.e(NEG_INFINITY), NEG_INFINITY);
+        assert_eq!(modulus.d(INFINITY), INFINITY);
+        assert!(modulus.d(NAN).is_nan());
+        assert!(modulus.zeta(INFINITY).is_nan());
+        assert!(modulus.lambda(INFINITY).is_nan());
+        assert_eq!(modulus.f(0.0), 0.0);
+
+        assert_eq!(modulus.am(0.0), 0.0);
+        assert_eq!(modulus.am(NEG_INFINITY), NEG_INFINITY);
+        assert!(modulus.sn_cn_dn(INFINITY).0.is_nan());
+
+        let modulus = EllipticModulus::new(0.0f32).unwrap();
+        assert_eq!(modulus.f(0.5), 0.5);
+        assert_eq!(modulus.am(0.5), 0.5);
+        assert_eq!(modulus.lambda(0.5), 0.5f32.sin());
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Error type returned by the elliptic integral functions.
+//!
+//! Every fallible function returns `Result<T, EllipError>`. The variants allow callers
+//! to branch on the kind of failure, while [ErrorInfo] records the function name and the
+//! offending argument.
+//!
+//! # Migrating from [StrErr]
+//! Prior versions returned [StrErr] (`&'static str`). The messages are unchanged and available
+//! through [EllipError::message]. [EllipError] converts into [StrErr], so functions returning
+//! `Result<T, StrErr>` can keep using the `?` operator, and [EllipError] can be compared with
+//! a message string directly.
+//! ```
+//! use ellip::{ellipk, EllipError, StrErr};
+//!
+//! fn legacy(m: f64) -> Result<f64, StrErr> {
+//!     Ok(ellipk(m)?)
+//! }
+//! assert_eq!(legacy(1.1), Err("ellipk: m must not be greater than 1."));
+//!
+//! let err = ellipk(1.1).unwrap_err();
+//! assert!(matches!(err, EllipError::DomainError(_)));
+//! assert_eq!(err, "ellipk: m must not be greater than 1.");
+//! assert_eq!(err.func(), "ellipk");
+//! assert_eq!(err.arg(), Some("m"));
+//! assert_eq!(err.value(), Some(1.1));
+//! ```
+
+use core::fmt;
+
+use crate::StrErr;
+
+/// Details of an [EllipError].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ErrorInfo {
+    /// Name of the function that raised the error.
+    pub func: &'static str,
+    /// Name of the offending argument, or a comma-separated list when several arguments are involved.
+    pub arg: Option<&'static str>,
+    /// Value of the offending argument, if the error is caused by a single finite or infinite value.
+    pub value: Option<f64>,
+    /// Limit of the function at a [Singularity](EllipError::Singularity), e.g., ±∞ at a pole,
+    /// if it is known.
+    pub limit: Option<f64>,
+    /// Error message in the form `"func: Message."`.
+    pub message: StrErr,
+}
+
+/// Error returned by the elliptic integral functions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EllipError {
+    /// The arguments are outside the domain of the function.
+    DomainError(ErrorInfo),
+    /// At least one of the arguments is NAN.
+    NanArgument(ErrorInfo),
+    /// An argument is infinite where the function is undefined.
+    InfiniteArgument(ErrorInfo),
+    /// The arguments lie on a pole or a branch point of the function.
+    Singularity(ErrorInfo),
+    /// The result is infinite for finite arguments. Only raised under [Policy](crate::policy::Policy).
+    Overflow(ErrorInfo),
+    /// The iteration did not converge.
+    NoConvergence(ErrorInfo),
+    /// The function failed for an unknown reason.
+    Unexpected(ErrorInfo),
+}
+
+impl EllipError {
+    /// Returns the details of the error.
+    pub fn info(&self) -> &ErrorInfo {
+        match self {
+            Self::DomainError(info)
+            | Self::NanArgument(info)
+            | Self::InfiniteArgument(info)
+            | Self::Singularity(info)
+            | Self::Overflow(info)
+            | Self::NoConvergence(info)
+            | Self::Unexpected(info) => info,
+        }
+    }
+
+    /// Returns the name of the function that raised the error.
+    pub fn func(&self) -> &'static str {
+        self.info().func
+    }
+
+    /// Returns the name of the offending argument.
+    pub fn arg(&self) -> Option<&'static str> {
+        self.info().arg
+    }
+
+    /// Returns the value of the offending argument.
+    pub fn value(&self) -> Option<f64> {
+        self.info().value
+    }
+
+    /// Returns the limit of the function at the singularity, if it is known.
+    pub fn limit(&self) -> Option<f64> {
+        self.info().limit
+    }
+
+    /// Returns the error message, identical to the [StrErr] returned by prior versions.
+    pub fn message(&self) -> StrErr {
+        self.info().message
+    }
+}
+
+impl fmt::Display for EllipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(any(test, feature = "std"))]
+impl std::error::Error for EllipError {}
+
+impl From<EllipError> for StrErr {
+    fn from(err: EllipError) -> Self {
+        err.message()
+    }
+}
+
+impl PartialEq<&str> for EllipError {
+    fn eq(&self, other: &&str) -> bool {
+        self.message() == *other
+    }
+}
+
+impl PartialEq<EllipError> for &str {
+    fn eq(&self, other: &EllipError) -> bool {
+        *self == other.message()
+    }
+}
+
+/// Macro to construct an `Err(EllipError)`.
+///
+/// The message is prefixed with the function name, i.e., `"func: message"`.
+macro_rules! err {
+    ($kind:ident, $fn_name:ident, $msg:expr) => {
+        $crate::error::err!(@info $kind, $fn_name, $msg, None, None, None)
+    };
+    ($kind:ident, $fn_name:ident, $msg:expr, [$first:ident $(, $var:ident)* $(,)?]) => {
+        $crate::error::err!(@info $kind, $fn_name, $msg, Some(concat![stringify!($first) $(, ", ", stringify!($var))*]), None, None)
+    };
+    ($kind:ident, $fn_name:ident, $msg:expr, $var:ident) => {
+        $crate::error::err!(@info $kind, $fn_name, $msg, Some(stringify!($var)), num_traits::ToPrimitive::to_f64(&$var), None)
+    };
+    // Singularity with the limit of the function, where NAN means unknown.
+    ($kind:ident, $fn_name:ident, $msg:expr, [$first:ident $(, $var:ident)* $(,)?], limit: $limit:expr) => {
+        $crate::error::err!(@info $kind, $fn_name, $msg, Some(concat![stringify!($first) $(, ", ", stringify!($var))*]), None, $crate::error::err!(@limit $limit))
+    };
+    ($kind:ident, $fn_name:ident, $msg:expr, $var:ident, limit: $limit:expr) => {
+        $crate::error::err!(@info $kind, $fn_name, $msg, Some(stringify!($var)), num_traits::ToPrimitive::to_f64(&$var), $crate::error::err!(@limit $limit))
+    };
+    (@limit $limit:expr) => {
+        num_traits::ToPrimitive::to_f64(&$limit).filter(|limit: &f64| !limit.is_nan())
+    };
+    (@info $kind:ident, $fn_name:ident, $msg:expr, $arg:expr, $value:expr, $limit:expr) => {
+        Err($crate::EllipError::$kind($crate::error::ErrorInfo {
+            func: stringify!($fn_name),
+            arg: $arg,
+            value: $value,
+            limit: $limit,
+            message: concat![stringify!($fn_name), ": ", $msg],
+        }))
+    };
+}
+pub(crate) use err;
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fail(x: f64) -> Result<f64, EllipError> {
+        if x.is_nan() {
+            return err!(NanArgument, fail, "Arguments cannot be NAN.", [x]);
+        }
+        if x < 0.0 {
+            return err!(DomainError, fail, "x must be non-negative.", x);
+        }
+        err!(Unexpected, fail, "Unexpected error.")
+    }
+
+    #[test]
+    fn test_ellip_error() {
+        let err = fail(-1.0).unwrap_err();
+        assert!(matches!(err, EllipError::DomainError(_)));
+        assert_eq!(err.func(), "fail");
+        assert_eq!(err.arg(), Some("x"));
+        assert_eq!(err.value(), Some(-1.0));
+        assert_eq!(err.message(), "fail: x must be non-negative.");
+        assert_eq!(err, "fail: x must be non-negative.");
+        assert_eq!("fail: x must be non-negative.", err);
+        assert_eq!(err.to_string(), "fail: x must be non-negative.");
+
+        let err = fail(f64::NAN).unwrap_err();
+        assert!(matches!(err, EllipError::NanArgument(_)));
+        assert_eq!(err.value(), None);
+
+        let err = fail(1.0).unwrap_err();
+        assert!(matches!(err, EllipError::Unexpected(_)));
+        assert_eq!(err.arg(), None);
+        let legacy: StrErr = err.into();
+        assert_eq!(legacy, "fail: Unexpected error.");
+
+        let boxed: Box<dyn std::error::Error> = Box::new(err);
+        assert_eq!(boxed.to_string(), "fail: Unexpected error.");
+    }
+
+    #[test]
+    fn test_error_kinds() {
+        use crate::*;
+
+        let err = ellipf(1.0, f64::NAN).unwrap_err();
+        assert!(matches!(err, EllipError::NanArgument(_)));
+        assert_eq!(err.arg(), Some("m"));
+
+        let err = elliprd(0.0, 0.0, 1.0).unwrap_err();
+        assert!(matches!(err, EllipError::Singularity(_)));
+        assert_eq!(err.arg(), Some("x, y"));
+
+        let err = cel(0.0, 1.0, 1.0, 1.0).unwrap_err();
+        assert!(matches!(err, EllipError::Singularity(_)));
+        assert_eq!(
+            (err.func(), err.arg(), err.value()),
+            ("cel", Some("kc"), Some(0.0))
+        );
+
+        let err = elliprf(-1.0, 1.0, 1.0).unwrap_err();
+        assert!(matches!(err, EllipError::DomainError(_)));
+        assert_eq!(err.arg(), Some("x, y, z"));
+
+        let err = jacobi_zeta(f64::INFINITY, 0.5).unwrap_err();
+        assert!(matches!(err, EllipError::InfiniteArgument(_)));
+        assert_eq!(err.value(), Some(f64::INFINITY));
+    }
+}
@@ -0,0 +1,573 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Analog elliptic (Cauer) lowpass filters.
+//!
+//! The elliptic filter of order N has equiripple magnitude in both the passband ω ≤ 1 and the
+//! stopband ω ≥ 1/k, with the squared magnitude
+//! ```text
+//!               1
+//! |H(jω)|²  =  ─────────────,   εp² = 10^(Ap/10) - 1,   εs² = 10^(As/10) - 1,
+//!              1 + εp² Rₙ²(ω)
+//! ```
+//! for the passband ripple Ap and the stopband attenuation As in dB. The selectivity k and the
+//! discrimination k₁ = εp/εs are tied by the degree equation (Orfanidis, 2006, §3)
+//! ```text
+//!     K(k) K'(k₁)
+//! N = ───────────,   q = q₁^(1/N),
+//!     K'(k) K(k₁)
+//! ```
+//! where q = exp(-πK'/K) is the nome. The ratios K/K' are evaluated by [ellipk_ratio], and the
+//! selectivity is recovered from the nome by the theta series
+//! ```text
+//!            ⎛ Σ q^(n(n+1))  ⎞⁴
+//! k² = 16q ⎜ ───────────── ⎟ .
+//!            ⎝ 1 + 2Σ q^(n²) ⎠
+//! ```
+//! The zeros and the poles are j/(k cd(uᵢK, k)) and j cd((uᵢ - jv₀)K, k) with
+//! uᵢ = (2i - 1)/N, where v₀ = F(arctan(1/εp), k₁') / (NK(k₁)) shifts the poles into the
+//! left half-plane. The complex argument is separated by the addition theorem, so the design
+//! needs only the real [sn, cn, and dn](EllipticModulus::sn_cn_dn) and does not allocate.
+//!
+//! The filters are normalized to the passband edge ω = 1. Scale the poles, the zeros, and the
+//! gain by the passband edge for other frequencies.
+//!
+//! # Examples
+//! ```
+//! use ellip::{filter::*, util::assert_close};
+//!
+//! // 1 dB passband ripple and 40 dB attenuation from 1.6 times the passband edge
+//! let order = elliptic_order(1.0, 40.0, 1.6).unwrap();
+//! assert_eq!(order, 4);
+//!
+//! let filter = EllipticFilter::new(order, 1.0, 40.0).unwrap();
+//! assert_close(filter.stopband_edge(), 1.5154840743221604, 1e-14);
+//! assert_close(filter.magnitude(1.0), 10f64.powf(-1.0 / 20.0), 1e-14);
+//!
+//! let (re, im) = filter.poles().next().unwrap();
+//! assert_close(re, -0.1052812646211715, 1e-14);
+//! assert_close(im, 0.99371081120877209, 1e-14);
+//! ```
+//!
+//! # References
+//! - Orfanidis, Sophocles J. “Lecture Notes on Elliptic Filter Design.” Rutgers University, 2006. <https://www.ece.rutgers.edu/~orfanidi/ece521/notes.pdf>.
+//! - Lutovac, Miroslav D., Dejan V. Tošić, and Brian L. Evans. Filter Design for Signal Processing Using MATLAB and Mathematica. Upper Saddle River: Prentice Hall, 2001.
+//! - Reinhardt, W. P., and P. L. Walker. “DLMF: Chapter 22 Jacobian Elliptic Functions.” Accessed February 19, 2025. <https://dlmf.nist.gov/22.8>.
+
+use num_traits::Float;
+
+use crate::{crate_util::check, ellipk_ratio, error::err, EllipError, EllipticModulus};
+
+/// Maximum number of terms of the theta series.
+const MAX_TERMS: usize = 100;
+
+/// Validates the passband ripple and the stopband attenuation in dB.
+macro_rules! check_spec {
+    ($fn_name:ident, $ripple:ident, $attenuation:ident) => {
+        check!(@nan, $fn_name, [$ripple, $attenuation]);
+        check!(@inf, $fn_name, [$ripple, $attenuation]);
+        if $ripple <= T::zero() {
+            return err!(
+                DomainError,
+                $fn_name,
+                "passband_ripple must be positive.",
+                $ripple
+            );
+        }
+        if $attenuation <= $ripple {
+            return err!(
+                DomainError,
+                $fn_name,
+                "stopband_attenuation must exceed passband_ripple.",
+                [$ripple, $attenuation]
+            );
+        }
+    };
+}
+
+/// Computes the minimum order of the elliptic filter meeting the specification.
+/// ```text
+///      ⎡ K(k) K'(k₁) ⎤
+/// N  = ⎢ ─────────── ⎥,   k = 1/ωs,   k₁ = εp/εs
+///      ⎢ K'(k) K(k₁) ⎥
+/// ```
+///
+/// The passband extends to ω = 1 and the stopband starts at ωs, see [mod@crate::filter].
+///
+/// ## Parameters
+/// - passband_ripple: maximum attenuation in the passband in dB (Ap). Ap ∈ ℝ, Ap > 0.
+/// - stopband_attenuation: minimum attenuation in the stopband in dB (As). As ∈ ℝ, As > Ap.
+/// - stopband_edge: stopband edge relative to the passband edge (ωs). ωs ∈ ℝ, ωs > 1.
+///
+/// ## Domain
+/// - Returns error if Ap ≤ 0, As ≤ Ap, or ωs ≤ 1.
+/// - Returns error if any argument is infinite or NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::filter::elliptic_order;
+///
+/// assert_eq!(elliptic_order(0.5, 60.0, 1.2).unwrap(), 8);
+/// assert_eq!(elliptic_order(0.5, 60.0, 2.0).unwrap(), 5);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn elliptic_order<T: Float>(
+    passband_ripple: T,
+    stopband_attenuation: T,
+    stopband_edge: T,
+) -> Result<usize, EllipError> {
+    check_spec!(elliptic_order, passband_ripple, stopband_attenuation);
+    check!(@nan, elliptic_order, [stopband_edge]);
+    check!(@inf, elliptic_order, [stopband_edge]);
+    if stopband_edge <= 1.0 {
+        return err!(
+            DomainError,
+            elliptic_order,
+            "stopband_edge must be greater than 1.",
+            stopband_edge
+        );
+    }
+
+    // K(k)/K'(k) from k² = 1/ωs² or from k'² = (ωs² - 1)/ωs², whichever is smaller
+    let m = 1.0 / (stopband_edge * stopband_edge);
+    let mc = (stopband_edge - 1.0) * (stopband_edge + 1.0) * m;
+    let ratio = if m <= mc {
+        ellipk_ratio(m)?
+    } else {
+        1.0 / ellipk_ratio(mc)?
+    };
+    let order = ratio / ellipk_ratio(discrimination(passband_ripple, stopband_attenuation))?;
+    // The tolerance keeps the order of a specification taken from a designed filter.
+    match (order * (1.0 - 64.0 * epsilon!())).ceil().to_usize() {
+        Some(order) => Ok(order.max(1)),
+        None => err!(
+            DomainError,
+            elliptic_order,
+            "The order is too large.",
+            [passband_ripple, stopband_attenuation, stopband_edge]
+        ),
+    }
+}
+
+/// Computes the squared discrimination k₁² = εp²/εs² from the attenuations in dB.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn discrimination<T: Float>(passband_ripple: T, stopband_attenuation: T) -> T {
+    let scale = 10.0.ln() / 10.0;
+    (passband_ripple * scale).exp_m1() / (stopband_attenuation * scale).exp_m1()
+}
+
+/// Computes k² = 16q (Σ q^(n(n+1)) / (1 + 2Σ q^(n²)))⁴ from the nome q ≤ e^(-π).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn nome_to_m<T: Float>(q: T) -> T {
+    let (mut num, mut den) = (1.0, 1.0);
+    let mut power = 1.0;
+    for n in 1..MAX_TERMS {
+        // q^(n²) and q^(n(n+1)) from q^((n-1)n)
+        let q_n = q.powi(n as i32);
+        let square = power * q_n;
+        power = square * q_n;
+        den = den + 2.0 * square;
+        num = num + power;
+        if square <= epsilon!() * den {
+            break;
+        }
+    }
+    let ratio = num / den;
+    16.0 * q * (ratio * ratio) * (ratio * ratio)
+}
+
+/// Analog elliptic lowpass filter of order N, see [mod@crate::filter].
+///
+/// The transfer function is
+/// ```text
+///             ∏ (s² + ωᵢ²)
+/// H(s) = H₀ ──────────────
+///             ∏ (s - pᵢ)
+/// ```
+/// with the zeros ±jωᵢ and the poles pᵢ in the left half-plane. The poles come in conjugate
+/// pairs, with one real pole if N is odd. The gain H₀ normalizes H(0) to 1 if N is odd and to
+/// 1/√(1 + εp²) if N is even.
+///
+/// ## Parameters
+/// - order: order of the filter (N). N ≥ 1.
+/// - passband_ripple: maximum attenuation in the passband in dB (Ap). Ap ∈ ℝ, Ap > 0.
+/// - stopband_attenuation: minimum attenuation in the stopband in dB (As). As ∈ ℝ, As > Ap.
+#[derive(Debug, Clone, Copy)]
+pub struct EllipticFilter<T: Float> {
+    order: usize,
+    ripple: T,
+    selectivity: T,
+    /// Context of the selectivity k.
+    modulus: EllipticModulus<T>,
+    /// Context of the complementary selectivity k'.
+    complement: EllipticModulus<T>,
+    /// Imaginary shift v₀K of the poles.
+    shift: T,
+    gain: T,
+}
+
+impl<T: Float> EllipticFilter<T> {
+    /// Designs the filter of order N for the passband ripple and the stopband attenuation.
+    ///
+    /// The stopband edge 1/k follows from the degree equation, see [EllipticFilter::stopband_edge].
+    /// Use [elliptic_order] to find the order for a given stopband edge.
+    ///
+    /// ## Domain
+    /// - Returns error if N = 0, Ap ≤ 0, or As ≤ Ap.
+    /// - Returns error if the selectivity k is too close to 1, i.e., 1 - k² < 256ε, where the
+    ///   magnitude at the passband edge is no longer accurate.
+    /// - Returns error if any argument is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(
+        order: usize,
+        passband_ripple: T,
+        stopband_attenuation: T,
+    ) -> Result<Self, EllipError> {
+        if order == 0 {
+            return err!(
+                DomainError,
+                EllipticFilter,
+                "order must be positive.",
+                order
+            );
+        }
+        check_spec!(EllipticFilter, passband_ripple, stopband_attenuation);
+
+        let m1 = discrimination(passband_ripple, stopband_attenuation);
+        let ratio1 = ellipk_ratio(m1)?;
+        let n = T::from(order).unwrap();
+        // ln q = -π / (N K(k₁)/K'(k₁)), and the complementary nome has ln q' = π² / ln q.
+        let ln_q = -pi!() / (n * ratio1);
+        let (m, mc) = if ln_q <= -pi!() {
+            let m = nome_to_m(ln_q.exp());
+            (m, 1.0 - m)
+        } else {
+            let mc = nome_to_m((pi!() * pi!() / ln_q).exp());
+            (1.0 - mc, mc)
+        };
+        // The zeros near the passband edge carry the digits of mc, so the magnitude at ω = 1
+        // has a relative error of about 2ε/mc, and 1/k rounds to 1 once mc is a few ulps.
+        if mc < 256.0 * epsilon!() {
+            return err!(
+                DomainError,
+                EllipticFilter,
+                "The selectivity is too close to 1.",
+                order
+            );
+        }
+        // The smaller of m and mc carries the digits of both.
+        let (modulus, complement) = if m <= mc {
+            (
+                EllipticModulus::new(m)?,
+                EllipticModulus::from_kc(m.sqrt())?,
+            )
+        } else {
+            (
+                EllipticModulus::from_kc(mc.sqrt())?,
+                EllipticModulus::new(mc)?,
+            )
+        };
+
+        // v₀ = F(arctan(1/εp), k₁') / (NK(k₁)), where K(k₁) = K'(k₁) K(k₁)/K'(k₁)
+        let ripple = (passband_ripple * 10.0.ln() / 10.0).exp_m1().sqrt();
+        let discrimination = EllipticModulus::from_kc(m1.sqrt())?;
+        let shift = modulus.ellipk() * discrimination.f((1.0 / ripple).atan())
+            / (n * discrimination.ellipk() * ratio1);
+
+        let mut filter = Self {
+            order,
+            ripple,
+            selectivity: m.sqrt(),
+            modulus,
+            complement,
+            shift,
+            gain: 1.0,
+        };
+        // H₀ = H(0) ∏|pᵢ| / ∏ωᵢ²
+        #[allow(clippy::manual_is_multiple_of)] // usize::is_multiple_of requires Rust 1.87
+        let mut gain = if order % 2 == 0 {
+            1.0 / ripple.hypot(1.0)
+        } else {
+            1.0
+        };
+        for (re, im) in filter.poles() {
+            gain = gain * if im == 0.0 { -re } else { re * re + im * im };
+        }
+        for omega in filter.zeros() {
+            gain = gain / (omega * omega);
+        }
+        filter.gain = gain;
+        Ok(filter)
+    }
+
+    /// Returns the order N.
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Returns the passband ripple factor εp = √(10^(Ap/10) - 1).
+    pub fn ripple_factor(&self) -> T {
+        self.ripple
+    }
+
+    /// Returns the selectivity k, the ratio of the passband edge to the stopband edge.
+    pub fn selectivity(&self) -> T {
+        self.selectivity
+    }
+
+    /// Returns the stopband edge 1/k, from which the attenuation is at least As.
+    pub fn stopband_edge(&self) -> T {
+        self.selectivity.recip()
+    }
+
+    /// Returns the gain H₀.
+    pub fn gain(&self) -> T {
+        self.gain
+    }
+
+    /// Returns the frequencies ωᵢ = 1/(k cd(uᵢK, k)) of the zeros ±jωᵢ in ascending order,
+    /// i = 1, …, ⌊N/2⌋.
+    pub fn zeros(&self) -> impl Iterator<Item = T> + '_ {
+        (1..=self.order / 2).map(move |i| {
+            let (_, cn, dn) = self.modulus.sn_cn_dn(self.angle(i));
+            dn / (self.selectivity * cn)
+        })
+    }
+
+    /// Returns the poles pᵢ = j cd((uᵢ - jv₀)K, k) as (Re pᵢ, Im pᵢ) with Im pᵢ ≥ 0 in
+    /// descending order of Im pᵢ, i = 1, …, ⌈N/2⌉.
+    ///
+    /// The conjugates of the complex poles are also poles. If N is odd, the last pole is real.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn poles(&self) -> impl Iterator<Item = (T, T)> + '_ {
+        let (sv, cv, dv) = self.complement.sn_cn_dn(self.shift);
+        let kc2 = self.modulus.kc() * self.modulus.kc();
+        #[allow(clippy::manual_div_ceil)] // usize::div_ceil requires Rust 1.73
+        let pairs = (self.order + 1) / 2;
+        (1..=pairs).map(move |i| {
+            if 2 * i - 1 == self.order {
+                // j cd(K - jv₀K) = -sc(v₀K, k')
+                return (-sv / cv, 0.0);
+            }
+            // cd(x - jy) = sn(x + K - jy) by the addition theorem, with the functions of y
+            // at the complementary modulus (Reinhardt and Walker, 2025, §22.8)
+            let (sn, cn, dn) = self.modulus.sn_cn_dn(self.angle(i));
+            let den = dn * dn * cv * cv + self.selectivity * self.selectivity * cn * cn * sv * sv;
+            (-kc2 * sn * sv * cv / den, cn * dn * dv / den)
+        })
+    }
+
+    /// Computes the magnitude |H(jω)|.
+    pub fn magnitude(&self, omega: T) -> T {
+        let w2 = omega * omega;
+        let mut magnitude = self.gain;
+        for zero in self.zeros() {
+            magnitude = magnitude * (zero * zero - w2).abs();
+        }
+        for (re, im) in self.poles() {
+            magnitude = magnitude
+                / if im == T::zero() {
+                    re.hypot(omega)
+                } else {
+                    re.hypot(omega - im) * re.hypot(omega + im)
+                };
+        }
+        magnitude
+    }
+
+    /// Returns uᵢK with uᵢ = (2i - 1)/N.
+    fn angle(&self, i: usize) -> T {
+        T::from(2 * i - 1).unwrap() / T::from(self.order).unwrap() * self.modulus.ellipk()
+    }
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+
+    /// Filters designed with mpmath at 60 digits: (N, Ap, As), 1/k, H₀, ωᵢ, and pᵢ.
+    type Reference = (
+        (usize, f64, f64),
+        f64,
+        f64,
+        &'static [f64],
+        &'static [(f64, f64)],
+    );
+
+    const REFERENCES: [Reference; 6] = [
+        (
+            (4, 1.0, 40.0),
+            1.5154840743221604,
+            0.01,
+            &[1.6095504012251537, 3.5252874329960022],
+            &[
+                (-0.1052812646211715, 0.99371081120877209),
+                (-0.36429059587342147, 0.47860276764064979),
+            ],
+        ),
+        (
+            (5, 0.5, 60.0),
+            1.7766373973034499,
+            0.0080944629832746984,
+            &[1.852260186147556, 2.8470779076397901],
+            &[
+                (-0.091559249190058615, 1.0124236419528349),
+                (-0.28890670817936479, 0.6762768219062187),
+                (-0.40278938096188705, 0.0),
+            ],
+        ),
+        (
+            (3, 0.1, 20.0),
+            1.7554327515961388,
+            0.48101776343647617,
+            &[1.980842888509131],
+            &[
+                (-0.34784088560504406, 1.2177743341962065),
+                (-1.1766995346465643, 0.0),
+            ],
+        ),
+        (
+            (8, 0.01, 100.0),
+            1.9700373781241554,
+            1.0e-5,
+            &[
+                2.0032505779130148,
+                2.3178391276156882,
+                3.3732327793714357,
+                9.4133581113504013,
+            ],
+            &[
+                (-0.077751854301824189, 1.0769823426882019),
+                (-0.23914572450351927, 0.94644080964899419),
+                (-0.40053598386116272, 0.66780115511983168),
+                (-0.51310402406473525, 0.24442411845640819),
+            ],
+        ),
+        (
+            (1, 1.0, 20.0),
+            19.553759057846146,
+            1.9652267283602719,
+            &[],
+            &[(-1.9652267283602719, 0.0)],
+        ),
+        (
+            (2, 3.0, 30.0),
+            2.9031734287916762,
+            0.031622776601683793,
+            &[4.0424064779874432],
+            &[(-0.31489748089224387, 0.79420870924004425)],
+        ),
+    ];
+
+    #[test]
+    fn test_elliptic_filter() {
+        for ((order, ripple, attenuation), edge, gain, zeros, poles) in REFERENCES {
+            let filter = EllipticFilter::new(order, ripple, attenuation).unwrap();
+            assert_close!(edge, filter.stopband_edge(), 5e-15);
+            assert_close!(gain, filter.gain(), 5e-14);
+            assert_eq!(filter.zeros().count(), zeros.len());
+            for (expected, zero) in zeros.iter().zip(filter.zeros()) {
+                assert_close!(*expected, zero, 5e-15);
+            }
+            assert_eq!(filter.poles().count(), poles.len());
+            for ((re, im), pole) in poles.iter().zip(filter.poles()) {
+                assert_close!(*re, pole.0, 5e-15);
+                assert_close!(*im, pole.1, 5e-15);
+            }
+        }
+    }
+
+    #[test]
+    fn test_elliptic_filter_magnitude() {
+        for ((order, ripple, attenuation), ..) in REFERENCES {
+            let filter = EllipticFilter::new(order, ripple, attenuation).unwrap();
+            let db = |omega: f64| -20.0 * filter.magnitude(omega).log10();
+            // Equiripple edges of the passband and the stopband
+            assert_close!(ripple, db(1.0), 1e-12);
+            assert_close!(attenuation, db(filter.stopband_edge()), 1e-12);
+            if order % 2 == 1 {
+                assert_close!(1.0, filter.magnitude(0.0), 1e-14);
+            } else {
+                assert_close!(ripple, db(0.0), 1e-12);
+            }
+            for zero in filter.zeros() {
+                assert!(filter.magnitude(zero) < 1e-12);
+            }
+            for i in 0..100 {
+                let omega = i as f64 / 100.0;
+                assert!(db(omega) <= ripple * (1.0 + 1e-12));
+                assert!(
+                    db(filter.stopband_edge() / omega.max(1e-3)) >= attenuation * (1.0 - 1e-12)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_elliptic_order() {
+        for ((order, ripple, attenuation), edge, ..) in REFERENCES {
+            assert_eq!(elliptic_order(ripple, attenuation, edge).unwrap(), order);
+            assert_eq!(
+                elliptic_order(ripple, attenuation, edge * 0.999).unwrap(),
+                order + 1
+            );
+        }
+        // Sharp transitions near ωs = 1
+        let filter = EllipticFilter::new(20, 0.1, 80.0).unwrap();
+        assert_eq!(
+            elliptic_order(0.1, 80.0, filter.stopband_edge()).unwrap(),
+            20
+        );
+    }
+
+    #[test]
+    fn test_elliptic_filter_high_order() {
+        let filter = EllipticFilter::new(60, 0.5, 60.0).unwrap();
+        assert!(filter.stopband_edge() > 1.0);
+        assert_close!(
+            1.0 / filter.ripple_factor().hypot(1.0),
+            filter.magnitude(1.0),
+            1e-2
+        );
+        for order in [64, 78, 100] {
+            assert_eq!(
+                EllipticFilter::new(order, 0.5, 60.0).unwrap_err(),
+                "EllipticFilter: The selectivity is too close to 1."
+            );
+        }
+    }
+
+    #[test]
+    fn test_elliptic_filter_err() {
+        use std::f64::{INFINITY, NAN};
+
+        assert_eq!(
+            EllipticFilter::new(0, 1.0, 40.0).unwrap_err(),
+            "EllipticFilter: order must be positive."
+        );
+        assert_eq!(
+            EllipticFilter::new(3, 0.0, 40.0).unwrap_err(),
+            "EllipticFilter: passband_ripple must be positive."
+        );
+        assert_eq!(
+            EllipticFilter::new(3, 1.0, 1.0).unwrap_err(),
+            "EllipticFilter: stopband_attenuation must exceed passband_ripple."
+        );
+        assert_eq!(
+            EllipticFilter::new(3, NAN, 40.0).unwrap_err(),
+            "EllipticFilter: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            elliptic_order(1.0, INFINITY, 2.0).unwrap_err(),
+            "elliptic_order: stopband_attenuation cannot be infinite."
+        );
+        assert_eq!(
+            elliptic_order(1.0, 40.0, 1.0).unwrap_err(),
+            "elliptic_order: stopband_edge must be greater than 1."
+        );
+    }
+}
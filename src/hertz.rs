@@ -0,0 +1,640 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Hertzian contact of two elastic bodies with an elliptical contact area.
+//!
+//! Near the point of first contact, the gap between two bodies with the principal curvatures
+//! (1/R₁', 1/R₁'') and (1/R₂', 1/R₂''), whose principal planes are inclined at the angle α,
+//! is Ax² + By² with A ≤ B, where
+//! ```text
+//! A + B = (1/R₁' + 1/R₁'' + 1/R₂' + 1/R₂'') / 2,
+//! B - A = √((1/R₁' - 1/R₁'')² + (1/R₂' - 1/R₂'')² + 2(1/R₁' - 1/R₁'')(1/R₂' - 1/R₂'') cos 2α) / 2.
+//! ```
+//! Under the normal load P, the bodies touch over the ellipse with the semi-axes a ≥ b,
+//! a along x, carrying the pressure p₀√(1 - x²/a² - y²/b²). The eccentricity e² = m = 1 - b²/a²
+//! solves
+//! ```text
+//! B     K(m) - D(m)
+//! ─  =  ───────────,
+//! A     (1 - m) D(m)
+//! ```
+//! after which
+//! ```text
+//!        3PD(m)                3P               3PK(m)
+//! a³  =  ───────,   p₀  =  ──────,   δ  =  ───────,
+//!        2πE*A              2πab              2πE*a
+//! ```
+//! with the approach δ of the distant points of the bodies and the contact modulus E*
+//! (Johnson, 1985, §4.2). The equation is solved by Newton's method in w = -ln(1 - m). Near
+//! the circular contact, the ratio B/A - 1 = (K - (2 - m)D) / ((1 - m)D) is formed from the
+//! series of K - (2 - m)D, so that the eccentricity remains accurate as e → 0.
+//!
+//! The stresses on the axis of the contact are principal, and are given in closed form by
+//! [elliprd](crate::elliprd) from the potential of the pressure (Sackfield and Hills, 1983).
+//!
+//! # Examples
+//! ```
+//! use ellip::{hertz::{contact_modulus, HertzContact}, util::assert_close};
+//!
+//! // Steel ball of radius 10 mm in a groove of radius 10.5 mm, under 1 kN
+//! let modulus = contact_modulus(210e9, 0.3, 210e9, 0.3).unwrap();
+//! let contact = HertzContact::new([100.0, 100.0], [-1.0 / 0.0105, 0.0], 0.0, 1e3, modulus)
+//!     .unwrap();
+//! let (a, b) = contact.semi_axes();
+//! assert_close(a, 1.6057343267801022e-3, 1e-13);
+//! assert_close(b, 2.2490513581736037e-4, 1e-13);
+//! assert_close(contact.max_pressure(), 1.3221122275975912e9, 1e-13);
+//! ```
+//!
+//! # References
+//! - Johnson, K. L. Contact Mechanics. Cambridge: Cambridge University Press, 1985. <https://doi.org/10.1017/CBO9781139171731>.
+//! - Sackfield, A., and D. A. Hills. “Some Useful Results in the Classical Hertz Contact Problem.” The Journal of Strain Analysis for Engineering Design 18, no. 2 (1983): 101–5. <https://doi.org/10.1243/03093247V182101>.
+
+use num_traits::Float;
+
+use crate::{
+    carlson::elliprd_unchecked,
+    crate_util::{check, solve_increasing},
+    error::err,
+    EllipError, EllipticModulus,
+};
+
+/// Computes the contact modulus E* of two elastic bodies.
+/// ```text
+///  1      1 - ν₁²     1 - ν₂²
+/// ──  =  ───────  +  ───────
+/// E*       E₁          E₂
+/// ```
+///
+/// ## Parameters
+/// - e1, e2: Young's moduli (E₁, E₂). E ∈ ℝ, E > 0.
+/// - nu1, nu2: Poisson's ratios (ν₁, ν₂). ν ∈ ℝ, -1 < ν ≤ 1/2.
+///
+/// ## Domain
+/// - Returns error if E ≤ 0 or ν is outside (-1, 1/2], or any argument is NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{hertz::contact_modulus, util::assert_close};
+///
+/// assert_close(contact_modulus(2.0, 0.5, 2.0, 0.5).unwrap(), 4.0 / 3.0, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn contact_modulus<T: Float>(e1: T, nu1: T, e2: T, nu2: T) -> Result<T, EllipError> {
+    check!(@nan, contact_modulus, [e1, nu1, e2, nu2]);
+    if e1.min(e2) <= 0.0 {
+        return err!(
+            DomainError,
+            contact_modulus,
+            "Young's moduli must be positive.",
+            [e1, e2]
+        );
+    }
+    if nu1.min(nu2) <= -1.0 || nu1.max(nu2) > 0.5 {
+        return err!(
+            DomainError,
+            contact_modulus,
+            "Poisson's ratios must be in (-1, 1/2].",
+            [nu1, nu2]
+        );
+    }
+    Ok(1.0 / ((1.0 - nu1) * (1.0 + nu1) / e1 + (1.0 - nu2) * (1.0 + nu2) / e2))
+}
+
+/// Hertzian contact of two bodies under a normal load, see [mod@crate::hertz].
+///
+/// The coordinates x and y lie along the major and the minor axes of the contact ellipse, and
+/// z points into either body. Stresses are negative in compression.
+///
+/// ## Parameters
+/// - curvatures1: principal curvatures of the first body (1/R₁', 1/R₁''), positive if convex.
+/// - curvatures2: principal curvatures of the second body (1/R₂', 1/R₂''), positive if convex.
+/// - angle: angle between the planes of 1/R₁' and 1/R₂' (α). α ∈ ℝ.
+/// - load: normal load (P). P ∈ ℝ, P > 0, P is finite.
+/// - modulus: contact modulus (E*), see [contact_modulus]. E* ∈ ℝ, E* > 0, E* is finite.
+#[derive(Debug, Clone, Copy)]
+pub struct HertzContact<T: Float> {
+    load: T,
+    modulus: T,
+    /// Relative curvatures A ≤ B.
+    a_curvature: T,
+    b_curvature: T,
+    m: T,
+    a: T,
+    b: T,
+    max_pressure: T,
+    approach: T,
+}
+
+/// Largest w = -ln(1 - m), where 1 - m is the square root of the smallest normal number.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn max_w<T: Float>() -> T {
+    -0.5 * T::min_positive_value().ln()
+}
+
+/// Computes (K - (2 - m)D) / m, which is 3π/16 at m = 0.
+///
+/// Uses the series of the Gauss hypergeometric functions of K and D for small m, where the
+/// difference cancels.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn ratio_numerator<T: Float>(m: T, k: T, d: T) -> T {
+    if m >= 0.1 {
+        return (k - (2.0 - m) * d) / m;
+    }
+    // K = π/2 Σ aₙmⁿ and D = π/4 Σ dₙmⁿ
+    let (mut a, mut d) = (1.0, 1.0);
+    let (mut sum, mut power) = (0.0, 1.0);
+    for n in 1..100 {
+        let n = T::from(n).unwrap();
+        a = a * (n - 0.5) * (n - 0.5) / (n * n);
+        let d_prev = d;
+        d = d * (n - 0.5) * (n + 0.5) / ((n + 1.0) * n);
+        let term = power * (pi_2!() * (a - d) + 0.25 * pi!() * d_prev);
+        sum = sum + term;
+        if term.abs() <= epsilon!() * sum {
+            break;
+        }
+        power = power * m;
+    }
+    sum
+}
+
+/// Returns ln(B/A) at w = -ln(1 - m) and its derivative, with the context of the modulus.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn log_ratio<T: Float>(w: T) -> Result<(T, T, EllipticModulus<T>), EllipError> {
+    let modulus = EllipticModulus::from_kc((-0.5 * w).exp())?;
+    let (k, d) = (modulus.ellipk(), modulus.ellipd());
+    let m = -(-w).exp_m1();
+    let h = ratio_numerator(m, k, d);
+    // B/A - 1 = mh / ((1 - m)D), with dK/dw = (K - D) / 2 and dD/dw = h / 2
+    let value = (m * h * w.exp() / d).ln_1p();
+    let slope = 1.5 - 0.5 * h * (1.0 / (k - d) + 1.0 / d);
+    Ok((value, slope, modulus))
+}
+
+impl<T: Float> HertzContact<T> {
+    /// Constructs the contact of two bodies with the principal curvatures, under the load P.
+    ///
+    /// ## Domain
+    /// - Returns error if P ≤ 0 or E* ≤ 0.
+    /// - Returns error if the relative curvatures A and B are not positive, where the surfaces
+    ///   are conforming.
+    /// - Returns error if B/A is too large for the eccentricity to be represented.
+    /// - Returns error if any argument is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(
+        curvatures1: [T; 2],
+        curvatures2: [T; 2],
+        angle: T,
+        load: T,
+        modulus: T,
+    ) -> Result<Self, EllipError> {
+        let [k1, l1] = curvatures1;
+        let [k2, l2] = curvatures2;
+        check!(@nan, HertzContact, [k1, l1, k2, l2, angle, load, modulus]);
+        if load.min(modulus) <= 0.0 {
+            return err!(
+                DomainError,
+                HertzContact,
+                "load and modulus must be positive.",
+                [load, modulus]
+            );
+        }
+        check!(@inf, HertzContact, [k1, l1, k2, l2, angle, load, modulus]);
+
+        let (sin, cos) = angle.sin_cos();
+        let (s2, c2) = (sin * sin, cos * cos);
+        let sum = 0.5 * (k1 + l1 + k2 + l2);
+        let (d1, d2) = (k1 - l1, k2 - l2);
+        let diff = 0.5
+            * (d1 * d1 + d2 * d2 + 2.0 * d1 * d2 * (c2 - s2))
+                .max(0.0)
+                .sqrt();
+        // AB = det(C₁ + C₂) / 4 with the curvature tensors rotated into one frame
+        let det = k1 * l1 + k2 * l2 + k1 * (k2 * s2 + l2 * c2) + l1 * (k2 * c2 + l2 * s2);
+        let b_curvature = 0.5 * (sum + diff);
+        if sum <= 0.0 || det <= 0.0 {
+            return err!(
+                DomainError,
+                HertzContact,
+                "The relative curvatures must be positive.",
+                [k1, l1, k2, l2]
+            );
+        }
+        let a_curvature = 0.25 * det / b_curvature;
+
+        let target = (diff / a_curvature).ln_1p();
+        let hi = max_w();
+        if target >= log_ratio(hi)?.0 {
+            return err!(
+                DomainError,
+                HertzContact,
+                "The ratio of the relative curvatures is too large.",
+                [k1, l1, k2, l2]
+            );
+        }
+        // B/A - 1 ≈ 3m/4 for small m, and B/A ≈ e^w / (w/2 + ln 4 - 1) near m = 1.
+        let small = 4.0 / 3.0 * diff / a_curvature;
+        let w0 = if small < 0.5 {
+            -(-small).ln_1p()
+        } else {
+            target + (0.5 * target + 0.4).ln()
+        };
+        let w = if diff == 0.0 {
+            Some(0.0)
+        } else {
+            solve_increasing(0.0, hi, w0.max(T::min_positive_value()).min(hi), |w| {
+                let (value, slope, _) = log_ratio(w)?;
+                Ok((value - target, slope))
+            })?
+        };
+        let w = match w {
+            Some(w) => w,
+            None => return err!(NoConvergence, HertzContact, "Failed to converge."),
+        };
+
+        let ctx = log_ratio(w)?.2;
+        let scale = 3.0 * load / (2.0 * pi!() * modulus);
+        let a = (scale * ctx.ellipd() / a_curvature).cbrt();
+        let b = ctx.kc() * a;
+        Ok(Self {
+            load,
+            modulus,
+            a_curvature,
+            b_curvature,
+            m: -(-w).exp_m1(),
+            a,
+            b,
+            max_pressure: 3.0 * load / (2.0 * pi!() * a * b),
+            approach: scale * ctx.ellipk() / a,
+        })
+    }
+
+    /// Returns the normal load P.
+    pub fn load(&self) -> T {
+        self.load
+    }
+
+    /// Returns the contact modulus E*.
+    pub fn modulus(&self) -> T {
+        self.modulus
+    }
+
+    /// Returns the relative curvatures (A, B) with A ≤ B.
+    pub fn relative_curvatures(&self) -> (T, T) {
+        (self.a_curvature, self.b_curvature)
+    }
+
+    /// Returns the parameter m = e² = 1 - b²/a².
+    pub fn m(&self) -> T {
+        self.m
+    }
+
+    /// Returns the eccentricity e of the contact ellipse.
+    pub fn eccentricity(&self) -> T {
+        self.m.sqrt()
+    }
+
+    /// Returns the semi-axes (a, b) of the contact ellipse with a ≥ b.
+    pub fn semi_axes(&self) -> (T, T) {
+        (self.a, self.b)
+    }
+
+    /// Returns the approach δ of the distant points of the bodies.
+    pub fn approach(&self) -> T {
+        self.approach
+    }
+
+    /// Returns the maximum pressure p₀ at the center of the contact.
+    pub fn max_pressure(&self) -> T {
+        self.max_pressure
+    }
+
+    /// Returns the pressure p₀√(1 - x²/a² - y²/b²), which is zero outside the contact.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn pressure(&self, x: T, y: T) -> T {
+        let (x, y) = (x / self.a, y / self.b);
+        self.max_pressure * (1.0 - x * x - y * y).max(0.0).sqrt()
+    }
+
+    /// Returns the principal stresses (σx, σy, σz) on the axis of the contact at the depth z.
+    ///
+    /// With λ = z² and S = √((a² + λ)(b² + λ)),
+    /// ```text
+    /// σx / abp₀ = (1 - ν) z Jx - ν z Jz - (1 - 2ν) / (a² + λ + S),
+    /// σy / abp₀ = (1 - ν) z Jy - ν z Jz - (1 - 2ν) / (b² + λ + S),
+    /// σz / abp₀ = -1 / S,
+    /// ```
+    /// where Jx = 2/3 RD(b² + λ, λ, a² + λ), Jy = 2/3 RD(a² + λ, λ, b² + λ), and
+    /// Jz = 2/3 RD(a² + λ, b² + λ, λ).
+    ///
+    /// ## Parameters
+    /// - z: depth. z ∈ ℝ, z ≥ 0.
+    /// - nu: Poisson's ratio of the body (ν).
+    ///
+    /// Returns NAN if z < 0.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn stress(&self, z: T, nu: T) -> (T, T, T) {
+        if z < 0.0 {
+            return (nan!(), nan!(), nan!());
+        }
+        let (a2, b2, l) = (self.a * self.a, self.b * self.b, z * z);
+        let s = ((a2 + l) * (b2 + l)).sqrt();
+        let (jx, jy, jz) = if z == 0.0 {
+            // z Jz → 2 / ab
+            (0.0, 0.0, 2.0 / (self.a * self.b))
+        } else {
+            (
+                2.0 / 3.0 * z * elliprd_unchecked(b2 + l, l, a2 + l),
+                2.0 / 3.0 * z * elliprd_unchecked(a2 + l, l, b2 + l),
+                2.0 / 3.0 * z * elliprd_unchecked(a2 + l, b2 + l, l),
+            )
+        };
+        let scale = self.a * self.b * self.max_pressure;
+        (
+            scale * ((1.0 - nu) * jx - nu * jz - (1.0 - 2.0 * nu) / (a2 + l + s)),
+            scale * ((1.0 - nu) * jy - nu * jz - (1.0 - 2.0 * nu) / (b2 + l + s)),
+            -scale / s,
+        )
+    }
+
+    /// Returns the maximum shear stress on the axis of the contact and its depth (τ, z).
+    ///
+    /// The shear stress is half the difference of the largest and the smallest principal
+    /// stresses from [HertzContact::stress], maximized over 0 ≤ z ≤ 2b by the golden-section
+    /// search.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn max_shear_stress(&self, nu: T) -> (T, T) {
+        let shear = |z: T| {
+            let (sx, sy, sz) = self.stress(z, nu);
+            0.5 * (sx.max(sy).max(sz) - sx.min(sy).min(sz))
+        };
+        let ratio = 0.5 * (5.0.sqrt() - 1.0);
+        let (mut lo, mut hi) = (0.0, 2.0 * self.b);
+        let mut z1 = hi - ratio * (hi - lo);
+        let mut z2 = lo + ratio * (hi - lo);
+        let (mut f1, mut f2) = (shear(z1), shear(z2));
+        for _ in 0..200 {
+            if hi - lo <= epsilon!() * self.b {
+                break;
+            }
+            if f1 < f2 {
+                lo = z1;
+                z1 = z2;
+                f1 = f2;
+                z2 = lo + ratio * (hi - lo);
+                f2 = shear(z2);
+            } else {
+                hi = z2;
+                z2 = z1;
+                f2 = f1;
+                z1 = hi - ratio * (hi - lo);
+                f1 = shear(z1);
+            }
+        }
+        let z = 0.5 * (lo + hi);
+        (shear(z), z)
+    }
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+
+    /// mpmath references from the ratio equation solved by bisection,
+    /// (curvatures1, curvatures2, α, P, E*), (A, B, m, a, b, p₀, δ).
+    const REFERENCES: [([f64; 6], [f64; 7]); 7] = [
+        (
+            [1.0, 1.0, 1.0, 1.0, 0.0, 1.0],
+            [
+                1.0,
+                1.0,
+                0.0,
+                0.72112478515370419,
+                0.72112478515370419,
+                0.91816459314634433,
+                1.0400419115259521,
+            ],
+        ),
+        (
+            [1.0, 0.5, 0.8, 0.2, 0.7, 2.0],
+            [
+                0.41405268937775235,
+                0.83594731062224768,
+                0.60728273938090216,
+                0.94330051324263795,
+                0.59113961660563303,
+                1.7125026243443466,
+                0.66054913180271913,
+            ],
+        ),
+        (
+            [1.0, 1.0, 1.000001, 1.0, 0.0, 1.0],
+            [
+                1.0,
+                1.0000005,
+                6.6666627772314869e-7,
+                0.72112484524742126,
+                0.72112460487257302,
+                0.91816474617375738,
+                1.0400419981960897,
+            ],
+        ),
+        (
+            [1.0, 1.0, 1.0 + 1e-12, 1.0, 0.0, 1.0],
+            [
+                1.0,
+                1.0000000000005,
+                6.6672593372117172e-13,
+                0.72112478515376429,
+                0.72112478515352389,
+                0.91816459314649737,
+                1.0400419115260387,
+            ],
+        ),
+        (
+            [10.0, 0.0, 0.0, 0.001, 0.0, 1.0],
+            [
+                0.00050000000000000001,
+                5.0,
+                0.99998298555177297,
+                17.770973331303174,
+                0.073302730111300542,
+                0.36653041726614939,
+                0.18477019777979515,
+            ],
+        ),
+        (
+            [1.0, 0.0, 0.0, 1.0, 0.3, 1.0],
+            [
+                0.35223989666933022,
+                0.64776010333066978,
+                0.55554336059923222,
+                1.124074209364911,
+                0.74939308719966614,
+                0.56680897743343875,
+                0.80884579110223404,
+            ],
+        ),
+        (
+            [200.0, 0.0, 1e-6, 1e-6, 0.0, 1.0],
+            [
+                4.9999999999999998e-7,
+                100.0000005,
+                0.99999999955152437,
+                219.98893901038144,
+                0.0046587592601169509,
+                0.46587592945353104,
+                0.026367970438681224,
+            ],
+        ),
+    ];
+
+    #[test]
+    fn test_hertz_contact() {
+        let moduli = [1.0, 3.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+        for (([k1, l1, k2, l2, angle, load], expected), modulus) in REFERENCES.iter().zip(moduli) {
+            let contact =
+                HertzContact::new([*k1, *l1], [*k2, *l2], *angle, *load, modulus).unwrap();
+            let [a_curvature, b_curvature, m, a, b, p0, approach] = *expected;
+            let curvatures = contact.relative_curvatures();
+            assert_close!(a_curvature, curvatures.0, 1e-14);
+            assert_close!(b_curvature, curvatures.1, 1e-14);
+            if m == 0.0 {
+                assert_eq!(contact.m(), 0.0);
+            } else {
+                assert_close!(m, contact.m(), 1e-13);
+            }
+            let (sa, sb) = contact.semi_axes();
+            assert_close!(a, sa, 1e-14);
+            assert_close!(b, sb, 1e-14);
+            assert_close!(p0, contact.max_pressure(), 1e-14);
+            assert_close!(approach, contact.approach(), 1e-14);
+            // P = 2/3 πabp₀
+            assert_close!(
+                *load,
+                2.0 / 3.0 * std::f64::consts::PI * sa * sb * contact.max_pressure(),
+                1e-14
+            );
+        }
+    }
+
+    #[test]
+    fn test_hertz_contact_circular() {
+        // a³ = 3PR / 4E* and δ = a² / R with R = 1/2
+        let contact = HertzContact::new([1.0, 1.0], [1.0, 1.0], 0.0, 1.0, 1.0).unwrap();
+        let a = 0.375.cbrt();
+        assert_eq!(contact.eccentricity(), 0.0);
+        assert_close!(a, contact.semi_axes().0, 1e-15);
+        assert_close!(2.0 * a * a, contact.approach(), 1e-15);
+        assert_close!(0.0, contact.pressure(a, 0.0), 1e-15);
+        assert_eq!(contact.pressure(a, a), 0.0);
+        assert_close!(
+            contact.max_pressure() * 0.75.sqrt(),
+            contact.pressure(0.0, 0.5 * a),
+            1e-15
+        );
+    }
+
+    #[test]
+    fn test_hertz_contact_stress() {
+        // mpmath references of the closed form at z = b/2 with ν = 0.3, which agrees with the
+        // integral of the Boussinesq solution, and of the maximum by the golden-section search.
+        for (i, stress, shear) in [
+            (
+                0,
+                [
+                    -0.16559404501486976,
+                    -0.16559404501486976,
+                    -0.73453167451707546,
+                ],
+                [0.2846498480440986, 0.34676329864488575],
+            ),
+            (
+                1,
+                [
+                    -0.46793207730649041,
+                    -0.37225788242434232,
+                    -1.461637273684783,
+                ],
+                [0.55051226193871185, 0.34722526229002716],
+            ),
+            (
+                4,
+                [
+                    -0.1364898585109175,
+                    -0.12462563281526802,
+                    -0.32783407429264499,
+                ],
+                [0.11035834371614851, 0.057623192455865327],
+            ),
+            (
+                6,
+                [
+                    -0.17276023974916085,
+                    -0.15915827307305267,
+                    -0.41669209891223721,
+                ],
+                [0.1398966438954929, 0.0036624899992227996],
+            ),
+        ] {
+            let ([k1, l1, k2, l2, angle, load], _) = REFERENCES[i];
+            let modulus = if i == 1 { 3.0 } else { 1.0 };
+            let contact = HertzContact::new([k1, l1], [k2, l2], angle, load, modulus).unwrap();
+            let b = contact.semi_axes().1;
+            let (sx, sy, sz) = contact.stress(0.5 * b, 0.3);
+            assert_close!(stress[0], sx, 1e-13);
+            assert_close!(stress[1], sy, 1e-13);
+            assert_close!(stress[2], sz, 1e-13);
+            let (tau, depth) = contact.max_shear_stress(0.3);
+            assert_close!(shear[0], tau, 1e-14);
+            assert_close!(shear[1], depth, 1e-7);
+        }
+
+        // σx = -p₀(2ν + (1 - 2ν)b / (a + b)) and σz = -p₀ at the surface
+        let contact = HertzContact::new([1.0, 0.5], [0.8, 0.2], 0.7, 2.0, 3.0).unwrap();
+        let (a, b) = contact.semi_axes();
+        let p0 = contact.max_pressure();
+        let (sx, sy, sz) = contact.stress(0.0, 0.3);
+        assert_close!(-p0 * (0.6 + 0.4 * b / (a + b)), sx, 1e-15);
+        assert_close!(-p0 * (0.6 + 0.4 * a / (a + b)), sy, 1e-15);
+        assert_close!(-p0, sz, 1e-15);
+        assert!(contact.stress(-1.0, 0.3).0.is_nan());
+    }
+
+    #[test]
+    fn test_hertz_contact_err() {
+        assert_eq!(
+            HertzContact::new([1.0, 1.0], [-1.0, -1.0], 0.0, 1.0, 1.0).unwrap_err(),
+            "HertzContact: The relative curvatures must be positive."
+        );
+        assert_eq!(
+            HertzContact::new([1.0, 0.0], [1.0, 0.0], 0.0, 1.0, 1.0).unwrap_err(),
+            "HertzContact: The relative curvatures must be positive."
+        );
+        assert_eq!(
+            HertzContact::new([1.0, 1.0], [1.0, 1.0], 0.0, 0.0, 1.0).unwrap_err(),
+            "HertzContact: load and modulus must be positive."
+        );
+        assert_eq!(
+            HertzContact::new([1.0, 1e-300], [0.0, 0.0], 0.0, 1.0, 1.0).unwrap_err(),
+            "HertzContact: The ratio of the relative curvatures is too large."
+        );
+        assert_eq!(
+            HertzContact::new([1.0, f64::NAN], [1.0, 1.0], 0.0, 1.0, 1.0).unwrap_err(),
+            "HertzContact: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            contact_modulus(1.0, 0.6, 1.0, 0.3).unwrap_err(),
+            "contact_modulus: Poisson's ratios must be in (-1, 1/2]."
+        );
+        assert_eq!(
+            contact_modulus(0.0, 0.3, 1.0, 0.3).unwrap_err(),
+            "contact_modulus: Young's moduli must be positive."
+        );
+    }
+}
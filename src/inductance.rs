@@ -0,0 +1,664 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Mutual inductances, self-inductances, and forces of coaxial loops and solenoids.
+//!
+//! Maxwell's formula for the mutual inductance of the coaxial circular loops of radii a and b
+//! at the axial distance d is (Rosa and Grover, 1912, §11)
+//! ```text
+//!                   ⎡⎛2    ⎞        2     ⎤
+//! M  =  μ₀ √(ab)  ⎢⎜─ - k⎟ K(k) - ─ E(k)⎥,   k² = 4ab / ((a + b)² + d²)
+//!                   ⎣⎝k    ⎠        k     ⎦
+//! ```
+//! The bracket vanishes like k³ for distant loops, and computing k² loses the digits of the
+//! small complementary modulus when the loops nearly touch. Here, both are avoided by the
+//! descending Landen transformation of [mod@crate::ring], which evaluates the inductance
+//! from the distances r₁ = √((a - b)² + d²) and r₂ = √((a + b)² + d²) with [cel].
+//!
+//! The inductances are returned per unit μ₀ and per turn squared, in the unit of the lengths.
+//!
+//! # Examples
+//! ```
+//! use ellip::{inductance::*, util::assert_close};
+//!
+//! // Two loops of 10 cm radius, 1 cm apart, in henries
+//! let mu0 = 1.25663706212e-6;
+//! let m = mu0 * coaxial_loop_mutual_inductance(0.1, 0.1, 0.01).unwrap();
+//! assert_close(m, 3.002876305336186e-7, 1e-10);
+//!
+//! // Long solenoids approach L = μ₀πa²N² / l.
+//! assert_close(nagaoka_coefficient(1.0, 1e6).unwrap(), 1.0, 1e-6);
+//! ```
+//!
+//! # References
+//! - Rosa, Edward B., and Frederick W. Grover. “Formulas and Tables for the Calculation of Mutual and Self-Inductance.” Bulletin of the Bureau of Standards 8, no. 1 (1912): 1–237. <https://doi.org/10.6028/bulletin.169>.
+//! - Nagaoka, Hantaro. “The Inductance Coefficients of Solenoids.” Journal of the College of Science, Imperial University, Tokyo 27, no. 6 (1909): 1–33.
+//! - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19.12>.
+
+use num_traits::Float;
+
+use crate::{
+    bulirsch::BulirschConst,
+    cel,
+    crate_util::{check, pow2_scale},
+    cylinder::{sin2_cos2_integral, sin2_cos2_series},
+    error::err,
+    EllipError,
+};
+
+/// Maximum number of terms of the series of E(k) - 1 for small kc.
+const MAX_TERMS: usize = 100;
+
+/// Validates the radii of the loops or solenoids.
+macro_rules! check_radii {
+    ($fn_name:ident, [$($var:ident),+ $(,)?]) => {
+        check!(@inf, $fn_name, [$($var),+]);
+        $(
+            if $var <= T::zero() {
+                return err!(
+                    DomainError,
+                    $fn_name,
+                    concat![stringify!($var), " must be positive."],
+                    $var
+                );
+            }
+        )+
+    };
+}
+
+/// Computes the mutual inductance of the coaxial circular loops per unit μ₀.
+/// ```text
+///        16a²b²
+/// M  =  ──────── cel(λc, 1, 0, 1),   λc = 2√(r₁r₂) / (r₁ + r₂)
+///       (r₁+r₂)³
+/// ```
+///
+/// The loops of radii a and b lie in parallel planes at the axial distance d, with
+/// r₁ = √((a - b)² + d²) and r₂ = √((a + b)² + d²). This is Maxwell's formula after the
+/// descending Landen transformation, see [mod@crate::inductance].
+///
+/// ## Parameters
+/// - a, b: radii of the loops. a, b ∈ ℝ, a, b > 0.
+/// - d: axial distance. d ∈ ℝ.
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, b ≤ 0, a or b is infinite, or any argument is NAN.
+///
+/// ## Special Cases
+/// - M = ∞ for coincident loops, where a = b and d = 0.
+/// - M = 0 for d = ±∞.
+///
+/// # Examples
+/// ```
+/// use ellip::{inductance::coaxial_loop_mutual_inductance, util::assert_close};
+///
+/// assert_close(coaxial_loop_mutual_inductance(1.0, 2.0, 0.5).unwrap(), 0.763410079809441, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn coaxial_loop_mutual_inductance<T: Float + BulirschConst<T>>(
+    a: T,
+    b: T,
+    d: T,
+) -> Result<T, EllipError> {
+    check!(@nan, coaxial_loop_mutual_inductance, [a, b, d]);
+    check_radii!(coaxial_loop_mutual_inductance, [a, b]);
+    if d.is_infinite() {
+        return Ok(0.0);
+    }
+
+    // M is proportional to the scale of the lengths.
+    let scale = pow2_scale(a.max(b).max(d.abs()));
+    let (a, b, d) = (a / scale, b / scale, d / scale);
+    let r1 = (a - b).hypot(d);
+    if r1 == 0.0 {
+        return Ok(inf!());
+    }
+    let r2 = (a + b).hypot(d);
+    let s = r1 + r2;
+    let t = a / s * (b / s);
+    let kc = 2.0 * (r1 / s * (r2 / s)).sqrt();
+    Ok(16.0 * t * (t * s * scale) * cel(kc, 1.0, 0.0, 1.0)?)
+}
+
+/// Computes the axial force between the coaxial circular loops per unit μ₀I₁I₂.
+/// ```text
+///               4a²b²d
+/// F  =  - ───────────────── cel(λc, 1, 2, λc²)
+///         r₁² r₂² (r₁ + r₂)
+/// ```
+///
+/// The loop of radius a is at z = 0 and the loop of radius b at z = d, with r₁, r₂, and λc as
+/// in [coaxial_loop_mutual_inductance]. The force F = I₁I₂ ∂M/∂d acts on the second loop, so
+/// loops with currents in the same direction attract. The force on the first loop is -F.
+///
+/// ## Parameters
+/// - a, b: radii of the loops. a, b ∈ ℝ, a, b > 0.
+/// - d: axial position of the second loop. d ∈ ℝ.
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, b ≤ 0, a or b is infinite, or any argument is NAN.
+/// - Returns error for coincident loops, where a = b and d = 0.
+///
+/// ## Special Cases
+/// - F = 0 for d = 0 or d = ±∞.
+///
+/// # Examples
+/// ```
+/// use ellip::{inductance::coaxial_loop_force, util::assert_close};
+///
+/// assert_close(coaxial_loop_force(1.0, 2.0, 0.5).unwrap(), -0.3811615031278377, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn coaxial_loop_force<T: Float + BulirschConst<T>>(a: T, b: T, d: T) -> Result<T, EllipError> {
+    check!(@nan, coaxial_loop_force, [a, b, d]);
+    check_radii!(coaxial_loop_force, [a, b]);
+    if d.is_infinite() {
+        return Ok(0.0);
+    }
+
+    if a == b && d == 0.0 {
+        return err!(
+            Singularity,
+            coaxial_loop_force,
+            "The loops must not coincide.",
+            [a, b, d]
+        );
+    }
+    // F does not depend on the scale of the lengths.
+    let scale = pow2_scale(a.max(b).max(d.abs()));
+    let (a, b, d) = (a / scale, b / scale, d / scale);
+    let r1 = (a - b).hypot(d);
+    let r2 = (a + b).hypot(d);
+    let s = r1 + r2;
+    let kc = 2.0 * (r1 / s * (r2 / s)).sqrt();
+    let ratio = a / r1 * (b / r2);
+    Ok(-4.0 * ratio * (ratio * (d / s)) * cel(kc, 1.0, 2.0, kc * kc)?)
+}
+
+/// Computes Nagaoka's coefficient of the solenoid.
+/// ```text
+///          4    ⎡ kc²                         ⎤
+/// k_L  =  ────  ⎢ ─── (K(k) - E(k)) + E(k) - k⎥,   k² = 4a² / (4a² + l²)
+///         3πkc  ⎣  k²                         ⎦
+/// ```
+///
+/// The self-inductance of the solenoid of radius a, length l, and N turns, wound as a thin
+/// current sheet, is L = μ₀πa²N²k_L / l, see [solenoid_self_inductance]. The coefficient
+/// decreases from 1 for long solenoids to 0 for flat coils. The bracket is evaluated as
+/// (E - 1) + kc² / (1 + k) + kc² D(k), with E - 1 from its logarithmic series for small kc
+/// (Carlson, 2025, §19.12.2), so the coefficient stays accurate for flat coils.
+///
+/// ## Parameters
+/// - a: radius. a ∈ ℝ, a > 0.
+/// - l: length. l ∈ ℝ, l > 0.
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, l ≤ 0, a or l is infinite, or any argument is NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{inductance::nagaoka_coefficient, util::assert_close};
+///
+/// assert_close(nagaoka_coefficient(1.0, 2.0).unwrap(), 0.6884226073203766, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn nagaoka_coefficient<T: Float + BulirschConst<T>>(a: T, l: T) -> Result<T, EllipError> {
+    check!(@nan, nagaoka_coefficient, [a, l]);
+    check_radii!(nagaoka_coefficient, [a, l]);
+
+    // kc = l / √(4a² + l²) and k = 2a / √(4a² + l²), from the scaled lengths
+    let scale = pow2_scale(a.max(l));
+    let (a, l) = (a / scale, l / scale);
+    let r = (2.0 * a).hypot(l);
+    let (k, kc) = (2.0 * a / r, l / r);
+    if kc == 0.0 {
+        // The coefficient underflows with l / a.
+        return Ok(0.0);
+    }
+
+    // The bracket divided by kc
+    let e_minus_one = if kc < 0.3 {
+        ellipe_minus_one_series(kc)
+    } else {
+        (cel(kc, 1.0, 1.0, kc * kc)? - 1.0) / kc
+    };
+    let bracket = e_minus_one + kc / (1.0 + k) + kc * cel(kc, 1.0, 0.0, 1.0)?;
+    Ok(4.0 / (3.0 * pi!()) * bracket)
+}
+
+/// Computes (E(k) - 1) / kc from the series in kc (Carlson, 2025, §19.12.2).
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn ellipe_minus_one_series<T: Float>(kc: T) -> T {
+    let kc2 = kc * kc;
+    let ln = -kc.ln();
+    // d(i) = ψ(1 + i) - ψ(1/2 + i)
+    let mut d = 4.0.ln();
+    let mut coef = 0.5 * kc;
+    let mut ans = 0.0;
+    for i in 0..MAX_TERMS {
+        let i = T::from(i).unwrap();
+        let term = coef * (ln + d - 1.0 / ((2.0 * i + 1.0) * (2.0 * i + 2.0)));
+        ans = ans + term;
+        if term.abs() <= epsilon!() * ans {
+            break;
+        }
+        coef = coef * kc2 * (i + 0.5) * (i + 1.5) / ((i + 1.0) * (i + 2.0));
+        d = d + 1.0 / (i + 1.0) - 1.0 / (i + 0.5);
+    }
+    ans
+}
+
+/// Computes the self-inductance of the solenoid per unit μ₀N².
+/// ```text
+///       πa²
+/// L  =  ─── k_L
+///        l
+/// ```
+///
+/// The solenoid of radius a and length l is wound as a thin current sheet, and k_L is
+/// [nagaoka_coefficient]. The inductance excludes the internal inductance of the wire and
+/// the corrections for its round cross-section and the spacing of the turns.
+///
+/// ## Parameters
+/// - a: radius. a ∈ ℝ, a > 0.
+/// - l: length. l ∈ ℝ, l > 0.
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, l ≤ 0, a or l is infinite, or any argument is NAN.
+///
+/// # Examples
+/// ```
+/// use ellip::{inductance::solenoid_self_inductance, util::assert_close};
+///
+/// // 100 turns, 2 cm in radius, 10 cm long, in henries
+/// let mu0 = 1.25663706212e-6;
+/// let l = mu0 * 100.0 * 100.0 * solenoid_self_inductance(0.02, 0.1).unwrap();
+/// assert_close(l, 1.3420347529544352e-4, 1e-10);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn solenoid_self_inductance<T: Float + BulirschConst<T>>(a: T, l: T) -> Result<T, EllipError> {
+    check!(@nan, solenoid_self_inductance, [a, l]);
+    check_radii!(solenoid_self_inductance, [a, l]);
+    Ok(pi!() * a * (a / l * nagaoka_coefficient(a, l)?))
+}
+
+/// Computes the mutual inductance of the coaxial solenoids per unit μ₀N₁N₂.
+/// ```text
+///            8a₁²a₂²       ⎡                                                 ⎤
+/// M  =  ──────────────── ⎢ U(d + h₁ + h₂) - U(d + h₁ - h₂) - U(d - h₁ + h₂) + U(d - h₁ - h₂)⎥
+///       l₁l₂(a₁ + a₂)²   ⎣                                                 ⎦
+/// ```
+/// where h₁ = l₁/2, h₂ = l₂/2, and
+/// ```text
+///                           π/2
+///                          ⌠  sin²θ cos²θ √(1 - k² sin²θ)
+/// U(ζ)  =  √(ζ² + (a₁ + a₂)²)  ⎮  ───────────────────────────── dθ
+///                          ⌡        1 - n sin²θ
+///                         0
+/// ```
+/// with k² = 4a₁a₂ / (ζ² + (a₁ + a₂)²) and n = 4a₁a₂ / (a₁ + a₂)². The integral is reduced to
+/// [cel], or summed as a power series when n is small.
+///
+/// The solenoids of radii a₁ and a₂ and lengths l₁ and l₂ are wound as thin current sheets,
+/// and their centers are at the axial distance d. The solenoids may overlap or be nested. As
+/// in the four-term formula of Rosa and Grover (1912, §13), the terms cancel for distant
+/// solenoids, so about 2 log₁₀(d / (l₁ + l₂)) digits are lost at the distance d. They also
+/// cancel for solenoids much shorter than their radii, which are better treated as loops by
+/// [coaxial_loop_mutual_inductance].
+///
+/// ## Parameters
+/// - a1, a2: radii. a1, a2 ∈ ℝ, a1, a2 > 0.
+/// - l1, l2: lengths. l1, l2 ∈ ℝ, l1, l2 > 0.
+/// - d: axial distance between the centers. d ∈ ℝ.
+///
+/// ## Domain
+/// - Returns error if a1, a2, l1, or l2 is not positive or is infinite, or any argument is
+///   NAN.
+///
+/// ## Special Cases
+/// - M = 0 for d = ±∞.
+///
+/// # Examples
+/// ```
+/// use ellip::{inductance::{coaxial_solenoid_mutual_inductance, solenoid_self_inductance}, util::assert_close};
+///
+/// // A solenoid with itself
+/// let m = coaxial_solenoid_mutual_inductance(1.0, 2.0, 1.0, 2.0, 0.0).unwrap();
+/// assert_close(m, solenoid_self_inductance(1.0, 2.0).unwrap(), 1e-14);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn coaxial_solenoid_mutual_inductance<T: Float + BulirschConst<T>>(
+    a1: T,
+    l1: T,
+    a2: T,
+    l2: T,
+    d: T,
+) -> Result<T, EllipError> {
+    check!(@nan, coaxial_solenoid_mutual_inductance, [a1, l1, a2, l2, d]);
+    check_radii!(coaxial_solenoid_mutual_inductance, [a1, l1, a2, l2]);
+    if d.is_infinite() {
+        return Ok(0.0);
+    }
+
+    // M is proportional to the scale of the lengths.
+    let scale = pow2_scale(a1.max(l1).max(a2).max(l2).max(d.abs()));
+    let (a1, l1, a2, l2, d) = (a1 / scale, l1 / scale, a2 / scale, l2 / scale, d / scale);
+    let (h1, h2) = (l1 / 2.0, l2 / 2.0);
+    let sum = a1 + a2;
+    let n = 4.0 * (a1 / sum) * (a2 / sum);
+    let gamma = (a1 - a2).abs() / sum;
+    let u = |zeta: T| -> Result<T, EllipError> {
+        // The squares are formed relative to the larger of |ζ| and a₁ + a₂.
+        let w = pow2_scale(zeta.abs().max(sum));
+        let (zeta, sum, diff) = (zeta / w, sum / w, (a1 - a2) / w);
+        let p = zeta * zeta + sum * sum;
+        let m = 4.0 * (a1 / w) * (a2 / w) / p;
+        let kc = ((zeta * zeta + diff * diff) / p).sqrt();
+        Ok(w * p.sqrt() * sin2_cos2_sqrt_integral(n, m, kc, gamma)?)
+    };
+    let terms = u(d + h1 + h2)? - u(d + h1 - h2)? - u(d - h1 + h2)? + u(d - h1 - h2)?;
+    let ratio = a1 * (a2 / sum);
+    Ok(8.0 * (ratio / l1) * (ratio / l2) * terms * scale)
+}
+
+/// Computes the integral of sin²θ cos²θ √(1 - m sin²θ) / (1 - n sin²θ) over [0, π/2] for
+/// 0 ≤ m ≤ n ≤ 1, where kc² = 1 - m and γ² = 1 - n.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn sin2_cos2_sqrt_integral<T: Float + BulirschConst<T>>(
+    n: T,
+    m: T,
+    kc: T,
+    gamma: T,
+) -> Result<T, EllipError> {
+    if n < 0.5 {
+        return Ok(sin2_cos2_series(n, m, -0.5));
+    }
+    if kc == 0.0 {
+        // The integral of sin²θ cos θ
+        return Ok(1.0 / 3.0);
+    }
+
+    // J(0, m) + (n - m) (J(n, m) - J(0, m)) / n, where J is the integral with 1/√(1 - m sin²θ)
+    let j0 = sin2_cos2_integral(0.0, m, kc, 1.0)?;
+    let j = sin2_cos2_integral(n, m, kc, gamma)?;
+    Ok(j0 + (n - m) * (j - j0) / n)
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_close, ellipe, ellipk};
+    use std::f64::consts::PI;
+
+    /// Maxwell's formula
+    fn maxwell(a: f64, b: f64, d: f64) -> f64 {
+        let m = 4.0 * a * b / ((a + b) * (a + b) + d * d);
+        let k = m.sqrt();
+        (a * b).sqrt() * ((2.0 / k - k) * ellipk(m).unwrap() - 2.0 / k * ellipe(m).unwrap())
+    }
+
+    #[test]
+    fn test_coaxial_loop_mutual_inductance() {
+        for (a, b, d) in [
+            (1.0, 2.0, 0.5),
+            (1.0, 1.0, 1.0),
+            (3.0, 0.5, 2.0),
+            (1.0, 1.5, 0.0),
+        ] {
+            assert_close!(
+                maxwell(a, b, d),
+                coaxial_loop_mutual_inductance(a, b, d).unwrap(),
+                2e-14
+            );
+            assert_close!(
+                coaxial_loop_mutual_inductance(a, b, d).unwrap(),
+                coaxial_loop_mutual_inductance(b, a, -d).unwrap(),
+                1e-15
+            );
+        }
+
+        // mpmath references with the nearly touching and the distant loops
+        assert_close!(
+            9.28978193419936,
+            coaxial_loop_mutual_inductance(1.0, 1.0, 1e-4).unwrap(),
+            5e-15
+        );
+        assert_close!(
+            1.5707916144206425e-9,
+            coaxial_loop_mutual_inductance(1.0, 1.0, 1e3).unwrap(),
+            5e-15
+        );
+        // Dipole limit πa²b² / 2d³
+        assert_close!(
+            PI / 2.0 * 1e-30,
+            coaxial_loop_mutual_inductance(1.0, 1.0, 1e10).unwrap(),
+            1e-15
+        );
+        // Close loops, M ≈ a (ln(8a / r₁) - 2)
+        let r1 = 1e-12;
+        assert_close!(
+            (8.0 / r1).ln() - 2.0,
+            coaxial_loop_mutual_inductance(1.0, 1.0, r1).unwrap(),
+            1e-15
+        );
+    }
+
+    #[test]
+    fn test_coaxial_loop_force() {
+        // F = ∂M/∂d
+        for (a, b, d) in [(1.0, 2.0, 0.5), (1.0, 1.0, 1.0), (3.0, 0.5, -2.0)] {
+            let h = 1e-5;
+            let dm = (coaxial_loop_mutual_inductance(a, b, d + h).unwrap()
+                - coaxial_loop_mutual_inductance(a, b, d - h).unwrap())
+                / (2.0 * h);
+            assert_close!(dm.abs(), coaxial_loop_force(a, b, d).unwrap().abs(), 1e-9);
+            assert_eq!(dm.signum(), coaxial_loop_force(a, b, d).unwrap().signum());
+        }
+        assert_eq!(coaxial_loop_force(1.0, 2.0, 0.0), Ok(0.0));
+        // Dipoles, F = -3π a²b² / 2d⁴
+        assert_close!(
+            3.0 * PI / 2.0 * 1e-40,
+            -coaxial_loop_force(1.0, 1.0, 1e10).unwrap(),
+            1e-15
+        );
+        // Close parallel wires, F = -a / d
+        assert_close!(1e8, -coaxial_loop_force(1.0, 1.0, 1e-8).unwrap(), 1e-7);
+    }
+
+    #[test]
+    fn test_nagaoka_coefficient() {
+        // mpmath references of the closed form
+        for (l, expected) in [
+            (2.0, 0.6884226073203766),
+            (0.5, 0.36543189740035403),
+            (0.1, 0.12361480970476302),
+            (1e-4, 3.434494254031654e-4),
+            (1e-8, 6.366236648401482e-8),
+            (100.0, 0.9915617338687447),
+        ] {
+            assert_close!(expected, nagaoka_coefficient(1.0, l).unwrap(), 5e-15);
+            assert_close!(expected, nagaoka_coefficient(3.0, 3.0 * l).unwrap(), 5e-15);
+        }
+        // Both branches of E - 1
+        let kc: f64 = 0.3;
+        let l = 2.0 * kc / (1.0 - kc * kc).sqrt();
+        assert_close!(
+            nagaoka_coefficient(1.0, l * (1.0 - 1e-15)).unwrap(),
+            nagaoka_coefficient(1.0, l * (1.0 + 1e-15)).unwrap(),
+            1e-14
+        );
+    }
+
+    #[test]
+    fn test_coaxial_solenoid_mutual_inductance() {
+        // mpmath references by integrating Maxwell's formula over both lengths
+        for ((a1, l1, a2, l2, d), expected) in [
+            ((1.0, 2.0, 0.5, 1.0, 0.3), 0.2658675316919669),
+            ((1.0, 2.0, 1.0, 2.0, 2.0), 0.20375293109719925),
+            ((1.0, 0.5, 3.0, 1.0, 2.0), 0.29891599570987276),
+            ((1.0, 1.0, 1.1, 3.0, 0.0), 0.8655090224428584),
+            ((0.1, 1.0, 2.0, 0.2, 0.5), 0.007023405834568558),
+        ] {
+            assert_close!(
+                expected,
+                coaxial_solenoid_mutual_inductance(a1, l1, a2, l2, d).unwrap(),
+                5e-14
+            );
+            assert_close!(
+                expected,
+                coaxial_solenoid_mutual_inductance(a2, l2, a1, l1, -d).unwrap(),
+                5e-14
+            );
+        }
+
+        // Self-inductance and the inner solenoid in a long one, M = πa₂² / l₁
+        for l in [0.1, 1.0, 5.0] {
+            assert_close!(
+                solenoid_self_inductance(1.0, l).unwrap(),
+                coaxial_solenoid_mutual_inductance(1.0, l, 1.0, l, 0.0).unwrap(),
+                1e-13
+            );
+        }
+        assert_close!(
+            PI * 0.25 / 1e4,
+            coaxial_solenoid_mutual_inductance(1.0, 1e4, 0.5, 1.0, 0.0).unwrap(),
+            1e-7
+        );
+
+        // Short solenoids approach loops
+        assert_close!(
+            coaxial_loop_mutual_inductance(1.0, 2.0, 0.5).unwrap(),
+            coaxial_solenoid_mutual_inductance(1.0, 1e-4, 2.0, 1e-4, 0.5).unwrap(),
+            1e-7
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        for scale in [1e-200, 1e-100, 1e100, 1e200] {
+            for (a, b, d) in [(1.0, 2.0, 0.5), (1.0, 1.0, 1.0), (3.0, 0.5, -2.0)] {
+                assert_close!(
+                    scale * coaxial_loop_mutual_inductance(a, b, d).unwrap(),
+                    coaxial_loop_mutual_inductance(scale * a, scale * b, scale * d).unwrap(),
+                    1e-15
+                );
+                let f = coaxial_loop_force(a, b, d).unwrap();
+                let scaled = coaxial_loop_force(scale * a, scale * b, scale * d).unwrap();
+                assert_close!(f.abs(), scaled.abs(), 1e-15);
+                assert_eq!(f.signum(), scaled.signum());
+            }
+            assert_close!(
+                nagaoka_coefficient(1.0, 2.0).unwrap(),
+                nagaoka_coefficient(scale, 2.0 * scale).unwrap(),
+                1e-15
+            );
+            assert_close!(
+                scale * solenoid_self_inductance(1.0, 2.0).unwrap(),
+                solenoid_self_inductance(scale, 2.0 * scale).unwrap(),
+                1e-15
+            );
+            assert_close!(
+                scale * 0.2658675316919669,
+                coaxial_solenoid_mutual_inductance(
+                    scale,
+                    2.0 * scale,
+                    0.5 * scale,
+                    scale,
+                    0.3 * scale
+                )
+                .unwrap(),
+                5e-14
+            );
+        }
+
+        // mpmath references with the lengths of very different scales
+        assert_close!(
+            0.39317514837200473e-200,
+            coaxial_loop_mutual_inductance(1e-200, 1e-200, 1e-200).unwrap(),
+            1e-15
+        );
+        assert_close!(
+            1.5707963267948966e-300,
+            coaxial_loop_mutual_inductance(1.0, 1.0, 1e100).unwrap(),
+            1e-15
+        );
+        assert_eq!(coaxial_loop_mutual_inductance(1.0, 1.0, 1e200), Ok(0.0));
+        assert_close!(
+            391.5189073506676,
+            coaxial_loop_mutual_inductance(1.0, 1.0, 1e-170).unwrap(),
+            1e-15
+        );
+        assert_close!(
+            2.2038343152119438e-298,
+            nagaoka_coefficient(1e300, 1.0).unwrap(),
+            1e-14
+        );
+        assert_close!(1.0, nagaoka_coefficient(1.0, 1e300).unwrap(), 1e-15);
+        assert_eq!(nagaoka_coefficient(f64::MAX, f64::MIN_POSITIVE), Ok(0.0));
+    }
+
+    #[test]
+    fn test_f32() {
+        assert_close!(
+            0.7634101f32,
+            coaxial_loop_mutual_inductance(1.0, 2.0, 0.5).unwrap(),
+            1e-6
+        );
+        assert_close!(
+            1.5707964e-30f32,
+            coaxial_loop_mutual_inductance(1.0, 1.0, 1e10).unwrap(),
+            1e-6
+        );
+        assert_eq!(coaxial_loop_mutual_inductance(1.0f32, 1.0, 1e20), Ok(0.0));
+        assert_close!(
+            0.3811615f32,
+            -coaxial_loop_force(1e-20, 2e-20, 0.5e-20).unwrap(),
+            1e-6
+        );
+        assert_close!(
+            1.5161464e-19f32,
+            nagaoka_coefficient(1e20, 1.0).unwrap(),
+            1e-5
+        );
+        assert_close!(
+            0.68842261f32,
+            nagaoka_coefficient(1e20, 2e20).unwrap(),
+            1e-6
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            coaxial_loop_mutual_inductance(0.0, 1.0, 1.0).unwrap_err(),
+            "coaxial_loop_mutual_inductance: a must be positive."
+        );
+        assert_eq!(
+            coaxial_loop_mutual_inductance(1.0, 1.0, 0.0),
+            Ok(f64::INFINITY)
+        );
+        assert_eq!(
+            coaxial_loop_mutual_inductance(1.0, 1.0, f64::INFINITY),
+            Ok(0.0)
+        );
+        assert_eq!(
+            coaxial_loop_force(1.0, 1.0, 0.0).unwrap_err(),
+            "coaxial_loop_force: The loops must not coincide."
+        );
+        assert_eq!(
+            nagaoka_coefficient(1.0, f64::NAN).unwrap_err(),
+            "nagaoka_coefficient: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            solenoid_self_inductance(1.0, -1.0).unwrap_err(),
+            "solenoid_self_inductance: l must be positive."
+        );
+        assert_eq!(
+            coaxial_solenoid_mutual_inductance(1.0, 1.0, f64::INFINITY, 1.0, 0.0).unwrap_err(),
+            "coaxial_solenoid_mutual_inductance: a2 cannot be infinite."
+        );
+    }
+}
@@ -0,0 +1,1064 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Interval evaluation of elliptic integrals.
+//!
+//! The functions in this module take closed intervals [lo, hi] as inputs and return an
+//! enclosure of the range of the corresponding integral over the input box. The enclosure
+//! is guaranteed to contain the exact range, not only the values returned by the point
+//! functions. It is computed in interval arithmetic with outward rounding:
+//!
+//! - Every operation is rounded to nearest and then moved outward by ε|r| + ε MIN_POSITIVE,
+//!   past the neighbouring floating-point number, which bounds the rounding error of a
+//!   correctly rounded operation, including subnormal results.
+//! - The symmetric integrals are evaluated by Carlson's duplication theorem. After the last step,
+//!   the remaining integral is enclosed using RF(t,t,t) = t^(-1/2), RD(t,t,t) = RJ(t,t,t,t) = t^(-3/2),
+//!   and the monotonicity of the integrals, so there is no truncation error to estimate.
+//! - sin φ and cos φ are enclosed by their Taylor polynomials and the Lagrange remainder,
+//!   after reducing φ by a two-term representation of π.
+//!
+//! The Legendre integrals are expressed in terms of the symmetric ones. Their monotonicity
+//! is used to reduce the box to the corners where the extrema lie.
+//!
+//! | Function                     | Monotonicity                                        |
+//! |------------------------------|-----------------------------------------------------|
+//! | [ellipk](fn@ellipk)(m)       | increasing in m                                     |
+//! | [ellipe](fn@ellipe)(m)       | decreasing in m                                     |
+//! | [ellipf](fn@ellipf)(φ, m)    | increasing in φ, increasing in m for φ ≥ 0, decreasing in m for φ ≤ 0 |
+//! | [elliprf](fn@elliprf)(x, y, z) | decreasing in x, y, and z                         |
+//! | [elliprd](fn@elliprd)(x, y, z) | decreasing in x, y, and z                         |
+//! | [elliprj](fn@elliprj)(x, y, z, p) | decreasing in x, y, z, and p for p > 0         |
+//!
+//! The bounds assume IEEE 754 binary arithmetic with round-to-nearest and a correctly
+//! rounded square root, as provided by [f32] and [f64]. The functions are therefore bounded
+//! by the sealed trait [IntervalFloat], which is implemented for these types only.
+//!
+//! # Examples
+//! ```
+//! use ellip::interval::{self, Interval};
+//!
+//! // Enclose the period factor K(m) of a pendulum with uncertain amplitude.
+//! let m = Interval::new(0.2, 0.21).unwrap();
+//! let k = interval::ellipk(m).unwrap();
+//! assert!(k.contains(1.6596235986105280));
+//! assert!(k.contains(ellip::ellipk(0.205).unwrap()));
+//! ```
+
+use num_traits::Float;
+
+use crate::{error::err, EllipError};
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Floating-point types for which the outward rounding of [Interval] is rigorous, namely IEEE
+/// 754 binary types with round-to-nearest and a correctly rounded square root.
+///
+/// This trait is sealed and implemented for [f32] and [f64] only. Other types, such as
+/// [DoubleDouble](crate::DoubleDouble), are rejected at compile time.
+/// ```compile_fail
+/// use ellip::{interval::{self, Interval}, DoubleDouble};
+///
+/// let m = Interval::point(DoubleDouble::from(0.5));
+/// let k = interval::ellipk(m);
+/// ```
+pub trait IntervalFloat: Float + sealed::Sealed {}
+
+impl IntervalFloat for f32 {}
+impl IntervalFloat for f64 {}
+
+/// Closed interval [lo, hi] with lo ≤ hi.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval<T> {
+    /// Lower bound
+    pub lo: T,
+    /// Upper bound
+    pub hi: T,
+}
+
+impl<T: IntervalFloat> Interval<T> {
+    /// Creates a new interval [lo, hi].
+    ///
+    /// Returns error if either bound is NAN or if lo > hi.
+    pub fn new(lo: T, hi: T) -> Result<Self, EllipError> {
+        if lo.is_nan() || hi.is_nan() {
+            return err!(NanArgument, Interval, "Bounds cannot be NAN.", [lo, hi]);
+        }
+        if lo > hi {
+            return err!(
+                DomainError,
+                Interval,
+                "lo must not be greater than hi.",
+                [lo, hi]
+            );
+        }
+        Ok(Self { lo, hi })
+    }
+
+    /// Creates a degenerate interval [x, x].
+    pub fn point(x: T) -> Self {
+        Self { lo: x, hi: x }
+    }
+
+    /// Returns true if x lies within the interval.
+    pub fn contains(&self, x: T) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Returns the width of the interval, hi - lo.
+    pub fn width(&self) -> T {
+        self.hi - self.lo
+    }
+
+    /// Returns the midpoint of the interval.
+    pub fn mid(&self) -> T {
+        self.lo + (self.hi - self.lo) / (T::one() + T::one())
+    }
+
+    /// Rounds the round-to-nearest bounds lo and hi outward.
+    fn outward(lo: T, hi: T) -> Self {
+        Self {
+            lo: round_down(lo),
+            hi: round_up(hi),
+        }
+    }
+
+    /// Clamps the lower bound of a quantity known to be non-negative.
+    fn nonneg(self) -> Self {
+        Self {
+            lo: self.lo.max(T::zero()),
+            hi: self.hi,
+        }
+    }
+
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self::outward(self.lo + other.lo, self.hi + other.hi)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        Self::outward(self.lo - other.hi, self.hi - other.lo)
+    }
+
+    fn mul(self, other: Self) -> Self {
+        // 0 ∞ only arises from an infinite bound, which is not attained, so it is taken as 0.
+        let prod = |a: T, b: T| {
+            let p = a * b;
+            if p.is_nan() {
+                T::zero()
+            } else {
+                p
+            }
+        };
+        let p = [
+            prod(self.lo, other.lo),
+            prod(self.lo, other.hi),
+            prod(self.hi, other.lo),
+            prod(self.hi, other.hi),
+        ];
+        Self::outward(
+            p[0].min(p[1]).min(p[2].min(p[3])),
+            p[0].max(p[1]).max(p[2].max(p[3])),
+        )
+    }
+
+    /// Divides by a positive number.
+    fn div(self, d: T) -> Self {
+        Self::outward(self.lo / d, self.hi / d)
+    }
+
+    /// Multiplies by a power of two f, which is exact unless the result is subnormal.
+    fn scale(self, f: T) -> Self {
+        let tiny = T::min_positive_value() * T::epsilon();
+        Self {
+            lo: self.lo * f - tiny,
+            hi: self.hi * f + tiny,
+        }
+    }
+
+    fn sqr(self) -> Self {
+        let (a, b) = (self.lo.abs(), self.hi.abs());
+        if self.lo <= T::zero() && self.hi >= T::zero() {
+            Self::outward(T::zero(), (a * a).max(b * b)).nonneg()
+        } else {
+            Self::outward((a * a).min(b * b), (a * a).max(b * b)).nonneg()
+        }
+    }
+
+    fn sqrt(self) -> Self {
+        Self::outward(self.lo.max(T::zero()).sqrt(), self.hi.sqrt()).nonneg()
+    }
+
+    /// Computes the reciprocal of a non-negative quantity.
+    fn recip(self) -> Self {
+        Self::outward(self.hi.recip(), self.lo.max(T::zero()).recip()).nonneg()
+    }
+
+    /// Returns the smallest interval containing all intervals in `args`.
+    fn hull(args: &[Self]) -> Self {
+        args.iter().fold(args[0], |a, b| Self {
+            lo: a.lo.min(b.lo),
+            hi: a.hi.max(b.hi),
+        })
+    }
+}
+
+/// Returns a lower bound of the real numbers whose round-to-nearest value is r.
+///
+/// The result is at most the predecessor of r, since ε|r| is at least the spacing of
+/// the floating-point numbers at r, and ε MIN_POSITIVE is the spacing of the subnormals.
+#[inline]
+fn round_down<T: IntervalFloat>(r: T) -> T {
+    if r == T::infinity() {
+        return T::max_value();
+    }
+    r - (r.abs() * T::epsilon() + T::min_positive_value() * T::epsilon())
+}
+
+/// Returns an upper bound of the real numbers whose round-to-nearest value is r.
+#[inline]
+fn round_up<T: IntervalFloat>(r: T) -> T {
+    if r == T::neg_infinity() {
+        return T::min_value();
+    }
+    r + (r.abs() * T::epsilon() + T::min_positive_value() * T::epsilon())
+}
+
+/// Maximum number of duplication steps, enough to bring the ratio of the largest to the
+/// smallest positive f64 down to one. Stopping early only widens the enclosure.
+const MAX_ITER: usize = 1100;
+
+/// Returns true if the lower bounds of the arguments agree to a few ε, and likewise the upper bounds.
+/// A bound is also settled if it contains a zero or an infinity that the duplication cannot remove.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn converged<T: IntervalFloat>(args: &[Interval<T>], first: bool) -> bool {
+    let tol = 8.0 * T::epsilon();
+    let agree = |bound: fn(&Interval<T>) -> T| {
+        let (min, max) = args
+            .iter()
+            .map(bound)
+            .fold((T::infinity(), T::zero()), |(min, max), v| {
+                (min.min(v), max.max(v))
+            });
+        max - min <= tol * min || max.is_infinite() || (!first && min.is_zero())
+    };
+    agree(|a| a.lo) && agree(|a| a.hi)
+}
+
+/// Encloses RF over a box of non-negative arguments.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn rf_box<T: IntervalFloat>(x: Interval<T>, y: Interval<T>, z: Interval<T>) -> Interval<T> {
+    let (mut x, mut y, mut z) = (x, y, z);
+    for n in 0..MAX_ITER {
+        if converged(&[x, y, z], n == 0) {
+            break;
+        }
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx.mul(sy).add(sy.mul(sz)).add(sz.mul(sx));
+        x = x.add(lambda).scale(0.25).nonneg();
+        y = y.add(lambda).scale(0.25).nonneg();
+        z = z.add(lambda).scale(0.25).nonneg();
+    }
+    // RF(t,t,t) = t^(-1/2)
+    Interval::hull(&[x, y, z]).sqrt().recip()
+}
+
+/// Encloses RD over a box of non-negative arguments.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn rd_box<T: IntervalFloat>(x: Interval<T>, y: Interval<T>, z: Interval<T>) -> Interval<T> {
+    let (mut x, mut y, mut z) = (x, y, z);
+    let mut sum = Interval::point(0.0);
+    let mut fac = Interval::point(1.0);
+    for n in 0..MAX_ITER {
+        if converged(&[x, y, z], n == 0) {
+            break;
+        }
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx.mul(sy).add(sy.mul(sz)).add(sz.mul(sx));
+        let term = sz.mul(z.add(lambda)).recip();
+        sum = sum.add(term.mul(fac));
+        fac = fac.scale(0.25).nonneg();
+        x = x.add(lambda).scale(0.25).nonneg();
+        y = y.add(lambda).scale(0.25).nonneg();
+        z = z.add(lambda).scale(0.25).nonneg();
+    }
+    // RD(t,t,t) = t^(-3/2)
+    let t = Interval::hull(&[x, y, z]);
+    let tail = t.mul(t.sqrt()).recip();
+    sum.mul(Interval::point(3.0)).add(tail.mul(fac)).nonneg()
+}
+
+/// Encloses RJ at a point with non-negative arguments. On a wide box, the enclosure is
+/// loose because p appears in both the numerator and the denominator of α/β.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn rj_point<T: IntervalFloat>(
+    x: Interval<T>,
+    y: Interval<T>,
+    z: Interval<T>,
+    p: Interval<T>,
+) -> Interval<T> {
+    let (mut x, mut y, mut z, mut p) = (x, y, z, p);
+    let one = Interval::point(1.0);
+    let mut sum = Interval::point(0.0);
+    let mut fac = Interval::point(1.0);
+    for n in 0..MAX_ITER {
+        if converged(&[x, y, z, p], n == 0) {
+            break;
+        }
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx.mul(sy).add(sy.mul(sz)).add(sz.mul(sx));
+        // RC(α, β) with α = (p(√x + √y + √z) + √(xyz))² and β = p(p + λ)², evaluated
+        // as RF(α/β, 1, 1)/√β to avoid overflow.
+        let (sp, pl) = (p.sqrt(), p.add(lambda));
+        let (sp_inv, pl_inv) = (sp.recip(), pl.recip());
+        let ratio = sp
+            .mul(sx.add(sy).add(sz))
+            .mul(pl_inv)
+            .add(sx.mul(sy).mul(pl_inv).mul(sz.mul(sp_inv)));
+        let term = rf_box(ratio.sqr(), one, one).mul(sp_inv).mul(pl_inv);
+        sum = sum.add(term.mul(fac));
+        fac = fac.scale(0.25).nonneg();
+        x = x.add(lambda).scale(0.25).nonneg();
+        y = y.add(lambda).scale(0.25).nonneg();
+        z = z.add(lambda).scale(0.25).nonneg();
+        p = p.add(lambda).scale(0.25).nonneg();
+    }
+    // RJ(t,t,t,t) = t^(-3/2)
+    let t = Interval::hull(&[x, y, z, p]);
+    let tail = t.mul(t.sqrt()).recip();
+    sum.mul(Interval::point(3.0)).add(tail.mul(fac)).nonneg()
+}
+
+/// Returns π/2 as hi + lo, where hi is π/2 rounded to T and lo encloses the remainder.
+fn frac_pi_2<T: IntervalFloat>() -> (T, Interval<T>) {
+    use core::f64::consts::FRAC_PI_2;
+    // π/2 - FRAC_PI_2
+    const FRAC_PI_2_LO: f64 = 6.123233995736766e-17;
+    let hi = T::from(FRAC_PI_2).unwrap();
+    let lo = T::from(FRAC_PI_2 - hi.to_f64().unwrap() + FRAC_PI_2_LO).unwrap();
+    (hi, Interval::outward(lo, lo))
+}
+
+/// Encloses sin a and cos a for |a| ≤ 1.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn sin_cos_taylor<T: IntervalFloat>(a: Interval<T>) -> (Interval<T>, Interval<T>) {
+    if !(a.lo >= -1.0 && a.hi <= 1.0) {
+        let unit = Interval { lo: -1.0, hi: 1.0 };
+        return (unit, unit);
+    }
+    let a2 = a.sqr();
+    let one = Interval::point(1.0);
+    let (mut s, mut c) = (one, one);
+    for k in (1..=12).rev() {
+        let k = T::from(2 * k).unwrap();
+        s = one.sub(a2.mul(s).div(k * (k + 1.0)));
+        c = one.sub(a2.mul(c).div((k - 1.0) * k));
+    }
+    // The Lagrange remainders are bounded by |a|²⁷/27! ≤ |a|/27! and |a|²⁶/26! ≤ a²/26! for |a| ≤ 1.
+    let rem_s = round_up(a.lo.abs().max(a.hi.abs()) * 1e-28);
+    let rem_c = round_up(a2.hi * 2.5e-27);
+    (
+        a.mul(s).add(Interval {
+            lo: -rem_s,
+            hi: rem_s,
+        }),
+        c.add(Interval {
+            lo: -rem_c,
+            hi: rem_c,
+        }),
+    )
+}
+
+/// Encloses sin r and cos r for |r| ≤ π/2.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn sin_cos<T: IntervalFloat>(r: Interval<T>) -> (Interval<T>, Interval<T>) {
+    let (hi, lo) = frac_pi_2::<T>();
+    let frac_pi_4 = hi / 2.0;
+    if r.hi > frac_pi_4 {
+        // sin r = cos(π/2 - r), cos r = sin(π/2 - r)
+        let (s, c) = sin_cos_taylor(Interval::point(hi).sub(r).add(lo));
+        (c, s)
+    } else if r.lo < -frac_pi_4 {
+        // sin r = -cos(π/2 + r), cos r = sin(π/2 + r)
+        let (s, c) = sin_cos_taylor(Interval::point(hi).add(r).add(lo));
+        (c.neg(), s)
+    } else {
+        sin_cos_taylor(r)
+    }
+}
+
+/// Encloses K(m) = RF(0, 1 - m, 1) for m ≤ 1.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn ellipk_box<T: IntervalFloat>(m: Interval<T>) -> Interval<T> {
+    let one = Interval::point(1.0);
+    rf_box(Interval::point(0.0), one.sub(m).nonneg(), one)
+}
+
+/// Encloses E(m) = (1 - m) / 3 (RD(0, 1 - m, 1) + RD(0, 1, 1 - m)) for m ≤ 1.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn ellipe_point<T: IntervalFloat>(m: T) -> Interval<T> {
+    let one = Interval::point(1.0);
+    if m == 1.0 {
+        return one;
+    }
+    let zero = Interval::point(0.0);
+    let mc = one.sub(Interval::point(m)).nonneg();
+    mc.div(3.0)
+        .mul(rd_box(zero, mc, one).add(rd_box(zero, one, mc)))
+}
+
+/// Encloses sin r, cos²r, and 1 - m sin²r = cos²r + (1 - m) sin²r for |r| ≤ π/2.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn ellipf_args<T: IntervalFloat>(r: Interval<T>, m: T) -> (Interval<T>, Interval<T>, Interval<T>) {
+    let (s, c) = sin_cos(r);
+    let c2 = c.sqr();
+    let mc = Interval::point(1.0).sub(Interval::point(m));
+    (s, c2, c2.add(s.sqr().mul(mc)))
+}
+
+/// Encloses F(φ, m) = sin φ RF(cos²φ, 1 - m sin²φ, 1) after reducing φ to [-π/2, π/2].
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn ellipf_point<T: IntervalFloat>(phi: T, m: T) -> Interval<T> {
+    let (hi, lo) = frac_pi_2::<T>();
+    let one = Interval::point(1.0);
+    // F(φ, m) = F(r, m) + 2jK(m) with r = φ - jπ. Returns r and π/2 - |r|.
+    let reduce = |j: T| {
+        let r = if j == 0.0 {
+            Interval::point(phi)
+        } else {
+            let two_j = Interval::point(2.0 * j);
+            Interval::point(phi)
+                .sub(two_j.mul(Interval::point(hi)))
+                .sub(two_j.mul(lo))
+        };
+        let abs_r = Interval {
+            lo: r.lo.max(-r.hi).max(0.0),
+            hi: r.hi.max(-r.lo),
+        };
+        (r, Interval::point(hi).sub(abs_r).add(lo))
+    };
+
+    let mut j = if phi.abs() <= hi {
+        0.0
+    } else {
+        (phi / (2.0 * hi)).round()
+    };
+    let (mut r, mut d) = reduce(j);
+    if d.hi < 0.0 {
+        // Rounding picked the neighbouring period.
+        j = j + r.lo.signum();
+        (r, d) = reduce(j);
+    }
+    let k = || ellipk_box(Interval::point(m));
+    if d.lo < 0.0 {
+        // φ is within rounding of (j ± 1/2)π, where F = (2j ± 1)K. The distance is
+        // at most |d| and |∂F/∂φ| ≤ 1/√(1 - max(m, 0)).
+        let dist = d.lo.abs().max(d.hi.abs());
+        let lip = one.sub(Interval::point(m.max(0.0))).sqrt().recip();
+        let spread = lip.mul(Interval {
+            lo: -dist,
+            hi: dist,
+        });
+        return Interval::point(2.0 * j + r.mid().signum())
+            .mul(k())
+            .add(spread);
+    }
+
+    let (s, c2, y) = ellipf_args(r, m);
+    let f = s.mul(rf_box(c2, y.nonneg(), one));
+    if j == 0.0 {
+        return f;
+    }
+    f.add(Interval::point(2.0 * j).mul(k()))
+}
+
+/// Computes an enclosure of the [complete elliptic integral of the first kind](crate::ellipk) over an interval.
+///
+/// K(m) = RF(0, 1 - m, 1) is evaluated over the interval. Since K(m) is increasing in m,
+/// the enclosure spans [K(m.lo), K(m.hi)].
+///
+/// ## Parameters
+/// - m: interval of the elliptic parameter. m.hi ≤ 1.
+///
+/// ## Domain
+/// - Returns error if m.hi > 1.
+/// - The upper bound of the enclosure is ∞ if m.hi = 1.
+///
+/// # Examples
+/// ```
+/// use ellip::interval::{self, Interval};
+///
+/// let k = interval::ellipk(Interval::new(0.4, 0.6).unwrap()).unwrap();
+/// assert!(k.contains(ellip::ellipk(0.5).unwrap()));
+/// ```
+pub fn ellipk<T: IntervalFloat>(m: Interval<T>) -> Result<Interval<T>, EllipError> {
+    if m.hi > T::one() {
+        return err!(DomainError, ellipk, "m must not be greater than 1.", [m]);
+    }
+    Ok(ellipk_box(m))
+}
+
+/// Computes an enclosure of the [complete elliptic integral of the second kind](crate::ellipe) over an interval.
+///
+/// E(m) is decreasing in m, so the enclosure is [E(m.hi), E(m.lo)], where the endpoints are
+/// evaluated as E(m) = (1 - m) / 3 (RD(0, 1 - m, 1) + RD(0, 1, 1 - m)).
+///
+/// ## Parameters
+/// - m: interval of the elliptic parameter. m.hi ≤ 1.
+///
+/// ## Domain
+/// - Returns error if m.hi > 1.
+///
+/// # Examples
+/// ```
+/// use ellip::interval::{self, Interval};
+///
+/// let e = interval::ellipe(Interval::new(0.4, 0.6).unwrap()).unwrap();
+/// assert!(e.contains(ellip::ellipe(0.5).unwrap()));
+/// ```
+pub fn ellipe<T: IntervalFloat>(m: Interval<T>) -> Result<Interval<T>, EllipError> {
+    if m.hi > T::one() {
+        return err!(DomainError, ellipe, "m must not be greater than 1.", [m]);
+    }
+    Ok(Interval {
+        lo: ellipe_point(m.hi).lo,
+        hi: ellipe_point(m.lo).hi,
+    })
+}
+
+/// Computes an enclosure of the [incomplete elliptic integral of the first kind](crate::ellipf) over an interval box.
+///
+/// F(φ,m) is increasing in φ. It is increasing in m for φ ≥ 0 and decreasing in m for φ ≤ 0.
+/// The enclosure is therefore determined by two corners of the box, where the integral is
+/// evaluated as F(φ,m) = sin φ RF(cos²φ, 1 - m sin²φ, 1) after reducing φ to [-π/2, π/2].
+///
+/// ## Parameters
+/// - phi: interval of the amplitude angle (φ). φ ∈ ℝ.
+/// - m: interval of the elliptic parameter. m ∈ ℝ.
+///
+/// ## Domain
+/// - Returns error if m.hi > 1 and m sin²φ ≥ 1 for some point in the box, or if this cannot
+///   be ruled out within the rounding error.
+/// - Returns error if m.hi ≥ 1 and |φ| > π/2 for some point in the box.
+/// - Since π/2 is not representable, F(φ, 1) is finite at every point of a box with |φ| < π/2.
+///
+/// # Examples
+/// ```
+/// use ellip::interval::{self, Interval};
+/// use std::f64::consts::FRAC_PI_4;
+///
+/// let phi = Interval::new(FRAC_PI_4 - 1e-3, FRAC_PI_4 + 1e-3).unwrap();
+/// let f = interval::ellipf(phi, Interval::point(0.5)).unwrap();
+/// assert!(f.contains(ellip::ellipf(FRAC_PI_4, 0.5).unwrap()));
+/// ```
+pub fn ellipf<T: IntervalFloat>(
+    phi: Interval<T>,
+    m: Interval<T>,
+) -> Result<Interval<T>, EllipError> {
+    let one = T::one();
+    if m.hi >= one {
+        // Beyond the first quarter period, the integrand becomes complex for m > 1
+        // and the integral diverges for m = 1. FRAC_PI_2 may be rounded above π/2.
+        let (half_pi, lo) = frac_pi_2::<T>();
+        let phi_max = phi.lo.abs().max(phi.hi.abs());
+        let beyond = phi_max > half_pi || (phi_max == half_pi && lo.lo <= T::zero());
+        let complex = m.hi > one && {
+            let y = ellipf_args(Interval::point(phi_max), m.hi).2.lo;
+            y <= T::zero() || y.is_nan()
+        };
+        if beyond || complex {
+            return err!(
+                DomainError,
+                ellipf,
+                "m sin²φ must be smaller than one.",
+                [phi, m]
+            );
+        }
+    }
+
+    let m_lo = if phi.lo < T::zero() { m.hi } else { m.lo };
+    let m_hi = if phi.hi < T::zero() { m.lo } else { m.hi };
+    Ok(Interval {
+        lo: ellipf_point(phi.lo, m_lo).lo,
+        hi: ellipf_point(phi.hi, m_hi).hi,
+    })
+}
+
+/// Computes an enclosure of the [symmetric elliptic integral of the first kind](crate::elliprf) over an interval box.
+///
+/// RF is evaluated over the box by the duplication theorem. Since RF(x,y,z) is decreasing
+/// in each argument, the enclosure spans [RF(x.hi, y.hi, z.hi), RF(x.lo, y.lo, z.lo)].
+///
+/// ## Parameters
+/// - x, y, z: intervals of the arguments. x.lo, y.lo, z.lo ≥ 0.
+///
+/// ## Domain
+/// - Returns error if any lower bound is negative.
+/// - Returns error if more than one upper bound is zero.
+/// - The upper bound of the enclosure is ∞ if more than one lower bound is zero.
+///
+/// # Examples
+/// ```
+/// use ellip::interval::{self, Interval};
+///
+/// let x = Interval::new(1.0, 1.5).unwrap();
+/// let y = Interval::new(2.0, 2.5).unwrap();
+/// let z = Interval::new(3.0, 3.5).unwrap();
+/// let rf = interval::elliprf(x, y, z).unwrap();
+/// assert!(rf.contains(ellip::elliprf(1.2, 2.2, 3.2).unwrap()));
+/// ```
+pub fn elliprf<T: IntervalFloat>(
+    x: Interval<T>,
+    y: Interval<T>,
+    z: Interval<T>,
+) -> Result<Interval<T>, EllipError> {
+    if x.lo.min(y.lo).min(z.lo) < T::zero() {
+        return err!(
+            DomainError,
+            elliprf,
+            "Arguments must be non-negative.",
+            [x, y, z]
+        );
+    }
+    if [x.hi, y.hi, z.hi].iter().filter(|v| v.is_zero()).count() > 1 {
+        return err!(Singularity, elliprf, "At most one argument can be zero.", [x, y, z], limit: T::infinity());
+    }
+    Ok(rf_box(x, y, z))
+}
+
+/// Computes an enclosure of the [degenerate elliptic integral of the third kind](crate::elliprd) over an interval box.
+///
+/// RD is evaluated over the box by the duplication theorem. Since RD(x,y,z) is decreasing
+/// in each argument, the enclosure spans [RD(x.hi, y.hi, z.hi), RD(x.lo, y.lo, z.lo)].
+///
+/// ## Parameters
+/// - x, y: intervals of the arguments. x.lo, y.lo ≥ 0.
+/// - z: interval of the argument. z.hi > 0.
+///
+/// ## Domain
+/// - Returns error if x.lo < 0, y.lo < 0, or z.lo < 0.
+/// - Returns error if x.hi = y.hi = 0 or z.hi = 0.
+/// - The upper bound of the enclosure is ∞ if x.lo = y.lo = 0 or z.lo = 0.
+///
+/// # Examples
+/// ```
+/// use ellip::interval::{self, Interval};
+///
+/// let x = Interval::new(1.0, 1.5).unwrap();
+/// let y = Interval::new(2.0, 2.5).unwrap();
+/// let z = Interval::new(3.0, 3.5).unwrap();
+/// let rd = interval::elliprd(x, y, z).unwrap();
+/// assert!(rd.contains(ellip::elliprd(1.2, 2.2, 3.2).unwrap()));
+/// ```
+pub fn elliprd<T: IntervalFloat>(
+    x: Interval<T>,
+    y: Interval<T>,
+    z: Interval<T>,
+) -> Result<Interval<T>, EllipError> {
+    if x.lo.min(y.lo) < T::zero() {
+        return err!(
+            DomainError,
+            elliprd,
+            "x and y must be non-negative.",
+            [x, y]
+        );
+    }
+    if x.hi.is_zero() && y.hi.is_zero() {
+        return err!(Singularity, elliprd, "Both x and y cannot be zero.", [x, y], limit: T::infinity());
+    }
+    if z.lo < T::zero() {
+        return err!(DomainError, elliprd, "z must be positive", [z]);
+    }
+    if z.hi.is_zero() {
+        return err!(Singularity, elliprd, "z must be positive", [z], limit: T::infinity());
+    }
+    Ok(rd_box(x, y, z))
+}
+
+/// Computes an enclosure of the [symmetric elliptic integral of the third kind](crate::elliprj) over an interval box.
+///
+/// For p > 0, RJ(x,y,z,p) is decreasing in each argument, so the enclosure is
+/// [RJ(x.hi, y.hi, z.hi, p.hi), RJ(x.lo, y.lo, z.lo, p.lo)], where the corners are
+/// evaluated by the duplication theorem.
+/// The Cauchy principal value for p < 0 is not monotonic and is not supported.
+///
+/// ## Parameters
+/// - x, y, z: intervals of the arguments. x.lo, y.lo, z.lo ≥ 0.
+/// - p: interval of the argument. p.lo ≥ 0, p.hi > 0.
+///
+/// ## Domain
+/// - Returns error if x.lo < 0, y.lo < 0, z.lo < 0, or p.lo < 0.
+/// - Returns error if more than one of x.hi, y.hi, and z.hi are zero, or p.hi = 0.
+/// - The upper bound of the enclosure is ∞ if more than one of x.lo, y.lo, and z.lo are zero, or p.lo = 0.
+///
+/// # Examples
+/// ```
+/// use ellip::interval::{self, Interval};
+///
+/// let x = Interval::new(1.0, 1.5).unwrap();
+/// let y = Interval::new(2.0, 2.5).unwrap();
+/// let z = Interval::new(3.0, 3.5).unwrap();
+/// let p = Interval::new(4.0, 4.5).unwrap();
+/// let rj = interval::elliprj(x, y, z, p).unwrap();
+/// assert!(rj.contains(ellip::elliprj(1.2, 2.2, 3.2, 4.2).unwrap()));
+/// ```
+pub fn elliprj<T: IntervalFloat>(
+    x: Interval<T>,
+    y: Interval<T>,
+    z: Interval<T>,
+    p: Interval<T>,
+) -> Result<Interval<T>, EllipError> {
+    if p.lo < T::zero() {
+        return err!(
+            DomainError,
+            elliprj,
+            "p must be non-negative for interval evaluation.",
+            [p]
+        );
+    }
+    if p.hi.is_zero() {
+        return err!(Singularity, elliprj, "p cannot be zero.", [p], limit: T::infinity());
+    }
+    if x.lo.min(y.lo).min(z.lo) < T::zero() {
+        return err!(
+            DomainError,
+            elliprj,
+            "x, y, and z must be non-negative.",
+            [x, y, z]
+        );
+    }
+    if [x.hi, y.hi, z.hi].iter().filter(|v| v.is_zero()).count() > 1 {
+        return err!(Singularity, elliprj, "At most one argument can be zero.", [x, y, z], limit: T::infinity());
+    }
+    let corner = |x, y, z, p| {
+        rj_point(
+            Interval::point(x),
+            Interval::point(y),
+            Interval::point(z),
+            Interval::point(p),
+        )
+    };
+    Ok(Interval {
+        lo: corner(x.hi, y.hi, z.hi, p.hi).lo,
+        hi: corner(x.lo, y.lo, z.lo, p.lo).hi,
+    })
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn iv(lo: f64, hi: f64) -> Interval<f64> {
+        Interval::new(lo, hi).unwrap()
+    }
+
+    /// Asserts that the enclosure contains the exact range [lo, hi] and exceeds it by at most 1e-13 relative.
+    fn assert_tight(enclosure: Interval<f64>, lo: f64, hi: f64) {
+        let rtol = 1e-13;
+        let (elo, ehi) = (enclosure.lo, enclosure.hi);
+        assert!(
+            elo <= lo && hi <= ehi,
+            "[{lo}, {hi}] is not enclosed by [{elo}, {ehi}]"
+        );
+        assert!(
+            lo - elo <= rtol * lo.abs() && (ehi - hi <= rtol * hi.abs() || ehi == hi),
+            "[{elo}, {ehi}] is too wide for [{lo}, {hi}]"
+        );
+    }
+
+    #[test]
+    fn test_interval_new() {
+        assert_eq!(
+            Interval::new(1.0, 0.0).unwrap_err(),
+            "Interval: lo must not be greater than hi."
+        );
+        assert_eq!(
+            Interval::new(f64::NAN, 0.0).unwrap_err(),
+            "Interval: Bounds cannot be NAN."
+        );
+        let a = iv(1.0, 3.0);
+        assert_eq!(a.width(), 2.0);
+        assert_eq!(a.mid(), 2.0);
+        assert!(Interval::point(2.0).contains(2.0));
+    }
+
+    #[test]
+    fn test_outward_rounding() {
+        // Zero bounds are widened as well.
+        let zero = Interval::point(0.0).add(Interval::point(0.0));
+        assert!(zero.lo < 0.0 && zero.hi > 0.0);
+        let third = Interval::point(1.0).div(3.0);
+        assert!(third.lo < third.hi && third.contains(1.0 / 3.0));
+        let tiny = Interval::point(f64::MIN_POSITIVE).mul(Interval::point(0.5));
+        assert!(tiny.lo < 0.5 * f64::MIN_POSITIVE && tiny.hi > 0.5 * f64::MIN_POSITIVE);
+        let huge = Interval::point(f64::MAX).add(Interval::point(f64::MAX));
+        assert_eq!(huge.hi, f64::INFINITY);
+        assert!(huge.lo.is_finite());
+    }
+
+    #[test]
+    fn test_ellipk_interval() {
+        // Reference values from mpmath
+        assert_tight(
+            ellipk(iv(0.2, 0.7)).unwrap(),
+            1.659623598610528,
+            2.075363135292469,
+        );
+        assert_tight(
+            ellipk(iv(-5.0, 0.99)).unwrap(),
+            0.955503927064044,
+            3.695637362989874,
+        );
+        let cases = [
+            (1.0 - 2f64.powi(-50), 18.714973875118528),
+            (1e-300, FRAC_PI_2),
+            (-1e300, 3.4677405831022676e-148),
+        ];
+        for (m, k) in cases {
+            assert_tight(ellipk(Interval::point(m)).unwrap(), k, k);
+        }
+
+        assert_eq!(ellipk(iv(0.5, 1.0)).unwrap().hi, f64::INFINITY);
+        assert_eq!(
+            ellipk(iv(0.5, 1.1)).unwrap_err(),
+            "ellipk: m must not be greater than 1."
+        );
+    }
+
+    #[test]
+    fn test_ellipe_interval() {
+        assert_tight(
+            ellipe(iv(0.2, 0.7)).unwrap(),
+            1.2416705679458229,
+            1.489035058095853,
+        );
+        assert_tight(ellipe(iv(-5.0, 1.0)).unwrap(), 1.0, 2.8301982463458772);
+        let cases = [
+            (1.0 - 2f64.powi(-50), 1.000000000000008),
+            (1e-300, FRAC_PI_2),
+            (-1e10, 100000.0000669961),
+        ];
+        for (m, e) in cases {
+            assert_tight(ellipe(Interval::point(m)).unwrap(), e, e);
+        }
+        assert_eq!(
+            ellipe(iv(0.5, 1.1)).unwrap_err(),
+            "ellipe: m must not be greater than 1."
+        );
+    }
+
+    #[test]
+    fn test_ellipf_interval() {
+        // The minimum of a box crossing φ = 0 lies at (φ.lo, m.hi).
+        assert_tight(
+            ellipf(iv(-0.4, 1.2), iv(-0.5, 0.9)).unwrap(),
+            -0.4099258092672005,
+            1.5648981345066715,
+        );
+        assert_tight(
+            ellipf(iv(0.3, 1.2), iv(-0.5, 0.9)).unwrap(),
+            0.29783269983291705,
+            1.5648981345066715,
+        );
+        assert_tight(
+            ellipf(iv(-0.4, 1.2), iv(-0.5, -0.5)).unwrap(),
+            -0.3950049845125458,
+            1.1106876317603203,
+        );
+        // Several periods
+        assert_tight(
+            ellipf(iv(-7.0, 5.0), iv(-3.0, 0.95)).unwrap(),
+            -12.416311475654915,
+            9.804077029605413,
+        );
+        assert_tight(
+            ellipf(iv(-7.0, 5.0), Interval::point(-3.0)).unwrap(),
+            -4.921371605203169,
+            3.380082932613584,
+        );
+
+        let cases = [
+            (FRAC_PI_4, 0.5, 0.8260178762492452),
+            (FRAC_PI_2, 0.5, 1.8540746773013719),
+            (3.0 * FRAC_PI_2, 0.5, 5.562224031904115),
+            (100.0, 0.99, 235.96326856956088),
+            (0.5, 3.0, 0.593784687154398),
+            (1e-300, 0.5, 1e-300),
+            // Close to the poles F(±π/2, 1) = ±∞
+            (FRAC_PI_2, 1.0, 38.025003373828866),
+            (-FRAC_PI_2, 1.0, -38.025003373828866),
+        ];
+        for (phi, m, f) in cases {
+            let enclosure = ellipf(Interval::point(phi), Interval::point(m)).unwrap();
+            assert_tight(enclosure, f, f);
+        }
+
+        // m > 1
+        assert!(ellipf(iv(0.1, 0.5), iv(1.0, 2.0)).is_ok());
+        assert_eq!(
+            ellipf(iv(0.1, 1.0), iv(1.0, 2.0)).unwrap_err(),
+            "ellipf: m sin²φ must be smaller than one."
+        );
+        assert_eq!(
+            ellipf(iv(0.1, 3.0), iv(1.0, 1.1)).unwrap_err(),
+            "ellipf: m sin²φ must be smaller than one."
+        );
+        assert_eq!(
+            ellipf(iv(0.1, 3.0), iv(0.5, 1.0)).unwrap_err(),
+            "ellipf: m sin²φ must be smaller than one."
+        );
+    }
+
+    #[test]
+    fn test_f32_interval() {
+        let point = Interval::point;
+        // Reference values from mpmath at the f32 arguments
+        assert!(ellipk(point(0.7f32)).unwrap().contains(2.0753631));
+        assert!(ellipe(point(0.2f32)).unwrap().contains(1.4890351));
+        assert!(ellipf(point(5.0f32), point(0.95f32))
+            .unwrap()
+            .contains(9.804077));
+        // The largest f32 below π/2
+        let below = f32::from_bits(core::f32::consts::FRAC_PI_2.to_bits() - 1);
+        assert!(ellipf(point(below), point(1.0f32))
+            .unwrap()
+            .contains(17.092308));
+        assert!(elliprj(point(1.0f32), point(2.0), point(3.0), point(4.0))
+            .unwrap()
+            .contains(0.2398481));
+        // FRAC_PI_2 is rounded above π/2 in f32, where F(φ, 1) diverges.
+        assert_eq!(
+            ellipf(point(core::f32::consts::FRAC_PI_2), point(1.0f32)).unwrap_err(),
+            "ellipf: m sin²φ must be smaller than one."
+        );
+    }
+
+    #[test]
+    fn test_elliprf_interval() {
+        let (x, y, z) = (iv(1.0, 1.5), iv(2.0, 2.5), iv(3.0, 3.5));
+        assert_tight(
+            elliprf(x, y, z).unwrap(),
+            0.6433192661902409,
+            0.7269459354689082,
+        );
+        // Interior points of the box
+        assert_tight(
+            elliprf(iv(1.0, 1.5), Interval::point(2.0), Interval::point(3.2)).unwrap(),
+            0.6792830874327619,
+            0.7175667272266095,
+        );
+        let cases = [
+            ([0.0, 1e-300, 1.0], 346.77405831022674),
+            ([1e-310, 1.0, 2.0], 1.3110287771460598),
+            ([1e300, 1e-300, 1.0], 3.4677405831022676e-148),
+            ([0.0, 1.0, 1.0], FRAC_PI_2),
+        ];
+        for ([x, y, z], rf) in cases {
+            let enclosure =
+                elliprf(Interval::point(x), Interval::point(y), Interval::point(z)).unwrap();
+            assert_tight(enclosure, rf, rf);
+        }
+
+        assert_eq!(
+            elliprf(iv(0.0, 1.0), iv(0.0, 1.0), iv(1.0, 2.0))
+                .unwrap()
+                .hi,
+            f64::INFINITY
+        );
+        assert_eq!(
+            elliprf(iv(-1.0, 1.0), y, z).unwrap_err(),
+            "elliprf: Arguments must be non-negative."
+        );
+        assert_eq!(
+            elliprf(iv(0.0, 0.0), iv(0.0, 0.0), z).unwrap_err(),
+            "elliprf: At most one argument can be zero."
+        );
+    }
+
+    #[test]
+    fn test_elliprd_interval() {
+        let (x, y, z) = (iv(1.0, 1.5), iv(2.0, 2.5), iv(3.0, 3.5));
+        assert_tight(
+            elliprd(x, y, z).unwrap(),
+            0.21272432718264372,
+            0.29046028102899063,
+        );
+        let cases = [
+            ([0.0, 1e-10, 1.0], 35.69765948087268),
+            ([1e300, 1.0, 1.0], 1.5e-150),
+            ([1.0, 1.0, 1e-300], 3e150),
+        ];
+        for ([x, y, z], rd) in cases {
+            let enclosure =
+                elliprd(Interval::point(x), Interval::point(y), Interval::point(z)).unwrap();
+            assert_tight(enclosure, rd, rd);
+        }
+
+        assert_eq!(elliprd(x, y, iv(0.0, 1.0)).unwrap().hi, f64::INFINITY);
+        assert_eq!(
+            elliprd(iv(0.0, 1.0), iv(0.0, 1.0), z).unwrap().hi,
+            f64::INFINITY
+        );
+        assert_eq!(
+            elliprd(x, y, iv(-1.0, 1.0)).unwrap_err(),
+            "elliprd: z must be positive"
+        );
+        assert_eq!(
+            elliprd(iv(0.0, 0.0), iv(0.0, 0.0), z).unwrap_err(),
+            "elliprd: Both x and y cannot be zero."
+        );
+    }
+
+    #[test]
+    fn test_elliprj_interval() {
+        let (x, y, z, p) = (iv(1.0, 1.5), iv(2.0, 2.5), iv(3.0, 3.5), iv(4.0, 4.5));
+        assert_tight(
+            elliprj(x, y, z, p).unwrap(),
+            0.18052215337830257,
+            0.23984809974956775,
+        );
+        let cases = [
+            ([2.0, 3.0, 4.0, 5.0], 0.14297579667156754),
+            ([0.0, 1.0, 2.0, 1e-10], 333213.3552385267),
+            ([0.0, 0.5, 1.0, 1e5], 5.547362557369342e-05),
+            ([1e-300, 1.0, 1e300, 1.0], 3e-150),
+        ];
+        for ([x, y, z, p], rj) in cases {
+            let enclosure = elliprj(
+                Interval::point(x),
+                Interval::point(y),
+                Interval::point(z),
+                Interval::point(p),
+            )
+            .unwrap();
+            assert_tight(enclosure, rj, rj);
+        }
+
+        assert_eq!(elliprj(x, y, z, iv(0.0, 1.0)).unwrap().hi, f64::INFINITY);
+        assert_eq!(
+            elliprj(iv(0.0, 1.0), iv(0.0, 1.0), z, p).unwrap().hi,
+            f64::INFINITY
+        );
+        assert_eq!(
+            elliprj(x, y, z, iv(-1.0, 1.0)).unwrap_err(),
+            "elliprj: p must be non-negative for interval evaluation."
+        );
+        assert_eq!(
+            elliprj(x, y, z, iv(0.0, 0.0)).unwrap_err(),
+            "elliprj: p cannot be zero."
+        );
+    }
+}
@@ -13,7 +13,7 @@
 
 use num_traits::Float;
 
-use crate::{carlson::elliprd_unchecked, crate_util::check, StrErr};
+use crate::{carlson::elliprd_unchecked, crate_util::check, error::err, EllipError};
 
 /// Computes [complete elliptic integral of Legendre's type](https://dlmf.nist.gov/19.2.E8).
 /// ```text
@@ -59,13 +59,13 @@ use crate::{carlson::elliprd_unchecked, crate_util::check, StrErr};
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed April 17, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn ellipd<T: Float>(m: T) -> Result<T, StrErr> {
+pub fn ellipd<T: Float>(m: T) -> Result<T, EllipError> {
     if m >= 1.0 {
         // D evaluates to inf at m=1.
         if m == 1.0 {
             return Ok(inf!());
         }
-        return Err("ellipd: m must not be greater than 1.");
+        return err!(DomainError, ellipd, "m must not be greater than 1.", m);
     }
 
     if m.abs() <= epsilon!() {
@@ -86,7 +86,7 @@ pub fn ellipd<T: Float>(m: T) -> Result<T, StrErr> {
         return Ok(ans);
     }
     check!(@nan, ellipd, [m]);
-    Err("ellipd: Unexpected error.")
+    err!(Unexpected, ellipd, "Unexpected error.")
 }
 
 #[cfg(not(feature = "test_force_fail"))]
@@ -116,13 +116,16 @@ mod tests {
         // m < 0: should be valid
         assert!(ellipd(-1.0).unwrap().is_finite());
         // m > 1: should return Err
-        assert_eq!(ellipd(1.1), Err("ellipd: m must not be greater than 1."));
+        assert_eq!(
+            ellipd(1.1).unwrap_err(),
+            "ellipd: m must not be greater than 1."
+        );
         // m = NaN: should return Err
-        assert_eq!(ellipd(NAN), Err("ellipd: Arguments cannot be NAN."));
+        assert_eq!(ellipd(NAN).unwrap_err(), "ellipd: Arguments cannot be NAN.");
         // m = inf: should return Err
         assert_eq!(
-            ellipd(INFINITY),
-            Err("ellipd: m must not be greater than 1.")
+            ellipd(INFINITY).unwrap_err(),
+            "ellipd: m must not be greater than 1."
         );
         // m -> -inf: D(m) = 1/sqrt(-m)
         assert_eq!(ellipd(-MAX).unwrap(), 1.0 / MAX.sqrt());
@@ -133,5 +136,5 @@ mod tests {
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(ellipd(0.5), Err("ellipd: Unexpected error."));
+    assert_eq!(ellipd(0.5).unwrap_err(), "ellipd: Unexpected error.");
 }
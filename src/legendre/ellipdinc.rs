@@ -11,7 +11,7 @@
 //  Use, modification and distribution are subject to the
 //  Boost Software License, Version 1.0.
 
-use crate::{carlson::elliprd_unchecked, crate_util::check, ellipd, StrErr};
+use crate::{carlson::elliprd_unchecked, crate_util::check, ellipd, error::err, EllipError};
 use num_traits::Float;
 
 /// Computes [incomplete elliptic integral of Legendre's type](https://dlmf.nist.gov/19.2.E6).
@@ -66,7 +66,7 @@ use num_traits::Float;
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed April 17, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn ellipdinc<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
+pub fn ellipdinc<T: Float>(phi: T, m: T) -> Result<T, EllipError> {
     if m < 1e-2 * min_val!() {
         return Ok(0.0);
     }
@@ -102,7 +102,12 @@ pub fn ellipdinc<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
     let cm1 = cosp2 / sinp2; // c - 1
 
     if m * sinp2 > 1.0 {
-        return Err("ellipdinc: m sin²φ must be smaller than one.");
+        return err!(
+            DomainError,
+            ellipdinc,
+            "m sin²φ must be smaller than one.",
+            [phi, m]
+        );
     }
 
     let mut result = 0.0;
@@ -122,7 +127,7 @@ pub fn ellipdinc<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
         return Ok(ans);
     }
     check!(@nan, ellipdinc, [phi, m]);
-    Err("ellipdinc: Unexpected error.")
+    err!(Unexpected, ellipdinc, "Unexpected error.")
 }
 
 #[cfg(not(feature = "test_force_fail"))]
@@ -154,8 +159,8 @@ mod tests {
         assert_eq!(ellipdinc(FRAC_PI_2, 1.0).unwrap(), INFINITY);
         // m * sin^2(phi) >= 1: should return Err
         assert_eq!(
-            ellipdinc(FRAC_PI_2, 2.0),
-            Err("ellipdinc: m sin²φ must be smaller than one.")
+            ellipdinc(FRAC_PI_2, 2.0).unwrap_err(),
+            "ellipdinc: m sin²φ must be smaller than one."
         );
         // phi = 0: D(0, m) = 0
         assert_eq!(ellipdinc(0.0, 0.5).unwrap(), 0.0);
@@ -170,12 +175,12 @@ mod tests {
         );
         // phi = nan or m = nan: should return Err
         assert_eq!(
-            ellipdinc(NAN, 0.5),
-            Err("ellipdinc: Arguments cannot be NAN.")
+            ellipdinc(NAN, 0.5).unwrap_err(),
+            "ellipdinc: Arguments cannot be NAN."
         );
         assert_eq!(
-            ellipdinc(0.5, NAN),
-            Err("ellipdinc: Arguments cannot be NAN.")
+            ellipdinc(0.5, NAN).unwrap_err(),
+            "ellipdinc: Arguments cannot be NAN."
         );
         // phi = inf: D(inf, m) = inf
         assert_eq!(ellipdinc(INFINITY, 0.5).unwrap(), INFINITY);
@@ -183,8 +188,8 @@ mod tests {
         assert_eq!(ellipdinc(NEG_INFINITY, 0.5).unwrap(), NEG_INFINITY);
         // m = inf: should return Err
         assert_eq!(
-            ellipdinc(0.5, INFINITY),
-            Err("ellipdinc: m sin²φ must be smaller than one.")
+            ellipdinc(0.5, INFINITY).unwrap_err(),
+            "ellipdinc: m sin²φ must be smaller than one."
         );
         // m = -inf: D(phi, -inf) = 0.0
         assert_eq!(ellipdinc(0.5, NEG_INFINITY).unwrap(), 0.0);
@@ -193,5 +198,5 @@ mod tests {
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(ellipdinc(0.5, 0.5), Err("ellipdinc: Unexpected error."));
+    assert_eq!(ellipdinc(0.5, 0.5).unwrap_err(), "ellipdinc: Unexpected error.");
 }
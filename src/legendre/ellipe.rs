@@ -13,7 +13,12 @@
 
 use num_traits::Float;
 
-use crate::{carlson::elliprg_unchecked, crate_util::check, polyeval, StrErr};
+use crate::{
+    carlson::elliprg_unchecked,
+    crate_util::{check, is_extended_precision},
+    error::err,
+    polyeval, EllipError,
+};
 
 /// Computes [complete elliptic integral of the second kind](https://dlmf.nist.gov/19.2.E8).
 /// ```text
@@ -59,7 +64,7 @@ use crate::{carlson::elliprg_unchecked, crate_util::check, polyeval, StrErr};
 /// - Abramowitz, Milton, and Irene A. Stegun. Handbook of Mathematical Functions: With Formulas, Graphs and Mathematical Tables. Unabridged, Unaltered and corr. Republ. of the 1964 ed. With Conference on mathematical tables, National science foundation, and Massachusetts institute of technology. Dover Books on Advanced Mathematics. Dover publ, 1972.
 /// - The SciPy community. “Scipy.Special.Ellipe — SciPy v1.16.0 Manual.” Accessed July 28, 2025. <https://docs.scipy.org/doc/scipy-1.16.0/reference/generated/scipy.special.ellipe.html>.
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn ellipe<T: Float>(m: T) -> Result<T, StrErr> {
+pub fn ellipe<T: Float>(m: T) -> Result<T, EllipError> {
     let mut m = m;
     let mut c = 1.0;
     if m < 0.0 {
@@ -76,8 +81,11 @@ pub fn ellipe<T: Float>(m: T) -> Result<T, StrErr> {
 
 #[inline]
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-fn _ellipe<T: Float>(m: T) -> Result<T, StrErr> {
-    match (m * 20.0).to_i64() {
+fn _ellipe<T: Float>(m: T) -> Result<T, EllipError> {
+    let branch = (m * 20.0).to_i64();
+    match branch {
+        // The polynomial approximations are accurate to f64 only, use elliprg instead.
+        Some(_) if is_extended_precision::<T>() => ellipe_precise(m),
         Some(0) | Some(1) => {
             let coeffs = [
                 1.550973351780472328,
@@ -268,22 +276,22 @@ fn _ellipe<T: Float>(m: T) -> Result<T, StrErr> {
             #[cfg(not(feature = "test_force_fail"))]
             if m > 1.0 {
                 // Infinity cases
-                return Err("ellipe: m must not be greater than 1.");
+                return err!(DomainError, ellipe, "m must not be greater than 1.", m);
             }
-            Err("ellipe: Unexpected error.")
+            err!(Unexpected, ellipe, "Unexpected error.")
         }
     }
 }
 
 #[inline]
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-fn ellipe_precise<T: Float>(m: T) -> Result<T, StrErr> {
+fn ellipe_precise<T: Float>(m: T) -> Result<T, EllipError> {
     // Special cases: https://dlmf.nist.gov/19.6.E1
     if m >= 1.0 {
         if m == 1.0 {
             return Ok(1.0);
         }
-        return Err("ellipe: m must not be greater than 1.");
+        return err!(DomainError, ellipe, "m must not be greater than 1.", m);
     }
 
     Ok(2.0 * elliprg_unchecked(0.0, 1.0 - m, 1.0))
@@ -311,7 +319,10 @@ mod tests {
     fn test_ellipe_special_cases() {
         use std::f64::{consts::FRAC_PI_2, INFINITY, NAN, NEG_INFINITY};
         // m > 1: should return Err
-        assert_eq!(ellipe(1.1), Err("ellipe: m must not be greater than 1."));
+        assert_eq!(
+            ellipe(1.1).unwrap_err(),
+            "ellipe: m must not be greater than 1."
+        );
         // m = 0: E(0) = pi/2
         assert_eq!(ellipe(0.0).unwrap(), FRAC_PI_2);
         // m = 1: E(1) = 1
@@ -319,18 +330,38 @@ mod tests {
         // m < 0: should be valid, compare with reference value
         assert!(ellipe(-1.0).unwrap().is_finite());
         // m = NaN: should return Err
-        assert_eq!(ellipe(NAN), Err("ellipe: Arguments cannot be NAN."));
+        assert_eq!(ellipe(NAN).unwrap_err(), "ellipe: Arguments cannot be NAN.");
         // m = inf: should return Err
         assert_eq!(
-            ellipe(INFINITY),
-            Err("ellipe: m must not be greater than 1.")
+            ellipe(INFINITY).unwrap_err(),
+            "ellipe: m must not be greater than 1."
         );
         // m = -inf: E(-inf) = inf
         assert_eq!(ellipe(NEG_INFINITY).unwrap(), INFINITY);
     }
+
+    #[test]
+    fn test_ellipe_double_double() {
+        use crate::test_util::{assert_close_dd, dd};
+        assert_close_dd(
+            ellipe(dd("0.5")).unwrap(),
+            "1.350643881047675502520174735338726",
+            1e-31,
+        );
+        assert_close_dd(
+            ellipe(dd("-3")).unwrap(),
+            "2.422112055136919049607125799097957",
+            1e-31,
+        );
+        assert_close_dd(
+            ellipe(dd("0.99")).unwrap(),
+            "1.015993545025223935639448275295414",
+            1e-31,
+        );
+    }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(ellipe(f64::INFINITY), Err("ellipe: Unexpected error."));
+    assert_eq!(ellipe(f64::INFINITY).unwrap_err(), "ellipe: Unexpected error.");
 }
@@ -16,7 +16,9 @@ use num_traits::Float;
 use crate::{
     carlson::{elliprd_unchecked, elliprf_unchecked},
     crate_util::check,
-    ellipe, StrErr,
+    ellipe,
+    error::err,
+    EllipError,
 };
 
 /// Computes [incomplete elliptic integral of the second kind](https://dlmf.nist.gov/19.2.E5).
@@ -71,7 +73,7 @@ use crate::{
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed April 17, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
 /// - The MathWorks, Inc. “ellipticE.” Accessed April 21, 2025. <https://www.mathworks.com/help/symbolic/sym.elliptice.html>.
-pub fn ellipeinc<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
+pub fn ellipeinc<T: Float>(phi: T, m: T) -> Result<T, EllipError> {
     let ans = ellipeinc_unchecked(phi, m)?;
     // Infinites are expected to be handled properly by ellipeinc_unchecked
     if !ans.is_nan() {
@@ -80,7 +82,7 @@ pub fn ellipeinc<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
     #[cfg(feature = "test_force_fail")]
     let ans = nan!();
     check!(@nan, ellipeinc, [phi, m]);
-    Err("ellipeinc: Unexpected error.")
+    err!(Unexpected, ellipeinc, "Unexpected error.")
 }
 
 /// Unsafe version of [ellipeinc].
@@ -91,7 +93,7 @@ pub fn ellipeinc<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
 /// - NAN arguments
 #[inline]
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn ellipeinc_unchecked<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
+pub fn ellipeinc_unchecked<T: Float>(phi: T, m: T) -> Result<T, EllipError> {
     if phi == 0.0 {
         return Ok(0.0);
     }
@@ -147,7 +149,12 @@ pub fn ellipeinc_unchecked<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
     } else {
         let s2p = rphi.sin() * rphi.sin();
         if m * s2p >= 1.0 {
-            return Err("ellipeinc: m sin²φ must be smaller than one.");
+            return err!(
+                DomainError,
+                ellipeinc,
+                "m sin²φ must be smaller than one.",
+                [phi, m]
+            );
         }
         let c2p = rphi.cos() * rphi.cos();
         let c = 1.0 / s2p;
@@ -165,7 +172,7 @@ pub fn ellipeinc_unchecked<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
 }
 
 #[inline]
-fn ellipe_wrapper<T: Float>(m: T) -> Result<T, StrErr> {
+fn ellipe_wrapper<T: Float>(m: T) -> Result<T, EllipError> {
     check!(@nan, ellipeinc, [m]);
     ellipe(m)
 }
@@ -213,8 +220,8 @@ mod tests {
         assert_eq!(ellipeinc(0.4, 1.0).unwrap(), 0.4.sin());
         // m * sin^2(phi) >= 1: should return Err
         assert_eq!(
-            ellipeinc(FRAC_PI_2, 2.0),
-            Err("ellipeinc: m sin²φ must be smaller than one.")
+            ellipeinc(FRAC_PI_2, 2.0).unwrap_err(),
+            "ellipeinc: m sin²φ must be smaller than one."
         );
         // phi = 0: E(0, m) = 0
         assert_eq!(ellipeinc(0.0, 0.5).unwrap(), 0.0);
@@ -226,12 +233,12 @@ mod tests {
         assert!(ellipeinc(FRAC_PI_2, -1.0).unwrap().is_finite());
         // phi = nan or m = nan: should return Err
         assert_eq!(
-            ellipeinc(NAN, 0.5),
-            Err("ellipeinc: Arguments cannot be NAN.")
+            ellipeinc(NAN, 0.5).unwrap_err(),
+            "ellipeinc: Arguments cannot be NAN."
         );
         assert_eq!(
-            ellipeinc(0.5, NAN),
-            Err("ellipeinc: Arguments cannot be NAN.")
+            ellipeinc(0.5, NAN).unwrap_err(),
+            "ellipeinc: Arguments cannot be NAN."
         );
         // phi > 1/epsilon: E(phi, m) = 2 * phi * E(m) / pi
         assert_eq!(
@@ -244,8 +251,8 @@ mod tests {
         assert_eq!(ellipeinc(NEG_INFINITY, 0.5).unwrap(), NEG_INFINITY);
         // m = inf: should return Err
         assert_eq!(
-            ellipeinc(0.5, INFINITY),
-            Err("ellipeinc: m sin²φ must be smaller than one.")
+            ellipeinc(0.5, INFINITY).unwrap_err(),
+            "ellipeinc: m sin²φ must be smaller than one."
         );
         // m -> -inf: E(phi, m) = (1-cos(phi)) sqrt(-m)
         assert_eq!(
@@ -259,5 +266,5 @@ mod tests {
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(ellipeinc(0.5, 0.2), Err("ellipeinc: Unexpected error."));
+    assert_eq!(ellipeinc(0.5, 0.2).unwrap_err(), "ellipeinc: Unexpected error.");
 }
@@ -16,8 +16,9 @@ use num_traits::Float;
 use crate::{
     carlson::elliprf_unchecked,
     crate_util::{case, check},
+    error::err,
     legendre::ellipk::ellipk_precise_unchecked,
-    StrErr,
+    EllipError,
 };
 
 /// Computes [incomplete elliptic integral of the first kind](https://dlmf.nist.gov/19.2.E4).
@@ -72,7 +73,7 @@ use crate::{
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
 /// - The MathWorks, Inc. “ellipticF.” Accessed April 21, 2025. <https://www.mathworks.com/help/symbolic/sym.ellipticf.html>.
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn ellipf<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
+pub fn ellipf<T: Float>(phi: T, m: T) -> Result<T, EllipError> {
     let sign = phi.signum();
     let phi = phi.abs();
 
@@ -100,8 +101,14 @@ pub fn ellipf<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
 
     let sphi = rphi.sin();
     let s2p = sphi * sphi;
-    if m * s2p >= 1.0 {
-        return Err("ellipf: m sin²φ must be smaller than one.");
+    // m sin²θ reaches one within [0, φ] for m ≥ 1 and φ ≥ π/2
+    if m * s2p >= 1.0 || (mm != 0.0 && m >= 1.0) {
+        return err!(
+            DomainError,
+            ellipf,
+            "m sin²φ must be smaller than one.",
+            [phi, m]
+        );
     }
     let cphi = rphi.cos();
     let c2p = cphi * cphi;
@@ -160,7 +167,7 @@ pub fn ellipf<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
     }
     check!(@nan, ellipf, [phi, m]);
     case!(m == neg_inf!(), T::zero());
-    Err("ellipf: Unexpected error.")
+    err!(Unexpected, ellipf, "Unexpected error.")
 }
 
 #[cfg(not(feature = "test_force_fail"))]
@@ -183,12 +190,16 @@ mod tests {
         };
         // m * sin^2(phi) >= 1: should return Err
         assert_eq!(
-            ellipf(FRAC_PI_2, 1.0),
-            Err("ellipf: m sin²φ must be smaller than one.")
+            ellipf(FRAC_PI_2, 1.0).unwrap_err(),
+            "ellipf: m sin²φ must be smaller than one."
+        );
+        assert_eq!(
+            ellipf(FRAC_PI_2, 2.0).unwrap_err(),
+            "ellipf: m sin²φ must be smaller than one."
         );
         assert_eq!(
-            ellipf(FRAC_PI_2, 2.0),
-            Err("ellipf: m sin²φ must be smaller than one.")
+            ellipf(2.0, 1.0).unwrap_err(),
+            "ellipf: m sin²φ must be smaller than one."
         );
         // phi = 0: F(0, m) = 0
         assert_eq!(ellipf(0.0, 0.5).unwrap(), 0.0);
@@ -197,8 +208,14 @@ mod tests {
         // m < 0: should be valid
         assert!(ellipf(FRAC_PI_2, -1.0).unwrap().is_finite());
         // phi = nan or m = nan: should return Err
-        assert_eq!(ellipf(NAN, 0.5), Err("ellipf: Arguments cannot be NAN."));
-        assert_eq!(ellipf(0.5, NAN), Err("ellipf: Arguments cannot be NAN."));
+        assert_eq!(
+            ellipf(NAN, 0.5).unwrap_err(),
+            "ellipf: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            ellipf(0.5, NAN).unwrap_err(),
+            "ellipf: Arguments cannot be NAN."
+        );
         // phi = inf: F(inf, m) = inf
         assert_eq!(ellipf(INFINITY, 0.5).unwrap(), INFINITY);
         // phi = -inf: F(-inf, m) = -inf
@@ -213,15 +230,30 @@ mod tests {
         assert_eq!(ellipf(-1e-100, 0.4).unwrap(), (-1e-100).sin());
         // m = inf: should return Err
         assert_eq!(
-            ellipf(0.5, INFINITY),
-            Err("ellipf: m sin²φ must be smaller than one.")
+            ellipf(0.5, INFINITY).unwrap_err(),
+            "ellipf: m sin²φ must be smaller than one."
         );
         // m = -inf: F(phi, -inf) = 0.0
         assert_eq!(ellipf(0.5, NEG_INFINITY).unwrap(), 0.0);
     }
+
+    #[test]
+    fn test_ellipf_double_double() {
+        use crate::test_util::{assert_close_dd, dd};
+        assert_close_dd(
+            ellipf(dd("0.75"), dd("0.5")).unwrap(),
+            "0.7853818416393084150406952312024418",
+            1e-31,
+        );
+        assert_close_dd(
+            ellipf(dd("5"), dd("-2")).unwrap(),
+            "3.681851328475712929637162993351003",
+            1e-31,
+        );
+    }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(ellipf(0.5, 0.5), Err("ellipf: Unexpected error."));
+    assert_eq!(ellipf(0.5, 0.5).unwrap_err(), "ellipf: Unexpected error.");
 }
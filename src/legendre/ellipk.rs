@@ -14,8 +14,9 @@
 use num_traits::Float;
 
 use crate::{
-    crate_util::{check, declare},
-    polyeval, StrErr,
+    crate_util::{check, declare, is_extended_precision},
+    error::err,
+    polyeval, EllipError,
 };
 
 /// Computes [complete elliptic integral of the first kind](https://dlmf.nist.gov/19.2.E8).
@@ -61,8 +62,11 @@ use crate::{
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed April 17, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn ellipk<T: Float>(m: T) -> Result<T, StrErr> {
-    match (m * 20.0).to_i64() {
+pub fn ellipk<T: Float>(m: T) -> Result<T, EllipError> {
+    let branch = (m * 20.0).to_i64();
+    match branch {
+        // The polynomial approximations are accurate to f64 only, use AGM instead.
+        Some(_) if is_extended_precision::<T>() => ellipk_precise(m),
         Some(0) | Some(1) => {
             let coeffs = [
                 1.591003453790792180,
@@ -273,22 +277,22 @@ pub fn ellipk<T: Float>(m: T) -> Result<T, StrErr> {
             #[cfg(not(feature = "test_force_fail"))]
             if m > 1.0 {
                 // Also handles inf
-                return Err("ellipk: m must not be greater than 1.");
+                return err!(DomainError, ellipk, "m must not be greater than 1.", m);
             }
-            Err("ellipk: Unexpected error.")
+            err!(Unexpected, ellipk, "Unexpected error.")
         }
     }
 }
 
 #[inline]
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub(crate) fn ellipk_precise<T: Float>(m: T) -> Result<T, StrErr> {
+pub(crate) fn ellipk_precise<T: Float>(m: T) -> Result<T, EllipError> {
     // Special cases: https://dlmf.nist.gov/19.6.E1
     if m >= 1.0 {
         if m == 1.0 {
             return Ok(inf!());
         }
-        return Err("ellipk: m must not be greater than 1.");
+        return err!(DomainError, ellipk, "m must not be greater than 1.", m);
     }
 
     Ok(ellipk_precise_unchecked(m))
@@ -343,20 +347,43 @@ mod tests {
         // m < 0: should be valid, compare with reference value
         assert!(ellipk(-1.0).unwrap().is_finite());
         // m > 1: should return Err
-        assert_eq!(ellipk(1.1), Err("ellipk: m must not be greater than 1."));
+        assert_eq!(
+            ellipk(1.1).unwrap_err(),
+            "ellipk: m must not be greater than 1."
+        );
         // m = NaN: should return Err
-        assert_eq!(ellipk(NAN), Err("ellipk: Arguments cannot be NAN."));
+        assert_eq!(ellipk(NAN).unwrap_err(), "ellipk: Arguments cannot be NAN.");
         // m = inf: should return Err
         assert_eq!(
-            ellipk(INFINITY),
-            Err("ellipk: m must not be greater than 1.")
+            ellipk(INFINITY).unwrap_err(),
+            "ellipk: m must not be greater than 1."
         );
         // m = -inf: K(-inf) = 0
         assert_eq!(ellipk(NEG_INFINITY).unwrap(), 0.0);
     }
+
+    #[test]
+    fn test_ellipk_double_double() {
+        use crate::test_util::{assert_close_dd, dd};
+        assert_close_dd(
+            ellipk(dd("0.5")).unwrap(),
+            "1.854074677301371918433850347195260",
+            1e-31,
+        );
+        assert_close_dd(
+            ellipk(dd("-3")).unwrap(),
+            "1.078257823749821617719337499400161",
+            1e-31,
+        );
+        assert_close_dd(
+            ellipk(dd("0.99")).unwrap(),
+            "3.695637362989874677809954195262551",
+            1e-31,
+        );
+    }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(ellipk(f64::INFINITY), Err("ellipk: Unexpected error."));
+    assert_eq!(ellipk(f64::INFINITY).unwrap_err(), "ellipk: Unexpected error.");
 }
@@ -15,7 +15,9 @@ use num_traits::Float;
 use crate::{
     carlson::{elliprf_unchecked, elliprj_unchecked},
     crate_util::check,
-    ellipe, ellipk, StrErr,
+    ellipe, ellipk,
+    error::err,
+    EllipError,
 };
 
 /// Computes [complete elliptic integral of the third kind](https://dlmf.nist.gov/19.2.E8).
@@ -70,8 +72,8 @@ use crate::{
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed April 17, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn ellippi<T: Float>(n: T, m: T) -> Result<T, StrErr> {
-    if n > 1.0 - epsilon!() {
+pub fn ellippi<T: Float>(n: T, m: T) -> Result<T, EllipError> {
+    if n > 1.0 - epsilon!() && m <= 1.0 {
         if n > 1.0 {
             // n -> 1+
             // https://dlmf.nist.gov/19.6.E6
@@ -84,7 +86,8 @@ pub fn ellippi<T: Float>(n: T, m: T) -> Result<T, StrErr> {
             return Ok(-3.0.recip() * m / n * elliprj_unchecked(0.0, 1.0 - m, 1.0, 1.0 - m / n));
         }
         if n == 1.0 {
-            return Err("ellippi: n cannot be 1.");
+            // Limit as n → 1⁻
+            return err!(Singularity, ellippi, "n cannot be 1.", n, limit: inf!());
         }
         return Ok(inf!());
     }
@@ -97,7 +100,7 @@ pub fn ellippi<T: Float>(n: T, m: T) -> Result<T, StrErr> {
     check!(@nan, ellippi, [n, m]);
     if m > 1.0 - epsilon!() {
         if m > 1.0 {
-            return Err("ellippi: m must not be greater than 1.");
+            return err!(DomainError, ellippi, "m must not be greater than 1.", m);
         }
         // m -> 1-
         let sign = (1.0 - n).signum();
@@ -109,7 +112,7 @@ pub fn ellippi<T: Float>(n: T, m: T) -> Result<T, StrErr> {
         // m = -inf: Π(n, -inf) = 0
         return Ok(0.0);
     }
-    Err("ellippi: Unexpected error.")
+    err!(Unexpected, ellippi, "Unexpected error.")
 }
 
 /// Unsafe version of [ellippi](crate::ellippi).
@@ -251,11 +254,15 @@ mod tests {
         };
         // m > 1: should return Err
         assert_eq!(
-            ellippi(0.5, 1.1),
-            Err("ellippi: m must not be greater than 1.")
+            ellippi(0.5, 1.1).unwrap_err(),
+            "ellippi: m must not be greater than 1."
+        );
+        assert_eq!(
+            ellippi(2.0, 1.1).unwrap_err(),
+            "ellippi: m must not be greater than 1."
         );
         // n == 1: should return Err
-        assert_eq!(ellippi(1.0, 0.5), Err("ellippi: n cannot be 1."));
+        assert_eq!(ellippi(1.0, 0.5).unwrap_err(), "ellippi: n cannot be 1.");
         // n = 0: Π(0, m) = K(m)
         assert_eq!(ellippi(0.0, 0.5).unwrap(), ellipk(0.5).unwrap());
         // m = 0, n < 1: Π(n, 0) = pi/(2 sqrt(1-n))
@@ -284,17 +291,23 @@ mod tests {
         // m = -inf: Π(n, -inf) = 0
         assert_eq!(ellippi(0.5, NEG_INFINITY).unwrap(), 0.0);
         // n = nan or m = nan: should return Err
-        assert_eq!(ellippi(NAN, 0.5), Err("ellippi: Arguments cannot be NAN."));
-        assert_eq!(ellippi(0.5, NAN), Err("ellippi: Arguments cannot be NAN."));
+        assert_eq!(
+            ellippi(NAN, 0.5).unwrap_err(),
+            "ellippi: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            ellippi(0.5, NAN).unwrap_err(),
+            "ellippi: Arguments cannot be NAN."
+        );
         // m = inf: should return Err
         assert_eq!(
-            ellippi(0.5, INFINITY),
-            Err("ellippi: m must not be greater than 1.")
+            ellippi(0.5, INFINITY).unwrap_err(),
+            "ellippi: m must not be greater than 1."
         );
     }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(ellippi(0.5, 0.5), Err("ellippi: Unexpected error."));
+    assert_eq!(ellippi(0.5, 0.5).unwrap_err(), "ellippi: Unexpected error.");
 }
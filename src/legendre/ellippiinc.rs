@@ -17,8 +17,9 @@ use crate::{
     carlson::{elliprc_unchecked, elliprf_unchecked, elliprj_unchecked},
     crate_util::check,
     ellipf,
+    error::err,
     legendre::{ellipeinc::ellipeinc_unchecked, ellippi::ellippi_vc},
-    StrErr,
+    EllipError,
 };
 
 /// Computes [incomplete elliptic integral of the third kind](https://dlmf.nist.gov/19.2.E7).
@@ -86,8 +87,21 @@ use crate::{
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
 /// - Wolfram Research. “EllipticPi,” 2022. <https://reference.wolfram.com/language/ref/EllipticPi.html>.
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn ellippiinc<T: Float>(phi: T, n: T, m: T) -> Result<T, StrErr> {
-    let ans = ellippiinc_vc(phi, n, m, 1.0 - n)?;
+pub fn ellippiinc<T: Float>(phi: T, n: T, m: T) -> Result<T, EllipError> {
+    let ans = match ellippiinc_vc(phi, n, m, 1.0 - n) {
+        Ok(ans) => ans,
+        // The sign of the pole depends on phi before the reduction to [0, π/2].
+        Err(EllipError::Singularity(_)) => {
+            return err!(
+                Singularity,
+                ellippiinc,
+                "n sin²φ must not equal one.",
+                [phi, n],
+                limit: pole_limit(phi)
+            )
+        }
+        Err(err) => return Err(err),
+    };
     if ans.is_finite() {
         return Ok(ans);
     }
@@ -96,23 +110,33 @@ pub fn ellippiinc<T: Float>(phi: T, n: T, m: T) -> Result<T, StrErr> {
     if phi.is_infinite() {
         return Ok(phi.signum() * inf!());
     }
-    Err("ellippiinc: Unexpected error.")
+    err!(Unexpected, ellippiinc, "Unexpected error.")
 }
 
 #[inline]
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-fn ellippiinc_vc<T: Float>(phi: T, n: T, m: T, nc: T) -> Result<T, StrErr> {
+fn ellippiinc_vc<T: Float>(phi: T, n: T, m: T, nc: T) -> Result<T, EllipError> {
     // Note vc = 1-v presumably without cancellation error
     let sphi = phi.abs().sin();
     let sp2 = sphi * sphi;
     let mut result = 0.0;
 
     if m * sp2 > 1.0 {
-        return Err("ellippiinc: m sin²φ must be smaller or equal to one.");
+        return err!(
+            DomainError,
+            ellippiinc,
+            "m sin²φ must be smaller or equal to one.",
+            [phi, m]
+        );
     }
 
     if n * sp2 == 1.0 {
-        return Err("ellippiinc: n sin²φ must not equal one.");
+        return err!(
+            Singularity,
+            ellippiinc,
+            "n sin²φ must not equal one.",
+            [phi, n]
+        );
     }
 
     // Special cases:
@@ -146,9 +170,8 @@ fn ellippiinc_vc<T: Float>(phi: T, n: T, m: T, nc: T) -> Result<T, StrErr> {
         // Carlson's algorithm works only for |phi| <= pi/2,
         // use the integrand's periodicity to normalize phi
         if phi.abs() > 1.0 / epsilon!() {
-            // Invalid for v > 1, this case is caught above since v > 1 implies 1/v < sin^2(phi)
-            debug_assert!(n <= 1.0);
-
+            // ellippi_vc returns the Cauchy principal value for n > 1, where sin²φ is
+            // garbage and n sin²φ is not necessarily greater than one.
             // Phi is so large that phi%pi is necessarily zero (or garbage),
             // just return the second part of the duplication formula:
             result = 2.0 * phi.abs() * ellippi_vc(n, m, nc) / pi!();
@@ -158,7 +181,7 @@ fn ellippiinc_vc<T: Float>(phi: T, n: T, m: T, nc: T) -> Result<T, StrErr> {
             let mut sign = 1.0;
 
             if mm != 0.0 && m >= 1.0 {
-                return Err("ellippiinc: The result is complex.");
+                return err!(DomainError, ellippiinc, "The result is complex.", [phi, m]);
             }
 
             if mm % 2.0 > 0.5 {
@@ -342,7 +365,11 @@ fn ellippiinc_vc<T: Float>(phi: T, n: T, m: T, nc: T) -> Result<T, StrErr> {
 /// # References
 /// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19>.
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn ellippiinc_bulirsch<T: Float + BulirschConst<T>>(phi: T, n: T, m: T) -> Result<T, StrErr> {
+pub fn ellippiinc_bulirsch<T: Float + BulirschConst<T>>(
+    phi: T,
+    n: T,
+    m: T,
+) -> Result<T, EllipError> {
     ellippiinc_bulirsch_with_const::<T, T>(phi, n, m)
 }
 
@@ -353,7 +380,7 @@ pub fn ellippiinc_bulirsch_with_const<T: Float, C: BulirschConst<T>>(
     phi: T,
     n: T,
     m: T,
-) -> Result<T, StrErr> {
+) -> Result<T, EllipError> {
     if phi.is_infinite() {
         return Ok(phi);
     }
@@ -377,17 +404,38 @@ pub fn ellippiinc_bulirsch_with_const<T: Float, C: BulirschConst<T>>(
     }
 
     #[cfg(feature = "test_force_fail")]
-    let result: Result<T, &_> = Err("");
-
-    let err_str = match result.err().unwrap() {
-        "el3: kc must not be zero." => "ellippiinc: m must not be 1.",
-        "el3: 1 + px² cannot be zero." => "ellippiinc: 1 + (1-n)tan²φ cannot be zero.",
-        "el3: Failed to converge." => "ellippiinc: Failed to converge.",
-        "el3: Arguments cannot be NAN." => "ellippiinc: Arguments cannot be NAN.",
-        _ => "ellippiinc: Unexpected error.",
-    };
+    let result: Result<T, EllipError> = err!(Unexpected, el3, "");
+
+    match result.err().unwrap() {
+        EllipError::DomainError(info) if info.arg == Some("kc") => {
+            err!(DomainError, ellippiinc, "m must not be 1.", m)
+        }
+        EllipError::Singularity(_) => err!(
+            Singularity,
+            ellippiinc,
+            "1 + (1-n)tan²φ cannot be zero.",
+            [phi, n],
+            limit: pole_limit(phi)
+        ),
+        EllipError::NoConvergence(_) => err!(NoConvergence, ellippiinc, "Failed to converge."),
+        EllipError::NanArgument(_) => {
+            err!(
+                NanArgument,
+                ellippiinc,
+                "Arguments cannot be NAN.",
+                [phi, n, m]
+            )
+        }
+        _ => err!(Unexpected, ellippiinc, "Unexpected error."),
+    }
+}
 
-    Err(err_str)
+/// Returns the limit of Π(φ, n, m) at the pole n sin²φ = 1, where the integral diverges like
+/// -ln|φ - φ₀| / (n sin 2φ₀), or like tan φ for n = 1 approaching from within (-π/2, π/2).
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub(crate) fn pole_limit<T: Float>(phi: T) -> T {
+    (phi.sin() * phi.cos()).signum() * inf!()
 }
 
 #[cfg(not(feature = "test_force_fail"))]
@@ -429,13 +477,13 @@ mod tests {
         };
         // m * sin^2(phi) >= 1: should return Err
         assert_eq!(
-            ellippiinc(FRAC_PI_2, 0.5, 1.1),
-            Err("ellippiinc: m sin²φ must be smaller or equal to one.")
+            ellippiinc(FRAC_PI_2, 0.5, 1.1).unwrap_err(),
+            "ellippiinc: m sin²φ must be smaller or equal to one."
         );
         // n * sin^2(phi) = 1: should return Err
         assert_eq!(
-            ellippiinc(FRAC_PI_2, 1.0, 0.5),
-            Err("ellippiinc: n sin²φ must not equal one.")
+            ellippiinc(FRAC_PI_2, 1.0, 0.5).unwrap_err(),
+            "ellippiinc: n sin²φ must not equal one."
         );
         // Π(phi, 0, 0) = phi
         assert_eq!(ellippiinc(0.4, 0.0, 0.0).unwrap(), 0.4);
@@ -472,21 +520,21 @@ mod tests {
         assert_eq!(ellippiinc(NEG_INFINITY, 0.2, 0.5).unwrap(), -INFINITY);
         // phi % pi/2 !=0, m >= 1: should return Err
         assert_eq!(
-            ellippiinc(4.14159, 0.5, 1.0),
-            Err("ellippiinc: The result is complex.")
+            ellippiinc(4.14159, 0.5, 1.0).unwrap_err(),
+            "ellippiinc: The result is complex."
         );
         // phi = nan or n = nan or m = nan: should return Err
         assert_eq!(
-            ellippiinc(NAN, 0.5, 0.5),
-            Err("ellippiinc: Arguments cannot be NAN.")
+            ellippiinc(NAN, 0.5, 0.5).unwrap_err(),
+            "ellippiinc: Arguments cannot be NAN."
         );
         assert_eq!(
-            ellippiinc(0.5, NAN, 0.5),
-            Err("ellippiinc: Arguments cannot be NAN.")
+            ellippiinc(0.5, NAN, 0.5).unwrap_err(),
+            "ellippiinc: Arguments cannot be NAN."
         );
         assert_eq!(
-            ellippiinc(0.5, 0.5, NAN),
-            Err("ellippiinc: Arguments cannot be NAN.")
+            ellippiinc(0.5, 0.5, NAN).unwrap_err(),
+            "ellippiinc: Arguments cannot be NAN."
         );
     }
 
@@ -566,13 +614,13 @@ mod tests {
         use std::f64::{consts::FRAC_PI_2, INFINITY, NAN, NEG_INFINITY};
         // m * sin^2(phi) >= 1: should return Err
         assert_eq!(
-            ellippiinc_bulirsch(FRAC_PI_2, 0.5, 1.1),
-            Err("ellippiinc: m sin²φ must be smaller or equal to one.")
+            ellippiinc_bulirsch(FRAC_PI_2, 0.5, 1.1).unwrap_err(),
+            "ellippiinc: m sin²φ must be smaller or equal to one."
         );
         // n * sin^2(phi) = 1: should return Err
         assert_eq!(
-            ellippiinc_bulirsch(FRAC_PI_2, 1.0, 0.5),
-            Err("ellippiinc: n sin²φ must not equal one.")
+            ellippiinc_bulirsch(FRAC_PI_2, 1.0, 0.5).unwrap_err(),
+            "ellippiinc: n sin²φ must not equal one."
         );
         // Π(phi, 0, 0) = phi
         assert_eq!(ellippiinc_bulirsch(0.4, 0.0, 0.0).unwrap(), 0.4);
@@ -608,27 +656,27 @@ mod tests {
         );
         // phi % pi/2 !=0, m >= 1: should return Err
         assert_eq!(
-            ellippiinc_bulirsch(4.14159, 0.5, 1.0),
-            Err("ellippiinc: The result is complex.")
+            ellippiinc_bulirsch(4.14159, 0.5, 1.0).unwrap_err(),
+            "ellippiinc: The result is complex."
         );
 
         // Arguments cannot be NAN
         assert_eq!(
-            ellippiinc_bulirsch(NAN, 0.5, 0.5),
-            Err("ellippiinc: Arguments cannot be NAN.")
+            ellippiinc_bulirsch(NAN, 0.5, 0.5).unwrap_err(),
+            "ellippiinc: Arguments cannot be NAN."
         );
         assert_eq!(
-            ellippiinc_bulirsch(0.5, NAN, 0.5),
-            Err("ellippiinc: Arguments cannot be NAN.")
+            ellippiinc_bulirsch(0.5, NAN, 0.5).unwrap_err(),
+            "ellippiinc: Arguments cannot be NAN."
         );
         assert_eq!(
-            ellippiinc_bulirsch(0.5, 0.5, NAN),
-            Err("ellippiinc: Arguments cannot be NAN.")
+            ellippiinc_bulirsch(0.5, 0.5, NAN).unwrap_err(),
+            "ellippiinc: Arguments cannot be NAN."
         );
     }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(ellippiinc_bulirsch(0.5, 0.5, 0.5), Err("ellippiinc: Unexpected error."));
+    assert_eq!(ellippiinc_bulirsch(0.5, 0.5, 0.5).unwrap_err(), "ellippiinc: Unexpected error.");
 }
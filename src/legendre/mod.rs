@@ -21,6 +21,7 @@ pub use ellipeinc::ellipeinc;
 pub use ellipf::ellipf;
 pub use ellipk::ellipk;
 pub use ellippi::ellippi;
+pub(crate) use ellippiinc::pole_limit as ellippiinc_pole_limit;
 pub use ellippiinc::{ellippiinc, ellippiinc_bulirsch, ellippiinc_bulirsch_with_const};
 
 #[cfg(feature = "unstable")]
@@ -6,6 +6,7 @@
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![cfg_attr(feature = "test_force_fail", allow(unused))]
 #![allow(clippy::excessive_precision)]
+#![cfg_attr(test, allow(clippy::legacy_numeric_constants))]
 //! # Ellip - Elliptic integrals for Rust
 //!
 //! **Ellip** is a pure-Rust implementation of [elliptic integrals](https://dlmf.nist.gov/19).
@@ -23,7 +24,7 @@
 //! ```
 //! use ellip::*;
 //!
-//! fn ellipse_perimeter(a: f64, b: f64) -> Result<f64, StrErr> {
+//! fn ellipse_perimeter(a: f64, b: f64) -> Result<f64, EllipError> {
 //!     Ok(8.0 * elliprg(0.0, a * a, b * b)?)
 //! }
 //!
@@ -59,6 +60,35 @@
 //! ## Miscellaneous functions
 //! - [fn@jacobi_zeta]: Jacobi Zeta function (Z).
 //! - [fn@heuman_lambda]: Heuman Lambda function (Λ0).
+//! - [fn@ellipk_ratio]: Ratio of the complete elliptic integrals K(m) / K(1 - m), accurate as m → 0 and m → 1.
+//! ## Extended precision
+//! - [DoubleDouble]: Double-double floating-point type with about 32 significant digits.
+//! ## Repeated evaluation
+//! - [EllipticModulus]: Precomputed context for evaluating many amplitudes at a fixed parameter, including the Jacobi amplitude and the Jacobi elliptic functions sn, cn, and dn.
+//! - [mod@chebyshev]: Piecewise Chebyshev approximants of any function over an interval or a rectangle, with verified maximum error.
+//! ## Applications
+//! - [Ellipse]: Perimeter, arc length, and equal-arc-length sampling of ellipses, accurate up to eccentricities near one.
+//! - [mod@cnoidal]: Cnoidal waves of the KdV equation from the wave height, depth, and period or wavelength, with the profile, celerity, and mean-level corrections.
+//! - [mod@cylinder]: Magnetic fields of finite solenoids and axially and diametrically magnetized cylinders.
+//! - [mod@inductance]: Mutual inductance and force between coaxial loops, Nagaoka's coefficient, and mutual inductance of coaxial solenoids.
+//! - [mod@rf]: Characteristic impedance and effective permittivity of coplanar waveguides, grounded coplanar waveguides, coplanar strips, and edge-coupled striplines.
+//! - [mod@filter]: Analog elliptic (Cauer) lowpass filters with the minimum order for a specification, the degree equation, poles, zeros, gain, and magnitude response.
+//! - [mod@duffing]: Exact periods and trajectories of the undamped Duffing oscillator in the hardening, softening, and double-well cases, and periods in quartic potentials.
+//! - [mod@elastica]: Euler's elastica with tip deflection and rotation of end-loaded cantilevers, critical and post-buckling loads of columns, and rod shapes by arc length.
+//! - [mod@ellipsoid]: Surface areas of ellipsoids and their caps and zones, demagnetizing factors, and the potential of homogeneous ellipsoids.
+//! - [mod@hertz]: Hertzian contact of elastic bodies with the contact ellipse, approach, maximum pressure, and subsurface stresses.
+//! - [mod@pendulum]: Exact period, trajectory, and time-to-angle of the simple pendulum in libration, on the separatrix, and in rotation.
+//! - [mod@rigid_body]: Exact angular velocity, polhode period, and precession angle of the torque-free asymmetric rigid body.
+//! - [mod@ring]: Vector potential and magnetic field of current loops, potential and field of charged and massive rings, and the streamfunction and velocity of thin vortex rings.
+//! ## Interval evaluation
+//! - [mod@interval]: Guaranteed enclosures of [fn@ellipk], [fn@ellipe], [fn@ellipf], [fn@elliprf], [fn@elliprd], and [fn@elliprj] over input intervals, computed with outward rounding.
+//! ## Error handling
+//! - [EllipError]: Error type carrying the kind of failure, the function name, and the offending argument.
+//! - [mod@policy]: `*_with_policy` functions returning `Err`, NAN, ±∞, saturated values, or panicking on errors.
+//! - [mod@raw]: Functions returning plain values, with NAN on invalid arguments and ±∞ at poles.
+//! - [mod@domain]: Classification of the arguments as valid, pole, or out of domain before evaluation.
+//! ## Parameter conventions
+//! - [mod@params]: Validated parameter types converting between m, k, kc, and α, with infallible functions accepting them.
 //! ## Feature Flags
 //! - `unstable`: Enable unstable or untested features that might be changed without notice in the future.
 //! - `test_force_fail`: Used for testing only. Force tests to reach code unreachable under normal circumstances.
@@ -106,14 +136,38 @@
 //!    journal = {Journal of Open Source Software} }
 //! ```
 
-num_lazy::declare_nums! {@constant T}
 num_lazy::declare_nums! {@special T}
 
+// Constants are split into high and low parts, so that types more precise than f64
+// (e.g., DoubleDouble) get full accuracy. The low parts vanish when rounded to f64.
+macro_rules! declare_constant {
+    ($name:ident, $hi:expr, $lo:expr) => {
+        #[allow(unused_macros)]
+        macro_rules! $name {
+            () => {
+                T::from($hi).unwrap() + T::from($lo).unwrap()
+            };
+        }
+    };
+}
+declare_constant!(pi, core::f64::consts::PI, 1.2246467991473532e-16);
+declare_constant!(pi_2, core::f64::consts::FRAC_PI_2, 6.123233995736766e-17);
+declare_constant!(ln_2, core::f64::consts::LN_2, 2.3190468138462996e-17);
+
 mod crate_util;
 
 /// Static error str
+///
+/// Returned by prior versions. Use [EllipError] instead, which converts into [StrErr].
 pub type StrErr = &'static str;
 
+pub mod error;
+pub use error::EllipError;
+pub mod domain;
+pub mod params;
+pub mod policy;
+pub mod raw;
+
 pub mod legendre;
 // Legendre's complete integrals
 pub use legendre::ellipd;
@@ -147,9 +201,38 @@ pub use carlson::elliprj;
 
 // Miscellaneous functions
 pub mod misc;
+pub use misc::ellipk_ratio;
 pub use misc::heuman_lambda;
 pub use misc::jacobi_zeta;
 
+// Interval evaluation
+pub mod interval;
+
+// Extended precision
+pub mod double_double;
+pub use double_double::DoubleDouble;
+
+pub mod elliptic_modulus;
+pub use elliptic_modulus::EllipticModulus;
+
+pub mod chebyshev;
+
+// Applications
+pub mod ellipse;
+pub use ellipse::Ellipse;
+pub mod cnoidal;
+pub mod cylinder;
+pub mod duffing;
+pub mod elastica;
+pub mod ellipsoid;
+pub mod filter;
+pub mod hertz;
+pub mod inductance;
+pub mod pendulum;
+pub mod rf;
+pub mod rigid_body;
+pub mod ring;
+
 // Utilities
 mod polyeval;
 use polyeval::polyeval;
@@ -0,0 +1,183 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_traits::Float;
+
+use crate::{crate_util::check, error::err, EllipError};
+
+/// Maximum number of iterations of the arithmetic-geometric mean.
+const MAX_ITERATIONS: usize = 64;
+
+/// Computes the ratio of the complete elliptic integrals of the first kind K(m) / K(1 - m).
+/// ```text
+///          K(m)        AGM(1, √m)
+/// R(m) = ──────── = ─────────────
+///        K(1 - m)     AGM(1, √(1-m))
+/// ```
+///
+/// The ratio is evaluated from the arithmetic-geometric means without forming K(m) or
+/// K(1 - m), so it remains accurate as m → 0 and m → 1, where one of the integrals has
+/// a logarithmic singularity. This is the ratio needed by conformal mappings, see
+/// [mod@crate::rf].
+///
+/// ## Parameters
+/// - m: elliptic parameter. m ∈ ℝ, m ∈ [0, 1].
+///
+/// The elliptic modulus (k) is also frequently used instead of the parameter (m), where k² = m.
+///
+/// ## Domain
+/// - Returns error if m < 0 or m > 1.
+///
+/// ## Special Cases
+/// - R(0) = 0
+/// - R(1/2) = 1
+/// - R(1) = ∞
+/// - R(1 - m) = 1 / R(m)
+///
+/// # Related Functions
+/// - [ellipk_ratio](crate::ellipk_ratio)(m) = [ellipk](crate::ellipk)(m) / [ellipk](crate::ellipk)(1 - m)
+/// - The nome is q(m) = exp(-π / [ellipk_ratio](crate::ellipk_ratio)(m)).
+///
+/// # Examples
+/// ```
+/// use ellip::{ellipk_ratio, util::assert_close};
+///
+/// assert_close(ellipk_ratio(0.1).unwrap(), 0.625439773998664, 1e-15);
+/// assert_close(ellipk_ratio(1e-20).unwrap(), 0.06434487047601332, 1e-15);
+/// ```
+///
+/// # References
+/// - Carlson, B. C. “DLMF: Chapter 19 Elliptic Integrals.” Accessed February 19, 2025. <https://dlmf.nist.gov/19.8>.
+/// - Reinhardt, W. P., and P. L. Walker. “DLMF: Chapter 22 Jacobian Elliptic Functions.” Accessed February 19, 2025. <https://dlmf.nist.gov/22.2>.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellipk_ratio<T: Float>(m: T) -> Result<T, EllipError> {
+    let ans = ellipk_ratio_unchecked(m);
+    #[cfg(not(feature = "test_force_fail"))]
+    if ans.is_finite() {
+        return Ok(ans);
+    }
+    check!(@nan, ellipk_ratio, [m]);
+    if m == 1.0 {
+        return Ok(inf!());
+    }
+    if m < 0.0 || m > 1.0 {
+        return err!(
+            DomainError,
+            ellipk_ratio,
+            "m must satisfy 0.0 ≤ m ≤ 1.0.",
+            m
+        );
+    }
+    err!(Unexpected, ellipk_ratio, "Unexpected error.")
+}
+
+/// Unsafe version of [ellipk_ratio].
+/// <div class="warning">⚠️ Unstable feature. May subject to changes.</div>
+///
+/// Undefined behavior with invalid arguments and edge cases.
+/// # Known Invalid Cases
+/// - m < 0
+/// - m > 1
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ellipk_ratio_unchecked<T: Float>(m: T) -> T {
+    if m == 0.0 {
+        return 0.0;
+    }
+    if m == 1.0 {
+        return inf!();
+    }
+    // 1 - m is exact for m ≥ 1/2, so the small complementary parameter keeps its digits.
+    agm(m.sqrt()) / agm((1.0 - m).sqrt())
+}
+
+/// Arithmetic-geometric mean AGM(1, b) for b ∈ (0, 1].
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn agm<T: Float>(b: T) -> T {
+    let mut a = 1.0;
+    let mut b = b;
+    for _ in 0..MAX_ITERATIONS {
+        if a - b <= epsilon!() * a {
+            break;
+        }
+        (a, b) = (0.5 * (a + b), (a * b).sqrt());
+    }
+    0.5 * (a + b)
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_close, ellipk};
+
+    #[test]
+    fn test_ellipk_ratio() {
+        // mpmath references ellipk(m) / ellipk(1 - m)
+        let cases = [
+            (5e-324, 0.0042044157159806993),
+            (1e-300, 0.0045297400112601564),
+            (1e-20, 0.064344870476013323),
+            (1e-10, 0.12177452186843341),
+            (0.01, 0.42610933023021027),
+            (0.1, 0.62543977399866397),
+            (0.3, 0.82582629470155946),
+            (0.7, 1.2109084033966055),
+            (0.9, 1.5988749701776021),
+            (0.999999999999, 9.6777766288036811),
+            (0.9999999999, 8.2118983630518913),
+            (1.0 - f64::EPSILON, 12.355593608548489),
+        ];
+        for (m, expected) in cases {
+            assert_close!(expected, ellipk_ratio(m).unwrap(), 5e-15);
+        }
+        for m in [0.2, 0.4, 0.6, 0.8] {
+            assert_close!(
+                ellipk(m).unwrap() / ellipk(1.0 - m).unwrap(),
+                ellipk_ratio(m).unwrap(),
+                5e-15
+            );
+        }
+    }
+
+    #[test]
+    fn test_ellipk_ratio_special_cases() {
+        use std::f64::{INFINITY, NAN};
+
+        // R(0) = 0, R(1/2) = 1, R(1) = ∞
+        assert_eq!(ellipk_ratio(0.0).unwrap(), 0.0);
+        assert_eq!(ellipk_ratio(0.5).unwrap(), 1.0);
+        assert_eq!(ellipk_ratio(1.0).unwrap(), INFINITY);
+        // R(1 - m) = 1 / R(m)
+        assert_close!(
+            ellipk_ratio(0.25).unwrap(),
+            1.0 / ellipk_ratio(0.75).unwrap(),
+            1e-15
+        );
+        // m < 0 or m > 1: should return Err
+        assert_eq!(
+            ellipk_ratio(-0.5).unwrap_err(),
+            "ellipk_ratio: m must satisfy 0.0 ≤ m ≤ 1.0."
+        );
+        assert_eq!(
+            ellipk_ratio(1.5).unwrap_err(),
+            "ellipk_ratio: m must satisfy 0.0 ≤ m ≤ 1.0."
+        );
+        assert_eq!(
+            ellipk_ratio(INFINITY).unwrap_err(),
+            "ellipk_ratio: m must satisfy 0.0 ≤ m ≤ 1.0."
+        );
+        // NAN: should return Err
+        assert_eq!(
+            ellipk_ratio(NAN).unwrap_err(),
+            "ellipk_ratio: Arguments cannot be NAN."
+        );
+    }
+}
+
+#[cfg(feature = "test_force_fail")]
+crate::test_force_unreachable! {
+    assert_eq!(ellipk_ratio(0.3).unwrap_err(), "ellipk_ratio: Unexpected error.");
+}
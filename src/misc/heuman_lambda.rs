@@ -5,7 +5,9 @@
 
 use num_traits::Float;
 
-use crate::{carlson::elliprj_unchecked, crate_util::check, ellipf, ellipk, StrErr};
+use crate::{
+    carlson::elliprj_unchecked, crate_util::check, ellipf, ellipk, error::err, EllipError,
+};
 
 /// Computes [Heuman Lambda](https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint/heuman_lambda.html).
 /// ```text
@@ -49,7 +51,7 @@ use crate::{carlson::elliprj_unchecked, crate_util::check, ellipf, ellipk, StrEr
 /// # References
 /// - Maddock, John, Paul Bristow, Hubert Holin, and Xiaogang Zhang. “Boost Math Library: Special Functions - Elliptic Integrals.” Accessed August 30, 2025. <https://www.boost.org/doc/libs/1_88_0/libs/math/doc/html/math_toolkit/ellint.html>.
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn heuman_lambda<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
+pub fn heuman_lambda<T: Float>(phi: T, m: T) -> Result<T, EllipError> {
     let ans = heuman_lambda_unchecked(phi, m);
     #[cfg(not(feature = "test_force_fail"))]
     if ans.is_finite() {
@@ -57,10 +59,15 @@ pub fn heuman_lambda<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
     }
     check!(@nan, heuman_lambda, [phi, m]);
     if m < 0.0 || m >= 1.0 {
-        return Err("heuman_lambda: m must satisfy 0.0 ≤ m < 1.0.");
+        return err!(
+            DomainError,
+            heuman_lambda,
+            "m must satisfy 0.0 ≤ m < 1.0.",
+            m
+        );
     }
     check!(@inf, heuman_lambda, [phi]);
-    Err("heuman_lambda: Unexpected error.")
+    err!(Unexpected, heuman_lambda, "Unexpected error.")
 }
 
 /// Unsafe version of [heuman_lambda].
@@ -137,38 +144,38 @@ mod tests {
         assert_eq!(heuman_lambda(3.0 * FRAC_PI_2, 0.5).unwrap(), 3.0);
         // m > 1: should return Err
         assert_eq!(
-            heuman_lambda(1.0, 1.5),
-            Err("heuman_lambda: m must satisfy 0.0 ≤ m < 1.0.")
+            heuman_lambda(1.0, 1.5).unwrap_err(),
+            "heuman_lambda: m must satisfy 0.0 ≤ m < 1.0."
         );
         // m = 0: sin(phi)
         assert_eq!(heuman_lambda(1.0, 0.0).unwrap(), 1.0.sin());
         // m < 0: should return Err
         assert_eq!(
-            heuman_lambda(1.0, -1.0),
-            Err("heuman_lambda: m must satisfy 0.0 ≤ m < 1.0.")
+            heuman_lambda(1.0, -1.0).unwrap_err(),
+            "heuman_lambda: m must satisfy 0.0 ≤ m < 1.0."
         );
         // NANs: should return Err
         assert_eq!(
-            heuman_lambda(NAN, 1.0),
-            Err("heuman_lambda: Arguments cannot be NAN.")
+            heuman_lambda(NAN, 1.0).unwrap_err(),
+            "heuman_lambda: Arguments cannot be NAN."
         );
         assert_eq!(
-            heuman_lambda(1.0, NAN),
-            Err("heuman_lambda: Arguments cannot be NAN.")
+            heuman_lambda(1.0, NAN).unwrap_err(),
+            "heuman_lambda: Arguments cannot be NAN."
         );
         // inf: should return Err
         assert_eq!(
-            heuman_lambda(INFINITY, 0.5),
-            Err("heuman_lambda: phi cannot be infinite.")
+            heuman_lambda(INFINITY, 0.5).unwrap_err(),
+            "heuman_lambda: phi cannot be infinite."
         );
         assert_eq!(
-            heuman_lambda(-INFINITY, 0.5),
-            Err("heuman_lambda: phi cannot be infinite.")
+            heuman_lambda(-INFINITY, 0.5).unwrap_err(),
+            "heuman_lambda: phi cannot be infinite."
         );
     }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(heuman_lambda(0.5, 0.5), Err("heuman_lambda: Unexpected error."));
+    assert_eq!(heuman_lambda(0.5, 0.5).unwrap_err(), "heuman_lambda: Unexpected error.");
 }
@@ -5,7 +5,7 @@
 
 use num_traits::Float;
 
-use crate::{carlson::elliprj_unchecked, crate_util::check, ellipk, StrErr};
+use crate::{carlson::elliprj_unchecked, crate_util::check, ellipk, error::err, EllipError};
 
 /// Computes [Jacobi Zeta](https://dlmf.nist.gov/22.16.E33).
 /// ```text
@@ -51,14 +51,14 @@ use crate::{carlson::elliprj_unchecked, crate_util::check, ellipk, StrErr};
 /// - Reinhardt, W. P., and P. L. Walker. “DLMF: Chapter 22 Jacobian Elliptic Functions.” Accessed August 31, 2025. <https://dlmf.nist.gov/22>.
 /// - Weisstein, Eric W. “Jacobi Zeta Function.” Wolfram Research, Inc. Accessed August 31, 2025. <https://mathworld.wolfram.com/JacobiZetaFunction.html>.
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn jacobi_zeta<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
+pub fn jacobi_zeta<T: Float>(phi: T, m: T) -> Result<T, EllipError> {
     let ans = jacobi_zeta_unchecked(phi, m)?;
     if ans.is_finite() {
         return Ok(ans);
     }
     check!(@nan, jacobi_zeta, [phi, m]);
     check!(@inf, jacobi_zeta, [phi, m]);
-    Err("jacobi_zeta: Unexpected error.")
+    err!(Unexpected, jacobi_zeta, "Unexpected error.")
 }
 
 /// Unsafe version of [jacobi_zeta].
@@ -70,7 +70,7 @@ pub fn jacobi_zeta<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
 /// - m = -∞
 #[inline]
 #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
-pub fn jacobi_zeta_unchecked<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
+pub fn jacobi_zeta_unchecked<T: Float>(phi: T, m: T) -> Result<T, EllipError> {
     let sign = phi.signum();
     let phi = phi.abs();
 
@@ -83,7 +83,7 @@ pub fn jacobi_zeta_unchecked<T: Float>(phi: T, m: T) -> Result<T, StrErr> {
         0.0
     } else if m >= 1.0 {
         if m != 1.0 {
-            return Err("jacobi_zeta: m must not be greater than 1.");
+            return err!(DomainError, jacobi_zeta, "m must not be greater than 1.", m);
         }
         sign * sinp * cosp.signum()
     } else {
@@ -137,35 +137,35 @@ mod tests {
         assert_eq!(jacobi_zeta(FRAC_PI_6, 1.0).unwrap(), FRAC_PI_6.sin());
         // m > 1: should return Err
         assert_eq!(
-            jacobi_zeta(1.0, 1.5),
-            Err("jacobi_zeta: m must not be greater than 1.")
+            jacobi_zeta(1.0, 1.5).unwrap_err(),
+            "jacobi_zeta: m must not be greater than 1."
         );
         // NANs: should return Err
         assert_eq!(
-            jacobi_zeta(NAN, 1.0),
-            Err("jacobi_zeta: Arguments cannot be NAN.")
+            jacobi_zeta(NAN, 1.0).unwrap_err(),
+            "jacobi_zeta: Arguments cannot be NAN."
         );
         assert_eq!(
-            jacobi_zeta(1.0, NAN),
-            Err("jacobi_zeta: Arguments cannot be NAN.")
+            jacobi_zeta(1.0, NAN).unwrap_err(),
+            "jacobi_zeta: Arguments cannot be NAN."
         );
         // inf: should return Err
         assert_eq!(
-            jacobi_zeta(INFINITY, 1.0),
-            Err("jacobi_zeta: phi cannot be infinite.")
+            jacobi_zeta(INFINITY, 1.0).unwrap_err(),
+            "jacobi_zeta: phi cannot be infinite."
         );
         assert_eq!(
-            jacobi_zeta(-INFINITY, 1.0),
-            Err("jacobi_zeta: phi cannot be infinite.")
+            jacobi_zeta(-INFINITY, 1.0).unwrap_err(),
+            "jacobi_zeta: phi cannot be infinite."
         );
         assert_eq!(
-            jacobi_zeta(1.0, -INFINITY),
-            Err("jacobi_zeta: m cannot be infinite.")
+            jacobi_zeta(1.0, -INFINITY).unwrap_err(),
+            "jacobi_zeta: m cannot be infinite."
         );
     }
 }
 
 #[cfg(feature = "test_force_fail")]
 crate::test_force_unreachable! {
-    assert_eq!(jacobi_zeta(0.5, 0.5), Err("jacobi_zeta: Unexpected error."));
+    assert_eq!(jacobi_zeta(0.5, 0.5).unwrap_err(), "jacobi_zeta: Unexpected error.");
 }
@@ -15,3 +15,8 @@ pub mod heuman_lambda;
 #[cfg(not(feature = "unstable"))]
 mod heuman_lambda;
 pub use heuman_lambda::heuman_lambda;
+#[cfg(feature = "unstable")]
+pub mod ellipk_ratio;
+#[cfg(not(feature = "unstable"))]
+mod ellipk_ratio;
+pub use ellipk_ratio::ellipk_ratio;
@@ -0,0 +1,489 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Validated parameter types and functions accepting them.
+//!
+//! The Legendre integrals take the parameter m, while the Bulirsch integrals take the
+//! complementary modulus kc, where m = k² = 1 - kc². The types in this module validate the
+//! argument once and convert between the conventions, so that the value cannot be passed in
+//! the wrong convention.
+//!
+//! | Type                   | Symbol | Relation to m | Valid range |
+//! |------------------------|--------|---------------|-------------|
+//! | [Parameter]            | m      | m             | m ≤ 1       |
+//! | [Modulus]              | k      | m = k²        | \|k\| ≤ 1     |
+//! | [ComplementaryModulus] | kc     | m = 1 - kc²   | kc ≠ 0      |
+//! | [ModularAngle]         | α      | m = sin²α     | finite α    |
+//!
+//! [Characteristic] holds n of the Legendre integrals and p = 1 - n of the Bulirsch integrals.
+//!
+//! The functions in this module accept the validated types and return plain values.
+//! Since the parameter is in the domain, they are infallible. Poles give ±∞, while the remaining
+//! invalid combinations of arguments give NAN, identical to [raw].
+//!
+//! # Examples
+//! ```
+//! use ellip::params::{self, ComplementaryModulus, Modulus, Parameter};
+//!
+//! let k = Modulus::new(0.6).unwrap();
+//! assert_eq!(params::ellipk(k), ellip::ellipk(0.36).unwrap());
+//!
+//! // Convert to the Bulirsch convention, kc = √(1 - k²)
+//! let kc = ComplementaryModulus::try_from(Parameter::from(k)).unwrap();
+//! assert_eq!(kc.kc(), 0.8);
+//! assert!(Parameter::new(1.5).is_err());
+//! ```
+
+use num_traits::Float;
+
+use crate::{bulirsch::BulirschConst, error::err, raw, EllipError};
+
+/// Parameter m, satisfying m ≤ 1.
+///
+/// The complementary parameter mc = 1 - m is kept alongside m, so that a parameter converted
+/// from [ComplementaryModulus] or [ModularAngle] near m = 1 keeps its precision.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Parameter<T: Float> {
+    m: T,
+    mc: T,
+}
+
+/// Modulus k, satisfying |k| ≤ 1, where m = k².
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Modulus<T: Float>(T);
+
+/// Complementary modulus kc, satisfying kc ≠ 0, where m = 1 - kc².
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ComplementaryModulus<T: Float>(T);
+
+/// Modular angle α, where m = sin²α.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ModularAngle<T: Float>(T);
+
+/// Characteristic n, where p = 1 - n.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Characteristic<T: Float>(T);
+
+impl<T: Float> Parameter<T> {
+    /// Creates a parameter. Returns error if m > 1 or m is NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(m: T) -> Result<Self, EllipError> {
+        if m.is_nan() {
+            return err!(NanArgument, Parameter, "m cannot be NAN.", [m]);
+        }
+        if m > 1.0 {
+            return err!(DomainError, Parameter, "m must not be greater than 1.", m);
+        }
+        Ok(Self { m, mc: 1.0 - m })
+    }
+
+    /// Returns m.
+    pub fn m(self) -> T {
+        self.m
+    }
+
+    /// Returns the complementary parameter mc = 1 - m.
+    pub fn mc(self) -> T {
+        self.mc
+    }
+
+    /// Returns kc = √mc if m is close enough to 1 for the Bulirsch integrals to be more
+    /// accurate than the Legendre integrals of m.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn kc(self) -> Option<T> {
+        (self.mc > 0.0 && self.mc < 0.5).then(|| self.mc.sqrt())
+    }
+}
+
+impl<T: Float> Modulus<T> {
+    /// Creates a modulus. Returns error if |k| > 1 or k is NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(k: T) -> Result<Self, EllipError> {
+        if k.is_nan() {
+            return err!(NanArgument, Modulus, "k cannot be NAN.", [k]);
+        }
+        if k.abs() > 1.0 {
+            return err!(DomainError, Modulus, "|k| must not be greater than 1.", k);
+        }
+        Ok(Self(k))
+    }
+
+    /// Returns k.
+    pub fn k(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Float> ComplementaryModulus<T> {
+    /// Creates a complementary modulus. Returns error if kc = 0 or kc is NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(kc: T) -> Result<Self, EllipError> {
+        if kc.is_nan() {
+            return err!(NanArgument, ComplementaryModulus, "kc cannot be NAN.", [kc]);
+        }
+        if kc == 0.0 {
+            return err!(DomainError, ComplementaryModulus, "kc cannot be zero.", kc);
+        }
+        Ok(Self(kc))
+    }
+
+    /// Returns kc.
+    pub fn kc(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Float> ModularAngle<T> {
+    /// Creates a modular angle. Returns error if α is not finite.
+    pub fn new(alpha: T) -> Result<Self, EllipError> {
+        if alpha.is_nan() {
+            return err!(NanArgument, ModularAngle, "α cannot be NAN.", [alpha]);
+        }
+        if alpha.is_infinite() {
+            return err!(InfiniteArgument, ModularAngle, "α must be finite.", alpha);
+        }
+        Ok(Self(alpha))
+    }
+
+    /// Returns α.
+    pub fn alpha(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Float> Characteristic<T> {
+    /// Creates a characteristic from n of the Legendre integrals. Returns error if n is NAN.
+    pub fn from_n(n: T) -> Result<Self, EllipError> {
+        if n.is_nan() {
+            return err!(NanArgument, Characteristic, "n cannot be NAN.", [n]);
+        }
+        Ok(Self(n))
+    }
+
+    /// Creates a characteristic from p = 1 - n of the Bulirsch integrals. Returns error if p is NAN.
+    pub fn from_p(p: T) -> Result<Self, EllipError> {
+        if p.is_nan() {
+            return err!(NanArgument, Characteristic, "p cannot be NAN.", [p]);
+        }
+        Ok(Self(T::one() - p))
+    }
+
+    /// Returns n.
+    pub fn n(self) -> T {
+        self.0
+    }
+
+    /// Returns p = 1 - n.
+    pub fn p(self) -> T {
+        T::one() - self.0
+    }
+}
+
+impl<T: Float> From<Modulus<T>> for Parameter<T> {
+    fn from(k: Modulus<T>) -> Self {
+        let k = k.0;
+        Self {
+            m: k * k,
+            mc: (T::one() - k) * (T::one() + k),
+        }
+    }
+}
+
+impl<T: Float> From<ComplementaryModulus<T>> for Parameter<T> {
+    fn from(kc: ComplementaryModulus<T>) -> Self {
+        let kc = kc.0;
+        Self {
+            m: T::one() - kc * kc,
+            mc: kc * kc,
+        }
+    }
+}
+
+impl<T: Float> From<ModularAngle<T>> for Parameter<T> {
+    fn from(alpha: ModularAngle<T>) -> Self {
+        let (sin, cos) = alpha.0.sin_cos();
+        Self {
+            m: sin * sin,
+            mc: cos * cos,
+        }
+    }
+}
+
+impl<T: Float> From<ModularAngle<T>> for Modulus<T> {
+    fn from(alpha: ModularAngle<T>) -> Self {
+        Self(alpha.0.sin())
+    }
+}
+
+impl<T: Float> From<Modulus<T>> for ModularAngle<T> {
+    fn from(k: Modulus<T>) -> Self {
+        Self(k.0.asin())
+    }
+}
+
+impl<T: Float> TryFrom<Parameter<T>> for Modulus<T> {
+    type Error = EllipError;
+
+    /// Converts m to k = √m. Returns error if m < 0.
+    fn try_from(m: Parameter<T>) -> Result<Self, Self::Error> {
+        let m = m.m;
+        if m < T::zero() {
+            return err!(DomainError, Modulus, "m must be non-negative.", m);
+        }
+        Ok(Self(m.sqrt()))
+    }
+}
+
+impl<T: Float> TryFrom<Parameter<T>> for ComplementaryModulus<T> {
+    type Error = EllipError;
+
+    /// Converts m to kc = √(1 - m). Returns error if m = 1.
+    fn try_from(m: Parameter<T>) -> Result<Self, Self::Error> {
+        let (m, mc) = (m.m, m.mc);
+        if mc == T::zero() {
+            return err!(DomainError, ComplementaryModulus, "m cannot be 1.", m);
+        }
+        Ok(Self(mc.sqrt()))
+    }
+}
+
+impl<T: Float> TryFrom<Modulus<T>> for ComplementaryModulus<T> {
+    type Error = EllipError;
+
+    /// Converts k to kc = √(1 - k²). Returns error if |k| = 1.
+    fn try_from(k: Modulus<T>) -> Result<Self, Self::Error> {
+        Self::try_from(Parameter::from(k))
+    }
+}
+
+// Legendre's integrals
+// The complete integrals with 1/2 < m < 1 are evaluated by cel with kc = √mc, which does not
+// lose the digits of mc to the rounding of m.
+
+/// Computes [ellipk](crate::ellipk) with a validated parameter.
+pub fn ellipk<T: Float + BulirschConst<T>>(m: impl Into<Parameter<T>>) -> T {
+    let m = m.into();
+    match m.kc() {
+        Some(kc) => raw::cel1(kc),
+        None => raw::ellipk(m.m),
+    }
+}
+
+/// Computes [ellipe](crate::ellipe) with a validated parameter.
+pub fn ellipe<T: Float + BulirschConst<T>>(m: impl Into<Parameter<T>>) -> T {
+    let m = m.into();
+    match m.kc() {
+        Some(kc) => raw::cel2(kc, T::one(), m.mc),
+        None => raw::ellipe(m.m),
+    }
+}
+
+/// Computes [ellippi](crate::ellippi) with a validated characteristic and parameter.
+pub fn ellippi<T: Float + BulirschConst<T>>(n: Characteristic<T>, m: impl Into<Parameter<T>>) -> T {
+    let m = m.into();
+    match m.kc() {
+        Some(kc) if n.0 != T::one() => raw::cel(kc, n.p(), T::one(), T::one()),
+        _ => raw::ellippi(n.0, m.m),
+    }
+}
+
+/// Computes [ellipd](crate::ellipd) with a validated parameter.
+pub fn ellipd<T: Float + BulirschConst<T>>(m: impl Into<Parameter<T>>) -> T {
+    let m = m.into();
+    match m.kc() {
+        Some(kc) => raw::cel(kc, T::one(), T::zero(), T::one()),
+        None => raw::ellipd(m.m),
+    }
+}
+
+/// Computes [ellipf](crate::ellipf) with a validated parameter.
+pub fn ellipf<T: Float>(phi: T, m: impl Into<Parameter<T>>) -> T {
+    raw::ellipf(phi, m.into().m)
+}
+
+/// Computes [ellipeinc](crate::ellipeinc) with a validated parameter.
+pub fn ellipeinc<T: Float>(phi: T, m: impl Into<Parameter<T>>) -> T {
+    raw::ellipeinc(phi, m.into().m)
+}
+
+/// Computes [ellippiinc](crate::ellippiinc) with a validated characteristic and parameter.
+pub fn ellippiinc<T: Float>(phi: T, n: Characteristic<T>, m: impl Into<Parameter<T>>) -> T {
+    raw::ellippiinc(phi, n.0, m.into().m)
+}
+
+/// Computes [ellipdinc](crate::ellipdinc) with a validated parameter.
+pub fn ellipdinc<T: Float>(phi: T, m: impl Into<Parameter<T>>) -> T {
+    raw::ellipdinc(phi, m.into().m)
+}
+
+/// Computes [jacobi_zeta](crate::jacobi_zeta) with a validated parameter.
+pub fn jacobi_zeta<T: Float>(phi: T, m: impl Into<Parameter<T>>) -> T {
+    raw::jacobi_zeta(phi, m.into().m)
+}
+
+// Bulirsch's integrals
+/// Computes [cel](crate::cel) with a validated complementary modulus and characteristic.
+pub fn cel<T: Float + BulirschConst<T>>(
+    kc: ComplementaryModulus<T>,
+    p: Characteristic<T>,
+    a: T,
+    b: T,
+) -> T {
+    raw::cel(kc.0, p.p(), a, b)
+}
+
+/// Computes [cel1](crate::cel1) with a validated complementary modulus.
+pub fn cel1<T: Float + BulirschConst<T>>(kc: ComplementaryModulus<T>) -> T {
+    raw::cel1(kc.0)
+}
+
+/// Computes [cel2](crate::cel2) with a validated complementary modulus.
+pub fn cel2<T: Float + BulirschConst<T>>(kc: ComplementaryModulus<T>, a: T, b: T) -> T {
+    raw::cel2(kc.0, a, b)
+}
+
+/// Computes [el1](crate::el1) with a validated complementary modulus.
+pub fn el1<T: Float + BulirschConst<T>>(x: T, kc: ComplementaryModulus<T>) -> T {
+    raw::el1(x, kc.0)
+}
+
+/// Computes [el2](crate::el2) with a validated complementary modulus.
+pub fn el2<T: Float + BulirschConst<T>>(x: T, kc: ComplementaryModulus<T>, a: T, b: T) -> T {
+    raw::el2(x, kc.0, a, b)
+}
+
+/// Computes [el3](crate::el3) with a validated complementary modulus and characteristic.
+pub fn el3<T: Float + BulirschConst<T>>(
+    x: T,
+    kc: ComplementaryModulus<T>,
+    p: Characteristic<T>,
+) -> T {
+    raw::el3(x, kc.0, p.p())
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_6};
+
+    #[test]
+    fn test_params_validation() {
+        assert_eq!(
+            Parameter::new(1.5).unwrap_err(),
+            "Parameter: m must not be greater than 1."
+        );
+        assert!(matches!(
+            Parameter::new(f64::NAN).unwrap_err(),
+            EllipError::NanArgument(_)
+        ));
+        assert_eq!(
+            Modulus::new(-1.5).unwrap_err(),
+            "Modulus: |k| must not be greater than 1."
+        );
+        assert_eq!(
+            ComplementaryModulus::new(0.0).unwrap_err(),
+            "ComplementaryModulus: kc cannot be zero."
+        );
+        assert!(matches!(
+            ModularAngle::new(f64::INFINITY).unwrap_err(),
+            EllipError::InfiniteArgument(_)
+        ));
+        assert!(Characteristic::from_p(f64::NAN).is_err());
+        assert_eq!(Parameter::new(-2.0).unwrap().m(), -2.0);
+    }
+
+    #[test]
+    fn test_params_conversion() {
+        let m = Parameter::new(0.25).unwrap();
+        let k = Modulus::try_from(m).unwrap();
+        assert_eq!(k.k(), 0.5);
+        assert_eq!(Parameter::from(k), m);
+
+        let kc = ComplementaryModulus::try_from(m).unwrap();
+        assert_eq!(kc.kc(), 0.75.sqrt());
+        assert_close!(0.25, Parameter::from(kc).m(), 1e-15);
+
+        let alpha = ModularAngle::from(k);
+        assert_close!(FRAC_PI_6, alpha.alpha(), 1e-15);
+        assert_close!(0.25, Parameter::from(alpha).m(), 1e-15);
+        assert_close!(0.5, Modulus::from(alpha).k(), 1e-15);
+
+        assert!(Modulus::try_from(Parameter::new(-1.0).unwrap()).is_err());
+        assert!(ComplementaryModulus::try_from(Parameter::new(1.0).unwrap()).is_err());
+        assert!(ComplementaryModulus::try_from(Modulus::new(-1.0).unwrap()).is_err());
+
+        let n = Characteristic::from_n(0.25).unwrap();
+        assert_eq!(n.p(), 0.75);
+        assert_eq!(Characteristic::from_p(0.75).unwrap(), n);
+    }
+
+    #[test]
+    fn test_params_functions() {
+        let m = Parameter::new(0.36).unwrap();
+        let k = Modulus::new(0.6).unwrap();
+        let kc = ComplementaryModulus::new(0.8).unwrap();
+        let n = Characteristic::from_n(0.5).unwrap();
+
+        assert_eq!(ellipk(m), crate::ellipk(0.36).unwrap());
+        assert_eq!(ellipk(k), ellipk(m));
+        assert_close!(ellipk(m), ellipk(kc), 1e-15);
+        assert_eq!(ellipe(m), crate::ellipe(0.36).unwrap());
+        assert_eq!(ellippi(n, m), crate::ellippi(0.5, 0.36).unwrap());
+        assert_eq!(ellipd(m), crate::ellipd(0.36).unwrap());
+        assert_eq!(ellipf(0.7, m), crate::ellipf(0.7, 0.36).unwrap());
+        assert_eq!(ellipeinc(0.7, m), crate::ellipeinc(0.7, 0.36).unwrap());
+        assert_eq!(
+            ellippiinc(0.7, n, m),
+            crate::ellippiinc(0.7, 0.5, 0.36).unwrap()
+        );
+        assert_eq!(ellipdinc(0.7, m), crate::ellipdinc(0.7, 0.36).unwrap());
+        assert_eq!(jacobi_zeta(0.7, m), crate::jacobi_zeta(0.7, 0.36).unwrap());
+
+        assert_close!(ellipk(m), cel1(kc), 1e-15);
+        assert_close!(ellippi(n, m), cel(kc, n, 1.0, 1.0), 1e-15);
+        assert_eq!(cel2(kc, 1.0, 1.0), crate::cel2(0.8, 1.0, 1.0).unwrap());
+        assert_close!(ellipf(0.7, m), el1(0.7.tan(), kc), 1e-15);
+        assert_eq!(
+            el2(0.7, kc, 1.0, 1.0),
+            crate::el2(0.7, 0.8, 1.0, 1.0).unwrap()
+        );
+        assert_close!(ellippiinc(0.7, n, m), el3(0.7.tan(), kc, n), 1e-15);
+
+        // Poles
+        assert_eq!(ellipk(Parameter::new(1.0).unwrap()), f64::INFINITY);
+        assert_eq!(
+            ellippi(Characteristic::from_n(1.0).unwrap(), m),
+            f64::INFINITY
+        );
+        assert_eq!(ellipe(Modulus::new(1.0).unwrap()), 1.0);
+        assert_eq!(ellipk(Modulus::new(1.0).unwrap()), f64::INFINITY);
+
+        // m close to 1, where K ≈ ln(4 / kc)
+        let kc = ComplementaryModulus::new(1e-10).unwrap();
+        assert_close!((4e10f64).ln(), ellipk(kc), 1e-15);
+        assert_close!(1.0, ellipe(kc), 1e-15);
+        let alpha = ModularAngle::new(FRAC_PI_2).unwrap();
+        assert_close!((4.0 / FRAC_PI_2.cos()).ln(), ellipk(alpha), 1e-15);
+
+        let m = Parameter::new(0.9).unwrap();
+        assert_close!(crate::ellipk(0.9).unwrap(), ellipk(m), 1e-15);
+        assert_close!(crate::ellipe(0.9).unwrap(), ellipe(m), 1e-15);
+        assert_close!(crate::ellipd(0.9).unwrap(), ellipd(m), 1e-15);
+        for n in [0.5, -2.0] {
+            let expected = crate::ellippi(n, 0.9).unwrap();
+            assert_close!(
+                expected,
+                ellippi(Characteristic::from_n(n).unwrap(), m),
+                1e-15
+            );
+        }
+        let expected = crate::ellippi(1.5, 0.9).unwrap();
+        let actual = ellippi(Characteristic::from_n(1.5).unwrap(), m);
+        assert_close!(expected.abs(), actual.abs(), 1e-14);
+        assert_eq!(expected.signum(), actual.signum());
+    }
+}
@@ -0,0 +1,539 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Exact motion of the simple pendulum at any amplitude.
+//!
+//! The pendulum θ'' + ω₀² sin θ = 0 with the small-amplitude angular frequency ω₀ = √(g / L)
+//! conserves the energy
+//! ```text
+//!         θ'²          θ
+//! m  =  ───── + sin² ─,
+//!       4ω₀²          2
+//! ```
+//! normalized so that the pendulum librates for m < 1, approaches the upright position along
+//! the separatrix for m = 1, and rotates over the top for m > 1 (Lawden, 1989, §5.2). The
+//! motion is expressed by the Jacobi elliptic functions of [EllipticModulus]:
+//! ```text
+//! Libration:   sin(θ/2) = k sn(ω₀t + u₀, m),     θ' = 2kω₀ cn(ω₀t + u₀, m)
+//! Separatrix:  θ/2 = gd(ω₀t + u₀),               θ' = 2ω₀ sech(ω₀t + u₀)
+//! Rotation:    θ/2 = am(kω₀t + u₀, 1/m),         θ' = 2kω₀ dn(kω₀t + u₀, 1/m)
+//! ```
+//! where k = √m and the phase u₀ is the incomplete integral [ellipf](crate::ellipf) at the
+//! initial amplitude. The period of the libration with the amplitude θ₀ is
+//! T = 4K(sin²(θ₀/2)) / ω₀. The elliptic functions are evaluated by
+//! [EllipticModulus::from_kc] from the complementary modulus, which is formed without
+//! cancellation, so the periods and the trajectories remain accurate near the separatrix.
+//!
+//! # Examples
+//! ```
+//! use ellip::{pendulum::{Pendulum, Regime}, util::assert_close};
+//! use std::f64::consts::PI;
+//!
+//! // Released from rest at 90°
+//! let pendulum = Pendulum::new(1.0, PI / 2.0, 0.0).unwrap();
+//! assert_eq!(pendulum.regime(), Regime::Libration);
+//! assert_close(pendulum.period(), 7.416298709205487, 1e-15);
+//! assert_close(pendulum.angle(pendulum.period() / 4.0).abs(), 0.0, 1e-15);
+//!
+//! // Time to swing from 90° to 45°
+//! assert_close(pendulum.time_to_angle(PI / 4.0), 1.2665423283775874, 1e-14);
+//! ```
+//!
+//! # References
+//! - Lawden, Derek F. Elliptic Functions and Applications. Applied Mathematical Sciences 80. New York: Springer, 1989. <https://doi.org/10.1007/978-1-4757-3980-0>.
+//! - Reinhardt, W. P., and P. L. Walker. “DLMF: Chapter 22 Jacobian Elliptic Functions.” Accessed August 31, 2025. <https://dlmf.nist.gov/22.19>.
+
+use num_traits::Float;
+
+use crate::{
+    crate_util::{check, pow2_scale},
+    error::err,
+    EllipError, EllipticModulus,
+};
+
+/// Regime of the motion of the pendulum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Regime {
+    /// Oscillation about the lowest position, for m < 1.
+    Libration,
+    /// Asymptotic approach to the upright position, for m = 1.
+    Separatrix,
+    /// Rotation over the top, for m > 1.
+    Rotation,
+}
+
+/// Simple pendulum θ'' + ω₀² sin θ = 0, see [mod@crate::pendulum].
+///
+/// The phase and the period are computed at construction. The angle is continuous in time, so
+/// it grows without bound in rotation, and librates about the multiple of 2π nearest to the
+/// initial angle otherwise. The methods return NAN for NAN arguments.
+///
+/// ## Parameters
+/// - omega0: small-amplitude angular frequency (ω₀ = √(g / L)). ω₀ ∈ ℝ, ω₀ > 0, ω₀ is finite.
+/// - theta0: initial angle from the lowest position (θ(0)). θ(0) ∈ ℝ, θ(0) is finite.
+/// - dtheta0: initial angular velocity (θ'(0)). θ'(0) ∈ ℝ, θ'(0) is finite.
+#[derive(Debug, Clone, Copy)]
+pub struct Pendulum<T: Float> {
+    omega0: T,
+    regime: Regime,
+    /// Multiple of 2π nearest to the initial angle.
+    center: T,
+    /// k = √m and kc = √|1 - m|
+    k: T,
+    kc: T,
+    /// Direction of the rotation and the separatrix motion.
+    sign: T,
+    /// Phase at t = 0.
+    u0: T,
+    period: T,
+    /// Context at m in libration and at 1/m in rotation.
+    modulus: Option<EllipticModulus<T>>,
+}
+
+impl<T: Float> Pendulum<T> {
+    /// Constructs the pendulum with the initial angle θ(0) and angular velocity θ'(0).
+    ///
+    /// ## Domain
+    /// - Returns error if ω₀ ≤ 0, or any argument is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(omega0: T, theta0: T, dtheta0: T) -> Result<Self, EllipError> {
+        check!(@nan, Pendulum, [omega0, theta0, dtheta0]);
+        if omega0 <= 0.0 {
+            return err!(DomainError, Pendulum, "omega0 must be positive.", omega0);
+        }
+        check!(@inf, Pendulum, [omega0, theta0, dtheta0]);
+
+        // The rounding of 2π would move the angles near ±π across the top.
+        let center = if theta0.abs() <= pi!() {
+            0.0
+        } else {
+            (theta0 / (2.0 * pi!())).round() * 2.0 * pi!()
+        };
+        let (sin_half, cos_half) = (0.5 * (theta0 - center)).sin_cos();
+        // With v = θ'(0) / 2ω₀, scale ω₀ and ω₀v by a power of two near the larger of the two,
+        // so that neither v nor the squares overflow in fast rotation.
+        let half = 0.5 * dtheta0;
+        let scale = pow2_scale(half.abs().max(omega0));
+        let (a, b) = (half / scale, omega0 / scale);
+        // (1 - m) b² = (b cos(θ/2) - |a|) (b cos(θ/2) + |a|), without cancellation
+        let kc2 = (b * cos_half.abs() - a.abs()) * (b * cos_half.abs() + a.abs());
+        let m = (b * sin_half) * (b * sin_half) + a * a;
+        let (k, kc) = (m.sqrt() / b, kc2.abs().sqrt() / b);
+        let sign = if dtheta0 < 0.0 { -1.0 } else { 1.0 };
+
+        let mut pendulum = Self {
+            omega0,
+            regime: Regime::Separatrix,
+            center,
+            k,
+            kc,
+            sign,
+            u0: 0.0,
+            period: inf!(),
+            modulus: None,
+        };
+        if kc2 > 0.0 {
+            let modulus = EllipticModulus::from_kc(kc)?;
+            pendulum.regime = Regime::Libration;
+            // sn(u₀) = sin(θ/2) / k, cn(u₀) = v / k, and dn(u₀) = cos(θ/2)
+            pendulum.u0 = modulus.inverse_sn_cn_dn(sin_half, a / b, cos_half.abs());
+            pendulum.period = 4.0 * modulus.ellipk() / omega0;
+            pendulum.modulus = Some(modulus);
+        } else if kc2 < 0.0 {
+            // kc / k at the parameter 1/m
+            let modulus = EllipticModulus::from_kc((-kc2 / m).sqrt())?;
+            pendulum.regime = Regime::Rotation;
+            pendulum.u0 = modulus.f(sign * 0.5 * (theta0 - center));
+            pendulum.period = 2.0 * modulus.ellipk() / (k * omega0);
+            pendulum.modulus = Some(modulus);
+        } else {
+            // gd(u₀) = θ/2
+            pendulum.u0 = (sign * sin_half / cos_half).asinh();
+        }
+        Ok(pendulum)
+    }
+
+    /// Constructs the libration at the parameter of the modulus with the phase u₀ at t = 0,
+    /// about θ = 0.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub(crate) fn from_libration(omega0: T, modulus: EllipticModulus<T>, u0: T) -> Self {
+        let kc = modulus.kc();
+        Self {
+            omega0,
+            regime: Regime::Libration,
+            center: 0.0,
+            k: ((1.0 - kc) * (1.0 + kc)).sqrt(),
+            kc,
+            sign: 1.0,
+            u0,
+            period: 4.0 * modulus.ellipk() / omega0,
+            modulus: Some(modulus),
+        }
+    }
+
+    /// Returns the small-amplitude angular frequency ω₀.
+    pub fn omega0(&self) -> T {
+        self.omega0
+    }
+
+    /// Returns the regime of the motion.
+    pub fn regime(&self) -> Regime {
+        self.regime
+    }
+
+    /// Returns the energy parameter m = θ'² / 4ω₀² + sin²(θ/2).
+    pub fn m(&self) -> T {
+        self.k * self.k
+    }
+
+    /// Returns the period.
+    ///
+    /// The period of the libration is the time of a full swing and back. The period of the
+    /// rotation is the time to advance the angle by 2π. Returns ∞ on the separatrix.
+    pub fn period(&self) -> T {
+        self.period
+    }
+
+    /// Returns the amplitude of the libration, 2 arcsin k.
+    ///
+    /// Returns ∞ in rotation and π on the separatrix.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn amplitude(&self) -> T {
+        match self.regime {
+            Regime::Libration => 2.0 * self.k.atan2(self.kc),
+            Regime::Separatrix => pi!(),
+            Regime::Rotation => inf!(),
+        }
+    }
+
+    /// Returns the angle θ(t) and the angular velocity θ'(t).
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn state(&self, t: T) -> (T, T) {
+        let (k, omega0) = (self.k, self.omega0);
+        match (self.regime, self.modulus) {
+            (Regime::Libration, Some(modulus)) => {
+                let (sn, cn, dn) = modulus.sn_cn_dn(omega0 * t + self.u0);
+                // cos(θ/2) = dn
+                let theta = self.center + 2.0 * (k * sn).atan2(dn);
+                (theta, 2.0 * k * omega0 * cn)
+            }
+            (Regime::Rotation, Some(modulus)) => {
+                let u = k * omega0 * t + self.u0;
+                let theta = self.center + 2.0 * self.sign * modulus.am(u);
+                (theta, 2.0 * self.sign * k * omega0 * modulus.sn_cn_dn(u).2)
+            }
+            _ => {
+                let u = omega0 * t + self.u0;
+                let theta = self.center + 2.0 * self.sign * u.sinh().atan();
+                (theta, 2.0 * self.sign * omega0 / u.cosh())
+            }
+        }
+    }
+
+    /// Returns the angle θ(t).
+    pub fn angle(&self, t: T) -> T {
+        self.state(t).0
+    }
+
+    /// Returns the angular velocity θ'(t).
+    pub fn angular_velocity(&self, t: T) -> T {
+        self.state(t).1
+    }
+
+    /// Returns the integrals of cos θ and sin θ over [0, t].
+    ///
+    /// With ∫sn²(u) du = D(am(u)), so that no difference of E and F is formed.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub(crate) fn displacement(&self, t: T) -> (T, T) {
+        let (k, omega0) = (self.k, self.omega0);
+        match (self.regime, self.modulus) {
+            (Regime::Libration, Some(modulus)) => {
+                // cos θ = 1 - 2m sn², sin θ = 2k sn dn
+                let m = modulus.m();
+                let integral = |u: T| u - 2.0 * m * modulus.d(modulus.am(u));
+                let u = omega0 * t + self.u0;
+                let cn = modulus.sn_cn_dn(u).1 - modulus.sn_cn_dn(self.u0).1;
+                (
+                    (integral(u) - integral(self.u0)) / omega0,
+                    -2.0 * k * cn / omega0,
+                )
+            }
+            (Regime::Rotation, Some(modulus)) => {
+                // cos θ = 1 - 2sn², sin θ = ±2 sn cn at the parameter 1/m
+                let integral = |u: T| u - 2.0 * modulus.d(modulus.am(u));
+                let u = k * omega0 * t + self.u0;
+                let dn = modulus.sn_cn_dn(u).2 - modulus.sn_cn_dn(self.u0).2;
+                (
+                    (integral(u) - integral(self.u0)) / (k * omega0),
+                    -2.0 * self.sign * k * dn / omega0,
+                )
+            }
+            _ => {
+                // cos θ = 1 - 2tanh², sin θ = ±2 tanh sech
+                let integral = |u: T| 2.0 * u.tanh() - u;
+                let u = omega0 * t + self.u0;
+                let sech = 1.0 / u.cosh() - 1.0 / self.u0.cosh();
+                (
+                    (integral(u) - integral(self.u0)) / omega0,
+                    -2.0 * self.sign * sech / omega0,
+                )
+            }
+        }
+    }
+
+    /// Returns the earliest time t ≥ 0 at which θ(t) = θ.
+    ///
+    /// The angle is measured as in [Pendulum::angle], so the angles in libration lie within the
+    /// amplitude about the multiple of 2π nearest to θ(0). Returns NAN if the angle is never
+    /// reached.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn time_to_angle(&self, theta: T) -> T {
+        let half = 0.5 * (theta - self.center);
+        match (self.regime, self.modulus) {
+            (Regime::Libration, Some(modulus)) => {
+                let (sin_half, cos_half) = half.sin_cos();
+                let kc = modulus.kc();
+                if half.abs() > pi_2!() || cos_half < kc {
+                    return nan!();
+                }
+                // The speed v at the angle, from v² = cos²(θ/2) - kc²
+                let v = ((cos_half - kc) * (cos_half + kc)).sqrt();
+                let full = 4.0 * modulus.ellipk();
+                let wrap = |v: T| {
+                    let du = modulus.inverse_sn_cn_dn(sin_half, v, cos_half) - self.u0;
+                    let du = du - (du / full).floor() * full;
+                    // A phase short of a full period by rounding is the current one.
+                    if full - du <= 8.0 * epsilon!() * full {
+                        0.0
+                    } else {
+                        du
+                    }
+                };
+                wrap(v).min(wrap(-v)) / self.omega0
+            }
+            (Regime::Rotation, Some(modulus)) => {
+                let t = (modulus.f(self.sign * half) - self.u0) / (self.k * self.omega0);
+                if t >= 0.0 {
+                    t
+                } else {
+                    nan!()
+                }
+            }
+            _ => {
+                let half = self.sign * half;
+                if half.abs() >= pi_2!() {
+                    return nan!();
+                }
+                let t = ((half.sin() / half.cos()).asinh() - self.u0) / self.omega0;
+                if t >= 0.0 && t.is_finite() {
+                    t
+                } else {
+                    nan!()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+    use std::f64::consts::PI;
+
+    /// mpmath references by integrating θ'' = -ω₀² sin θ with the Taylor method,
+    /// (ω₀, θ(0), θ'(0), t, θ(t), θ'(t)).
+    const REFERENCES: [[f64; 6]; 6] = [
+        [2.0, 1.0, 0.5, 3.7, 0.9768260934652436, -0.6362600215130378],
+        [1.0, 3.1, 0.0, 5.0, 0.5137518775033094, -1.9339296462705455],
+        [
+            1.0,
+            -2.0,
+            1.0,
+            10.0,
+            -2.7163506677455516,
+            -0.10205788610899999,
+        ],
+        [1.0, 0.3, -2.5, 4.0, -7.980305772430987, -2.021719604734647],
+        [0.5, 7.0, 3.0, 2.0, 12.858735405433208, 3.0169241559217252],
+        [1.0, 0.0, 2.0, 2.0, 2.60352067209203, 0.5316044576681593],
+    ];
+
+    #[test]
+    fn test_pendulum() {
+        for [omega0, theta0, dtheta0, t, theta, dtheta] in REFERENCES {
+            let pendulum = Pendulum::new(omega0, theta0, dtheta0).unwrap();
+            let state = pendulum.state(t);
+            assert_close!(theta.abs(), state.0.abs(), 1e-13);
+            assert_close!(dtheta.abs(), state.1.abs(), 1e-13);
+            assert_eq!(theta.signum(), state.0.signum());
+            assert_eq!(dtheta.signum(), state.1.signum());
+
+            let start = pendulum.state(0.0);
+            assert_close!(theta0, start.0, 1e-14);
+            assert!((dtheta0 - start.1).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_pendulum_period() {
+        // mpmath references of 4K(sin²(θ₀/2)) / ω₀
+        for (theta0, expected) in [
+            (0.1, 6.28711454931048),
+            (PI / 2.0, 7.4162987092054875),
+            (3.0, 16.155539372393374),
+            (PI - 1e-6, 63.57980839753136),
+            (PI - 1e-12, 118.84100525872248),
+        ] {
+            let pendulum = Pendulum::new(1.0, theta0, 0.0).unwrap();
+            assert_close!(expected, pendulum.period(), 1e-14);
+            assert_close!(
+                expected / 2.0,
+                Pendulum::new(2.0, -theta0, 0.0).unwrap().period(),
+                1e-14
+            );
+            assert_close!(theta0, pendulum.amplitude(), 1e-14);
+        }
+
+        // Small amplitudes, T = 2π/ω₀ (1 + θ₀²/16 + 11θ₀⁴/3072)
+        let theta0: f64 = 1e-3;
+        let series = 2.0 * PI * (1.0 + theta0 * theta0 / 16.0 + 11.0 * theta0.powi(4) / 3072.0);
+        assert_close!(
+            series,
+            Pendulum::new(1.0, theta0, 0.0).unwrap().period(),
+            1e-15
+        );
+
+        // The angle advances by 2π over the period of the rotation.
+        let pendulum = Pendulum::new(1.5, 0.3, 4.0).unwrap();
+        let t = 0.7;
+        assert_close!(
+            pendulum.angle(t) + 2.0 * PI,
+            pendulum.angle(t + pendulum.period()),
+            1e-14
+        );
+        let pendulum = Pendulum::new(1.5, 0.3, -0.4).unwrap();
+        assert_close!(
+            pendulum.angle(t),
+            pendulum.angle(t + pendulum.period()),
+            1e-14
+        );
+    }
+
+    #[test]
+    fn test_pendulum_energy() {
+        for (omega0, theta0, dtheta0) in [(2.0, 1.0, 0.5), (1.0, 3.1, 0.0), (1.0, 0.3, -2.5)] {
+            let pendulum = Pendulum::new(omega0, theta0, dtheta0).unwrap();
+            for t in [0.1, 1.0, 3.0, 30.0] {
+                let (theta, dtheta) = pendulum.state(t);
+                let m = dtheta * dtheta / (4.0 * omega0 * omega0) + (theta / 2.0).sin().powi(2);
+                assert_close!(pendulum.m(), m, 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_time_to_angle() {
+        for (omega0, theta0, dtheta0) in [
+            (2.0, 1.0, 0.5),
+            (1.0, 3.1, 0.0),
+            (1.0, 0.3, -2.5),
+            (1.0, 0.0, 2.0),
+            (0.5, 7.0, -0.3),
+        ] {
+            let pendulum = Pendulum::new(omega0, theta0, dtheta0).unwrap();
+            assert!(pendulum.time_to_angle(theta0) <= 1e-15);
+            for dt in [0.2, 1.0, 2.5] {
+                let theta = pendulum.angle(dt);
+                let t = pendulum.time_to_angle(theta);
+                assert!(t <= dt * (1.0 + 1e-12));
+                assert_close!(theta, pendulum.angle(t), 1e-12);
+            }
+        }
+
+        let pendulum = Pendulum::new(1.0, 1.0, 0.0).unwrap();
+        assert!(pendulum.time_to_angle(1.5).is_nan());
+        assert!(pendulum.time_to_angle(-4.0).is_nan());
+        // From rest at the amplitude, a quarter period to the bottom
+        assert_close!(pendulum.period() / 4.0, pendulum.time_to_angle(0.0), 1e-14);
+        let pendulum = Pendulum::new(1.0, 0.3, -2.5).unwrap();
+        assert!(pendulum.time_to_angle(1.0).is_nan());
+    }
+
+    #[test]
+    fn test_pendulum_special_cases() {
+        // mpmath reference of 4K(sin²(PI/2))
+        const PERIOD_AT_PI: f64 = 154.87260221755525;
+
+        // At rest at the bottom and at the top
+        let pendulum = Pendulum::new(1.0, 0.0, 0.0).unwrap();
+        assert_eq!(pendulum.state(3.0), (0.0, 0.0));
+        assert_close!(2.0 * PI, pendulum.period(), 1e-15);
+        // PI is below π, so the pendulum slowly returns.
+        let pendulum = Pendulum::new(1.0, PI, 0.0).unwrap();
+        assert_eq!(pendulum.regime(), Regime::Libration);
+        assert_close!(PERIOD_AT_PI, pendulum.period(), 1e-14);
+        // Linearized about the top, θ = π - (π - PI) cosh t
+        assert_close!(
+            PI - 1.2246467991473532e-16 * 10f64.cosh(),
+            pendulum.angle(10.0),
+            1e-15
+        );
+        assert_close!(PI, pendulum.angle(PERIOD_AT_PI), 1e-15);
+        assert!(pendulum.angle(PERIOD_AT_PI / 2.0) < -PI + 1e-12);
+
+        // Separatrix, θ = 4 arctan(tanh(ω₀t / 2))
+        let pendulum = Pendulum::new(2.0, 0.0, 4.0).unwrap();
+        assert_eq!(pendulum.regime(), Regime::Separatrix);
+        assert_eq!(pendulum.period(), f64::INFINITY);
+        assert_eq!(pendulum.amplitude(), PI);
+        assert_close!(4.0 * 2.0f64.tanh().atan(), pendulum.angle(2.0), 1e-15);
+        assert_close!(PI, pendulum.angle(1e3), 1e-15);
+        assert_eq!(
+            Pendulum::new(1.0, 0.0, 2.0 + 1e-15).unwrap().regime(),
+            Regime::Rotation
+        );
+        assert_eq!(
+            Pendulum::new(1.0, 0.0, 2.0 - 1e-15).unwrap().regime(),
+            Regime::Libration
+        );
+        assert!(pendulum.angle(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_pendulum_fast_rotation() {
+        // θ ≈ θ'(0) t when the kinetic energy dominates, with the period 2π / θ'(0)
+        for (omega0, dtheta0) in [(1.0, 1e200), (1.0, -1e300), (1e-300, 1.0)] {
+            let pendulum = Pendulum::new(omega0, 0.0, dtheta0).unwrap();
+            assert_eq!(pendulum.regime(), Regime::Rotation);
+            let period = 2.0 * PI / dtheta0.abs();
+            assert_close!(period, pendulum.period(), 1e-15);
+            assert_close!(
+                dtheta0.abs(),
+                pendulum.angular_velocity(period / 3.0).abs(),
+                1e-15
+            );
+            assert_close!(2.0 * PI / 3.0, pendulum.angle(period / 3.0).abs(), 1e-15);
+            assert_eq!(pendulum.angle(period / 3.0).signum(), dtheta0.signum());
+        }
+    }
+
+    #[test]
+    fn test_pendulum_errors() {
+        assert_eq!(
+            Pendulum::new(0.0, 1.0, 0.0).unwrap_err(),
+            "Pendulum: omega0 must be positive."
+        );
+        assert_eq!(
+            Pendulum::new(1.0, f64::NAN, 0.0).unwrap_err(),
+            "Pendulum: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            Pendulum::new(1.0, 0.0, f64::INFINITY).unwrap_err(),
+            "Pendulum: dtheta0 cannot be infinite."
+        );
+    }
+}
@@ -0,0 +1,412 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Evaluation policies controlling the behavior on errors.
+//!
+//! Each function has a `*_with_policy` variant taking a [Policy] type parameter, which decides
+//! what happens on domain errors, poles, overflow, and non-convergence. Overflow refers to an
+//! infinite result from finite arguments, e.g., [ellipk](crate::ellipk)(1) = ∞. The design follows
+//! the [Boost Math policies](https://www.boost.org/doc/libs/release/libs/math/doc/html/policy.html).
+//!
+//! | Error                    | [EllipError] variants                                | [Policy] constant             |
+//! |--------------------------|------------------------------------------------------|-------------------------------|
+//! | Domain error             | DomainError, NanArgument, InfiniteArgument           | [Policy::DOMAIN_ERROR]        |
+//! | Pole                     | Singularity                                          | [Policy::POLE_ERROR]          |
+//! | Overflow                 | Overflow                                             | [Policy::OVERFLOW_ERROR]      |
+//! | Evaluation error         | NoConvergence, Unexpected                            | [Policy::EVALUATION_ERROR]    |
+//!
+//! # Examples
+//! ```
+//! use ellip::policy::{ellipk_with_policy, IeeePolicy, StrictPolicy};
+//!
+//! // NAN propagation for numeric kernels
+//! assert!(ellipk_with_policy::<f64, IeeePolicy>(2.0).unwrap().is_nan());
+//! assert_eq!(ellipk_with_policy::<f64, IeeePolicy>(1.0).unwrap(), f64::INFINITY);
+//!
+//! // Hard errors for validation
+//! assert!(ellipk_with_policy::<f64, StrictPolicy>(2.0).is_err());
+//! assert!(ellipk_with_policy::<f64, StrictPolicy>(1.0).is_err());
+//! ```
+
+use num_traits::Float;
+
+use crate::{bulirsch::BulirschConst, error::ErrorInfo, EllipError};
+
+/// Action taken by a [Policy] when an error occurs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorAction {
+    /// Return `Err`.
+    Error,
+    /// Return the IEEE value: NAN for domain and evaluation errors, ±∞ for overflow, and the
+    /// signed limit at poles, or NAN if the sign of the pole is unknown.
+    Ignore,
+    /// Return ±[max_value](Float::max_value) for poles and overflow, and NAN otherwise,
+    /// including poles of unknown sign.
+    Saturate,
+    /// Panic with the error message.
+    Panic,
+}
+
+/// Trait for controlling the behavior of the functions on errors
+///
+/// # Examples
+/// ```
+/// use ellip::policy::{elliprd_with_policy, ErrorAction, Policy};
+///
+/// struct Kernel;
+/// impl Policy for Kernel {
+///     const DOMAIN_ERROR: ErrorAction = ErrorAction::Ignore;
+///     const POLE_ERROR: ErrorAction = ErrorAction::Saturate;
+///     const OVERFLOW_ERROR: ErrorAction = ErrorAction::Saturate;
+///     const EVALUATION_ERROR: ErrorAction = ErrorAction::Error;
+/// }
+///
+/// assert!(elliprd_with_policy::<f64, Kernel>(-1.0, 1.0, 1.0).unwrap().is_nan());
+/// assert_eq!(elliprd_with_policy::<f64, Kernel>(0.0, 0.0, 1.0).unwrap(), f64::MAX);
+/// ```
+pub trait Policy {
+    /// Action on arguments outside the domain, NAN, or infinite arguments.
+    const DOMAIN_ERROR: ErrorAction;
+    /// Action on arguments at a pole or branch point.
+    const POLE_ERROR: ErrorAction;
+    /// Action on infinite results from finite arguments.
+    const OVERFLOW_ERROR: ErrorAction;
+    /// Action on non-convergence and unexpected failures.
+    const EVALUATION_ERROR: ErrorAction;
+}
+
+/// Default policy, identical to the functions without policy.
+///
+/// Returns `Err` on domain, pole, and evaluation errors, and ±∞ on overflow.
+pub struct DefaultPolicy;
+impl Policy for DefaultPolicy {
+    const DOMAIN_ERROR: ErrorAction = ErrorAction::Error;
+    const POLE_ERROR: ErrorAction = ErrorAction::Error;
+    const OVERFLOW_ERROR: ErrorAction = ErrorAction::Ignore;
+    const EVALUATION_ERROR: ErrorAction = ErrorAction::Error;
+}
+
+/// Strict policy returning `Err` on every error, including infinite results.
+pub struct StrictPolicy;
+impl Policy for StrictPolicy {
+    const DOMAIN_ERROR: ErrorAction = ErrorAction::Error;
+    const POLE_ERROR: ErrorAction = ErrorAction::Error;
+    const OVERFLOW_ERROR: ErrorAction = ErrorAction::Error;
+    const EVALUATION_ERROR: ErrorAction = ErrorAction::Error;
+}
+
+/// IEEE-like policy propagating NAN and ±∞. Never returns `Err`.
+pub struct IeeePolicy;
+impl Policy for IeeePolicy {
+    const DOMAIN_ERROR: ErrorAction = ErrorAction::Ignore;
+    const POLE_ERROR: ErrorAction = ErrorAction::Ignore;
+    const OVERFLOW_ERROR: ErrorAction = ErrorAction::Ignore;
+    const EVALUATION_ERROR: ErrorAction = ErrorAction::Ignore;
+}
+
+/// Saturating policy returning ±[max_value](Float::max_value) instead of ±∞. Never returns `Err`.
+pub struct SaturatingPolicy;
+impl Policy for SaturatingPolicy {
+    const DOMAIN_ERROR: ErrorAction = ErrorAction::Ignore;
+    const POLE_ERROR: ErrorAction = ErrorAction::Saturate;
+    const OVERFLOW_ERROR: ErrorAction = ErrorAction::Saturate;
+    const EVALUATION_ERROR: ErrorAction = ErrorAction::Ignore;
+}
+
+/// Policy panicking on every error, including infinite results.
+pub struct PanicPolicy;
+impl Policy for PanicPolicy {
+    const DOMAIN_ERROR: ErrorAction = ErrorAction::Panic;
+    const POLE_ERROR: ErrorAction = ErrorAction::Panic;
+    const OVERFLOW_ERROR: ErrorAction = ErrorAction::Panic;
+    const EVALUATION_ERROR: ErrorAction = ErrorAction::Panic;
+}
+
+/// Applies the action to the error, given the IEEE value and the saturated value.
+#[inline]
+fn act<T: Float>(
+    action: ErrorAction,
+    err: EllipError,
+    ieee: T,
+    saturated: T,
+) -> Result<T, EllipError> {
+    match action {
+        ErrorAction::Error => Err(err),
+        ErrorAction::Ignore => Ok(ieee),
+        ErrorAction::Saturate => Ok(saturated),
+        ErrorAction::Panic => panic!("{}", err),
+    }
+}
+
+/// Applies the policy to the result of a function.
+#[inline]
+pub(crate) fn apply<T: Float, P: Policy>(
+    result: Result<T, EllipError>,
+    finite_args: bool,
+    overflow: ErrorInfo,
+) -> Result<T, EllipError> {
+    match result {
+        Ok(ans) if finite_args && ans.is_infinite() => {
+            let saturated = ans.signum() * T::max_value();
+            act(
+                P::OVERFLOW_ERROR,
+                EllipError::Overflow(overflow),
+                ans,
+                saturated,
+            )
+        }
+        Ok(ans) => Ok(ans),
+        Err(err) => match err {
+            EllipError::DomainError(_)
+            | EllipError::NanArgument(_)
+            | EllipError::InfiniteArgument(_) => act(P::DOMAIN_ERROR, err, T::nan(), T::nan()),
+            EllipError::Singularity(info) => {
+                // NAN where the sign of the pole is unknown
+                let limit = info.limit.map_or(T::nan(), |limit| T::from(limit).unwrap());
+                let saturated = if limit.is_infinite() {
+                    limit.signum() * T::max_value()
+                } else {
+                    limit
+                };
+                act(P::POLE_ERROR, err, limit, saturated)
+            }
+            EllipError::Overflow(_) => act(P::OVERFLOW_ERROR, err, T::infinity(), T::max_value()),
+            EllipError::NoConvergence(_) | EllipError::Unexpected(_) => {
+                act(P::EVALUATION_ERROR, err, T::nan(), T::nan())
+            }
+        },
+    }
+}
+
+macro_rules! impl_with_policy {
+    ($fn:ident, $fn_with_policy:ident, [$($arg:ident),+] $(, $bound:path)?) => {
+        #[doc = concat!["Computes [", stringify!($fn), "](crate::", stringify!($fn), "). Control the behavior on errors using [Policy]."]]
+        pub fn $fn_with_policy<T: Float $(+ $bound)?, P: Policy>($($arg: T),+) -> Result<T, EllipError> {
+            apply::<T, P>(
+                crate::$fn($($arg),+),
+                true $(&& $arg.is_finite())+,
+                ErrorInfo {
+                    func: stringify!($fn),
+                    arg: None,
+                    value: None,
+                    limit: None,
+                    message: concat![stringify!($fn), ": Result is infinite."],
+                },
+            )
+        }
+    };
+}
+
+// Legendre's complete integrals
+impl_with_policy!(ellipk, ellipk_with_policy, [m]);
+impl_with_policy!(ellipe, ellipe_with_policy, [m]);
+impl_with_policy!(ellippi, ellippi_with_policy, [n, m]);
+impl_with_policy!(ellipd, ellipd_with_policy, [m]);
+
+// Legendre's incomplete integrals
+impl_with_policy!(ellipf, ellipf_with_policy, [phi, m]);
+impl_with_policy!(ellipeinc, ellipeinc_with_policy, [phi, m]);
+impl_with_policy!(ellippiinc, ellippiinc_with_policy, [phi, n, m]);
+impl_with_policy!(
+    ellippiinc_bulirsch,
+    ellippiinc_bulirsch_with_policy,
+    [phi, n, m],
+    BulirschConst<T>
+);
+impl_with_policy!(ellipdinc, ellipdinc_with_policy, [phi, m]);
+
+// Bulirsch's integrals
+impl_with_policy!(cel, cel_with_policy, [kc, p, a, b], BulirschConst<T>);
+impl_with_policy!(cel1, cel1_with_policy, [kc], BulirschConst<T>);
+impl_with_policy!(cel2, cel2_with_policy, [kc, a, b], BulirschConst<T>);
+impl_with_policy!(el1, el1_with_policy, [x, kc], BulirschConst<T>);
+impl_with_policy!(el2, el2_with_policy, [x, kc, a, b], BulirschConst<T>);
+impl_with_policy!(el3, el3_with_policy, [x, kc, p], BulirschConst<T>);
+
+// Carlson's symmetric integrals
+impl_with_policy!(elliprf, elliprf_with_policy, [x, y, z]);
+impl_with_policy!(elliprg, elliprg_with_policy, [x, y, z]);
+impl_with_policy!(elliprj, elliprj_with_policy, [x, y, z, p]);
+impl_with_policy!(elliprc, elliprc_with_policy, [x, y]);
+impl_with_policy!(elliprd, elliprd_with_policy, [x, y, z]);
+
+// Miscellaneous functions
+impl_with_policy!(jacobi_zeta, jacobi_zeta_with_policy, [phi, m]);
+impl_with_policy!(heuman_lambda, heuman_lambda_with_policy, [phi, m]);
+impl_with_policy!(ellipk_ratio, ellipk_ratio_with_policy, [m]);
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::{INFINITY, MAX, NAN};
+
+    #[test]
+    fn test_default_policy() {
+        assert_eq!(
+            ellipk_with_policy::<f64, DefaultPolicy>(0.5),
+            crate::ellipk(0.5)
+        );
+        assert_eq!(
+            ellipk_with_policy::<f64, DefaultPolicy>(2.0).unwrap_err(),
+            "ellipk: m must not be greater than 1."
+        );
+        assert_eq!(
+            ellipk_with_policy::<f64, DefaultPolicy>(1.0).unwrap(),
+            INFINITY
+        );
+        assert_eq!(
+            cel1_with_policy::<f64, DefaultPolicy>(0.0).unwrap_err(),
+            "cel1: kc cannot be zero."
+        );
+    }
+
+    #[test]
+    fn test_strict_policy() {
+        let err = ellipk_with_policy::<f64, StrictPolicy>(1.0).unwrap_err();
+        assert!(matches!(err, EllipError::Overflow(_)));
+        assert_eq!(err, "ellipk: Result is infinite.");
+        assert_eq!(err.func(), "ellipk");
+        assert!(matches!(
+            elliprc_with_policy::<f64, StrictPolicy>(1.0, 0.0).unwrap_err(),
+            EllipError::Singularity(_)
+        ));
+    }
+
+    #[test]
+    fn test_strict_policy_infinite_argument() {
+        // An infinite result from an infinite argument is not an overflow.
+        assert_eq!(
+            ellipf_with_policy::<f64, StrictPolicy>(INFINITY, 0.5).unwrap(),
+            INFINITY
+        );
+    }
+
+    #[test]
+    fn test_ieee_policy() {
+        assert!(ellipk_with_policy::<f64, IeeePolicy>(2.0).unwrap().is_nan());
+        assert!(ellipf_with_policy::<f64, IeeePolicy>(NAN, 0.5)
+            .unwrap()
+            .is_nan());
+        assert!(elliprf_with_policy::<f64, IeeePolicy>(-1.0, 1.0, 1.0)
+            .unwrap()
+            .is_nan());
+        assert!(jacobi_zeta_with_policy::<f64, IeeePolicy>(INFINITY, 0.5)
+            .unwrap()
+            .is_nan());
+        assert_eq!(
+            ellipk_with_policy::<f64, IeeePolicy>(1.0).unwrap(),
+            INFINITY
+        );
+        assert_eq!(
+            elliprc_with_policy::<f64, IeeePolicy>(1.0, 0.0).unwrap(),
+            INFINITY
+        );
+        assert_eq!(
+            cel_with_policy::<f64, IeeePolicy>(0.0, 1.0, 1.0, 1.0).unwrap(),
+            INFINITY
+        );
+        assert_eq!(
+            el1_with_policy::<f64, IeeePolicy>(0.5, 0.5),
+            crate::el1(0.5, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_pole_sign() {
+        use core::f64::{consts::FRAC_PI_2, NEG_INFINITY};
+
+        assert_eq!(
+            crate::cel(0.0, 1.0, 1.0, -1.0).unwrap_err().limit(),
+            Some(NEG_INFINITY)
+        );
+        assert_eq!(
+            crate::ellippi(1.0, 0.5).unwrap_err().limit(),
+            Some(INFINITY)
+        );
+        assert_eq!(crate::cel(0.0, 1.0, 1.0, 0.0).unwrap_err().limit(), None);
+
+        // Negative poles
+        assert_eq!(
+            cel_with_policy::<f64, IeeePolicy>(0.0, 1.0, 1.0, -1.0).unwrap(),
+            NEG_INFINITY
+        );
+        assert_eq!(
+            cel_with_policy::<f64, IeeePolicy>(0.0, -1.0, 1.0, 1.0).unwrap(),
+            NEG_INFINITY
+        );
+        assert_eq!(
+            cel2_with_policy::<f64, IeeePolicy>(0.0, 1.0, -2.0).unwrap(),
+            NEG_INFINITY
+        );
+        assert_eq!(
+            ellippiinc_with_policy::<f64, IeeePolicy>(-FRAC_PI_2, 1.0, 0.5).unwrap(),
+            NEG_INFINITY
+        );
+        assert_eq!(
+            ellippiinc_with_policy::<f64, IeeePolicy>(FRAC_PI_2, 1.0, 0.5).unwrap(),
+            INFINITY
+        );
+        assert_eq!(
+            el3_with_policy::<f64, IeeePolicy>(-2.0, 0.5, -0.25).unwrap(),
+            NEG_INFINITY
+        );
+        assert_eq!(
+            elliprj_with_policy::<f64, IeeePolicy>(0.0, 0.0, 1.0, -1.0).unwrap(),
+            NEG_INFINITY
+        );
+        assert_eq!(
+            cel_with_policy::<f64, SaturatingPolicy>(0.0, 1.0, 1.0, -1.0).unwrap(),
+            -MAX
+        );
+        assert_eq!(
+            ellippiinc_with_policy::<f64, SaturatingPolicy>(-FRAC_PI_2, 1.0, 0.5).unwrap(),
+            -MAX
+        );
+
+        // Unknown sign
+        assert!(cel_with_policy::<f64, IeeePolicy>(0.0, 1.0, 1.0, 0.0)
+            .unwrap()
+            .is_nan());
+        assert!(cel_with_policy::<f64, SaturatingPolicy>(0.0, 1.0, 1.0, 0.0)
+            .unwrap()
+            .is_nan());
+    }
+
+    #[test]
+    fn test_saturating_policy() {
+        assert_eq!(
+            ellipk_with_policy::<f64, SaturatingPolicy>(1.0).unwrap(),
+            MAX
+        );
+        assert_eq!(
+            elliprd_with_policy::<f64, SaturatingPolicy>(0.0, 0.0, 1.0).unwrap(),
+            MAX
+        );
+        assert_eq!(
+            ellipd_with_policy::<f64, SaturatingPolicy>(1.0).unwrap(),
+            MAX
+        );
+        assert_eq!(
+            ellipf_with_policy::<f64, SaturatingPolicy>(-INFINITY, 0.5).unwrap(),
+            -INFINITY
+        );
+        assert!(ellipe_with_policy::<f64, SaturatingPolicy>(2.0)
+            .unwrap()
+            .is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "ellipk: m must not be greater than 1.")]
+    fn test_panic_policy() {
+        let _ = ellipk_with_policy::<f64, PanicPolicy>(2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "ellipk: Result is infinite.")]
+    fn test_panic_policy_overflow() {
+        let _ = ellipk_with_policy::<f64, PanicPolicy>(1.0);
+    }
+}
@@ -0,0 +1,298 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! NAN-propagating functions returning plain values.
+//!
+//! Each function in this module has the same arguments as its counterpart at the crate root,
+//! but returns `T` instead of `Result`. Invalid arguments give NAN, poles give ±∞, or NAN if
+//! the sign of the pole is unknown, and the functions never panic. The arguments are checked
+//! against the [domain](mod@crate::domain) of each function, so arguments out of the domain
+//! give NAN even where the crate root function returns a value, e.g., F(∞, 2). Otherwise, the
+//! results are identical to the crate root functions under [IeeePolicy](crate::policy::IeeePolicy).
+//!
+//! ## Performance
+//! Invalid arguments and poles return before any evaluation, without building an
+//! [EllipError](crate::EllipError). Valid arguments cost one classification on top of the crate
+//! root functions.
+//!
+//! # Examples
+//! ```
+//! use ellip::raw;
+//!
+//! assert_eq!(raw::ellipk(0.5), ellip::ellipk(0.5).unwrap());
+//! assert!(raw::ellipk(2.0f64).is_nan());
+//! assert_eq!(raw::ellipk(1.0), f64::INFINITY);
+//!
+//! let ms = [0.1, 0.5, 1.5];
+//! let ks: Vec<f64> = ms.iter().map(|&m| raw::ellipk(m)).collect();
+//! assert!(ks[2].is_nan());
+//! ```
+
+use num_traits::Float;
+
+use crate::{
+    bulirsch::{cel_pole_limit, BulirschConst},
+    domain::{self, Domain},
+    legendre::ellippiinc_pole_limit,
+};
+
+macro_rules! impl_raw {
+    ($fn:ident, [$($arg:ident),+], pole: $pole:expr $(, $bound:path)?) => {
+        #[doc = concat!["Computes [", stringify!($fn), "](crate::", stringify!($fn), "), returning NAN on invalid arguments and the signed limit at poles."]]
+        #[inline]
+        pub fn $fn<T: Float $(+ $bound)?>($($arg: T),+) -> T {
+            match domain::$fn($($arg),+) {
+                Domain::Valid | Domain::PrincipalValue => {
+                    crate::$fn($($arg),+).unwrap_or_else(|_| T::nan())
+                }
+                Domain::Pole(_) => $pole,
+                Domain::OutOfDomain(_) => T::nan(),
+            }
+        }
+    };
+    ($fn:ident, [$($arg:ident),+] $(, $bound:path)?) => {
+        impl_raw!($fn, [$($arg),+], pole: T::infinity() $(, $bound)?);
+    };
+}
+
+// Legendre's complete integrals
+impl_raw!(ellipk, [m]);
+impl_raw!(ellipe, [m]);
+impl_raw!(ellippi, [n, m], pole: ellippi_pole_limit(n));
+impl_raw!(ellipd, [m]);
+
+// Legendre's incomplete integrals
+impl_raw!(ellipf, [phi, m]);
+impl_raw!(ellipeinc, [phi, m]);
+impl_raw!(ellippiinc, [phi, n, m], pole: ellippiinc_pole_limit(phi));
+impl_raw!(
+    ellippiinc_bulirsch,
+    [phi, n, m],
+    pole: ellippiinc_pole_limit(phi),
+    BulirschConst<T>
+);
+impl_raw!(ellipdinc, [phi, m], pole: phi.signum() * T::infinity());
+
+// Bulirsch's integrals
+impl_raw!(cel, [kc, p, a, b], pole: cel_pole_limit(p, b), BulirschConst<T>);
+impl_raw!(cel1, [kc], BulirschConst<T>);
+impl_raw!(cel2, [kc, a, b], pole: cel_pole_limit(T::one(), b), BulirschConst<T>);
+impl_raw!(el1, [x, kc], BulirschConst<T>);
+impl_raw!(el2, [x, kc, a, b], BulirschConst<T>);
+impl_raw!(el3, [x, kc, p], pole: x.signum() * T::infinity(), BulirschConst<T>);
+
+// Carlson's symmetric integrals
+impl_raw!(elliprf, [x, y, z]);
+impl_raw!(elliprg, [x, y, z]);
+// The pole at p = 0 tends to +∞, and the pole at x = y = 0 takes the sign of p.
+impl_raw!(elliprj, [x, y, z, p], pole: if p.is_zero() { T::infinity() } else { p.signum() * T::infinity() });
+impl_raw!(elliprc, [x, y]);
+impl_raw!(elliprd, [x, y, z]);
+
+// Miscellaneous functions
+impl_raw!(jacobi_zeta, [phi, m]);
+impl_raw!(heuman_lambda, [phi, m]);
+impl_raw!(ellipk_ratio, [m]);
+
+/// Limit of [ellippi] at n = 1 or m = 1. The principal value for n > 1 tends to -∞ at m = 1,
+/// and is NAN at n = ∞.
+#[inline]
+fn ellippi_pole_limit<T: Float>(n: T) -> T {
+    if n <= T::one() {
+        T::infinity()
+    } else if n.is_finite() {
+        -T::infinity()
+    } else {
+        T::nan()
+    }
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::{consts::FRAC_PI_2, INFINITY, NAN};
+
+    #[test]
+    fn test_raw_valid() {
+        assert_eq!(ellipk(0.5), crate::ellipk(0.5).unwrap());
+        assert_eq!(ellipe(0.5), crate::ellipe(0.5).unwrap());
+        assert_eq!(ellippi(0.3, 0.5), crate::ellippi(0.3, 0.5).unwrap());
+        assert_eq!(ellipd(0.5), crate::ellipd(0.5).unwrap());
+        assert_eq!(ellipf(0.7, 0.5), crate::ellipf(0.7, 0.5).unwrap());
+        assert_eq!(ellipeinc(0.7, 0.5), crate::ellipeinc(0.7, 0.5).unwrap());
+        assert_eq!(
+            ellippiinc(0.7, 0.3, 0.5),
+            crate::ellippiinc(0.7, 0.3, 0.5).unwrap()
+        );
+        assert_eq!(
+            ellippiinc_bulirsch(0.7, 0.3, 0.5),
+            crate::ellippiinc_bulirsch(0.7, 0.3, 0.5).unwrap()
+        );
+        assert_eq!(ellipdinc(0.7, 0.5), crate::ellipdinc(0.7, 0.5).unwrap());
+        assert_eq!(
+            cel(0.5, 1.0, 1.0, 1.0),
+            crate::cel(0.5, 1.0, 1.0, 1.0).unwrap()
+        );
+        assert_eq!(cel1(0.5), crate::cel1(0.5).unwrap());
+        assert_eq!(cel2(0.5, 1.0, 1.0), crate::cel2(0.5, 1.0, 1.0).unwrap());
+        assert_eq!(el1(0.5, 0.5), crate::el1(0.5, 0.5).unwrap());
+        assert_eq!(
+            el2(0.5, 0.5, 1.0, 1.0),
+            crate::el2(0.5, 0.5, 1.0, 1.0).unwrap()
+        );
+        assert_eq!(el3(0.5, 0.5, 0.5), crate::el3(0.5, 0.5, 0.5).unwrap());
+        assert_eq!(
+            elliprf(1.0, 2.0, 3.0),
+            crate::elliprf(1.0, 2.0, 3.0).unwrap()
+        );
+        assert_eq!(
+            elliprg(1.0, 2.0, 3.0),
+            crate::elliprg(1.0, 2.0, 3.0).unwrap()
+        );
+        assert_eq!(
+            elliprj(1.0, 2.0, 3.0, 4.0),
+            crate::elliprj(1.0, 2.0, 3.0, 4.0).unwrap()
+        );
+        assert_eq!(elliprc(1.0, 2.0), crate::elliprc(1.0, 2.0).unwrap());
+        assert_eq!(
+            elliprd(1.0, 2.0, 3.0),
+            crate::elliprd(1.0, 2.0, 3.0).unwrap()
+        );
+        assert_eq!(jacobi_zeta(0.7, 0.5), crate::jacobi_zeta(0.7, 0.5).unwrap());
+        assert_eq!(
+            heuman_lambda(0.7, 0.5),
+            crate::heuman_lambda(0.7, 0.5).unwrap()
+        );
+        assert_eq!(ellipk_ratio(0.3), crate::ellipk_ratio(0.3).unwrap());
+    }
+
+    #[test]
+    fn test_raw_invalid() {
+        assert!(ellipk(2.0).is_nan());
+        assert!(ellipe(NAN).is_nan());
+        assert!(ellippi(0.5, 2.0).is_nan());
+        assert!(ellipd(2.0).is_nan());
+        assert!(ellipf(1.0, 3.0).is_nan());
+        assert!(ellipeinc(NAN, 0.5).is_nan());
+        assert!(ellippiinc(1.0, 0.5, 3.0).is_nan());
+        assert!(ellippiinc_bulirsch(1.0, 0.5, 3.0).is_nan());
+        assert!(ellipdinc(1.0, 3.0).is_nan());
+        assert!(cel(NAN, 1.0, 1.0, 1.0).is_nan());
+        assert!(cel1(NAN).is_nan());
+        assert!(cel2(0.5, NAN, 1.0).is_nan());
+        assert!(el1(NAN, 0.5).is_nan());
+        assert!(el2(0.5, 0.5, NAN, 1.0).is_nan());
+        assert!(el3(0.5, NAN, 0.5).is_nan());
+        assert!(elliprf(-1.0, 1.0, 1.0).is_nan());
+        assert!(elliprg(-1.0, 1.0, 1.0).is_nan());
+        assert!(elliprj(-1.0, 1.0, 1.0, 1.0).is_nan());
+        assert!(elliprc(-1.0, 1.0).is_nan());
+        assert!(elliprd(-1.0, 1.0, 1.0).is_nan());
+        assert!(jacobi_zeta(0.5, 2.0).is_nan());
+        assert!(heuman_lambda(0.5, 2.0).is_nan());
+        assert!(ellipk_ratio(2.0).is_nan());
+    }
+
+    #[test]
+    fn test_raw_poles() {
+        assert_eq!(ellipk(1.0), INFINITY);
+        assert_eq!(ellipd(1.0), INFINITY);
+        assert_eq!(ellippi(1.0, 0.5), INFINITY);
+        assert_eq!(cel1(0.0), INFINITY);
+        assert_eq!(elliprc(1.0, 0.0), INFINITY);
+        assert_eq!(elliprd(0.0, 0.0, 1.0), INFINITY);
+        assert_eq!(elliprj(1.0, 1.0, 1.0, 0.0), INFINITY);
+        assert_eq!(ellipk_ratio(1.0), INFINITY);
+
+        // Negative poles
+        assert_eq!(cel(0.0, 1.0, 1.0, -1.0), -INFINITY);
+        assert_eq!(cel2(0.0, 1.0, -1.0), -INFINITY);
+        assert_eq!(ellippiinc(-FRAC_PI_2, 1.0, 0.5), -INFINITY);
+        assert_eq!(ellippiinc_bulirsch(-FRAC_PI_2, 1.0, 0.5), -INFINITY);
+        assert_eq!(el3(-2.0, 0.5, -0.25), -INFINITY);
+        assert_eq!(elliprj(0.0, 0.0, 1.0, -1.0), -INFINITY);
+
+        // Poles of unknown sign
+        assert!(cel(0.0, 1.0, 1.0, 0.0).is_nan());
+        assert!(cel2(0.0, 1.0, 0.0).is_nan());
+    }
+
+    /// Asserts that the raw function gives NAN out of the domain and agrees with the function
+    /// under [IeeePolicy] otherwise.
+    macro_rules! agree {
+        ($fn:ident, $fn_with_policy:ident, [$($arg:expr),+]) => {{
+            let args = [$($arg),+];
+            let raw = $fn($($arg),+);
+            let ieee = policy::$fn_with_policy::<f64, IeeePolicy>($($arg),+).unwrap();
+            let ok = match domain::$fn($($arg),+) {
+                Domain::OutOfDomain(_) => raw.is_nan(),
+                _ => raw == ieee || raw.is_nan() && ieee.is_nan(),
+            };
+            assert!(
+                ok,
+                "{}: args = {args:?}, raw = {raw:?}, ieee = {ieee:?}",
+                stringify!($fn)
+            );
+        }};
+    }
+
+    #[test]
+    fn test_raw_ieee() {
+        use crate::policy::{self, IeeePolicy};
+        use itertools::iproduct;
+
+        const VALUES: [f64; 10] = [-3.0, -1.0, -0.5, 0.0, 0.3, 0.5, 1.0, 1.5, 4.0, NAN];
+        const PHIS: [f64; 10] = [
+            -INFINITY, -2.0, -FRAC_PI_2, 0.0, 0.3, FRAC_PI_2, 2.0, 1e20, INFINITY, NAN,
+        ];
+
+        for m in VALUES {
+            agree!(ellipk, ellipk_with_policy, [m]);
+            agree!(ellipe, ellipe_with_policy, [m]);
+            agree!(ellipd, ellipd_with_policy, [m]);
+            agree!(cel1, cel1_with_policy, [m]);
+            agree!(ellipk_ratio, ellipk_ratio_with_policy, [m]);
+        }
+        for (x, y) in iproduct!(VALUES, VALUES) {
+            agree!(ellippi, ellippi_with_policy, [x, y]);
+            agree!(el1, el1_with_policy, [x, y]);
+            agree!(elliprc, elliprc_with_policy, [x, y]);
+        }
+        for (phi, m) in iproduct!(PHIS, VALUES) {
+            agree!(ellipf, ellipf_with_policy, [phi, m]);
+            agree!(ellipeinc, ellipeinc_with_policy, [phi, m]);
+            agree!(ellipdinc, ellipdinc_with_policy, [phi, m]);
+            agree!(jacobi_zeta, jacobi_zeta_with_policy, [phi, m]);
+            agree!(heuman_lambda, heuman_lambda_with_policy, [phi, m]);
+        }
+        for (phi, n, m) in iproduct!(PHIS, VALUES, VALUES) {
+            agree!(ellippiinc, ellippiinc_with_policy, [phi, n, m]);
+            agree!(
+                ellippiinc_bulirsch,
+                ellippiinc_bulirsch_with_policy,
+                [phi, n, m]
+            );
+        }
+        for (x, y, z) in iproduct!(VALUES, VALUES, VALUES) {
+            agree!(cel2, cel2_with_policy, [x, y, z]);
+            agree!(el3, el3_with_policy, [x, y, z]);
+            agree!(elliprf, elliprf_with_policy, [x, y, z]);
+            agree!(elliprg, elliprg_with_policy, [x, y, z]);
+            agree!(elliprd, elliprd_with_policy, [x, y, z]);
+        }
+        for (x, y, z, p) in iproduct!(VALUES, VALUES, VALUES, VALUES) {
+            agree!(cel, cel_with_policy, [x, y, z, p]);
+            agree!(el2, el2_with_policy, [x, y, z, p]);
+            agree!(elliprj, elliprj_with_policy, [x, y, z, p]);
+        }
+    }
+
+    #[test]
+    fn test_raw_f32() {
+        assert_eq!(ellipk(0.5f32), crate::ellipk(0.5f32).unwrap());
+        assert!(ellipk(2.0f32).is_nan());
+    }
+}
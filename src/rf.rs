@@ -0,0 +1,602 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Characteristic impedances and effective permittivities of planar transmission lines.
+//!
+//! Conformal mapping reduces the quasi-static capacitance of thin coplanar conductors to the
+//! ratio of complete elliptic integrals K(k) / K(k'). For the coplanar waveguide of strip width
+//! w and slot width g on a substrate of thickness h and relative permittivity εr
+//! (Wen, 1969; Simons, 2001, §2.2)
+//! ```text
+//!          η₀      K(k₀')                 εr - 1   K(k₁) K(k₀')
+//! Z₀  =  ────── ────────,   εeff = 1 + ────── ─────────────
+//!        4√εeff   K(k₀)                   2    K(k₁') K(k₀)
+//! ```
+//! with k₀ = w / (w + 2g) and k₁ = sinh(πw/4h) / sinh(π(w + 2g)/4h), where η₀ is the
+//! impedance of free space. The moduli tend to 0 for narrow strips and thin substrates and to 1
+//! for narrow slots, where K(k) or K(k') has a logarithmic singularity. Here, the logarithms of
+//! both k² and the complementary k'² are formed from the geometry without cancellation or
+//! underflow, and the ratio is evaluated by [ellipk_ratio] from whichever of them is smaller.
+//!
+//! The conductors are infinitely thin and the substrate is infinitely wide. All functions
+//! return the impedance in ohms; the lengths may be in any common unit.
+//!
+//! # Examples
+//! ```
+//! use ellip::{rf::*, util::assert_close};
+//!
+//! // 10 µm strip with 5 µm slots on 500 µm silicon
+//! let (z0, eps) = coplanar_waveguide(10.0, 5.0, 500.0, 11.9).unwrap();
+//! assert_close(z0, 47.44201344009144, 1e-14);
+//! assert_close(eps, 6.449612687932952, 1e-14);
+//!
+//! // Without the substrate, εeff = 1 and Z₀ = η₀ K(k) / K(k') for the strips.
+//! let (z0, eps) = coplanar_strips(1.0, 1.0, 1.0, 1.0).unwrap();
+//! assert_close(z0, 240.96830648533589, 1e-14);
+//! assert_eq!(eps, 1.0);
+//! ```
+//!
+//! # References
+//! - Wen, Cheng P. “Coplanar Waveguide: A Surface Strip Transmission Line Suitable for Nonreciprocal Gyromagnetic Device Applications.” IEEE Transactions on Microwave Theory and Techniques 17, no. 12 (1969): 1087–90. <https://doi.org/10.1109/TMTT.1969.1127105>.
+//! - Ghione, G., and C. Naldi. “Parameters of Coplanar Waveguides with Lower Ground Plane.” Electronics Letters 19, no. 18 (1983): 734–35. <https://doi.org/10.1049/el:19830500>.
+//! - Simons, Rainee N. Coplanar Waveguide Circuits, Components, and Systems. New York: Wiley, 2001. <https://doi.org/10.1002/0471224665>.
+//! - Cohn, Seymour B. “Shielded Coupled-Strip Transmission Line.” IRE Transactions on Microwave Theory and Techniques 3, no. 5 (1955): 29–38. <https://doi.org/10.1109/TMTT.1955.1124973>.
+
+use num_traits::Float;
+
+use crate::{crate_util::check, ellipk_ratio, error::err, EllipError};
+
+/// Impedance of free space η₀ = μ₀c in ohms (CODATA 2018).
+const FREE_SPACE_IMPEDANCE: f64 = 376.730313668;
+
+/// Validates the dimensions and the relative permittivity of the line.
+macro_rules! check_line {
+    ($fn_name:ident, [$($var:ident),+], $permittivity:ident) => {
+        check!(@nan, $fn_name, [$($var),+, $permittivity]);
+        check!(@inf, $fn_name, [$($var),+, $permittivity]);
+        $(
+            if $var <= T::zero() {
+                return err!(
+                    DomainError,
+                    $fn_name,
+                    concat![stringify!($var), " must be positive."],
+                    $var
+                );
+            }
+        )+
+        if $permittivity < T::one() {
+            return err!(
+                DomainError,
+                $fn_name,
+                "permittivity must not be less than 1.",
+                $permittivity
+            );
+        }
+    };
+}
+
+/// Computes the characteristic impedance and the effective permittivity of the coplanar waveguide.
+/// ```text
+///          η₀      K(k₀')                 εr - 1   K(k₁) K(k₀')
+/// Z₀  =  ────── ────────,   εeff = 1 + ────── ─────────────
+///        4√εeff   K(k₀)                   2    K(k₁') K(k₀)
+/// ```
+///
+/// The center strip of width w lies between two semi-infinite ground planes at the slot width
+/// g, on a substrate of thickness h without a lower ground plane. The moduli are
+/// k₀ = w / (w + 2g) and k₁ = sinh(πw/4h) / sinh(π(w + 2g)/4h).
+///
+/// ## Parameters
+/// - width: width of the center strip (w). w ∈ ℝ, w > 0.
+/// - gap: width of the slots (g). g ∈ ℝ, g > 0.
+/// - height: thickness of the substrate (h). h ∈ ℝ, h > 0.
+/// - permittivity: relative permittivity of the substrate (εr). εr ∈ ℝ, εr ≥ 1.
+///
+/// ## Returns
+/// The characteristic impedance Z₀ in ohms and the effective permittivity εeff.
+///
+/// ## Domain
+/// - Returns error if width, gap, or height are not positive or are infinite.
+/// - Returns error if permittivity < 1 or is infinite.
+///
+/// ## Special Cases
+/// - εeff → (εr + 1) / 2 as h → ∞.
+/// - εeff = 1 when εr = 1.
+///
+/// # Examples
+/// ```
+/// use ellip::{rf::coplanar_waveguide, util::assert_close};
+///
+/// let (z0, eps) = coplanar_waveguide(3.0, 1.0, 1000.0, 4.4).unwrap();
+/// assert_close(z0, 65.32397148391923, 1e-14);
+/// assert_close(eps, 2.6999980352720825, 1e-14);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn coplanar_waveguide<T: Float>(
+    width: T,
+    gap: T,
+    height: T,
+    permittivity: T,
+) -> Result<(T, T), EllipError> {
+    check_line!(coplanar_waveguide, [width, gap, height], permittivity);
+    let (ln_m0, ln_mc0) = linear_moduli(width, gap);
+    let (a, d) = slot_angles(width, gap, height);
+    let (ln_m1, ln_mc1) = sinh_moduli(a, d);
+
+    let r0 = ratio(ln_m0, ln_mc0)?;
+    let eps = 1.0 + (permittivity - 1.0) / 2.0 * ratio(ln_m1, ln_mc1)? / r0;
+    Ok((eta0::<T>() / (4.0 * eps.sqrt() * r0), eps))
+}
+
+/// Computes the characteristic impedance and the effective permittivity of the grounded coplanar waveguide.
+/// ```text
+///                η₀                1
+/// Z₀  =  ────────── ──────────────────────────,   q = K(k₃) K(k₀') / (K(k₃') K(k₀))
+///        2√εeff     K(k₀)/K(k₀') + K(k₃)/K(k₃')
+///
+///        1 + εr q
+/// εeff = ────────
+///         1 + q
+/// ```
+///
+/// The coplanar waveguide of [coplanar_waveguide] is backed by a lower ground plane under the
+/// substrate (Ghione and Naldi, 1983). The moduli are k₀ = w / (w + 2g) and
+/// k₃ = tanh(πw/4h) / tanh(π(w + 2g)/4h). As the substrate thins, the line becomes a
+/// parallel-plate line to the lower ground plane and εeff → εr.
+///
+/// ## Parameters
+/// - width: width of the center strip (w). w ∈ ℝ, w > 0.
+/// - gap: width of the slots (g). g ∈ ℝ, g > 0.
+/// - height: thickness of the substrate (h). h ∈ ℝ, h > 0.
+/// - permittivity: relative permittivity of the substrate (εr). εr ∈ ℝ, εr ≥ 1.
+///
+/// ## Returns
+/// The characteristic impedance Z₀ in ohms and the effective permittivity εeff.
+///
+/// ## Domain
+/// - Returns error if width, gap, or height are not positive or are infinite.
+/// - Returns error if permittivity < 1 or is infinite.
+///
+/// ## Special Cases
+/// - Tends to [coplanar_waveguide] as h → ∞.
+/// - εeff = 1 when εr = 1.
+///
+/// # Examples
+/// ```
+/// use ellip::{rf::grounded_coplanar_waveguide, util::assert_close};
+///
+/// let (z0, eps) = grounded_coplanar_waveguide(100.0, 10.0, 1.0, 4.4).unwrap();
+/// assert_close(z0, 1.7546549583388291, 1e-14);
+/// assert_close(eps, 4.3207435974163673, 1e-14);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn grounded_coplanar_waveguide<T: Float>(
+    width: T,
+    gap: T,
+    height: T,
+    permittivity: T,
+) -> Result<(T, T), EllipError> {
+    check_line!(
+        grounded_coplanar_waveguide,
+        [width, gap, height],
+        permittivity
+    );
+    let (ln_m0, ln_mc0) = linear_moduli(width, gap);
+    let (a, d) = slot_angles(width, gap, height);
+    let (ln_m3, ln_mc3) = tanh_moduli(a, d);
+
+    let r0 = ratio(ln_m0, ln_mc0)?;
+    let r3 = ratio(ln_m3, ln_mc3)?;
+    let q = r3 / r0;
+    let eps = (1.0 + permittivity * q) / (1.0 + q);
+    Ok((eta0::<T>() / (2.0 * eps.sqrt() * (r0 + r3)), eps))
+}
+
+/// Computes the characteristic impedance and the effective permittivity of the coplanar strips.
+/// ```text
+///          η₀   K(k)                 εr - 1   K(k₁) K(k')
+/// Z₀  =  ───── ─────,   εeff = 1 + ────── ────────────
+///        √εeff  K(k')                  2    K(k₁') K(k)
+/// ```
+///
+/// Two strips of width w are separated by the gap s on a substrate of thickness h. The strips
+/// are the dual of the slots of [coplanar_waveguide], with k = s / (s + 2w) and
+/// k₁ = sinh(πs/4h) / sinh(π(s + 2w)/4h).
+///
+/// ## Parameters
+/// - width: width of the strips (w). w ∈ ℝ, w > 0.
+/// - gap: separation of the strips (s). s ∈ ℝ, s > 0.
+/// - height: thickness of the substrate (h). h ∈ ℝ, h > 0.
+/// - permittivity: relative permittivity of the substrate (εr). εr ∈ ℝ, εr ≥ 1.
+///
+/// ## Returns
+/// The characteristic impedance Z₀ in ohms and the effective permittivity εeff.
+///
+/// ## Domain
+/// - Returns error if width, gap, or height are not positive or are infinite.
+/// - Returns error if permittivity < 1 or is infinite.
+///
+/// ## Special Cases
+/// - εeff → (εr + 1) / 2 as h → ∞.
+/// - εeff = 1 when εr = 1.
+///
+/// # Examples
+/// ```
+/// use ellip::{rf::coplanar_strips, util::assert_close};
+///
+/// let (z0, eps) = coplanar_strips(10.0, 5.0, 500.0, 11.9).unwrap();
+/// assert_close(z0, 78.04757444039338, 1e-14);
+/// assert_close(eps, 6.449540342326476, 1e-14);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn coplanar_strips<T: Float>(
+    width: T,
+    gap: T,
+    height: T,
+    permittivity: T,
+) -> Result<(T, T), EllipError> {
+    check_line!(coplanar_strips, [width, gap, height], permittivity);
+    let (ln_m, ln_mc) = linear_moduli(gap, width);
+    let (a, d) = slot_angles(gap, width, height);
+    let (ln_m1, ln_mc1) = sinh_moduli(a, d);
+
+    let r = ratio(ln_m, ln_mc)?;
+    let eps = 1.0 + (permittivity - 1.0) / 2.0 * ratio(ln_m1, ln_mc1)? / r;
+    Ok((eta0::<T>() * r / eps.sqrt(), eps))
+}
+
+/// Computes the even- and odd-mode characteristic impedances of the edge-coupled striplines.
+/// ```text
+///           η₀   K(kₑ')                  η₀   K(kₒ')
+/// Z₀ₑ  =  ───── ───────,     Z₀ₒ  =  ───── ───────
+///         4√εr   K(kₑ)                   4√εr   K(kₒ)
+///
+/// kₑ = tanh(πw/2b) tanh(π(w + s)/2b),   kₒ = tanh(πw/2b) coth(π(w + s)/2b)
+/// ```
+///
+/// Two strips of width w at the separation s lie midway between the ground planes at the
+/// spacing b, in a homogeneous dielectric (Cohn, 1955). Both modes are TEM, so the effective
+/// permittivity is εr. The strips decouple as s → ∞, where Z₀ₑ and Z₀ₒ tend to the
+/// impedance of the single stripline.
+///
+/// ## Parameters
+/// - width: width of the strips (w). w ∈ ℝ, w > 0.
+/// - gap: separation of the strips (s). s ∈ ℝ, s > 0.
+/// - spacing: spacing of the ground planes (b). b ∈ ℝ, b > 0.
+/// - permittivity: relative permittivity of the dielectric (εr). εr ∈ ℝ, εr ≥ 1.
+///
+/// ## Returns
+/// The even-mode impedance Z₀ₑ and the odd-mode impedance Z₀ₒ in ohms, and the effective
+/// permittivity εeff.
+///
+/// ## Domain
+/// - Returns error if width, gap, or spacing are not positive or are infinite.
+/// - Returns error if permittivity < 1 or is infinite.
+///
+/// # Examples
+/// ```
+/// use ellip::{rf::coupled_striplines, util::assert_close};
+///
+/// let (z_even, z_odd, eps) = coupled_striplines(1.0, 0.2, 2.0, 2.2).unwrap();
+/// assert_close(z_even, 82.84949860421576, 1e-14);
+/// assert_close(z_odd, 47.10370877643718, 1e-14);
+/// assert_eq!(eps, 2.2);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn coupled_striplines<T: Float>(
+    width: T,
+    gap: T,
+    spacing: T,
+    permittivity: T,
+) -> Result<(T, T, T), EllipError> {
+    check_line!(coupled_striplines, [width, gap, spacing], permittivity);
+    let a = pi!() * width / (2.0 * spacing);
+    let d = pi!() * gap / (2.0 * spacing);
+    let (ln_me, ln_mce) = tanh_product_moduli(a, d);
+    let (ln_mo, ln_mco) = tanh_moduli(a, d);
+
+    let scale = eta0::<T>() / (4.0 * permittivity.sqrt());
+    Ok((
+        scale / ratio(ln_me, ln_mce)?,
+        scale / ratio(ln_mo, ln_mco)?,
+        permittivity,
+    ))
+}
+
+/// Impedance of free space η₀.
+#[inline]
+fn eta0<T: Float>() -> T {
+    T::from(FREE_SPACE_IMPEDANCE).unwrap()
+}
+
+/// Computes K(k) / K(k') from ln k² and ln k'², using the smaller of k² and k'².
+///
+/// The logarithms keep the small parameter when it underflows, where
+/// K(k) / K(k') = π / ln(16 / k²) to machine precision.
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn ratio<T: Float>(ln_m: T, ln_mc: T) -> Result<T, EllipError> {
+    let small = |ln_m: T| {
+        if ln_m < epsilon!().ln() {
+            Ok(pi!() / (16.0.ln() - ln_m))
+        } else {
+            ellipk_ratio(ln_m.exp())
+        }
+    };
+    if ln_m <= ln_mc {
+        small(ln_m)
+    } else {
+        Ok(1.0 / small(ln_mc)?)
+    }
+}
+
+/// Angle πa/4h and the increment πb/2h to the angle π(a + 2b)/4h of the sinh and tanh moduli.
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn slot_angles<T: Float>(a: T, b: T, height: T) -> (T, T) {
+    (pi!() * a / (4.0 * height), pi!() * b / (2.0 * height))
+}
+
+/// Computes ln k² and ln k'² of k = a / (a + 2b).
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn linear_moduli<T: Float>(a: T, b: T) -> (T, T) {
+    let d = a + 2.0 * b;
+    (2.0 * (a / d).ln(), (4.0 * b / d).ln() + ((a + b) / d).ln())
+}
+
+/// Computes ln k² and ln k'² of k = sinh(a) / sinh(a + d) for a, d > 0.
+///
+/// The hyperbolic functions are written in e⁻²ᵃ and e⁻²ᵇ with b = a + d to avoid overflow for
+/// wide lines on thin substrates. The increment d is passed separately, since b - a loses its
+/// digits for narrow slots.
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn sinh_moduli<T: Float>(a: T, d: T) -> (T, T) {
+    let b = a + d;
+    let eb = (-2.0 * b).exp_m1();
+    (
+        2.0 * ((-2.0 * a).exp_m1() / eb).ln() - 2.0 * d,
+        ((-2.0 * d).exp_m1() / eb).ln() + ((-2.0 * (a + b)).exp_m1() / eb).ln(),
+    )
+}
+
+/// Computes ln k² and ln k'² of k = tanh(a) / tanh(a + d) for a, d > 0.
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn tanh_moduli<T: Float>(a: T, d: T) -> (T, T) {
+    let b = a + d;
+    let (u, v) = ((-2.0 * a).exp(), (-2.0 * b).exp());
+    let eb = (-2.0 * b).exp_m1();
+    (
+        2.0 * ((-2.0 * a).exp_m1() / eb * (1.0 + v) / (1.0 + u)).ln(),
+        4.0.ln() - 2.0 * a - 2.0 * u.ln_1p()
+            + ((-2.0 * d).exp_m1() / eb).ln()
+            + ((-2.0 * (a + b)).exp_m1() / eb).ln(),
+    )
+}
+
+/// Computes ln k² and ln k'² of k = tanh(a) tanh(a + d) for a, d > 0.
+#[inline]
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn tanh_product_moduli<T: Float>(a: T, d: T) -> (T, T) {
+    let b = a + d;
+    let (u, v) = ((-2.0 * a).exp(), (-2.0 * b).exp());
+    (
+        2.0 * ((-2.0 * a).exp_m1() * (-2.0 * b).exp_m1() / ((1.0 + u) * (1.0 + v))).ln(),
+        4.0.ln() - 2.0 * a - 2.0 * (u.ln_1p() + v.ln_1p())
+            + (-2.0 * d).exp().ln_1p()
+            + (u * v).ln_1p(),
+    )
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+
+    type LineFn = fn(f64, f64, f64, f64) -> Result<(f64, f64), EllipError>;
+
+    #[test]
+    fn test_coplanar_lines() {
+        // mpmath references with K(k) / K(k') evaluated at 500 digits
+        let cases: [(LineFn, [f64; 4], f64, f64); 15] = [
+            (
+                coplanar_waveguide,
+                [10.0, 5.0, 500.0, 11.9],
+                47.442013440091438,
+                6.4496126879329519,
+            ),
+            (
+                coplanar_waveguide,
+                [1.0, 1e-6, 1.0, 9.8],
+                8.4166183074078691,
+                5.3478013315882044,
+            ),
+            (
+                coplanar_waveguide,
+                [1e-6, 1.0, 1.0, 9.8],
+                414.09933969825893,
+                5.2967661073934285,
+            ),
+            (
+                coplanar_waveguide,
+                [100.0, 10.0, 1.0, 4.4],
+                73.60255053011603,
+                1.12975781340405,
+            ),
+            (
+                coplanar_waveguide,
+                [1.0, 1.0, 1e6, 2.2],
+                116.40757074900932,
+                1.5999999999997869,
+            ),
+            (
+                grounded_coplanar_waveguide,
+                [10.0, 5.0, 500.0, 11.9],
+                47.435793572216394,
+                6.4503872963136843,
+            ),
+            (
+                grounded_coplanar_waveguide,
+                [1.0, 1e-6, 1.0, 9.8],
+                8.2304476916045794,
+                5.4545816483393346,
+            ),
+            (
+                grounded_coplanar_waveguide,
+                [1e-6, 1.0, 1.0, 9.8],
+                400.26308574990746,
+                5.4757550575220192,
+            ),
+            (
+                grounded_coplanar_waveguide,
+                [100.0, 10.0, 1.0, 4.4],
+                1.7546549583388291,
+                4.3207435974163673,
+            ),
+            (
+                grounded_coplanar_waveguide,
+                [1.0, 1.0, 1e6, 2.2],
+                116.40757074895243,
+                1.6000000000002133,
+            ),
+            (
+                coplanar_strips,
+                [10.0, 5.0, 500.0, 11.9],
+                78.047574440393379,
+                6.4495403423264762,
+            ),
+            (
+                coplanar_strips,
+                [1.0, 1e-6, 1.0, 9.8],
+                16.176546448941628,
+                5.2967661073934285,
+            ),
+            (
+                coplanar_strips,
+                [1e-6, 1.0, 1.0, 9.8],
+                788.29404462028422,
+                5.3478013315882044,
+            ),
+            (
+                coplanar_strips,
+                [100.0, 10.0, 1.0, 4.4],
+                130.50875285258034,
+                1.0475270872655906,
+            ),
+            (
+                coplanar_strips,
+                [1.0, 1.0, 1e6, 2.2],
+                190.50217310180891,
+                1.5999999999997869,
+            ),
+        ];
+        for (f, [w, g, h, er], z0, eps) in cases {
+            let (z, e) = f(w, g, h, er).unwrap();
+            assert_close!(z0, z, 5e-15);
+            assert_close!(eps, e, 5e-15);
+        }
+    }
+
+    #[test]
+    fn test_coupled_striplines() {
+        // mpmath references with K(k) / K(k') evaluated at 500 digits
+        let cases = [
+            ([1.0, 0.2, 2.0, 2.2], 82.849498604215763, 47.103708776437178),
+            ([0.5, 1e-6, 1.0, 1.0], 130.70715950864301, 18.98671954970603),
+            (
+                [10.0, 0.1, 1.0, 4.4],
+                4.3733307415288391,
+                4.1349817824727411,
+            ),
+            (
+                [1e-3, 1.0, 1.0, 2.2],
+                320.50890656588044,
+                313.53898291169997,
+            ),
+            (
+                [1.0, 30.0, 1.0, 1.0],
+                65.353625145771003,
+                65.353625145771003,
+            ),
+        ];
+        for ([w, s, b, er], z_even, z_odd) in cases {
+            let (ze, zo, eps) = coupled_striplines(w, s, b, er).unwrap();
+            assert_close!(z_even, ze, 5e-15);
+            assert_close!(z_odd, zo, 5e-15);
+            assert_eq!(eps, er);
+        }
+    }
+
+    #[test]
+    fn test_rf_limits() {
+        // Free space: εeff = 1
+        let (_, eps) = coplanar_waveguide(1.0, 2.0, 3.0, 1.0).unwrap();
+        assert_close!(1.0, eps, 1e-15);
+        let (_, eps) = grounded_coplanar_waveguide(1.0, 2.0, 3.0, 1.0).unwrap();
+        assert_close!(1.0, eps, 1e-15);
+        // Square CPW in free space: k₀ = 1/√2 gives Z₀ = η₀ / 4
+        let w = 2.0 / (2.0.sqrt() - 1.0);
+        let (z0, _) = coplanar_waveguide(w, 1.0, 1e300, 1.0).unwrap();
+        assert_close!(FREE_SPACE_IMPEDANCE / 4.0, z0, 1e-14);
+        // Thick substrate: εeff → (εr + 1) / 2
+        let (_, eps) = coplanar_strips(1.0, 1.0, 1e12, 9.0).unwrap();
+        assert_close!(5.0, eps, 1e-12);
+        // Grounded CPW on a thin substrate approaches the parallel-plate line.
+        let (z0, eps) = grounded_coplanar_waveguide(1.0, 1.0, 1e-6, 4.0).unwrap();
+        assert_close!(FREE_SPACE_IMPEDANCE * 1e-6 / 2.0, z0, 1e-5);
+        assert_close!(4.0, eps, 1e-5);
+
+        // mpmath references with K(k) / K(k') evaluated at 3000 digits, where k² or k'² underflows
+        let (z0, eps) = grounded_coplanar_waveguide(1.0, 1.0, 1e-3, 4.0).unwrap();
+        assert_close!(0.18804886503503797, z0, 5e-15);
+        assert_close!(3.9961704939279236, eps, 5e-15);
+        let (z0, eps) = coplanar_strips(1000.0, 1.0, 1.0, 4.4).unwrap();
+        assert_close!(65.524273715346986, z0, 5e-15);
+        assert_close!(1.0097169499363521, eps, 5e-15);
+        let (z0, eps) = coplanar_waveguide(1.0, 1000.0, 1.0, 4.4).unwrap();
+        assert_close!(536.28948328495713, z0, 5e-15);
+        assert_close!(1.0097169499363521, eps, 5e-15);
+        let (z_even, z_odd, _) = coupled_striplines(300.0, 1.0, 1.0, 1.0).unwrap();
+        assert_close!(0.31349487944568264, z_even, 5e-15);
+        assert_close!(0.31346615677207168, z_odd, 5e-15);
+    }
+
+    #[test]
+    fn test_rf_err() {
+        use std::f64::{INFINITY, NAN};
+
+        assert_eq!(
+            coplanar_waveguide(0.0, 1.0, 1.0, 4.0).unwrap_err(),
+            "coplanar_waveguide: width must be positive."
+        );
+        assert_eq!(
+            grounded_coplanar_waveguide(1.0, -1.0, 1.0, 4.0).unwrap_err(),
+            "grounded_coplanar_waveguide: gap must be positive."
+        );
+        assert_eq!(
+            coplanar_strips(1.0, 1.0, 0.0, 4.0).unwrap_err(),
+            "coplanar_strips: height must be positive."
+        );
+        assert_eq!(
+            coupled_striplines(1.0, 1.0, 1.0, 0.5).unwrap_err(),
+            "coupled_striplines: permittivity must not be less than 1."
+        );
+        assert_eq!(
+            coplanar_waveguide(1.0, 1.0, INFINITY, 4.0).unwrap_err(),
+            "coplanar_waveguide: height cannot be infinite."
+        );
+        assert_eq!(
+            coupled_striplines(1.0, 1.0, 1.0, INFINITY).unwrap_err(),
+            "coupled_striplines: permittivity cannot be infinite."
+        );
+        assert_eq!(
+            coplanar_strips(NAN, 1.0, 1.0, 4.0).unwrap_err(),
+            "coplanar_strips: Arguments cannot be NAN."
+        );
+    }
+}
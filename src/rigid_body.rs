@@ -0,0 +1,654 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Exact torque-free rotation of an asymmetric rigid body.
+//!
+//! In the frame of the principal axes with the moments of inertia I₁ < I₂ < I₃, the angular
+//! velocity ω follows Euler's equations
+//! ```text
+//! I₁ω₁' = (I₂ - I₃) ω₂ω₃,   I₂ω₂' = (I₃ - I₁) ω₃ω₁,   I₃ω₃' = (I₁ - I₂) ω₁ω₂
+//! ```
+//! which conserve the energy 2E = I₁ω₁² + I₂ω₂² + I₃ω₃² and the angular momentum
+//! M² = I₁²ω₁² + I₂²ω₂² + I₃²ω₃². The tip of ω traces a closed polhode about the axis of the
+//! largest or the smallest moment, and the angular velocity is given by the Jacobi elliptic
+//! functions of λt + u₀ (Landau and Lifshitz, 1976, §37):
+//! ```text
+//! About I₃ (M² > 2EI₂):   ω = (A₁ cn, ±A₂ sn, ±A₃ dn)
+//! About I₁ (M² < 2EI₂):   ω = (±A₁ dn, ±A₂ sn, A₃ cn)
+//! Separatrix (M² = 2EI₂): ω = (A₁ sech, A₂ tanh, A₃ sech), up to the signs
+//! ```
+//! The parameter m and the complementary parameter
+//! ```text
+//!          (I₃ - I₁)(M² - 2EI₂)
+//! 1 - m  = ────────────────────     about I₃
+//!          (I₃ - I₂)(M² - 2EI₁)
+//! ```
+//! are formed from the sums of positive terms in ω, and the elliptic functions are evaluated
+//! by [EllipticModulus::from_kc], so the motion remains accurate near the separatrix, where
+//! the rotation about the intermediate axis is unstable. The period of ω is T = 4K(m) / λ.
+//!
+//! With the angular momentum along the fixed Z-axis and the Euler angles (φ, θ, ψ) of the
+//! body axis a of the polhode (a = 3, or a = 1 about I₁), the nutation and the spin follow
+//! from ω by cos θ = Iₐωₐ / M and the ratio of the other two components of Iω. The precession
+//! angle is
+//! ```text
+//!        M       M (Iₐ - I_c)                                      Iₐ (I₂ - I_c)
+//! φ  =  ── t  +  ──────────── [Π(n, am(λt + u₀)) - Π(n, am(u₀))],   n = - ─────────────
+//!       Iₐ          IₐI_c λ                                           I_c (Iₐ - I₂)
+//! ```
+//! where c is the other end axis and Π is [ellippiinc](crate::ellippiinc) with the parameter m.
+//!
+//! # Examples
+//! ```
+//! use ellip::{rigid_body::{FreeRigidBody, Polhode}, util::assert_close};
+//!
+//! let body = FreeRigidBody::new([1.0, 2.0, 3.0], [0.1, 0.2, 1.0]).unwrap();
+//! assert_eq!(body.polhode(), Polhode::Major);
+//! assert_close(body.period(), 6.2676168059805475, 1e-15);
+//!
+//! // ω returns after one period.
+//! let [w1, w2, w3] = body.angular_velocity(body.period());
+//! assert_close(w1, 0.1, 1e-14);
+//! assert_close(w2, 0.2, 1e-14);
+//! assert_close(w3, 1.0, 1e-14);
+//! ```
+//!
+//! # References
+//! - Landau, L. D., and E. M. Lifshitz. Mechanics. 3rd ed. Course of Theoretical Physics 1. Oxford: Pergamon Press, 1976.
+//! - Reinhardt, W. P., and P. L. Walker. “DLMF: Chapter 22 Jacobian Elliptic Functions.” Accessed August 31, 2025. <https://dlmf.nist.gov/22.19>.
+
+use num_traits::Float;
+
+use crate::{crate_util::pow2_scale, error::err, raw, EllipError, EllipticModulus};
+
+/// Axis about which the polhode closes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Polhode {
+    /// About the axis of the largest moment I₃, for M² > 2EI₂.
+    Major,
+    /// About the axis of the smallest moment I₁, for M² < 2EI₂.
+    Minor,
+    /// Asymptotic approach to the rotation about the intermediate axis, for M² = 2EI₂.
+    Separatrix,
+}
+
+/// Torque-free asymmetric rigid body, see [mod@crate::rigid_body].
+///
+/// The phase, the period, and the constants of the precession are computed at construction.
+/// The methods return NAN for NAN arguments.
+///
+/// ## Parameters
+/// - inertia: principal moments of inertia (I₁, I₂, I₃). 0 < I₁ < I₂ < I₃, Iᵢ is finite.
+/// - omega: initial angular velocity in the principal axes (ω(0)). ωᵢ ∈ ℝ, ωᵢ is finite.
+#[derive(Debug, Clone, Copy)]
+pub struct FreeRigidBody<T: Float> {
+    inertia: [T; 3],
+    energy: T,
+    momentum: T,
+    polhode: Polhode,
+    /// Amplitudes multiplied by the signs of the components
+    amplitudes: [T; 3],
+    lambda: T,
+    /// Phase at t = 0.
+    u0: T,
+    period: T,
+    /// Characteristic n and the coefficient of Π in the precession
+    n: T,
+    coefficient: T,
+    /// Π(n, am(u₀))
+    pi0: T,
+    modulus: Option<EllipticModulus<T>>,
+}
+
+impl<T: Float> FreeRigidBody<T> {
+    /// Constructs the body with the principal moments of inertia and the initial angular
+    /// velocity.
+    ///
+    /// ## Domain
+    /// - Returns error unless 0 < I₁ < I₂ < I₃.
+    /// - Returns error if any argument is infinite or NAN.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn new(inertia: [T; 3], omega: [T; 3]) -> Result<Self, EllipError> {
+        let [i1, i2, i3] = inertia;
+        let [w1, w2, w3] = omega;
+        if inertia.iter().chain(omega.iter()).any(|x| x.is_nan()) {
+            return err!(
+                NanArgument,
+                FreeRigidBody,
+                "Arguments cannot be NAN.",
+                [i1, i2, i3, w1, w2, w3]
+            );
+        }
+        if inertia.iter().chain(omega.iter()).any(|x| x.is_infinite()) {
+            return err!(
+                InfiniteArgument,
+                FreeRigidBody,
+                "Arguments must be finite.",
+                [i1, i2, i3, w1, w2, w3]
+            );
+        }
+        if !(i1 > 0.0 && i1 < i2 && i2 < i3) {
+            return err!(
+                DomainError,
+                FreeRigidBody,
+                "The moments of inertia must satisfy 0 < i1 < i2 < i3.",
+                [i1, i2, i3]
+            );
+        }
+
+        // The moments and ω are divided by powers of two, so that their squares and products
+        // neither overflow nor underflow. The energy, the momentum, the amplitudes, and λ are
+        // scaled back, while m, n, and the phase are ratios.
+        let si = pow2_scale(i3);
+        let w_max = w1.abs().max(w2.abs()).max(w3.abs());
+        let sw = if w_max == 0.0 { 1.0 } else { pow2_scale(w_max) };
+        let (i1, i2, i3) = (i1 / si, i2 / si, i3 / si);
+        let (w1, w2, w3) = (w1 / sw, w2 / sw, w3 / sw);
+
+        let energy = 0.5 * (i1 * w1 * w1 + i2 * w2 * w2 + i3 * w3 * w3);
+        let momentum = ((i1 * w1).powi(2) + (i2 * w2).powi(2) + (i3 * w3).powi(2)).sqrt();
+        let (d21, d31, d32) = (i2 - i1, i3 - i1, i3 - i2);
+        // 2EI₃ - M², M² - 2EI₁, and M² - 2EI₂ as the sums of the terms in ω
+        let p = i1 * d31 * w1 * w1 + i2 * d32 * w2 * w2;
+        let q = i2 * d21 * w2 * w2 + i3 * d31 * w3 * w3;
+        let r = i3 * d32 * w3 * w3 - i1 * d21 * w1 * w1;
+
+        let mut body = Self {
+            inertia,
+            energy: energy * si * sw * sw,
+            momentum: momentum * si * sw,
+            polhode: Polhode::Major,
+            amplitudes: [0.0; 3],
+            lambda: 0.0,
+            u0: 0.0,
+            period: inf!(),
+            n: -i3 * d21 / (i1 * d32),
+            coefficient: 0.0,
+            pi0: 0.0,
+            modulus: None,
+        };
+        if momentum == 0.0 {
+            body.modulus = Some(EllipticModulus::new(0.0)?);
+            return Ok(body);
+        }
+
+        let sign = |x: T| if x < 0.0 { -1.0 } else { 1.0 };
+        let a1 = (p / (i1 * d31)).sqrt();
+        let a3 = (q / (i3 * d31)).sqrt();
+        let product = i1 * i2 * i3;
+        if r < 0.0 {
+            // About I₁, with ω = (s A₁ dn, s A₂ sn, A₃ cn)
+            let s = sign(w1);
+            let a2 = (q / (i2 * d21)).sqrt();
+            let modulus = EllipticModulus::from_kc((d31 * -r / (d21 * p)).sqrt())?;
+            body.polhode = Polhode::Minor;
+            body.amplitudes = [s * a1 * sw, s * a2 * sw, a3 * sw];
+            body.lambda = (d21 * p / product).sqrt() * sw;
+            body.n = -i1 * d32 / (i3 * d21);
+            if a3 > 0.0 {
+                body.u0 = modulus.inverse_sn_cn_dn(s * w2 / a2, w3 / a3, s * w1 / a1);
+            }
+            body.modulus = Some(modulus);
+        } else if r > 0.0 {
+            // About I₃, with ω = (A₁ cn, s A₂ sn, s A₃ dn)
+            let s = sign(w3);
+            let a2 = (p / (i2 * d32)).sqrt();
+            let modulus = EllipticModulus::from_kc((d31 * r / (d32 * q)).sqrt())?;
+            body.amplitudes = [a1 * sw, s * a2 * sw, s * a3 * sw];
+            body.lambda = (d32 * q / product).sqrt() * sw;
+            if a1 > 0.0 {
+                body.u0 = modulus.inverse_sn_cn_dn(s * w2 / a2, w1 / a1, s * w3 / a3);
+            }
+            body.modulus = Some(modulus);
+        } else {
+            // ω = (s₁ A₁ sech, s₁s₃ A₂ tanh, s₃ A₃ sech), with sinh(u₀) = tanh / sech
+            let (s1, s3) = (sign(w1), sign(w3));
+            let a2 = (p / (i2 * d32)).sqrt();
+            body.polhode = Polhode::Separatrix;
+            body.amplitudes = [s1 * a1 * sw, s1 * s3 * a2 * sw, s3 * a3 * sw];
+            body.lambda = (d32 * q / product).sqrt() * sw;
+            body.u0 = ((s1 * s3 * w2 / a2) / (w1.abs() / a1)).asinh();
+        }
+
+        let (ia, ic) = if body.polhode == Polhode::Minor {
+            (i1, i3)
+        } else {
+            (i3, i1)
+        };
+        body.coefficient = momentum * (ia - ic) / (ia * ic * body.lambda / sw);
+        body.pi0 = body.pi(body.u0);
+        if let Some(modulus) = body.modulus {
+            body.period = 4.0 * modulus.ellipk() / body.lambda;
+        }
+        Ok(body)
+    }
+
+    /// Returns Π(n, am(u)).
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn pi(&self, u: T) -> T {
+        match self.modulus {
+            Some(modulus) => raw::ellippiinc(modulus.am(u), self.n, modulus.m()),
+            None => {
+                // Π(n, gd(u), 1) = (u + a arctan(a tanh u)) / (1 + a²) with a² = -n
+                let a = (-self.n).sqrt();
+                (u + a * (a * u.tanh()).atan()) / (1.0 - self.n)
+            }
+        }
+    }
+
+    /// Returns the principal moments of inertia (I₁, I₂, I₃).
+    pub fn inertia(&self) -> [T; 3] {
+        self.inertia
+    }
+
+    /// Returns the kinetic energy E = (I₁ω₁² + I₂ω₂² + I₃ω₃²) / 2.
+    pub fn energy(&self) -> T {
+        self.energy
+    }
+
+    /// Returns the magnitude of the angular momentum M.
+    pub fn angular_momentum(&self) -> T {
+        self.momentum
+    }
+
+    /// Returns the axis about which the polhode closes.
+    pub fn polhode(&self) -> Polhode {
+        self.polhode
+    }
+
+    /// Returns the parameter m of the Jacobi elliptic functions.
+    ///
+    /// Returns 1 on the separatrix.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn m(&self) -> T {
+        self.modulus.map_or(1.0, |modulus| modulus.m())
+    }
+
+    /// Returns the rate λ of the argument λt + u₀.
+    pub fn lambda(&self) -> T {
+        self.lambda
+    }
+
+    /// Returns the period of the angular velocity in the body, 4K(m) / λ.
+    ///
+    /// Returns ∞ on the separatrix and at rest.
+    pub fn period(&self) -> T {
+        self.period
+    }
+
+    /// Returns the angular velocity ω(t) in the principal axes.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn angular_velocity(&self, t: T) -> [T; 3] {
+        let [a1, a2, a3] = self.amplitudes;
+        let u = self.lambda * t + self.u0;
+        match (self.polhode, self.modulus) {
+            (Polhode::Major, Some(modulus)) => {
+                let (sn, cn, dn) = modulus.sn_cn_dn(u);
+                [a1 * cn, a2 * sn, a3 * dn]
+            }
+            (Polhode::Minor, Some(modulus)) => {
+                let (sn, cn, dn) = modulus.sn_cn_dn(u);
+                [a1 * dn, a2 * sn, a3 * cn]
+            }
+            _ => {
+                let (sech, tanh) = (u.cosh().recip(), u.tanh());
+                [a1 * sech, a2 * tanh, a3 * sech]
+            }
+        }
+    }
+
+    /// Returns the precession angle φ(t) - φ(0) of the body axis of the polhode about the
+    /// angular momentum, see [mod@crate::rigid_body].
+    ///
+    /// The angle increases monotonically at the rate
+    /// φ' = M (2E - Iₐωₐ²) / (M² - Iₐ²ωₐ²). Returns 0 at rest.
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    pub fn precession(&self, t: T) -> T {
+        if self.momentum == 0.0 {
+            return 0.0;
+        }
+        let ia = if self.polhode == Polhode::Minor {
+            self.inertia[0]
+        } else {
+            self.inertia[2]
+        };
+        let u = self.lambda * t + self.u0;
+        // At rest about the intermediate axis, φ' = M / I₂.
+        if self.u0.is_infinite() {
+            return self.momentum / self.inertia[1] * t;
+        }
+        self.momentum / ia * t + self.coefficient * (self.pi(u) - self.pi0)
+    }
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+
+    /// (I, ω(0), [t, ω(t), φ(t)])
+    type Reference = ([f64; 3], [f64; 3], [[f64; 5]; 2]);
+
+    /// mpmath references by integrating Euler's equations and φ' = M (2E - Iₐωₐ²) / (M² - Iₐ²ωₐ²)
+    /// with the Taylor method.
+    const REFERENCES: [Reference; 7] = [
+        (
+            [1.0, 2.0, 3.0],
+            [0.1, 0.2, 1.0],
+            [
+                [
+                    2.5,
+                    -0.19918017533532423,
+                    -0.10162311623537973,
+                    1.0049332883740112,
+                    5.148584161272731,
+                ],
+                [
+                    17.0,
+                    0.17046285389124788,
+                    -0.14471494547299213,
+                    1.0031712357247804,
+                    33.73890620118601,
+                ],
+            ],
+        ),
+        (
+            [1.0, 2.0, 3.0],
+            [1.0, 0.3, -0.2],
+            [
+                [
+                    2.5,
+                    0.9942379685963919,
+                    -0.3185763045195296,
+                    -0.19018336607835426,
+                    1.2038134742723385,
+                ],
+                [
+                    17.0,
+                    1.0278486553991142,
+                    -0.1831041823504674,
+                    0.242537185056757,
+                    9.449714514422965,
+                ],
+            ],
+        ),
+        (
+            [1.0, 2.0, 3.0],
+            [-1.0, 0.3, 0.2],
+            [
+                [
+                    2.5,
+                    -0.9942379685963919,
+                    -0.3185763045195296,
+                    0.19018336607835426,
+                    1.2038134742723385,
+                ],
+                [
+                    17.0,
+                    -1.0278486553991142,
+                    -0.1831041823504674,
+                    -0.242537185056757,
+                    9.449714514422965,
+                ],
+            ],
+        ),
+        (
+            [2.0, 3.0, 5.0],
+            [-0.5, 1.0, 0.4],
+            [
+                [
+                    2.5,
+                    -1.0921378915081743,
+                    -0.2392380110519217,
+                    0.5903838029736115,
+                    4.026038380017114,
+                ],
+                [
+                    17.0,
+                    -0.9104941954645066,
+                    -0.6488453745118637,
+                    0.5251665792821473,
+                    25.280216506962464,
+                ],
+            ],
+        ),
+        (
+            [1.0, 1.5, 4.0],
+            [0.3, -0.8, -0.6],
+            [
+                [
+                    2.5,
+                    -0.03726513928907723,
+                    0.8431423649153282,
+                    -0.596296431760506,
+                    5.368599652865605,
+                ],
+                [
+                    17.0,
+                    0.7995220708178854,
+                    0.44789682586513635,
+                    -0.6224642777591707,
+                    36.78476592518879,
+                ],
+            ],
+        ),
+        (
+            [1.0, 5.0, 9.0],
+            [0.75, 0.5, 0.25],
+            [
+                [
+                    2.5,
+                    0.08724928308458398,
+                    0.6869898289206984,
+                    0.029083094361527992,
+                    1.8309894361541352,
+                ],
+                [
+                    17.0,
+                    1.4278013457678994e-7,
+                    0.6892024376045052,
+                    4.7593378192263306e-8,
+                    11.82539068899667,
+                ],
+            ],
+        ),
+        (
+            [1.0, 5.0, 9.0],
+            [-0.75, 0.5, 0.25],
+            [
+                [
+                    2.5,
+                    -0.516469359032206,
+                    -0.6068803510349836,
+                    0.17215645301073534,
+                    4.072020175071175,
+                ],
+                [
+                    17.0,
+                    -8.974222860442804e-7,
+                    -0.6892024376042774,
+                    2.991407620147601e-7,
+                    14.105583734353113,
+                ],
+            ],
+        ),
+    ];
+
+    #[test]
+    fn test_rigid_body() {
+        for (inertia, omega, points) in REFERENCES {
+            let body = FreeRigidBody::new(inertia, omega).unwrap();
+            let start = body.angular_velocity(0.0);
+            for i in 0..3 {
+                assert_close!(omega[i], start[i], 1e-15);
+            }
+            assert_eq!(body.precession(0.0), 0.0);
+            for [t, w1, w2, w3, phi] in points {
+                let w = body.angular_velocity(t);
+                assert_close!(w1, w[0], 1e-13);
+                assert_close!(w2, w[1], 1e-13);
+                assert_close!(w3, w[2], 1e-13);
+                assert_close!(phi, body.precession(t), 1e-13);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rigid_body_period() {
+        // mpmath references of 4K(m) / λ
+        for (inertia, omega, polhode, expected) in [
+            (
+                [1.0, 2.0, 3.0],
+                [0.1, 0.2, 1.0],
+                Polhode::Major,
+                6.2676168059805475,
+            ),
+            (
+                [1.0, 2.0, 3.0],
+                [1.0, 0.3, -0.2],
+                Polhode::Minor,
+                10.988856416793947,
+            ),
+            (
+                [2.0, 3.0, 5.0],
+                [-0.5, 1.0, 0.4],
+                Polhode::Major,
+                13.781699542991136,
+            ),
+            (
+                [1.0, 1.5, 4.0],
+                [0.3, -0.8, -0.6],
+                Polhode::Major,
+                4.574836596853742,
+            ),
+        ] {
+            let body = FreeRigidBody::new(inertia, omega).unwrap();
+            assert_eq!(body.polhode(), polhode);
+            assert_close!(expected, body.period(), 1e-14);
+            let t = 0.8;
+            let (w, next) = (
+                body.angular_velocity(t),
+                body.angular_velocity(t + body.period()),
+            );
+            for i in 0..3 {
+                assert!((w[i] - next[i]).abs() < 1e-13, "{w:?} {next:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_rigid_body_invariants() {
+        for (inertia, omega, _) in REFERENCES {
+            let body = FreeRigidBody::new(inertia, omega).unwrap();
+            let [i1, i2, i3] = inertia;
+            for t in [0.3, 4.0, 50.0, 300.0] {
+                let [w1, w2, w3] = body.angular_velocity(t);
+                let energy = 0.5 * (i1 * w1 * w1 + i2 * w2 * w2 + i3 * w3 * w3);
+                let momentum = ((i1 * w1).powi(2) + (i2 * w2).powi(2) + (i3 * w3).powi(2)).sqrt();
+                assert_close!(body.energy(), energy, 1e-14);
+                assert_close!(body.angular_momentum(), momentum, 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rigid_body_scaling() {
+        // The motion scales with ω and does not depend on the scale of the moments, beyond the
+        // range of their squares.
+        let reference = FreeRigidBody::new([1.0, 2.0, 3.0], [1.0, 1.0, 0.0]).unwrap();
+        let t = 2.5;
+        let expected = reference.angular_velocity(t);
+
+        let body = FreeRigidBody::new([1e-200, 2e-200, 3e-200], [1.0, 1.0, 0.0]).unwrap();
+        assert_eq!(body.polhode(), Polhode::Minor);
+        assert_close!(reference.period(), body.period(), 1e-15);
+        assert_close!(
+            reference.angular_momentum() * 1e-200,
+            body.angular_momentum(),
+            1e-15
+        );
+        let w = body.angular_velocity(t);
+        for i in 0..3 {
+            assert!((expected[i] - w[i]).abs() < 1e-15, "{expected:?} {w:?}");
+        }
+        assert_close!(reference.precession(t), body.precession(t), 1e-15);
+
+        let body = FreeRigidBody::new([1.0, 2.0, 3.0], [1e200, 1e200, 0.0]).unwrap();
+        assert_eq!(body.polhode(), Polhode::Minor);
+        assert_close!(reference.period() * 1e-200, body.period(), 1e-15);
+        assert_close!(
+            reference.angular_momentum() * 1e200,
+            body.angular_momentum(),
+            1e-15
+        );
+        let w = body.angular_velocity(t * 1e-200);
+        for i in 0..3 {
+            assert!(
+                (expected[i] - w[i] * 1e-200).abs() < 1e-15,
+                "{expected:?} {w:?}"
+            );
+        }
+        assert_close!(reference.precession(t), body.precession(t * 1e-200), 1e-15);
+    }
+
+    #[test]
+    fn test_rigid_body_special_cases() {
+        // At rest
+        let body = FreeRigidBody::new([1.0, 2.0, 3.0], [0.0, 0.0, 0.0]).unwrap();
+        assert_eq!(body.angular_velocity(5.0), [0.0, 0.0, 0.0]);
+        assert_eq!(body.precession(5.0), 0.0);
+        assert_eq!(body.period(), f64::INFINITY);
+
+        // Steady rotation about the principal axes, with the period of the small wobble
+        let body = FreeRigidBody::new([1.0, 2.0, 3.0], [0.0, 0.0, 2.0]).unwrap();
+        assert_eq!(body.polhode(), Polhode::Major);
+        assert_eq!(body.angular_velocity(5.0), [0.0, 0.0, 2.0]);
+        assert_eq!(body.m(), 0.0);
+        // λ = ω₃ √((I₃ - I₂)(I₃ - I₁) / I₁I₂)
+        assert_close!(2.0 * std::f64::consts::PI / 2.0, body.period(), 1e-15);
+        let body = FreeRigidBody::new([1.0, 2.0, 3.0], [-1.0, 0.0, 0.0]).unwrap();
+        assert_eq!(body.polhode(), Polhode::Minor);
+        assert_eq!(body.angular_velocity(5.0), [-1.0, 0.0, 0.0]);
+        let body = FreeRigidBody::new([1.0, 2.0, 3.0], [0.0, 1.5, 0.0]).unwrap();
+        assert_eq!(body.polhode(), Polhode::Separatrix);
+        assert_eq!(body.angular_velocity(5.0), [0.0, 1.5, 0.0]);
+        // φ' = M / I₂
+        assert_close!(1.5 * 5.0, body.precession(5.0), 1e-15);
+
+        // The separatrix approaches the rotation about the intermediate axis.
+        let body = FreeRigidBody::new([1.0, 5.0, 9.0], [0.75, 0.5, 0.25]).unwrap();
+        assert_eq!(body.polhode(), Polhode::Separatrix);
+        assert_eq!(body.m(), 1.0);
+        assert_eq!(body.period(), f64::INFINITY);
+        let [w1, w2, w3] = body.angular_velocity(1e3);
+        assert_eq!((w1, w3), (0.0, 0.0));
+        assert_close!(body.angular_momentum() / 5.0, w2, 1e-15);
+    }
+
+    #[test]
+    fn test_rigid_body_errors() {
+        assert_eq!(
+            FreeRigidBody::new([1.0, 1.0, 3.0], [1.0, 0.0, 0.0]).unwrap_err(),
+            "FreeRigidBody: The moments of inertia must satisfy 0 < i1 < i2 < i3."
+        );
+        assert_eq!(
+            FreeRigidBody::new([3.0, 2.0, 1.0], [1.0, 0.0, 0.0]).unwrap_err(),
+            "FreeRigidBody: The moments of inertia must satisfy 0 < i1 < i2 < i3."
+        );
+        assert_eq!(
+            FreeRigidBody::new([0.0, 2.0, 3.0], [1.0, 0.0, 0.0]).unwrap_err(),
+            "FreeRigidBody: The moments of inertia must satisfy 0 < i1 < i2 < i3."
+        );
+        assert_eq!(
+            FreeRigidBody::new([1.0, 2.0, 3.0], [f64::NAN, 0.0, 0.0]).unwrap_err(),
+            "FreeRigidBody: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            FreeRigidBody::new([1.0, 2.0, f64::INFINITY], [1.0, 0.0, 0.0]).unwrap_err(),
+            "FreeRigidBody: Arguments must be finite."
+        );
+    }
+}
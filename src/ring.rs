@@ -0,0 +1,702 @@
+/*
+ * Ellip is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Fields of circular current loops, charged and massive rings, and thin vortex rings.
+//!
+//! The ring of radius a lies in the plane z = 0 centered on the z-axis, and the field point is
+//! given in cylindrical coordinates (ρ, z). All the fields share the distances to the nearest
+//! and farthest points of the ring
+//! ```text
+//! r₁ = √((ρ - a)² + z²),  r₂ = √((ρ + a)² + z²)
+//! ```
+//! The textbook forms in K(k) and E(k) with k² = 4aρ / r₂² cancel catastrophically near the
+//! axis, where the bracketed combinations vanish like ρ², and in the far field. Here, the
+//! descending Landen transformation with the modulus λ = (r₂ - r₁) / (r₂ + r₁) (Lamb, 1932,
+//! §161) cancels the leading terms analytically, and the remainders are evaluated by [cel]
+//! with the complementary modulus
+//! ```text
+//! λc = 2√(r₁r₂) / (r₁ + r₂)
+//! ```
+//! and coefficients of constant sign wherever the field does not change sign (Derby and
+//! Olbert, 2010).
+//!
+//! The functions return the fields of unit sources: the current loop for μ₀I = 1, the charged
+//! ring for q / 4πε₀ = 1, the massive ring for GM = 1, and the vortex ring for the circulation
+//! Γ = 1.
+//!
+//! # Examples
+//! ```
+//! use ellip::{ring::*, util::assert_close};
+//!
+//! // On the axis, B_z = μ₀I a² / 2(a² + z²)^(3/2).
+//! let (b_rho, b_z) = current_loop_field(2.0, 0.0, 1.0).unwrap();
+//! assert_eq!(b_rho, 0.0);
+//! assert_close(b_z, 4.0 / (2.0 * 5.0f64.powf(1.5)), 1e-15);
+//!
+//! // The field close to the axis stays accurate.
+//! let (b_rho, _) = current_loop_field(1.0, 1e-10, 1.0).unwrap();
+//! assert_close(b_rho, 3.0 / 4.0 / 2.0f64.powf(2.5) * 1e-10, 1e-14);
+//! ```
+//!
+//! # References
+//! - Derby, Norman, and Stanislaw Olbert. “Cylindrical Magnets and Ideal Solenoids.” American Journal of Physics 78, no. 3 (2010): 229–35. <https://doi.org/10.1119/1.3256157>.
+//! - Jackson, John David. Classical Electrodynamics. 3rd ed. New York: Wiley, 1999.
+//! - Lamb, Horace. Hydrodynamics. 6th ed. Cambridge: Cambridge University Press, 1932.
+
+use num_traits::Float;
+
+use crate::{bulirsch::BulirschConst, cel, crate_util::check, error::err, EllipError};
+
+/// Validates the arguments and returns early for field points at infinity.
+macro_rules! check_ring {
+    ($fn_name:ident, $a:ident, $rho:ident, $z:ident, $at_infinity:expr) => {
+        check!(@nan, $fn_name, [$a, $rho, $z]);
+        check!(@inf, $fn_name, [$a]);
+        if $a <= T::zero() {
+            return err!(DomainError, $fn_name, "a must be positive.", $a);
+        }
+        if $rho < T::zero() {
+            return err!(DomainError, $fn_name, "rho cannot be negative.", $rho);
+        }
+        if $rho.is_infinite() || $z.is_infinite() {
+            return Ok($at_infinity);
+        }
+    };
+}
+
+/// Returns the distance r = √(ρ² + z²) of the field point if the next term of the multipole
+/// expansion, smaller than the leading term by about (a / r)², is below the rounding error.
+fn far_field<T: Float>(a: T, rho: T, z: T) -> Option<T> {
+    let r = rho.hypot(z);
+    (r >= a / T::epsilon()).then_some(r)
+}
+
+/// Distances and Landen modulus of the field point (x, z) from the unit ring, for
+/// x, |z| < 1 / ε.
+struct Geometry<T> {
+    /// r₁² + r₂²
+    sum_sq: T,
+    r1: T,
+    r2: T,
+    /// r₁ + r₂
+    s: T,
+    /// λc
+    kc: T,
+}
+
+impl<T: Float> Geometry<T> {
+    #[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+    fn new(x: T, z: T) -> Self {
+        // r₁ does not underflow close to the ring.
+        let (r1, r2) = ((1.0 - x).hypot(z), (1.0 + x).hypot(z));
+        let s = r1 + r2;
+        Geometry {
+            sum_sq: r1 * r1 + r2 * r2,
+            r1,
+            r2,
+            s,
+            kc: 2.0 * (r1 / s * (r2 / s)).sqrt(),
+        }
+    }
+}
+
+/// Computes the field of the unit loop at (x, z) off the loop.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn loop_field<T: Float + BulirschConst<T>>(
+    x: T,
+    z: T,
+    g: &Geometry<T>,
+) -> Result<(T, T), EllipError> {
+    let b_rho = 2.0 * x * (z / g.r1) / (pi!() * g.r2 * g.r2 * g.r1 * g.s)
+        * cel(g.kc, 1.0, 2.0, g.kc * g.kc)?;
+
+    // w = r₁r₂ + c, avoiding the cancellation for c < 0
+    let c = 1.0 - x * x - z * z;
+    let w = if c >= 0.0 {
+        g.r1 * g.r2 + c
+    } else {
+        4.0 * z * z / (g.r1 * g.r2 - c)
+    };
+    // The coefficients of cel are divided by r₁.
+    let a = 2.0 * (1.0 + z * z - x * x) / (g.r2 * g.r1);
+    let b_z = cel(g.kc, 1.0, a, w)? / (pi!() * g.r1 * g.r2 * g.s);
+    Ok((b_rho, b_z))
+}
+
+/// Computes the dipole field of the loop of radius a at the distance r, in the unit of μ₀I.
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+fn dipole_field<T: Float>(a: T, rho: T, z: T, r: T) -> (T, T) {
+    // a² / 4r³ without overflow or underflow in the intermediate results
+    let scale = 0.25 * (a / r) * (a / r / r);
+    let (sin, cos) = (rho / r, z / r);
+    (3.0 * scale * sin * cos, scale * (3.0 * cos * cos - 1.0))
+}
+
+/// Computes the azimuthal vector potential A_φ of the circular current loop per unit μ₀I.
+/// ```text
+///         μ₀I    ⎛ a ⎞½ ⎡⎛    k²⎞             ⎤      8a²ρ
+/// A_φ  =  ───    ⎜ ─ ⎟  ⎢⎜1 - ──⎟ K(k) - E(k) ⎥  =  ─────── cel(λc, 1, 0, 1)
+///         π k    ⎝ ρ ⎠  ⎣⎝    2 ⎠             ⎦     π(r₁+r₂)³
+/// ```
+///
+/// The loop of radius a lies in the plane z = 0 centered on the z-axis and carries the current
+/// counterclockwise about the z-axis. See [mod@crate::ring] for the notation.
+///
+/// ## Parameters
+/// - a: radius of the loop. a ∈ ℝ, a > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, rho < 0, a is infinite, or any argument is NAN.
+///
+/// ## Special Cases
+/// - A_φ = 0 for rho = 0, or rho or z = ±∞.
+/// - A_φ = ∞ on the loop, where rho = a and z = 0.
+///
+/// # Examples
+/// ```
+/// use ellip::{ring::current_loop_vector_potential, util::assert_close};
+///
+/// assert_close(current_loop_vector_potential(2.0, 1.5, 0.7).unwrap(), 0.16372987175340301, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn current_loop_vector_potential<T: Float + BulirschConst<T>>(
+    a: T,
+    rho: T,
+    z: T,
+) -> Result<T, EllipError> {
+    check_ring!(current_loop_vector_potential, a, rho, z, 0.0);
+    if let Some(r) = far_field(a, rho, z) {
+        return Ok(0.25 * (a / r) * (a / r) * (rho / r));
+    }
+
+    let (x, z) = (rho / a, z / a);
+    let g = Geometry::new(x, z);
+    if g.r1 == 0.0 {
+        return Ok(inf!());
+    }
+    Ok(8.0 * x / (pi!() * g.s.powi(3)) * cel(g.kc, 1.0, 0.0, 1.0)?)
+}
+
+/// Computes the magnetic field (B_ρ, B_z) of the circular current loop per unit μ₀I.
+/// ```text
+///           2a²ρz
+/// B_ρ  =  ───────────── cel(λc, 1, 2, λc²)
+///         π r₁² r₂² (r₁+r₂)
+///
+///                 1           ⎛     2a²(a² + z² - ρ²)                     ⎞
+/// B_z  =  ──────────────── cel⎜λc, 1, ─────────────────, r₁(r₁r₂ + a² - ρ² - z²)⎟
+///         π r₁² r₂ (r₁+r₂)    ⎝            r₂                              ⎠
+/// ```
+///
+/// The loop of radius a lies in the plane z = 0 centered on the z-axis and carries the current
+/// counterclockwise about the z-axis. The second coefficient of B_z is rewritten as
+/// 4a²z² / (r₁r₂ - a² + ρ² + z²) when a² < ρ² + z². See [mod@crate::ring] for the notation.
+///
+/// ## Parameters
+/// - a: radius of the loop. a ∈ ℝ, a > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, rho < 0, a is infinite, or any argument is NAN.
+/// - Returns error on the loop, where rho = a and z = 0.
+///
+/// ## Special Cases
+/// - B_ρ = 0 for rho = 0 or z = 0.
+/// - B = (0, 0) for rho or z = ±∞.
+///
+/// # Examples
+/// ```
+/// use ellip::{ring::current_loop_field, util::assert_close};
+///
+/// let (b_rho, b_z) = current_loop_field(2.0, 1.5, 0.7).unwrap();
+/// assert_close(b_rho, 0.14343216778390282, 1e-15);
+/// assert_close(b_z, 0.20757093622063068, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn current_loop_field<T: Float + BulirschConst<T>>(
+    a: T,
+    rho: T,
+    z: T,
+) -> Result<(T, T), EllipError> {
+    check_ring!(current_loop_field, a, rho, z, (0.0, 0.0));
+    if let Some(r) = far_field(a, rho, z) {
+        return Ok(dipole_field(a, rho, z, r));
+    }
+
+    let (x, z) = (rho / a, z / a);
+    let g = Geometry::new(x, z);
+    if g.r1 == 0.0 {
+        return err!(
+            Singularity,
+            current_loop_field,
+            "The point must not lie on the loop.",
+            [rho, z]
+        );
+    }
+
+    let (b_rho, b_z) = loop_field(x, z, &g)?;
+    Ok((b_rho / a, b_z / a))
+}
+
+/// Computes the electrostatic potential of the uniformly charged ring per unit q / 4πε₀.
+/// ```text
+///            2 K(k)           4
+/// V  =  ───────────  =  ─────────── cel(λc, 1, 1, 1)
+///           π r₂         π (r₁+r₂)
+/// ```
+///
+/// The ring of radius a lies in the plane z = 0 centered on the z-axis. The Newtonian potential
+/// of the ring of mass M is -GM times this value. See [mod@crate::ring] for the notation.
+///
+/// ## Parameters
+/// - a: radius of the ring. a ∈ ℝ, a > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, rho < 0, a is infinite, or any argument is NAN.
+///
+/// ## Special Cases
+/// - V = 1 / √(a² + z²) for rho = 0.
+/// - V = 0 for rho or z = ±∞.
+/// - V = ∞ on the ring, where rho = a and z = 0.
+///
+/// # Examples
+/// ```
+/// use ellip::{ring::charged_ring_potential, util::assert_close};
+///
+/// assert_close(charged_ring_potential(2.0, 1.5, 0.7).unwrap(), 0.5058806285552566, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn charged_ring_potential<T: Float + BulirschConst<T>>(
+    a: T,
+    rho: T,
+    z: T,
+) -> Result<T, EllipError> {
+    check_ring!(charged_ring_potential, a, rho, z, 0.0);
+    if let Some(r) = far_field(a, rho, z) {
+        return Ok(1.0 / r);
+    }
+
+    let g = Geometry::new(rho / a, z / a);
+    if g.r1 == 0.0 {
+        return Ok(inf!());
+    }
+    Ok(4.0 / (pi!() * g.s) * cel(g.kc, 1.0, 1.0, 1.0)? / a)
+}
+
+/// Computes the gravitational field (g_ρ, g_z) of the ring per unit GM.
+/// ```text
+///                  -2               ⎛     2ρ(ρ² + z² - a²)                     ⎞
+/// g_ρ  =  ────────────────── cel⎜λc, 1, ────────────────, r₁(r₁r₂ - a² + ρ² - z²)/ρ⎟
+///         π r₁² r₂ (r₁+r₂)       ⎝            r₂                              ⎠
+///
+///                -2z              ⎛     r₁² + r₂²      ⎞
+/// g_z  =  ────────────────── cel⎜λc, 1, ─────────, 2r₁⎟
+///         π r₁² r₂ (r₁+r₂)       ⎝        r₂          ⎠
+/// ```
+///
+/// The ring of radius a and mass M lies in the plane z = 0 centered on the z-axis. The field
+/// points toward the ring, and the electric field of the ring of charge q is -q / 4πε₀GM times
+/// this value. The second coefficient of g_ρ is rewritten as 4ρz² / (r₁r₂ + a² - ρ² + z²) when
+/// a² + z² > ρ². See [mod@crate::ring] for the notation.
+///
+/// ## Parameters
+/// - a: radius of the ring. a ∈ ℝ, a > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, rho < 0, a is infinite, or any argument is NAN.
+/// - Returns error on the ring, where rho = a and z = 0.
+///
+/// ## Special Cases
+/// - g_ρ = 0 for rho = 0.
+/// - g_z = 0 for z = 0.
+/// - g = (0, 0) for rho or z = ±∞.
+///
+/// # Examples
+/// ```
+/// use ellip::{ring::ring_gravitational_field, util::assert_close};
+///
+/// let (g_rho, g_z) = ring_gravitational_field(2.0, 1.5, 0.7).unwrap();
+/// assert_close(g_rho, 0.023612007564405524, 1e-15);
+/// assert_close(g_z, -0.18022395351514785, 1e-15);
+/// ```
+#[numeric_literals::replace_float_literals(T::from(literal).unwrap())]
+pub fn ring_gravitational_field<T: Float + BulirschConst<T>>(
+    a: T,
+    rho: T,
+    z: T,
+) -> Result<(T, T), EllipError> {
+    check_ring!(ring_gravitational_field, a, rho, z, (0.0, 0.0));
+    if let Some(r) = far_field(a, rho, z) {
+        // The field of the point mass
+        return Ok((-rho / r / r / r, -z / r / r / r));
+    }
+
+    let (x, z) = (rho / a, z / a);
+    let g = Geometry::new(x, z);
+    if g.r1 == 0.0 {
+        return err!(
+            Singularity,
+            ring_gravitational_field,
+            "The point must not lie on the ring.",
+            [rho, z]
+        );
+    }
+    // The coefficients of cel are divided by r₁.
+    let den = pi!() * g.r1 * g.r2 * g.s;
+
+    // w / ρ with w = r₁r₂ - c, avoiding the cancellation for c > 0
+    let c = 1.0 + z * z - x * x;
+    let w = if c > 0.0 {
+        4.0 * x * z * z / (g.r1 * g.r2 + c)
+    } else {
+        (g.r1 * g.r2 - c) / x
+    };
+    let g_rho =
+        -2.0 * cel(
+            g.kc,
+            1.0,
+            2.0 * x * (x * x + z * z - 1.0) / (g.r2 * g.r1),
+            w,
+        )? / den;
+    let g_z = -2.0 * z * cel(g.kc, 1.0, g.sum_sq / (g.r2 * g.r1), 2.0)? / den;
+
+    Ok((g_rho / (a * a), g_z / (a * a)))
+}
+
+/// Computes the Stokes streamfunction ψ of the thin vortex ring per unit circulation Γ.
+/// ```text
+///        Γ                               8a²ρ²
+/// ψ  =  ── (r₁ + r₂) (K(λ) - E(λ))  =  ────────── cel(λc, 1, 0, 1)
+///       2π                            π(r₁+r₂)³
+/// ```
+///
+/// The ring of radius a lies in the plane z = 0 centered on the z-axis, and the fluid moves in
+/// the positive z-direction through the ring. The velocity is u_ρ = -∂ψ/∂z / ρ and
+/// u_z = ∂ψ/∂ρ / ρ, see [vortex_ring_velocity]. The streamfunction is ρA_φ, where A_φ is the
+/// vector potential of the current loop, see [current_loop_vector_potential].
+///
+/// ## Parameters
+/// - a: radius of the ring. a ∈ ℝ, a > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, rho < 0, a is infinite, or any argument is NAN.
+///
+/// ## Special Cases
+/// - ψ = 0 for rho = 0.
+/// - ψ = ∞ on the ring, where rho = a and z = 0.
+///
+/// # Examples
+/// ```
+/// use ellip::{ring::vortex_ring_streamfunction, util::assert_close};
+///
+/// assert_close(vortex_ring_streamfunction(2.0, 1.5, 0.7).unwrap(), 0.2455948076301045, 1e-15);
+/// ```
+pub fn vortex_ring_streamfunction<T: Float + BulirschConst<T>>(
+    a: T,
+    rho: T,
+    z: T,
+) -> Result<T, EllipError> {
+    check_ring!(vortex_ring_streamfunction, a, rho, z, T::zero());
+    Ok(rho * current_loop_vector_potential(a, rho, z)?)
+}
+
+/// Computes the velocity (u_ρ, u_z) induced by the thin vortex ring per unit circulation Γ.
+///
+/// The velocity equals the magnetic field of the current loop with μ₀I replaced by Γ, see
+/// [current_loop_field]. The ring of radius a lies in the plane z = 0 centered on the z-axis,
+/// and the fluid moves in the positive z-direction through the ring.
+///
+/// ## Parameters
+/// - a: radius of the ring. a ∈ ℝ, a > 0.
+/// - rho: distance from the z-axis. rho ∈ ℝ, rho ≥ 0.
+/// - z ∈ ℝ
+///
+/// ## Domain
+/// - Returns error if a ≤ 0, rho < 0, a is infinite, or any argument is NAN.
+/// - Returns error on the ring, where rho = a and z = 0.
+///
+/// # Examples
+/// ```
+/// use ellip::{ring::vortex_ring_velocity, util::assert_close};
+///
+/// // At the center, u_z = Γ / 2a.
+/// let (u_rho, u_z) = vortex_ring_velocity(2.0, 0.0, 0.0).unwrap();
+/// assert_eq!(u_rho, 0.0);
+/// assert_close(u_z, 0.25, 1e-15);
+/// ```
+pub fn vortex_ring_velocity<T: Float + BulirschConst<T>>(
+    a: T,
+    rho: T,
+    z: T,
+) -> Result<(T, T), EllipError> {
+    check_ring!(vortex_ring_velocity, a, rho, z, (T::zero(), T::zero()));
+    if let Some(r) = far_field(a, rho, z) {
+        return Ok(dipole_field(a, rho, z, r));
+    }
+
+    let (x, z) = (rho / a, z / a);
+    let g = Geometry::new(x, z);
+    if g.r1 == T::zero() {
+        return err!(
+            Singularity,
+            vortex_ring_velocity,
+            "The point must not lie on the ring.",
+            [rho, z]
+        );
+    }
+    let (u_rho, u_z) = loop_field(x, z, &g)?;
+    Ok((u_rho / a, u_z / a))
+}
+
+#[cfg(not(feature = "test_force_fail"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_close;
+    use std::f64::consts::PI;
+
+    /// a, rho, z, A_φ, B_ρ, B_z, V, g_ρ, and g_z computed by quadrature over the ring using
+    /// mpmath.
+    const REFERENCES: [[f64; 9]; 5] = [
+        [
+            2.0,
+            1.5,
+            0.7,
+            0.16372987175340301,
+            0.14343216778390282,
+            0.20757093622063068,
+            0.50588062855525657,
+            0.023612007564405524,
+            -0.18022395351514785,
+        ],
+        [
+            1.0,
+            1e-6,
+            0.3,
+            2.1968492780306074e-7,
+            1.8139122479173331e-7,
+            0.43936985560621026,
+            0.95782628522131666,
+            3.3053512073125901e-7,
+            -0.26362191336408893,
+        ],
+        [
+            1.0,
+            3.0,
+            0.01,
+            0.02902212242555242,
+            0.00011553217415315856,
+            -0.010567076929205482,
+            0.34321771586611478,
+            -0.1214490170698208,
+            -0.00048185150633484171,
+        ],
+        [
+            1.0,
+            100.0,
+            50.0,
+            1.7888543794237051e-5,
+            2.1467969908238248e-7,
+            -7.1545587370693362e-8,
+            0.0089443434598807188,
+            -7.1554174970851023e-5,
+            -3.5781381079407159e-5,
+        ],
+        [
+            1.0,
+            0.999,
+            1e-3,
+            1.0573376923900572,
+            79.616823515089153,
+            80.225718079363614,
+            2.7516124084790852,
+            158.01707569075821,
+            -159.23486481930685,
+        ],
+    ];
+
+    #[test]
+    fn test_references() {
+        for [a, rho, z, a_phi, b_rho_ref, b_z_ref, v, g_rho_ref, g_z_ref] in REFERENCES {
+            // The fields are ill-conditioned near the ring.
+            let rtol = if (rho - a).abs() < 0.01 { 1e-12 } else { 1e-14 };
+            assert_close!(
+                a_phi,
+                current_loop_vector_potential(a, rho, z).unwrap(),
+                rtol
+            );
+            assert_close!(
+                rho * a_phi,
+                vortex_ring_streamfunction(a, rho, z).unwrap(),
+                rtol
+            );
+            assert_close!(v, charged_ring_potential(a, rho, z).unwrap(), rtol);
+
+            // Compare magnitudes, since assert_close! divides by the signed value.
+            let check = |expected: f64, actual: f64| {
+                assert_close!(expected.abs(), actual.abs(), rtol);
+                assert_eq!(expected.signum(), actual.signum());
+            };
+            let (b_rho, b_z) = current_loop_field(a, rho, z).unwrap();
+            check(b_rho_ref, b_rho);
+            check(b_z_ref, b_z);
+            assert_eq!(
+                current_loop_field(a, rho, z),
+                vortex_ring_velocity(a, rho, z)
+            );
+            let (g_rho, g_z) = ring_gravitational_field(a, rho, z).unwrap();
+            check(g_rho_ref, g_rho);
+            check(g_z_ref, g_z);
+        }
+    }
+
+    #[test]
+    fn test_axis() {
+        for z in [0.0, 0.3, 1.0, 7.0, 1e5] {
+            let r2: f64 = 4.0 + z * z;
+            let (b_rho, b_z) = current_loop_field(2.0, 0.0, z).unwrap();
+            assert_eq!(b_rho, 0.0);
+            assert_close!(2.0 / r2.powf(1.5), b_z, 5e-16);
+            assert_eq!(current_loop_vector_potential(2.0, 0.0, z), Ok(0.0));
+            assert_close!(
+                1.0 / r2.sqrt(),
+                charged_ring_potential(2.0, 0.0, z).unwrap(),
+                5e-16
+            );
+            let (g_rho, g_z) = ring_gravitational_field(2.0, 0.0, z).unwrap();
+            assert_eq!(g_rho, 0.0);
+            assert_close!(z / r2.powf(1.5), -g_z, 5e-16);
+        }
+
+        // Leading terms off the axis, where the textbook forms lose all digits
+        let rho = 1e-10;
+        let (b_rho, _) = current_loop_field(1.0, rho, 1.0).unwrap();
+        assert_close!(3.0 * rho / (4.0 * 2.0f64.powf(2.5)), b_rho, 1e-15);
+        let (g_rho, _) = ring_gravitational_field(1.0, rho, 1.0).unwrap();
+        assert_close!(rho / (2.0 * 2.0f64.powf(2.5)), -g_rho, 1e-15);
+        let (g_rho, _) = ring_gravitational_field(1.0, rho, 0.0).unwrap();
+        assert_close!(rho / 2.0, g_rho, 1e-15);
+        assert_close!(
+            rho / (4.0 * 2.0f64.powf(1.5)),
+            current_loop_vector_potential(1.0, rho, 1.0).unwrap(),
+            1e-15
+        );
+    }
+
+    #[test]
+    fn test_far_field() {
+        // Magnetic dipole μ₀m / 4π = a² / 4 and point mass
+        let (rho, z) = (6e3, 8e3);
+        let r = 1e4f64;
+        let (b_rho, b_z) = current_loop_field(1.0, rho, z).unwrap();
+        assert_close!(3.0 * rho * z / (4.0 * r.powi(5)), b_rho, 1e-7);
+        assert_close!((3.0 * z * z - r * r) / (4.0 * r.powi(5)), b_z, 1e-7);
+        assert_close!(1.0 / r, charged_ring_potential(1.0, rho, z).unwrap(), 1e-7);
+        let (g_rho, g_z) = ring_gravitational_field(1.0, rho, z).unwrap();
+        assert_close!(rho / r.powi(3), -g_rho, 1e-7);
+        assert_close!(z / r.powi(3), -g_z, 1e-7);
+
+        // Beyond the reach of the squared distances
+        let (b_rho, b_z) = current_loop_field(1.0, 0.0, 1e100).unwrap();
+        assert_eq!(b_rho, 0.0);
+        assert_close!(0.5e-300, b_z, 1e-15);
+        assert_eq!(current_loop_field(1.0, 0.0, 1e200), Ok((0.0, 0.0)));
+        assert_close!(
+            0.25e-200,
+            current_loop_vector_potential(1e-100, 1.0, 0.0).unwrap(),
+            1e-15
+        );
+        assert_eq!(charged_ring_potential(1e-200, 0.0, 1.0), Ok(1.0));
+        assert_close!(
+            1e-200,
+            charged_ring_potential(1e-200, 1e200, 1.0).unwrap(),
+            1e-15
+        );
+        let (g_rho, g_z) = ring_gravitational_field(1e-200, 3.0, 4.0).unwrap();
+        assert_close!(3.0 / 125.0, -g_rho, 1e-15);
+        assert_close!(4.0 / 125.0, -g_z, 1e-15);
+        assert_eq!(current_loop_field(1.0f32, 0.5, 1e20), Ok((0.0, 0.0)));
+        assert_close!(
+            1e-20f32,
+            charged_ring_potential(1.0f32, 0.5, 1e20).unwrap(),
+            1e-6
+        );
+    }
+
+    #[test]
+    fn test_near_ring() {
+        // Straight wire, B = μ₀I / 2πd and A_φ ≈ μ₀I (ln(8a / d) - 2) / 2π
+        let d = 1e-200;
+        let (b_rho, _) = current_loop_field(1.0, 1.0, d).unwrap();
+        assert_close!(1.0 / (2.0 * PI * d), b_rho, 1e-15);
+        assert_close!(
+            ((8.0 / d).ln() - 2.0) / (2.0 * PI),
+            current_loop_vector_potential(1.0, 1.0, d).unwrap(),
+            1e-15
+        );
+        // Line mass, g = 2GM / 2πa d
+        let (_, g_z) = ring_gravitational_field(1.0, 1.0, d).unwrap();
+        assert_close!(1.0 / (PI * d), -g_z, 1e-15);
+    }
+
+    #[test]
+    fn test_special_cases() {
+        let inf = f64::INFINITY;
+        assert_eq!(current_loop_vector_potential(1.0, 1.0, 0.0), Ok(inf));
+        assert_eq!(charged_ring_potential(1.0, 1.0, 0.0), Ok(inf));
+        assert_eq!(vortex_ring_streamfunction(1.0, 1.0, 0.0), Ok(inf));
+        assert_eq!(
+            current_loop_field(1.0, 1.0, 0.0).unwrap_err(),
+            "current_loop_field: The point must not lie on the loop."
+        );
+        assert_eq!(
+            ring_gravitational_field(1.0, 1.0, 0.0).unwrap_err(),
+            "ring_gravitational_field: The point must not lie on the ring."
+        );
+        assert_eq!(
+            vortex_ring_velocity(1.0, 1.0, 0.0).unwrap_err(),
+            "vortex_ring_velocity: The point must not lie on the ring."
+        );
+
+        assert_eq!(charged_ring_potential(1.0, inf, 0.0), Ok(0.0));
+        assert_eq!(current_loop_field(1.0, 1.0, -inf), Ok((0.0, 0.0)));
+        assert_eq!(ring_gravitational_field(1.0, inf, inf), Ok((0.0, 0.0)));
+
+        // Symmetric about the plane of the ring
+        let (b_rho, b_z) = current_loop_field(1.0, 0.5, 0.3).unwrap();
+        assert_eq!(current_loop_field(1.0, 0.5, -0.3), Ok((-b_rho, b_z)));
+        let (g_rho, g_z) = ring_gravitational_field(1.0, 0.5, 0.3).unwrap();
+        assert_eq!(ring_gravitational_field(1.0, 0.5, -0.3), Ok((g_rho, -g_z)));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            current_loop_field(f64::NAN, 1.0, 0.0).unwrap_err(),
+            "current_loop_field: Arguments cannot be NAN."
+        );
+        assert_eq!(
+            charged_ring_potential(0.0, 1.0, 0.0).unwrap_err(),
+            "charged_ring_potential: a must be positive."
+        );
+        assert_eq!(
+            ring_gravitational_field(1.0, -1.0, 0.0).unwrap_err(),
+            "ring_gravitational_field: rho cannot be negative."
+        );
+        assert_eq!(
+            vortex_ring_streamfunction(f64::INFINITY, 1.0, 0.0).unwrap_err(),
+            "vortex_ring_streamfunction: a cannot be infinite."
+        );
+    }
+}
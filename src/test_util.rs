@@ -98,9 +98,9 @@ macro_rules! compare_test_data_boost {
         compare_test_data_boost!($filename, wrapped_func, f64, $rtol, 0.0)
     }};
     ($filename:expr, $func:expr, $t:ident, $rtol:expr, $atol:expr) => {{
-        use crate::compare_test_data;
         use ellip_dev_utils::parser;
         use std::path::Path;
+        use $crate::compare_test_data;
 
         let path = Path::new("./tests/data/boost").join($filename);
         match parser::read_boost_data(&path.to_str().unwrap()) {
@@ -145,9 +145,9 @@ macro_rules! compare_test_data_wolfram {
     }};
     ($path:expr, $filename:expr, $func:expr, $t:ident, $rtol:expr, $atol:expr) => {{
         {
-            use crate::compare_test_data;
             use ellip_dev_utils::parser;
             use std::path::Path;
+            use $crate::compare_test_data;
 
             let path = Path::new($path).join($filename);
             match parser::read_wolfram_data(&path.to_str().unwrap()) {
@@ -173,21 +173,6 @@ macro_rules! assert_close {
     };
 }
 
-#[cfg(feature = "test_force_fail")]
-#[macro_export]
-macro_rules! test_force_unreachable {
-    ($($inner:tt)*) => {
-        mod tests {
-            use super::*;
-
-            #[test]
-            fn force_unreachable() {
-                $($inner)*
-            }
-        }
-    };
-}
-
 pub fn linspace(start: f64, end: f64, num: usize) -> Vec<f64> {
     if num < 2 {
         return vec![start];
@@ -202,3 +187,21 @@ pub fn linspace(start: f64, end: f64, num: usize) -> Vec<f64> {
 
     result
 }
+
+/// Parses a [DoubleDouble](crate::DoubleDouble) from a decimal string.
+pub fn dd(s: &str) -> crate::DoubleDouble {
+    s.parse().unwrap()
+}
+
+/// Asserts that a [DoubleDouble](crate::DoubleDouble) is within the relative tolerance of
+/// a decimal reference value.
+pub fn assert_close_dd(actual: crate::DoubleDouble, expected: &str, rtol: f64) {
+    let expected = dd(expected);
+    let relative = ((actual - expected) / expected).hi().abs();
+    if relative > rtol {
+        panic!(
+            "Assertion failed: expected = {}, got = {}, relative = {:e}, rtol = {:e}",
+            expected, actual, relative, rtol
+        )
+    }
+}